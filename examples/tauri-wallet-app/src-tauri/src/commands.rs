@@ -132,6 +132,68 @@ pub async fn validate_address(
     Ok(result.into())
 }
 
+/// Route a wallet call by method name, so new methods need no handler entry
+///
+/// `params` holds the same named arguments as the matching individual
+/// command, e.g. `{ "address": "..." }` for `validateAddress`.
+#[tauri::command]
+pub async fn wallet_command(
+    method: String,
+    params: serde_json::Value,
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<serde_json::Value>, String> {
+    let result = {
+        let wallet_manager = state.wallet_manager.lock()
+            .map_err(|e| AppError::Generic(format!("Failed to acquire wallet lock: {}", e)))?;
+        dispatch_wallet_command(&wallet_manager, &method, params).await
+    };
+
+    if let Err(e) = &result {
+        error!("Wallet command {} failed: {}", method, e);
+    }
+
+    Ok(result.into())
+}
+
+async fn dispatch_wallet_command(
+    wallet_manager: &WalletManager,
+    method: &str,
+    params: serde_json::Value,
+) -> Result<serde_json::Value> {
+    let value = match method {
+        "initialize" => {
+            let config: WalletConfig = param(&params, "config")?;
+            serde_json::to_value(wallet_manager.initialize(config).await?)?
+        }
+        "getBalance" => serde_json::to_value(wallet_manager.get_balance().await?)?,
+        "getAddress" => serde_json::to_value(wallet_manager.get_address().await?)?,
+        "sendTransaction" => {
+            let request: SendTransactionRequest = param(&params, "request")?;
+            info!("Sending transaction to: {}, amount: {}", request.recipient, request.amount);
+            serde_json::to_value(wallet_manager.send_transaction(request).await?)?
+        }
+        "getTransactions" => serde_json::to_value(wallet_manager.get_transactions().await?)?,
+        "getStatus" => serde_json::to_value(wallet_manager.get_status().await?)?,
+        "destroy" => serde_json::to_value(wallet_manager.destroy().await?)?,
+        "validateAddress" => {
+            let address: String = param(&params, "address")?;
+            serde_json::to_value(wallet_manager.validate_address(&address)?)?
+        }
+        _ => return Err(AppError::MethodNotFound(method.to_string())),
+    };
+
+    Ok(value)
+}
+
+/// Deserialize the named parameter of a dispatched command
+fn param<T: serde::de::DeserializeOwned>(params: &serde_json::Value, name: &str) -> Result<T> {
+    let value = params
+        .get(name)
+        .cloned()
+        .ok_or_else(|| AppError::Validation(format!("Missing parameter: {}", name)))?;
+    Ok(serde_json::from_value(value)?)
+}
+
 // ============================================================================
 // Storage Commands
 // ============================================================================
@@ -19,6 +19,9 @@ pub enum AppError {
     #[error("Network error: {0}")]
     Network(String),
     
+    #[error("Method not found: {0}")]
+    MethodNotFound(String),
+    
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     
@@ -48,6 +51,7 @@ impl From<AppError> for ErrorResponse {
             AppError::Ffi(_) => ("FFI_ERROR", error.to_string()),
             AppError::Validation(_) => ("VALIDATION_ERROR", error.to_string()),
             AppError::Network(_) => ("NETWORK_ERROR", error.to_string()),
+            AppError::MethodNotFound(_) => ("METHOD_NOT_FOUND", error.to_string()),
             AppError::Io(_) => ("IO_ERROR", error.to_string()),
             AppError::Serialization(_) => ("SERIALIZATION_ERROR", error.to_string()),
             AppError::Generic(_) => ("GENERIC_ERROR", error.to_string()),
@@ -53,6 +53,7 @@ fn main() {
             wallet_get_status,
             wallet_destroy,
             validate_address,
+            wallet_command,
             // Storage commands
             secure_storage_store,
             secure_storage_retrieve,
@@ -8,6 +8,8 @@ import type {
   StorageInfo,
   PlatformInfo,
   ApiResponse,
+  WalletCommands,
+  WalletMethod,
   NetworkType,
   NETWORKS
 } from '../types/wallet';
@@ -135,6 +137,25 @@ export class TauriWalletService {
     return response.data!;
   }
 
+  /**
+   * Call a wallet method through the single `wallet_command` dispatcher
+   */
+  async command<M extends WalletMethod>(
+    method: M,
+    params: WalletCommands[M]['params']
+  ): Promise<WalletCommands[M]['result']> {
+    const response = await invoke<ApiResponse<WalletCommands[M]['result']>>('wallet_command', {
+      method,
+      params
+    });
+
+    if (!response.success) {
+      throw new Error(response.error?.error || `Wallet command ${method} failed`);
+    }
+
+    return response.data as WalletCommands[M]['result'];
+  }
+
   /**
    * Get storage information and metrics
    */
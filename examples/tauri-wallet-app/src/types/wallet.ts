@@ -76,6 +76,22 @@ export interface ApiResponse<T> {
   timestamp: number;
 }
 
+/**
+ * Methods routed by the `wallet_command` dispatcher, with their params and results
+ */
+export interface WalletCommands {
+  initialize: { params: { config: WalletConfig }; result: null };
+  getBalance: { params: Record<string, never>; result: Balance };
+  getAddress: { params: Record<string, never>; result: string };
+  sendTransaction: { params: { request: SendTransactionRequest }; result: string };
+  getTransactions: { params: Record<string, never>; result: TransactionInfo[] };
+  getStatus: { params: Record<string, never>; result: WalletStatus };
+  destroy: { params: Record<string, never>; result: null };
+  validateAddress: { params: { address: string }; result: boolean };
+}
+
+export type WalletMethod = keyof WalletCommands;
+
 export interface WalletState {
   balance?: Balance;
   address?: string;
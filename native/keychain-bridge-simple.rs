@@ -30,7 +30,8 @@ pub fn set_item(item: KeychainItem) -> Result<()> {
   #[cfg(target_os = "macos")]
   {
     // Placeholder implementation - Security framework integration to be completed
-    println!("Setting keychain item: service={}, account={}", item.service, item.account);
+    let account = crate::namespaced_key(&item.account);
+    println!("Setting keychain item: service={}, account={}", item.service, account);
     Ok(())
   }
   
@@ -49,6 +50,7 @@ pub fn get_item(_env: Env, service: String, account: String) -> Result<Option<Js
   #[cfg(target_os = "macos")]
   {
     // Placeholder implementation
+    let account = crate::namespaced_key(&account);
     println!("Getting keychain item: service={}, account={}", service, account);
     Ok(None)
   }
@@ -68,6 +70,7 @@ pub fn delete_item(service: String, account: String) -> Result<()> {
   #[cfg(target_os = "macos")]
   {
     // Placeholder implementation
+    let account = crate::namespaced_key(&account);
     println!("Deleting keychain item: service={}, account={}", service, account);
     Ok(())
   }
@@ -87,6 +90,7 @@ pub fn item_exists(service: String, account: String) -> Result<bool> {
   #[cfg(target_os = "macos")]
   {
     // Placeholder implementation
+    let account = crate::namespaced_key(&account);
     println!("Checking keychain item: service={}, account={}", service, account);
     Ok(false)
   }
@@ -103,6 +107,7 @@ pub fn get_item_info(service: String, account: String) -> Result<Option<Keychain
   #[cfg(target_os = "macos")]
   {
     // Placeholder implementation
+    let account = crate::namespaced_key(&account);
     println!("Getting keychain item info: service={}, account={}", service, account);
     Ok(None)
   }
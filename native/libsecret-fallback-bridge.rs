@@ -331,7 +331,7 @@ pub fn store_secret(
 
     let mut attributes = HashMap::new();
     attributes.insert("service".to_string(), service);
-    attributes.insert("account".to_string(), account);
+    attributes.insert("account".to_string(), crate::namespaced_key(&account));
     attributes.insert("application".to_string(), "tari-wallet".to_string());
 
     service_guard.store_secret_internal(
@@ -357,7 +357,7 @@ pub fn retrieve_secret(
 
     let mut attributes = HashMap::new();
     attributes.insert("service".to_string(), service);
-    attributes.insert("account".to_string(), account);
+    attributes.insert("account".to_string(), crate::namespaced_key(&account));
     attributes.insert("application".to_string(), "tari-wallet".to_string());
 
     match service_guard.retrieve_secret_internal(&schema_name, &attributes) {
@@ -382,7 +382,7 @@ pub fn delete_secret(
 
     let mut attributes = HashMap::new();
     attributes.insert("service".to_string(), service);
-    attributes.insert("account".to_string(), account);
+    attributes.insert("account".to_string(), crate::namespaced_key(&account));
     attributes.insert("application".to_string(), "tari-wallet".to_string());
 
     match service_guard.delete_secret_internal(&schema_name, &attributes) {
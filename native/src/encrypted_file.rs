@@ -0,0 +1,437 @@
+// @fileoverview Encrypted-file secure storage fallback
+//
+// Last-resort backend for machines without a platform secret store, such as
+// headless Linux servers and CI runners. Values are sealed with AES-256-GCM
+// under a key derived from a user passphrase with Argon2id and kept in a
+// single JSON file. Anyone holding both the file and the passphrase can read
+// every value, so this backend is reported as less secure than the platform
+// stores and should only be used when `is_secure_storage_available` is false.
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::Argon2;
+use napi::bindgen_prelude::Buffer;
+use napi::Result;
+use napi_derive::napi;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use zeroize::Zeroizing;
+
+use crate::{namespaced_key, strip_namespace};
+
+/// Backend name reported in metadata
+pub const ENCRYPTED_FILE_BACKEND: &str = "encrypted-file";
+
+/// Bumped whenever the file layout changes
+const FILE_VERSION: u32 = 1;
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const KEY_LENGTH: usize = 32;
+
+/// Sealed on creation so a wrong passphrase is detected when opening
+const PASSPHRASE_CHECK: &[u8] = b"tari-encrypted-file-storage";
+
+/// Error types for encrypted-file storage operations
+#[derive(Debug)]
+pub enum EncryptedFileError {
+    NotInitialized,
+    InvalidPassphrase,
+    Io(String),
+    Corrupt(String),
+    Crypto(String),
+}
+
+impl From<EncryptedFileError> for napi::Error {
+    fn from(err: EncryptedFileError) -> Self {
+        match err {
+            EncryptedFileError::NotInitialized => napi::Error::new(
+                napi::Status::GenericFailure,
+                "Encrypted file storage has not been initialized",
+            ),
+            EncryptedFileError::InvalidPassphrase => napi::Error::new(
+                napi::Status::InvalidArg,
+                "Invalid passphrase for encrypted file storage",
+            ),
+            EncryptedFileError::Io(msg) => napi::Error::new(
+                napi::Status::GenericFailure,
+                format!("Storage file error: {}", msg),
+            ),
+            EncryptedFileError::Corrupt(msg) => napi::Error::new(
+                napi::Status::GenericFailure,
+                format!("Storage file is corrupt: {}", msg),
+            ),
+            EncryptedFileError::Crypto(msg) => napi::Error::new(
+                napi::Status::GenericFailure,
+                format!("Encryption failed: {}", msg),
+            ),
+        }
+    }
+}
+
+type FileResult<T> = std::result::Result<T, EncryptedFileError>;
+
+/// Metadata of a value in the encrypted file, readable without decrypting it
+#[napi(object)]
+pub struct EncryptedFileMetadata {
+    pub created: i64,
+    pub modified: i64,
+    pub size: u32,
+    pub backend: String,
+    /// Always false: the file is only as safe as its passphrase
+    pub secure: bool,
+}
+
+/// A value sealed under the file key, with the storage key as associated data
+#[derive(Serialize, Deserialize)]
+struct SealedValue {
+    nonce: Vec<u8>,
+    ciphertext: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    value: SealedValue,
+    created: i64,
+    modified: i64,
+    size: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StorageFile {
+    version: u32,
+    salt: Vec<u8>,
+    check: SealedValue,
+    entries: BTreeMap<String, Entry>,
+}
+
+/// An open encrypted storage file
+pub struct EncryptedFileStore {
+    path: PathBuf,
+    cipher: Aes256Gcm,
+    file: StorageFile,
+}
+
+impl EncryptedFileStore {
+    /// Open the file at `path`, creating it if it does not exist yet
+    pub fn open(path: &Path, passphrase: &str) -> FileResult<Self> {
+        if !path.exists() {
+            return Self::create(path, passphrase);
+        }
+
+        let contents = fs::read(path).map_err(|e| EncryptedFileError::Io(e.to_string()))?;
+        let file: StorageFile = serde_json::from_slice(&contents)
+            .map_err(|e| EncryptedFileError::Corrupt(e.to_string()))?;
+        if file.version != FILE_VERSION {
+            return Err(EncryptedFileError::Corrupt(format!(
+                "unsupported version {}",
+                file.version
+            )));
+        }
+
+        let cipher = derive_cipher(passphrase, &file.salt)?;
+        let check = open_value(&cipher, "", &file.check)
+            .map_err(|_| EncryptedFileError::InvalidPassphrase)?;
+        if check != PASSPHRASE_CHECK {
+            return Err(EncryptedFileError::InvalidPassphrase);
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            cipher,
+            file,
+        })
+    }
+
+    fn create(path: &Path, passphrase: &str) -> FileResult<Self> {
+        let mut salt = vec![0u8; SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let cipher = derive_cipher(passphrase, &salt)?;
+        let check = seal_value(&cipher, "", PASSPHRASE_CHECK)?;
+
+        let store = Self {
+            path: path.to_path_buf(),
+            cipher,
+            file: StorageFile {
+                version: FILE_VERSION,
+                salt,
+                check,
+                entries: BTreeMap::new(),
+            },
+        };
+        store.save()?;
+        Ok(store)
+    }
+
+    /// Store a value, keeping the creation time of one it replaces
+    pub fn store(&mut self, key: &str, value: &[u8]) -> FileResult<()> {
+        self.store_batch(&[(key.to_string(), value)])
+    }
+
+    /// Store several values in a single write, so either all or none are kept
+    pub fn store_batch(&mut self, values: &[(String, &[u8])]) -> FileResult<()> {
+        let now = chrono::Utc::now().timestamp_millis();
+        let sealed = values
+            .iter()
+            .map(|(key, value)| {
+                let created = self
+                    .file
+                    .entries
+                    .get(key)
+                    .map_or(now, |entry| entry.created);
+                let entry = Entry {
+                    value: seal_value(&self.cipher, key, value)?,
+                    created,
+                    modified: now,
+                    size: value.len(),
+                };
+                Ok((key.clone(), entry))
+            })
+            .collect::<FileResult<Vec<_>>>()?;
+
+        let replaced: Vec<_> = sealed
+            .into_iter()
+            .map(|(key, entry)| {
+                let previous = self.file.entries.insert(key.clone(), entry);
+                (key, previous)
+            })
+            .collect();
+
+        self.save().map_err(|e| {
+            // Put back what the failed write replaced, newest first
+            for (key, previous) in replaced.into_iter().rev() {
+                match previous {
+                    Some(entry) => self.file.entries.insert(key, entry),
+                    None => self.file.entries.remove(&key),
+                };
+            }
+            e
+        })
+    }
+
+    pub fn retrieve(&self, key: &str) -> FileResult<Option<Vec<u8>>> {
+        self.file
+            .entries
+            .get(key)
+            .map(|entry| open_value(&self.cipher, key, &entry.value))
+            .transpose()
+    }
+
+    /// Remove a value, returning whether it existed
+    pub fn remove(&mut self, key: &str) -> FileResult<bool> {
+        if self.file.entries.remove(key).is_none() {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.file.entries.keys()
+    }
+
+    pub fn metadata(&self, key: &str) -> Option<EncryptedFileMetadata> {
+        self.file
+            .entries
+            .get(key)
+            .map(|entry| EncryptedFileMetadata {
+                created: entry.created,
+                modified: entry.modified,
+                size: entry.size as u32,
+                backend: ENCRYPTED_FILE_BACKEND.to_string(),
+                secure: false,
+            })
+    }
+
+    /// Write the file through a temporary file, so a crash cannot truncate it
+    fn save(&self) -> FileResult<()> {
+        let contents = serde_json::to_vec(&self.file)
+            .map_err(|e| EncryptedFileError::Corrupt(e.to_string()))?;
+        let temp_path = self.path.with_extension("tmp");
+
+        write_private(&temp_path, &contents)?;
+        fs::rename(&temp_path, &self.path).map_err(|e| EncryptedFileError::Io(e.to_string()))
+    }
+}
+
+fn derive_cipher(passphrase: &str, salt: &[u8]) -> FileResult<Aes256Gcm> {
+    let mut key = Zeroizing::new([0u8; KEY_LENGTH]);
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+        .map_err(|e| EncryptedFileError::Crypto(e.to_string()))?;
+
+    Aes256Gcm::new_from_slice(key.as_ref()).map_err(|e| EncryptedFileError::Crypto(e.to_string()))
+}
+
+fn seal_value(cipher: &Aes256Gcm, key: &str, value: &[u8]) -> FileResult<SealedValue> {
+    let mut nonce = vec![0u8; NONCE_LENGTH];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let payload = Payload {
+        msg: value,
+        aad: key.as_bytes(),
+    };
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), payload)
+        .map_err(|e| EncryptedFileError::Crypto(e.to_string()))?;
+
+    Ok(SealedValue { nonce, ciphertext })
+}
+
+fn open_value(cipher: &Aes256Gcm, key: &str, sealed: &SealedValue) -> FileResult<Vec<u8>> {
+    if sealed.nonce.len() != NONCE_LENGTH {
+        return Err(EncryptedFileError::Corrupt(
+            "invalid nonce length".to_string(),
+        ));
+    }
+
+    let payload = Payload {
+        msg: &sealed.ciphertext,
+        aad: key.as_bytes(),
+    };
+    cipher
+        .decrypt(Nonce::from_slice(&sealed.nonce), payload)
+        .map_err(|_| EncryptedFileError::Corrupt(format!("value for {} failed to decrypt", key)))
+}
+
+/// Write a file readable only by the current user
+fn write_private(path: &Path, contents: &[u8]) -> FileResult<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options
+        .open(path)
+        .map_err(|e| EncryptedFileError::Io(e.to_string()))?;
+    std::io::Write::write_all(&mut file, contents)
+        .and_then(|_| file.sync_all())
+        .map_err(|e| EncryptedFileError::Io(e.to_string()))
+}
+
+/// A key and value for `encrypted_file_store_batch`
+#[napi(object)]
+pub struct EncryptedFileEntry {
+    pub key: String,
+    pub value: Buffer,
+}
+
+/// A key and its value, if stored, from `encrypted_file_retrieve_batch`
+#[napi(object)]
+pub struct EncryptedFileLookup {
+    pub key: String,
+    pub value: Option<Buffer>,
+}
+
+// Global store, opened by `init_encrypted_file_storage`
+static STORE: Mutex<Option<EncryptedFileStore>> = Mutex::new(None);
+
+fn with_store<T>(operation: impl FnOnce(&mut EncryptedFileStore) -> FileResult<T>) -> Result<T> {
+    let mut store = STORE.lock().map_err(|_| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            "Failed to acquire encrypted file storage lock",
+        )
+    })?;
+    let store = store.as_mut().ok_or(EncryptedFileError::NotInitialized)?;
+    Ok(operation(store)?)
+}
+
+/// Run `operation` on the encrypted file if one has been opened
+pub(crate) fn with_open_store<T>(
+    operation: impl FnOnce(&mut EncryptedFileStore) -> FileResult<T>,
+) -> Option<FileResult<T>> {
+    STORE.lock().ok()?.as_mut().map(operation)
+}
+
+/// Open (or create) the encrypted storage file at `path` with `passphrase`
+#[napi]
+pub fn init_encrypted_file_storage(path: String, passphrase: String) -> Result<()> {
+    let passphrase = Zeroizing::new(passphrase);
+    if passphrase.is_empty() {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "Encrypted file storage requires a passphrase",
+        ));
+    }
+
+    let opened = EncryptedFileStore::open(Path::new(&path), &passphrase)?;
+    let mut store = STORE.lock().map_err(|_| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            "Failed to acquire encrypted file storage lock",
+        )
+    })?;
+    *store = Some(opened);
+    Ok(())
+}
+
+/// Store a value in the encrypted file
+#[napi]
+pub fn encrypted_file_store(key: String, data: Buffer) -> Result<()> {
+    with_store(|store| store.store(&namespaced_key(&key), &data))
+}
+
+/// Store several values in the encrypted file in one atomic write
+#[napi]
+pub fn encrypted_file_store_batch(entries: Vec<EncryptedFileEntry>) -> Result<()> {
+    let values: Vec<_> = entries
+        .iter()
+        .map(|entry| (namespaced_key(&entry.key), entry.value.as_ref()))
+        .collect();
+    with_store(|store| store.store_batch(&values))
+}
+
+/// Retrieve a value from the encrypted file
+#[napi]
+pub fn encrypted_file_retrieve(key: String) -> Result<Option<Buffer>> {
+    let value = with_store(|store| store.retrieve(&namespaced_key(&key)))?;
+    Ok(value.map(Buffer::from))
+}
+
+/// Retrieve several values from the encrypted file
+#[napi]
+pub fn encrypted_file_retrieve_batch(keys: Vec<String>) -> Result<Vec<EncryptedFileLookup>> {
+    with_store(|store| {
+        keys.into_iter()
+            .map(|key| {
+                let value = store.retrieve(&namespaced_key(&key))?;
+                Ok(EncryptedFileLookup {
+                    key,
+                    value: value.map(Buffer::from),
+                })
+            })
+            .collect()
+    })
+}
+
+/// Remove a value from the encrypted file, returning whether it existed
+#[napi]
+pub fn encrypted_file_remove(key: String) -> Result<bool> {
+    with_store(|store| store.remove(&namespaced_key(&key)))
+}
+
+/// List the keys in the encrypted file within the active namespace
+#[napi]
+pub fn encrypted_file_list() -> Result<Vec<String>> {
+    with_store(|store| {
+        Ok(store
+            .keys()
+            .filter_map(|key| strip_namespace(key))
+            .collect())
+    })
+}
+
+/// Get a value's metadata, which always reports the insecure fallback backend
+#[napi]
+pub fn encrypted_file_get_metadata(key: String) -> Result<Option<EncryptedFileMetadata>> {
+    with_store(|store| Ok(store.metadata(&namespaced_key(&key))))
+}
@@ -15,6 +15,10 @@ pub mod windows;
 #[cfg(target_os = "linux")]
 pub mod linux;
 
+// Encrypted-file fallback for machines without a platform store
+pub mod encrypted_file;
+pub use encrypted_file::*;
+
 // Tauri backend (optional)
 #[cfg(feature = "tauri-backend")]
 pub mod tauri;
@@ -46,6 +50,13 @@ pub use libsecret_fallback_bridge::*;
 
 use napi_derive::napi;
 use napi::{Result, Env};
+use std::sync::RwLock;
+
+/// Namespace prefixed to every storage key, so several wallets can share a machine
+static NAMESPACE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Separates the namespace from the key it prefixes
+const NAMESPACE_SEPARATOR: char = '/';
 
 /// Initialize the native secure storage module
 #[napi]
@@ -69,6 +80,48 @@ pub fn init_secure_storage(env: Env) -> Result<()> {
     Ok(())
 }
 
+/// Initialize secure storage with keys scoped to `namespace`
+///
+/// Stores, lookups, removals and listings only see keys within the
+/// namespace, so e.g. each wallet profile can keep its own secrets.
+#[napi]
+pub fn init_secure_storage_with_namespace(env: Env, namespace: String) -> Result<()> {
+    if namespace.is_empty() || namespace.contains(NAMESPACE_SEPARATOR) {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("Namespace must be non-empty and not contain '{}'", NAMESPACE_SEPARATOR)
+        ));
+    }
+
+    init_secure_storage(env)?;
+
+    let mut active = NAMESPACE.write().map_err(|_| napi::Error::new(
+        napi::Status::GenericFailure,
+        "Failed to acquire storage namespace lock"
+    ))?;
+    *active = Some(namespace);
+    Ok(())
+}
+
+/// Key under which `key` is stored in the active namespace
+pub(crate) fn namespaced_key(key: &str) -> String {
+    match NAMESPACE.read().ok().and_then(|namespace| namespace.clone()) {
+        Some(namespace) => format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, key),
+        None => key.to_string(),
+    }
+}
+
+/// Key as seen within the active namespace, or None if stored outside it
+pub(crate) fn strip_namespace(stored_key: &str) -> Option<String> {
+    match NAMESPACE.read().ok().and_then(|namespace| namespace.clone()) {
+        Some(namespace) => stored_key
+            .strip_prefix(namespace.as_str())
+            .and_then(|rest| rest.strip_prefix(NAMESPACE_SEPARATOR))
+            .map(str::to_string),
+        None => Some(stored_key.to_string()),
+    }
+}
+
 /// Get platform information
 #[napi]
 pub fn get_platform_info() -> Result<String> {
@@ -86,6 +139,9 @@ pub fn get_platform_info() -> Result<String> {
 }
 
 /// Check if secure storage is available on this platform
+///
+/// When it is not, `init_encrypted_file_storage` provides a less secure
+/// passphrase-protected fallback.
 #[napi]
 pub fn is_secure_storage_available() -> Result<bool> {
     #[cfg(target_os = "macos")]
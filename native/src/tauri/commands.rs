@@ -9,7 +9,9 @@ use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
 use tauri::State;
 
-use super::storage::{TauriSecureStorage, StorageResult, StorageMetadata, StorageInfo};
+use super::storage::{
+    TauriSecureStorage, StorageResult, StorageMetadata, StorageInfo, StorageEntry, BatchEntryResult,
+};
 
 /// Global storage instance (thread-safe)
 static STORAGE: OnceLock<TauriSecureStorage> = OnceLock::new();
@@ -69,6 +71,26 @@ pub async fn secure_storage_retrieve(
     Ok(result.into())
 }
 
+/// Store several values in one call, with a result per entry
+#[tauri::command]
+pub async fn secure_storage_store_batch(
+    entries: Vec<StorageEntry>,
+) -> Result<StorageResponse<Vec<BatchEntryResult>>, String> {
+    let storage = get_storage();
+    let result = storage.store_batch(entries).await;
+    Ok(result.into())
+}
+
+/// Retrieve several values in one call, with a result per key
+#[tauri::command]
+pub async fn secure_storage_retrieve_batch(
+    keys: Vec<String>,
+) -> Result<StorageResponse<Vec<BatchEntryResult>>, String> {
+    let storage = get_storage();
+    let result = storage.retrieve_batch(keys).await;
+    Ok(result.into())
+}
+
 /// Remove data securely
 #[tauri::command]
 pub async fn secure_storage_remove(
@@ -256,6 +278,8 @@ pub fn register_commands<R: tauri::Runtime>() -> impl Fn(tauri::Builder<R>) -> t
         builder.invoke_handler(tauri::generate_handler![
             secure_storage_store,
             secure_storage_retrieve,
+            secure_storage_store_batch,
+            secure_storage_retrieve_batch,
             secure_storage_remove,
             secure_storage_exists,
             secure_storage_list,
@@ -275,6 +299,8 @@ pub fn tauri_plugin() -> tauri::plugin::TauriPlugin<tauri::Wry> {
         .invoke_handler(tauri::generate_handler![
             secure_storage_store,
             secure_storage_retrieve,
+            secure_storage_store_batch,
+            secure_storage_retrieve_batch,
             secure_storage_remove,
             secure_storage_exists,
             secure_storage_list,
@@ -11,6 +11,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Mutex;
 
+use crate::encrypted_file::{with_open_store, ENCRYPTED_FILE_BACKEND};
+use crate::{namespaced_key, strip_namespace};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StorageResult<T> {
     pub success: bool,
@@ -18,12 +21,42 @@ pub struct StorageResult<T> {
     pub error: Option<String>,
 }
 
+/// Metadata recorded alongside each stored value, readable without decrypting it
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StorageMetadata {
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub modified_at: chrono::DateTime<chrono::Utc>,
+    /// Size of the stored value in bytes
     pub size: usize,
     pub encrypted: bool,
+    /// Backend holding the value, as named in `StorageInfo::backend_type`
+    pub backend: String,
+}
+
+/// Suffix of the entry holding a key's metadata, stored next to its value
+const METADATA_KEY_SUFFIX: &str = "::metadata";
+
+/// Backend name for values kept in the in-memory fallback store
+const FALLBACK_BACKEND: &str = "fallback";
+
+fn metadata_key(key: &str) -> String {
+    format!("{}{}", key, METADATA_KEY_SUFFIX)
+}
+
+/// A key and value written by `store_batch`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StorageEntry {
+    pub key: String,
+    pub value: Vec<u8>,
+}
+
+/// Outcome for one key of a batch operation
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchEntryResult {
+    pub key: String,
+    /// The retrieved value, for `retrieve_batch`
+    pub value: Option<Vec<u8>>,
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -60,15 +93,99 @@ impl TauriSecureStorage {
         }
     }
 
-    /// Store data securely
+    /// Store data securely, recording its metadata alongside it
+    ///
+    /// Overwriting a key keeps its original creation time.
     pub async fn store(&self, key: &str, value: &[u8]) -> StorageResult<()> {
+        let backend = match self.store_value(&namespaced_key(key), value).await {
+            StorageResult { success: true, data: Some(backend), .. } => backend,
+            result => return StorageResult {
+                success: false,
+                data: None,
+                error: result.error,
+            },
+        };
+
+        let now = chrono::Utc::now();
+        let created_at = self.read_metadata(key).await.map_or(now, |metadata| metadata.created_at);
+        let metadata = StorageMetadata {
+            created_at,
+            modified_at: now,
+            size: value.len(),
+            encrypted: backend != FALLBACK_BACKEND,
+            backend: backend.to_string(),
+        };
+
+        // The value is already stored, so a metadata failure is not fatal
+        match serde_json::to_vec(&metadata) {
+            Ok(serialized) => {
+                let result = self
+                    .store_value(&namespaced_key(&metadata_key(key)), &serialized)
+                    .await;
+                if !result.success {
+                    eprintln!("Failed to store metadata for {}: {:?}", key, result.error);
+                }
+            }
+            Err(e) => eprintln!("Failed to serialize metadata for {}: {}", key, e),
+        }
+
+        StorageResult {
+            success: true,
+            data: Some(()),
+            error: None,
+        }
+    }
+
+    /// Store several values, reporting the outcome of each
+    ///
+    /// Platform stores have no transactions, so entries are written one at
+    /// a time and a failed entry neither stops nor undoes the others.
+    pub async fn store_batch(&self, entries: Vec<StorageEntry>) -> StorageResult<Vec<BatchEntryResult>> {
+        let mut results = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let result = self.store(&entry.key, &entry.value).await;
+            results.push(BatchEntryResult {
+                key: entry.key,
+                value: None,
+                error: if result.success { None } else { result.error },
+            });
+        }
+
+        StorageResult {
+            success: true,
+            data: Some(results),
+            error: None,
+        }
+    }
+
+    /// Retrieve several values, reporting the outcome of each
+    pub async fn retrieve_batch(&self, keys: Vec<String>) -> StorageResult<Vec<BatchEntryResult>> {
+        let mut results = Vec::with_capacity(keys.len());
+        for key in keys {
+            let result = self.retrieve(&key).await;
+            results.push(BatchEntryResult {
+                key,
+                value: result.data,
+                error: result.error,
+            });
+        }
+
+        StorageResult {
+            success: true,
+            data: Some(results),
+            error: None,
+        }
+    }
+
+    /// Store a raw value, returning the name of the backend that took it
+    async fn store_value(&self, key: &str, value: &[u8]) -> StorageResult<&'static str> {
         // Try platform-specific secure storage first
         #[cfg(target_os = "macos")]
         if let Some(ref keychain) = self.keychain {
             match keychain.store(key, value).await {
                 Ok(_) => return StorageResult {
                     success: true,
-                    data: Some(()),
+                    data: Some("keychain"),
                     error: None,
                 },
                 Err(e) => {
@@ -82,7 +199,7 @@ impl TauriSecureStorage {
             match credential_store.store(key, value).await {
                 Ok(_) => return StorageResult {
                     success: true,
-                    data: Some(()),
+                    data: Some("credential-store"),
                     error: None,
                 },
                 Err(e) => {
@@ -96,7 +213,7 @@ impl TauriSecureStorage {
             match secret_service.store(key, value).await {
                 Ok(_) => return StorageResult {
                     success: true,
-                    data: Some(()),
+                    data: Some("secret-service"),
                     error: None,
                 },
                 Err(e) => {
@@ -105,6 +222,16 @@ impl TauriSecureStorage {
             }
         }
 
+        match with_open_store(|store| store.store(key, value)) {
+            Some(Ok(())) => return StorageResult {
+                success: true,
+                data: Some(ENCRYPTED_FILE_BACKEND),
+                error: None,
+            },
+            Some(Err(e)) => eprintln!("Encrypted file storage failed: {:?}", e),
+            None => {}
+        }
+
         // Fallback to encrypted in-memory storage
         match self.fallback_store.lock() {
             Ok(mut store) => {
@@ -112,7 +239,7 @@ impl TauriSecureStorage {
                 store.insert(key.to_string(), value.to_vec());
                 StorageResult {
                     success: true,
-                    data: Some(()),
+                    data: Some(FALLBACK_BACKEND),
                     error: None,
                 }
             }
@@ -126,6 +253,8 @@ impl TauriSecureStorage {
 
     /// Retrieve data securely
     pub async fn retrieve(&self, key: &str) -> StorageResult<Vec<u8>> {
+        let key = &namespaced_key(key);
+
         // Try platform-specific secure storage first
         #[cfg(target_os = "macos")]
         if let Some(ref keychain) = self.keychain {
@@ -169,6 +298,16 @@ impl TauriSecureStorage {
             }
         }
 
+        match with_open_store(|store| store.retrieve(key)) {
+            Some(Ok(Some(data))) => return StorageResult {
+                success: true,
+                data: Some(data),
+                error: None,
+            },
+            Some(Err(e)) => eprintln!("Encrypted file retrieval failed: {:?}", e),
+            _ => {}
+        }
+
         // Fallback to in-memory storage
         match self.fallback_store.lock() {
             Ok(store) => {
@@ -194,8 +333,13 @@ impl TauriSecureStorage {
         }
     }
 
-    /// Remove data securely
+    /// Remove data securely, along with its metadata
     pub async fn remove(&self, key: &str) -> StorageResult<()> {
+        let _ = self.remove_value(&namespaced_key(&metadata_key(key))).await;
+        self.remove_value(&namespaced_key(key)).await
+    }
+
+    async fn remove_value(&self, key: &str) -> StorageResult<()> {
         let mut errors = Vec::new();
         let mut success = false;
 
@@ -224,6 +368,12 @@ impl TauriSecureStorage {
             }
         }
 
+        match with_open_store(|store| store.remove(key)) {
+            Some(Ok(_)) => success = true,
+            Some(Err(e)) => errors.push(format!("Encrypted file: {:?}", e)),
+            None => {}
+        }
+
         // Also remove from fallback storage
         if let Ok(mut store) = self.fallback_store.lock() {
             store.remove(key);
@@ -247,6 +397,8 @@ impl TauriSecureStorage {
 
     /// Check if key exists
     pub async fn exists(&self, key: &str) -> StorageResult<bool> {
+        let key = &namespaced_key(key);
+
         // Check platform-specific storage first
         #[cfg(target_os = "macos")]
         if let Some(ref keychain) = self.keychain {
@@ -287,6 +439,14 @@ impl TauriSecureStorage {
             }
         }
 
+        if let Some(Ok(Some(_))) = with_open_store(|store| Ok(store.metadata(key))) {
+            return StorageResult {
+                success: true,
+                data: Some(true),
+                error: None,
+            };
+        }
+
         // Check fallback storage
         if let Ok(store) = self.fallback_store.lock() {
             StorageResult {
@@ -303,7 +463,7 @@ impl TauriSecureStorage {
         }
     }
 
-    /// List all keys
+    /// List all keys in the active namespace
     pub async fn list(&self) -> StorageResult<Vec<String>> {
         let mut keys = std::collections::HashSet::new();
 
@@ -329,6 +489,10 @@ impl TauriSecureStorage {
             }
         }
 
+        if let Some(Ok(file_keys)) = with_open_store(|store| Ok(store.keys().cloned().collect::<Vec<_>>())) {
+            keys.extend(file_keys);
+        }
+
         // Add fallback storage keys
         if let Ok(store) = self.fallback_store.lock() {
             keys.extend(store.keys().cloned());
@@ -336,47 +500,54 @@ impl TauriSecureStorage {
 
         StorageResult {
             success: true,
-            data: Some(keys.into_iter().collect()),
+            data: Some(
+                keys.iter()
+                    .filter_map(|key| strip_namespace(key))
+                    .filter(|key| !key.ends_with(METADATA_KEY_SUFFIX))
+                    .collect(),
+            ),
             error: None,
         }
     }
 
-    /// Get storage metadata
+    /// Get the metadata recorded when a key was last stored
     pub async fn get_metadata(&self, key: &str) -> StorageResult<StorageMetadata> {
-        // For now, return basic metadata
-        // In production, this should be stored alongside the data
-        let now = chrono::Utc::now();
-        
-        if self.exists(key).await.data.unwrap_or(false) {
-            StorageResult {
+        if let Some(metadata) = self.read_metadata(key).await {
+            return StorageResult {
                 success: true,
-                data: Some(StorageMetadata {
-                    created_at: now,
-                    modified_at: now,
-                    size: 0, // Would need to be tracked separately
-                    encrypted: true,
-                }),
+                data: Some(metadata),
                 error: None,
-            }
+            };
+        }
+
+        let error = if self.exists(key).await.data.unwrap_or(false) {
+            // Stored before metadata was tracked
+            "No metadata recorded for key"
         } else {
-            StorageResult {
-                success: false,
-                data: None,
-                error: Some("Key not found".to_string()),
-            }
+            "Key not found"
+        };
+        StorageResult {
+            success: false,
+            data: None,
+            error: Some(error.to_string()),
         }
     }
 
-    /// Clear all data
+    async fn read_metadata(&self, key: &str) -> Option<StorageMetadata> {
+        let serialized = self.retrieve(&metadata_key(key)).await.data?;
+        serde_json::from_slice(&serialized).ok()
+    }
+
+    /// Clear all data in the active namespace
     pub async fn clear(&self) -> StorageResult<()> {
         let mut success = false;
 
         // Clear platform-specific storage (be careful with this!)
         // In production, this should only clear Tari-specific entries
 
-        // Clear fallback storage
+        // Clear fallback storage, leaving other namespaces alone
         if let Ok(mut store) = self.fallback_store.lock() {
-            store.clear();
+            store.retain(|key, _| strip_namespace(key).is_none());
             success = true;
         }
 
@@ -427,6 +598,12 @@ impl TauriSecureStorage {
             limitations.push("Requires D-Bus".to_string());
         }
 
+        if !available && with_open_store(|_| Ok(())).is_some() {
+            backend_type = ENCRYPTED_FILE_BACKEND.to_string();
+            available = true;
+            limitations.push("Only as secure as its passphrase".to_string());
+        }
+
         StorageResult {
             success: true,
             data: Some(StorageInfo {
@@ -2,7 +2,8 @@
 // This handles NAPI-RS code generation and Tari FFI integration
 
 use std::env;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 fn main() {
     // NAPI-RS build setup
@@ -96,6 +97,9 @@ fn main() {
     println!("cargo:rustc-env=NAPI_FFI_VERSION=0.0.1");
     println!("cargo:rustc-env=BUILD_PROFILE={}", profile);
     println!("cargo:rustc-env=BUILD_TARGET={}", target);
+    println!("cargo:rustc-env=TARI_CORE_VERSION={}", tari_version);
+    println!("cargo:rustc-env=BUILD_NETWORK={}", network_type);
+    println!("cargo:rustc-env=GIT_COMMIT={}", git_commit());
     
     // Generate build info
     let build_info = format!(
@@ -107,6 +111,33 @@ fn main() {
     println!("cargo:rustc-env=BUILD_INFO={}", build_info);
 }
 
+/// Short hash of the commit being built, or "unknown" outside a git checkout
+fn git_commit() -> String {
+    // HEAD itself only changes when switching branches; a new commit moves
+    // the branch ref it points to, which may live in packed-refs
+    let git_dir = Path::new("../../.git");
+    println!("cargo:rerun-if-changed={}", git_dir.join("HEAD").display());
+    let branch_ref = fs::read_to_string(git_dir.join("HEAD"))
+        .ok()
+        .and_then(|head| head.strip_prefix("ref: ").map(|name| git_dir.join(name.trim())));
+    for path in branch_ref.into_iter().chain([git_dir.join("packed-refs")]) {
+        // A missing file would make cargo rerun the script on every build
+        if path.exists() {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+    }
+
+    std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
 /// Resolve the Tari source path based on version and network
 fn resolve_tari_source_path(version: &str, network: &str) -> String {
     // Check for explicit TARI_SOURCE_PATH first
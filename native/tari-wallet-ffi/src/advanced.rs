@@ -0,0 +1,1356 @@
+/**
+ * Advanced transaction features for the Tari wallet FFI
+ *
+ * Covenants are built from a JavaScript filter specification and kept
+ * behind opaque handles until they are attached to a transaction output.
+ * TariScripts are compiled from their textual opcode form and can be
+ * executed locally to test spending conditions before they are used.
+ */
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Mutex;
+use tari_crypto::ristretto::{RistrettoPublicKey, RistrettoSchnorr};
+use tari_crypto::tari_utilities::hex::{self, Hex};
+use tari_crypto::tari_utilities::ByteArray;
+
+use crate::crypto::{parse_signature, signature_to_hex};
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+
+/// Opaque handle to a compiled covenant
+pub type CovenantHandle = i64;
+
+/// Opaque handle to a compiled TariScript
+pub type ScriptHandle = i64;
+
+/// Maximum nesting depth accepted for covenant filter specifications
+const MAX_COVENANT_DEPTH: usize = 32;
+
+/// Maximum number of items allowed on the script execution stack
+const MAX_STACK_SIZE: usize = 255;
+
+/// Maximum number of public keys in a multisig condition
+const MAX_MULTISIG_KEYS: usize = 32;
+
+static COVENANTS: Lazy<Mutex<HashMap<CovenantHandle, CovenantFilter>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static SCRIPTS: Lazy<Mutex<HashMap<ScriptHandle, TariScript>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_ADVANCED_HANDLE: AtomicI64 = AtomicI64::new(1);
+
+fn generate_advanced_handle() -> i64 {
+    NEXT_ADVANCED_HANDLE.fetch_add(1, Ordering::SeqCst)
+}
+
+fn lock_error() -> TariWalletError {
+    TariWalletError::new(WalletErrorCode::ThreadingError, "Failed to acquire advanced store lock")
+        .component("advanced")
+}
+
+fn invalid_spec(message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(WalletErrorCode::SchemaValidationFailed, message).component("covenant")
+}
+
+/// Covenant token byte codes, as in tari_core's `covenants::byte_codes`
+mod covenant_codes {
+    pub const FILTER_IDENTITY: u8 = 0x20;
+    pub const FILTER_AND: u8 = 0x21;
+    pub const FILTER_OR: u8 = 0x22;
+    pub const FILTER_OUTPUT_HASH_EQ: u8 = 0x30;
+    pub const FILTER_FIELD_EQ: u8 = 0x33;
+
+    pub const ARG_HASH: u8 = 0x01;
+    pub const ARG_PUBLIC_KEY: u8 = 0x02;
+    pub const ARG_COMMITMENT: u8 = 0x03;
+    pub const ARG_UINT: u8 = 0x06;
+    pub const ARG_OUTPUT_FIELD: u8 = 0x07;
+    pub const ARG_OUTPUT_TYPE: u8 = 0x0a;
+}
+
+/// Output fields that can be compared by a `field_eq` filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputField {
+    Commitment,
+    SenderOffsetPublicKey,
+    FeaturesOutputType,
+    FeaturesMaturity,
+}
+
+impl OutputField {
+    fn parse(name: &str) -> TariResult<Self> {
+        match name {
+            "commitment" => Ok(OutputField::Commitment),
+            "sender_offset_public_key" => Ok(OutputField::SenderOffsetPublicKey),
+            "features_output_type" => Ok(OutputField::FeaturesOutputType),
+            "features_maturity" => Ok(OutputField::FeaturesMaturity),
+            other => Err(invalid_spec(format!("Unknown output field: {}", other))),
+        }
+    }
+
+    fn code(&self) -> u8 {
+        match self {
+            OutputField::Commitment => 0x00,
+            OutputField::SenderOffsetPublicKey => 0x02,
+            OutputField::FeaturesOutputType => 0x05,
+            OutputField::FeaturesMaturity => 0x06,
+        }
+    }
+}
+
+/// Value a `field_eq` filter compares an output field against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CovenantArg {
+    PublicKey([u8; 32]),
+    Commitment([u8; 32]),
+    OutputType(u8),
+    Uint(u64),
+}
+
+/// Parsed covenant filter tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CovenantFilter {
+    Identity,
+    And(Box<CovenantFilter>, Box<CovenantFilter>),
+    Or(Box<CovenantFilter>, Box<CovenantFilter>),
+    OutputHashEq([u8; 32]),
+    FieldEq(OutputField, CovenantArg),
+}
+
+impl CovenantFilter {
+    /// Parse a covenant filter from a JavaScript specification object
+    pub fn from_spec(spec: &Value) -> TariResult<Self> {
+        Self::parse(spec, 0)
+    }
+
+    fn parse(spec: &Value, depth: usize) -> TariResult<Self> {
+        if depth > MAX_COVENANT_DEPTH {
+            return Err(invalid_spec(format!(
+                "Covenant nesting exceeds maximum depth of {}",
+                MAX_COVENANT_DEPTH
+            )));
+        }
+
+        let filter_type = spec
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid_spec("Covenant filter requires a string 'type' field"))?;
+
+        match filter_type {
+            "identity" => Ok(CovenantFilter::Identity),
+            "and" | "or" => {
+                let filters = spec
+                    .get("filters")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| invalid_spec(format!("'{}' filter requires a 'filters' array", filter_type)))?;
+
+                if filters.len() < 2 {
+                    return Err(invalid_spec(format!(
+                        "'{}' filter requires at least two filters",
+                        filter_type
+                    )));
+                }
+
+                let mut parsed = filters
+                    .iter()
+                    .map(|f| Self::parse(f, depth + 1))
+                    .collect::<TariResult<Vec<_>>>()?
+                    .into_iter();
+
+                let first = parsed.next().expect("length checked above");
+                Ok(parsed.fold(first, |left, right| {
+                    if filter_type == "and" {
+                        CovenantFilter::And(Box::new(left), Box::new(right))
+                    } else {
+                        CovenantFilter::Or(Box::new(left), Box::new(right))
+                    }
+                }))
+            }
+            "output_hash_eq" => {
+                let value = spec
+                    .get("value")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid_spec("'output_hash_eq' filter requires a hex 'value'"))?;
+                Ok(CovenantFilter::OutputHashEq(parse_hex_32(value, "output hash")?))
+            }
+            "field_eq" => {
+                let field_name = spec
+                    .get("field")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid_spec("'field_eq' filter requires a 'field' name"))?;
+                let field = OutputField::parse(field_name)?;
+                let value = spec
+                    .get("value")
+                    .ok_or_else(|| invalid_spec("'field_eq' filter requires a 'value'"))?;
+                Ok(CovenantFilter::FieldEq(field, parse_field_value(field, value)?))
+            }
+            other => Err(invalid_spec(format!(
+                "Unknown covenant filter type: '{}' (expected identity, and, or, field_eq or output_hash_eq)",
+                other
+            ))),
+        }
+    }
+
+    /// Serialize to the Tari covenant token byte encoding
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_bytes(&mut bytes);
+        bytes
+    }
+
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        use covenant_codes::*;
+
+        match self {
+            CovenantFilter::Identity => out.push(FILTER_IDENTITY),
+            CovenantFilter::And(left, right) => {
+                out.push(FILTER_AND);
+                left.write_bytes(out);
+                right.write_bytes(out);
+            }
+            CovenantFilter::Or(left, right) => {
+                out.push(FILTER_OR);
+                left.write_bytes(out);
+                right.write_bytes(out);
+            }
+            CovenantFilter::OutputHashEq(hash) => {
+                out.push(FILTER_OUTPUT_HASH_EQ);
+                out.push(ARG_HASH);
+                out.extend_from_slice(hash);
+            }
+            CovenantFilter::FieldEq(field, arg) => {
+                out.push(FILTER_FIELD_EQ);
+                out.push(ARG_OUTPUT_FIELD);
+                out.push(field.code());
+                match arg {
+                    CovenantArg::PublicKey(key) => {
+                        out.push(ARG_PUBLIC_KEY);
+                        out.extend_from_slice(key);
+                    }
+                    CovenantArg::Commitment(commitment) => {
+                        out.push(ARG_COMMITMENT);
+                        out.extend_from_slice(commitment);
+                    }
+                    CovenantArg::OutputType(output_type) => {
+                        out.push(ARG_OUTPUT_TYPE);
+                        out.push(*output_type);
+                    }
+                    CovenantArg::Uint(value) => {
+                        out.push(ARG_UINT);
+                        write_varint(*value, out);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn parse_hex_32(value: &str, what: &str) -> TariResult<[u8; 32]> {
+    let bytes = hex::from_hex(value)
+        .map_err(|_| invalid_spec(format!("Invalid hex for {}: {}", what, value)))?;
+    bytes
+        .try_into()
+        .map_err(|_| invalid_spec(format!("{} must be 32 bytes", what)))
+}
+
+fn parse_field_value(field: OutputField, value: &Value) -> TariResult<CovenantArg> {
+    match field {
+        OutputField::Commitment => {
+            let hex_value = value
+                .as_str()
+                .ok_or_else(|| invalid_spec("commitment value must be a hex string"))?;
+            Ok(CovenantArg::Commitment(parse_hex_32(hex_value, "commitment")?))
+        }
+        OutputField::SenderOffsetPublicKey => {
+            let hex_value = value
+                .as_str()
+                .ok_or_else(|| invalid_spec("sender_offset_public_key value must be a hex string"))?;
+            Ok(CovenantArg::PublicKey(parse_hex_32(hex_value, "public key")?))
+        }
+        OutputField::FeaturesOutputType => {
+            let output_type = match value {
+                Value::String(name) => match name.as_str() {
+                    "standard" => 0,
+                    "coinbase" => 1,
+                    "burn" => 2,
+                    "validator_node_registration" => 3,
+                    "code_template_registration" => 4,
+                    other => return Err(invalid_spec(format!("Unknown output type: {}", other))),
+                },
+                Value::Number(n) => n
+                    .as_u64()
+                    .filter(|n| *n <= u8::MAX as u64)
+                    .ok_or_else(|| invalid_spec("output type must be between 0 and 255"))?
+                    as u8,
+                _ => return Err(invalid_spec("output type must be a name or number")),
+            };
+            Ok(CovenantArg::OutputType(output_type))
+        }
+        OutputField::FeaturesMaturity => {
+            let maturity = match value {
+                Value::Number(n) => n.as_u64(),
+                Value::String(s) => s.parse::<u64>().ok(),
+                _ => None,
+            }
+            .ok_or_else(|| invalid_spec("maturity must be a non-negative integer"))?;
+            Ok(CovenantArg::Uint(maturity))
+        }
+    }
+}
+
+/// LEB128 variable-length integer encoding used by Tari consensus encoding
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Look up a covenant by handle
+pub(crate) fn get_covenant(handle: CovenantHandle) -> TariResult<CovenantFilter> {
+    COVENANTS
+        .lock()
+        .map_err(|_| lock_error())?
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| {
+            TariWalletError::new(
+                WalletErrorCode::InvalidHandle,
+                format!("Invalid covenant handle: {}", handle),
+            )
+            .component("covenant")
+        })
+}
+
+/// Create a covenant from a filter specification
+///
+/// Supported filter types are `identity`, `and`, `or`, `field_eq` and
+/// `output_hash_eq`, e.g. `{ type: "output_hash_eq", value: "<hex>" }`.
+#[napi]
+pub fn create_covenant(spec: Value) -> Result<CovenantHandle> {
+    let covenant = CovenantFilter::from_spec(&spec)?;
+
+    let handle = generate_advanced_handle();
+    COVENANTS
+        .lock()
+        .map_err(|_| lock_error())?
+        .insert(handle, covenant);
+
+    Ok(handle)
+}
+
+/// Get the serialized covenant as hex, ready to attach to an output
+#[napi]
+pub fn covenant_to_hex(handle: CovenantHandle) -> Result<String> {
+    Ok(hex::to_hex(&get_covenant(handle)?.to_bytes()))
+}
+
+/// Destroy a covenant handle
+#[napi]
+pub fn covenant_destroy(handle: CovenantHandle) -> Result<()> {
+    let removed = COVENANTS
+        .lock()
+        .map_err(|_| lock_error())?
+        .remove(&handle);
+
+    match removed {
+        Some(_) => Ok(()),
+        None => Err(napi::Error::new(
+            Status::InvalidArg,
+            format!("Invalid covenant handle: {}", handle),
+        )),
+    }
+}
+
+/// TariScript opcodes supported by the local script VM
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Opcode {
+    CheckHeightVerify(u64),
+    CheckHeight(u64),
+    CompareHeightVerify,
+    CompareHeight,
+    Nop,
+    PushZero,
+    PushOne,
+    PushInt(i64),
+    PushPubKey(Box<RistrettoPublicKey>),
+    Drop,
+    Dup,
+    Equal,
+    EqualVerify,
+    CheckSig(Box<[u8; 32]>),
+    CheckSigVerify(Box<[u8; 32]>),
+    CheckMultiSig(Box<MultiSig>),
+    CheckMultiSigVerify(Box<MultiSig>),
+    Return,
+}
+
+/// An m-of-n signature condition over a fixed message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSig {
+    threshold: u8,
+    public_keys: Vec<RistrettoPublicKey>,
+    message: [u8; 32],
+}
+
+impl MultiSig {
+    fn new(threshold: usize, public_keys: Vec<RistrettoPublicKey>, message: [u8; 32]) -> TariResult<Self> {
+        if threshold == 0 {
+            return Err(invalid_script("Multisig threshold must be greater than zero"));
+        }
+        if public_keys.len() > MAX_MULTISIG_KEYS {
+            return Err(invalid_script(format!(
+                "Multisig supports at most {} public keys",
+                MAX_MULTISIG_KEYS
+            )));
+        }
+        if threshold > public_keys.len() {
+            return Err(invalid_script(format!(
+                "Multisig threshold {} exceeds key count {}",
+                threshold,
+                public_keys.len()
+            )));
+        }
+
+        Ok(Self {
+            threshold: threshold as u8,
+            public_keys,
+            message,
+        })
+    }
+
+    /// Pop `threshold` signatures and check each one against a distinct key
+    fn check(&self, stack: &mut Vec<StackItem>) -> std::result::Result<bool, String> {
+        let mut signatures = Vec::with_capacity(self.threshold as usize);
+        for _ in 0..self.threshold {
+            match pop(stack)? {
+                StackItem::Signature(sig) => signatures.push(sig),
+                other => return Err(format!("Expected a signature on the stack, found {}", other)),
+            }
+        }
+
+        let mut used = vec![false; self.public_keys.len()];
+        for signature in &signatures {
+            let matched = self
+                .public_keys
+                .iter()
+                .enumerate()
+                .find(|(i, key)| !used[*i] && signature.verify(key, self.message));
+            match matched {
+                Some((i, _)) => used[i] = true,
+                None => return Ok(false),
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl Opcode {
+    /// Opcode byte values, matching the Tari script encoding
+    fn code(&self) -> u8 {
+        match self {
+            Opcode::Return => 0x60,
+            Opcode::CheckHeightVerify(_) => 0x66,
+            Opcode::CheckHeight(_) => 0x67,
+            Opcode::CompareHeightVerify => 0x68,
+            Opcode::CompareHeight => 0x69,
+            Opcode::Drop => 0x70,
+            Opcode::Dup => 0x71,
+            Opcode::Nop => 0x73,
+            Opcode::PushZero => 0x7a,
+            Opcode::PushOne => 0x7b,
+            Opcode::PushInt(_) => 0x7d,
+            Opcode::PushPubKey(_) => 0x7e,
+            Opcode::Equal => 0x95,
+            Opcode::EqualVerify => 0x96,
+            Opcode::CheckSig(_) => 0xac,
+            Opcode::CheckSigVerify(_) => 0xad,
+            Opcode::CheckMultiSig(_) => 0xae,
+            Opcode::CheckMultiSigVerify(_) => 0xaf,
+        }
+    }
+
+    fn parse(name: &str, args: &[&str]) -> TariResult<Self> {
+        let expect_args = |count: usize| -> TariResult<()> {
+            if args.len() != count {
+                return Err(invalid_script(format!(
+                    "{} expects {} argument(s), got {}",
+                    name,
+                    count,
+                    args.len()
+                )));
+            }
+            Ok(())
+        };
+        let parse_u64 = |value: &str| {
+            value
+                .parse::<u64>()
+                .map_err(|_| invalid_script(format!("{}: invalid height '{}'", name, value)))
+        };
+        let parse_message = |value: &str| -> TariResult<Box<[u8; 32]>> {
+            let bytes = hex::from_hex(value)
+                .map_err(|_| invalid_script(format!("{}: invalid message hex", name)))?;
+            let message: [u8; 32] = bytes
+                .try_into()
+                .map_err(|_| invalid_script(format!("{}: message must be 32 bytes", name)))?;
+            Ok(Box::new(message))
+        };
+
+        let opcode = match name {
+            "CheckHeightVerify" => {
+                expect_args(1)?;
+                Opcode::CheckHeightVerify(parse_u64(args[0])?)
+            }
+            "CheckHeight" => {
+                expect_args(1)?;
+                Opcode::CheckHeight(parse_u64(args[0])?)
+            }
+            "PushInt" => {
+                expect_args(1)?;
+                Opcode::PushInt(args[0].parse::<i64>().map_err(|_| {
+                    invalid_script(format!("PushInt: invalid integer '{}'", args[0]))
+                })?)
+            }
+            "PushPubKey" => {
+                expect_args(1)?;
+                let key = RistrettoPublicKey::from_hex(args[0])
+                    .map_err(|_| invalid_script(format!("PushPubKey: invalid public key '{}'", args[0])))?;
+                Opcode::PushPubKey(Box::new(key))
+            }
+            "CheckSig" => {
+                expect_args(1)?;
+                Opcode::CheckSig(parse_message(args[0])?)
+            }
+            "CheckSigVerify" => {
+                expect_args(1)?;
+                Opcode::CheckSigVerify(parse_message(args[0])?)
+            }
+            "CheckMultiSig" | "CheckMultiSigVerify" => {
+                // CheckMultiSig(m, n, key_1, ..., key_n, message)
+                let count = args
+                    .get(1)
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .ok_or_else(|| invalid_script(format!("{}: invalid key count", name)))?;
+                expect_args(count + 3)?;
+                let threshold = args[0]
+                    .parse::<usize>()
+                    .map_err(|_| invalid_script(format!("{}: invalid threshold '{}'", name, args[0])))?;
+                let public_keys = args[2..count + 2]
+                    .iter()
+                    .map(|key| {
+                        RistrettoPublicKey::from_hex(key)
+                            .map_err(|_| invalid_script(format!("{}: invalid public key '{}'", name, key)))
+                    })
+                    .collect::<TariResult<Vec<_>>>()?;
+                let multisig = Box::new(MultiSig::new(threshold, public_keys, *parse_message(args[count + 2])?)?);
+                if name == "CheckMultiSig" {
+                    Opcode::CheckMultiSig(multisig)
+                } else {
+                    Opcode::CheckMultiSigVerify(multisig)
+                }
+            }
+            _ => {
+                expect_args(0)?;
+                match name {
+                    "CompareHeightVerify" => Opcode::CompareHeightVerify,
+                    "CompareHeight" => Opcode::CompareHeight,
+                    "Nop" => Opcode::Nop,
+                    "PushZero" => Opcode::PushZero,
+                    "PushOne" => Opcode::PushOne,
+                    "Drop" => Opcode::Drop,
+                    "Dup" => Opcode::Dup,
+                    "Equal" => Opcode::Equal,
+                    "EqualVerify" => Opcode::EqualVerify,
+                    "Return" => Opcode::Return,
+                    other => return Err(invalid_script(format!("Unknown opcode: {}", other))),
+                }
+            }
+        };
+
+        Ok(opcode)
+    }
+
+    /// Decode the opcode with byte value `code` and its operands from `bytes`
+    fn read_bytes(code: u8, bytes: &mut &[u8]) -> TariResult<Self> {
+        let opcode = match code {
+            0x60 => Opcode::Return,
+            0x66 => Opcode::CheckHeightVerify(u64::from_le_bytes(take_array(bytes, code)?)),
+            0x67 => Opcode::CheckHeight(u64::from_le_bytes(take_array(bytes, code)?)),
+            0x68 => Opcode::CompareHeightVerify,
+            0x69 => Opcode::CompareHeight,
+            0x70 => Opcode::Drop,
+            0x71 => Opcode::Dup,
+            0x73 => Opcode::Nop,
+            0x7a => Opcode::PushZero,
+            0x7b => Opcode::PushOne,
+            0x7d => Opcode::PushInt(i64::from_le_bytes(take_array(bytes, code)?)),
+            0x7e => Opcode::PushPubKey(Box::new(read_public_key(bytes, code)?)),
+            0x95 => Opcode::Equal,
+            0x96 => Opcode::EqualVerify,
+            0xac => Opcode::CheckSig(Box::new(take_array(bytes, code)?)),
+            0xad => Opcode::CheckSigVerify(Box::new(take_array(bytes, code)?)),
+            0xae | 0xaf => {
+                let [threshold, count] = take_array(bytes, code)?;
+                let public_keys = (0..count)
+                    .map(|_| read_public_key(bytes, code))
+                    .collect::<TariResult<Vec<_>>>()?;
+                let multisig = Box::new(MultiSig::new(
+                    threshold as usize,
+                    public_keys,
+                    take_array(bytes, code)?,
+                )?);
+                if code == 0xae {
+                    Opcode::CheckMultiSig(multisig)
+                } else {
+                    Opcode::CheckMultiSigVerify(multisig)
+                }
+            }
+            other => return Err(invalid_script(format!("Unknown opcode byte: 0x{:02x}", other))),
+        };
+
+        Ok(opcode)
+    }
+
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        out.push(self.code());
+        match self {
+            Opcode::CheckHeightVerify(height) | Opcode::CheckHeight(height) => {
+                out.extend_from_slice(&height.to_le_bytes())
+            }
+            Opcode::PushInt(value) => out.extend_from_slice(&value.to_le_bytes()),
+            Opcode::PushPubKey(key) => out.extend_from_slice(key.as_bytes()),
+            Opcode::CheckSig(message) | Opcode::CheckSigVerify(message) => {
+                out.extend_from_slice(message.as_ref())
+            }
+            Opcode::CheckMultiSig(multisig) | Opcode::CheckMultiSigVerify(multisig) => {
+                out.push(multisig.threshold);
+                out.push(multisig.public_keys.len() as u8);
+                for key in &multisig.public_keys {
+                    out.extend_from_slice(key.as_bytes());
+                }
+                out.extend_from_slice(&multisig.message);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Item on the script execution stack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StackItem {
+    Number(i64),
+    PublicKey(RistrettoPublicKey),
+    Signature(RistrettoSchnorr),
+}
+
+impl StackItem {
+    /// Parse a stack item from its `Type(value)` string form
+    pub fn parse(input: &str) -> TariResult<Self> {
+        let (name, args) = split_call(input.trim())?;
+        let value = match args.as_slice() {
+            [value] => *value,
+            _ => return Err(invalid_script(format!("Stack item '{}' requires exactly one value", input))),
+        };
+
+        match name {
+            "Number" => value
+                .parse::<i64>()
+                .map(StackItem::Number)
+                .map_err(|_| invalid_script(format!("Invalid number stack item: {}", input))),
+            "PublicKey" => RistrettoPublicKey::from_hex(value)
+                .map(StackItem::PublicKey)
+                .map_err(|_| invalid_script(format!("Invalid public key stack item: {}", input))),
+            "Signature" => parse_signature(value).map(StackItem::Signature),
+            other => Err(invalid_script(format!(
+                "Unknown stack item type '{}' (expected Number, PublicKey or Signature)",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for StackItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StackItem::Number(n) => write!(f, "Number({})", n),
+            StackItem::PublicKey(key) => write!(f, "PublicKey({})", key.to_hex()),
+            StackItem::Signature(sig) => write!(f, "Signature({})", signature_to_hex(sig)),
+        }
+    }
+}
+
+/// A compiled TariScript
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TariScript {
+    opcodes: Vec<Opcode>,
+}
+
+impl TariScript {
+    /// Compile a script from whitespace-separated opcodes, e.g.
+    /// `CheckHeightVerify(100) PushPubKey(<hex>)`
+    pub fn compile(source: &str) -> TariResult<Self> {
+        let opcodes = tokenize(source)?
+            .into_iter()
+            .map(|token| {
+                let (name, args) = split_call(token)?;
+                Opcode::parse(name, &args)
+            })
+            .collect::<TariResult<Vec<_>>>()?;
+
+        if opcodes.is_empty() {
+            return Err(invalid_script("Script cannot be empty"));
+        }
+
+        Ok(Self { opcodes })
+    }
+
+    /// Serialize to the Tari script byte encoding
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for opcode in &self.opcodes {
+            opcode.write_bytes(&mut bytes);
+        }
+        bytes
+    }
+
+    /// Decode a script from the Tari script byte encoding
+    pub fn from_bytes(mut bytes: &[u8]) -> TariResult<Self> {
+        let mut opcodes = Vec::new();
+        while let Some((&code, rest)) = bytes.split_first() {
+            bytes = rest;
+            opcodes.push(Opcode::read_bytes(code, &mut bytes)?);
+        }
+
+        if opcodes.is_empty() {
+            return Err(invalid_script("Script cannot be empty"));
+        }
+
+        Ok(Self { opcodes })
+    }
+
+    /// Run the script against an input stack (bottom first) at the given block height
+    pub fn execute(&self, inputs: Vec<StackItem>, block_height: u64) -> std::result::Result<Vec<StackItem>, String> {
+        let mut stack = inputs;
+        if stack.len() > MAX_STACK_SIZE {
+            return Err("Input stack exceeds maximum stack size".to_string());
+        }
+
+        let height = i64::try_from(block_height).map_err(|_| "Block height out of range".to_string())?;
+
+        for opcode in &self.opcodes {
+            match opcode {
+                Opcode::CheckHeightVerify(lock_height) => {
+                    if block_height < *lock_height {
+                        return Err(format!(
+                            "CheckHeightVerify failed: block height {} is below {}",
+                            block_height, lock_height
+                        ));
+                    }
+                }
+                Opcode::CheckHeight(lock_height) => {
+                    let lock_height = i64::try_from(*lock_height).map_err(|_| "Lock height out of range".to_string())?;
+                    push(&mut stack, StackItem::Number(height - lock_height))?;
+                }
+                Opcode::CompareHeightVerify => {
+                    let target = pop_number(&mut stack)?;
+                    if height < target {
+                        return Err(format!(
+                            "CompareHeightVerify failed: block height {} is below {}",
+                            height, target
+                        ));
+                    }
+                }
+                Opcode::CompareHeight => {
+                    let target = pop_number(&mut stack)?;
+                    push(&mut stack, StackItem::Number(height - target))?;
+                }
+                Opcode::Nop => {}
+                Opcode::PushZero => push(&mut stack, StackItem::Number(0))?,
+                Opcode::PushOne => push(&mut stack, StackItem::Number(1))?,
+                Opcode::PushInt(value) => push(&mut stack, StackItem::Number(*value))?,
+                Opcode::PushPubKey(key) => push(&mut stack, StackItem::PublicKey(key.as_ref().clone()))?,
+                Opcode::Drop => {
+                    pop(&mut stack)?;
+                }
+                Opcode::Dup => {
+                    let top = stack.last().cloned().ok_or_else(|| "Stack underflow".to_string())?;
+                    push(&mut stack, top)?;
+                }
+                Opcode::Equal => {
+                    let a = pop(&mut stack)?;
+                    let b = pop(&mut stack)?;
+                    push(&mut stack, StackItem::Number((a == b) as i64))?;
+                }
+                Opcode::EqualVerify => {
+                    let a = pop(&mut stack)?;
+                    let b = pop(&mut stack)?;
+                    if a != b {
+                        return Err("EqualVerify failed: items are not equal".to_string());
+                    }
+                }
+                Opcode::CheckSig(message) => {
+                    let valid = check_signature(&mut stack, message.as_ref())?;
+                    push(&mut stack, StackItem::Number(valid as i64))?;
+                }
+                Opcode::CheckSigVerify(message) => {
+                    if !check_signature(&mut stack, message.as_ref())? {
+                        return Err("CheckSigVerify failed: invalid signature".to_string());
+                    }
+                }
+                Opcode::CheckMultiSig(multisig) => {
+                    let valid = multisig.check(&mut stack)?;
+                    push(&mut stack, StackItem::Number(valid as i64))?;
+                }
+                Opcode::CheckMultiSigVerify(multisig) => {
+                    if !multisig.check(&mut stack)? {
+                        return Err("CheckMultiSigVerify failed: not enough valid signatures".to_string());
+                    }
+                }
+                Opcode::Return => return Err("Script executed Return".to_string()),
+            }
+        }
+
+        Ok(stack)
+    }
+
+    /// The script key an output with this script is spent with, given `input_data`
+    ///
+    /// A spendable script finishes with exactly one public key on the stack.
+    /// Height checks pass, as a time lock only delays spending the output.
+    pub fn script_public_key(&self, input_data: Vec<StackItem>) -> std::result::Result<RistrettoPublicKey, String> {
+        let mut stack = self.execute(input_data, i64::MAX as u64)?;
+        match (stack.pop(), stack.len()) {
+            (Some(StackItem::PublicKey(key)), 0) => Ok(key),
+            (Some(StackItem::PublicKey(_)), remaining) => Err(format!(
+                "Script finished with {} stack items, expected 1",
+                remaining + 1
+            )),
+            (Some(other), _) => Err(format!("Script finished with {}, expected a public key", other)),
+            (None, _) => Err("Script finished with an empty stack, expected a public key".to_string()),
+        }
+    }
+
+    /// Lock height imposed by a height check, if any
+    pub fn lock_height(&self) -> Option<u64> {
+        self.opcodes.iter().find_map(|opcode| match opcode {
+            Opcode::CheckHeightVerify(height) | Opcode::CheckHeight(height) => Some(*height),
+            _ => None,
+        })
+    }
+
+    /// Multisig condition in the script, if any
+    pub fn multisig(&self) -> Option<&MultiSig> {
+        self.opcodes.iter().find_map(|opcode| match opcode {
+            Opcode::CheckMultiSig(multisig) | Opcode::CheckMultiSigVerify(multisig) => Some(multisig.as_ref()),
+            _ => None,
+        })
+    }
+}
+
+/// Take the next `N` operand bytes of the opcode with byte value `code`
+fn take_array<const N: usize>(bytes: &mut &[u8], code: u8) -> TariResult<[u8; N]> {
+    let Some((operand, rest)) = bytes.split_first_chunk::<N>() else {
+        return Err(invalid_script(format!(
+            "Script ends inside the operands of opcode 0x{:02x}",
+            code
+        )));
+    };
+    *bytes = rest;
+    Ok(*operand)
+}
+
+fn read_public_key(bytes: &mut &[u8], code: u8) -> TariResult<RistrettoPublicKey> {
+    let key: [u8; 32] = take_array(bytes, code)?;
+    RistrettoPublicKey::from_canonical_bytes(&key).map_err(|_| {
+        invalid_script(format!("Opcode 0x{:02x} has an invalid public key", code))
+    })
+}
+
+fn push(stack: &mut Vec<StackItem>, item: StackItem) -> std::result::Result<(), String> {
+    if stack.len() >= MAX_STACK_SIZE {
+        return Err("Stack overflow".to_string());
+    }
+    stack.push(item);
+    Ok(())
+}
+
+fn pop(stack: &mut Vec<StackItem>) -> std::result::Result<StackItem, String> {
+    stack.pop().ok_or_else(|| "Stack underflow".to_string())
+}
+
+fn pop_number(stack: &mut Vec<StackItem>) -> std::result::Result<i64, String> {
+    match pop(stack)? {
+        StackItem::Number(n) => Ok(n),
+        other => Err(format!("Expected a number on the stack, found {}", other)),
+    }
+}
+
+/// Pop a public key and then a signature, and verify the signature over `message`
+fn check_signature(stack: &mut Vec<StackItem>, message: &[u8]) -> std::result::Result<bool, String> {
+    let public_key = match pop(stack)? {
+        StackItem::PublicKey(key) => key,
+        other => return Err(format!("Expected a public key on the stack, found {}", other)),
+    };
+    let signature = match pop(stack)? {
+        StackItem::Signature(sig) => sig,
+        other => return Err(format!("Expected a signature on the stack, found {}", other)),
+    };
+
+    Ok(signature.verify(&public_key, message))
+}
+
+fn invalid_script(message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(WalletErrorCode::InvalidFormat, message).component("script")
+}
+
+/// Split script source into opcode tokens, keeping parenthesised arguments together
+fn tokenize(source: &str) -> TariResult<Vec<&str>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let mut depth = 0usize;
+
+    for (i, c) in source.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| invalid_script("Unbalanced ')' in script"))?
+            }
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    tokens.push(&source[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if start.is_none() {
+            start = Some(i);
+        }
+    }
+
+    if depth != 0 {
+        return Err(invalid_script("Unbalanced '(' in script"));
+    }
+    if let Some(s) = start {
+        tokens.push(&source[s..]);
+    }
+
+    Ok(tokens)
+}
+
+/// Split `Name(a, b)` into its name and trimmed arguments
+fn split_call(token: &str) -> TariResult<(&str, Vec<&str>)> {
+    match token.find('(') {
+        None => Ok((token, Vec::new())),
+        Some(open) => {
+            let inner = token[open + 1..]
+                .strip_suffix(')')
+                .ok_or_else(|| invalid_script(format!("Malformed token: {}", token)))?;
+            let args = if inner.trim().is_empty() {
+                Vec::new()
+            } else {
+                inner.split(',').map(str::trim).collect()
+            };
+            Ok((&token[..open], args))
+        }
+    }
+}
+
+/// Look up a compiled script by handle
+pub(crate) fn get_script(handle: ScriptHandle) -> TariResult<TariScript> {
+    SCRIPTS
+        .lock()
+        .map_err(|_| lock_error())?
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| {
+            TariWalletError::new(
+                WalletErrorCode::InvalidHandle,
+                format!("Invalid script handle: {}", handle),
+            )
+            .component("script")
+        })
+}
+
+/// Result of executing a script locally
+#[napi(object)]
+pub struct JsScriptResult {
+    /// Whether the script ran to completion leaving exactly one stack item
+    pub success: bool,
+    /// Remaining stack items, bottom first, in `Type(value)` form
+    pub final_stack: Vec<String>,
+    /// Reason execution failed, if it did
+    pub error: Option<String>,
+}
+
+/// Summary information about a compiled script
+#[napi(object)]
+pub struct JsScriptInfo {
+    pub opcode_count: u32,
+    pub serialized_size: u32,
+    pub lock_height: Option<i64>,
+    pub has_signature_check: bool,
+    /// Signatures required by a multisig condition
+    pub multisig_threshold: Option<u32>,
+    /// Public keys in a multisig condition
+    pub multisig_key_count: Option<u32>,
+}
+
+/// Compile a TariScript from its textual opcode form
+#[napi]
+pub fn compile_script(source: String) -> Result<ScriptHandle> {
+    let script = TariScript::compile(&source)?;
+
+    let handle = generate_advanced_handle();
+    SCRIPTS.lock().map_err(|_| lock_error())?.insert(handle, script);
+
+    Ok(handle)
+}
+
+/// Execute a compiled script against an input stack
+///
+/// Stack inputs are given bottom first as `Number(n)`, `PublicKey(<hex>)` or
+/// `Signature(<hex>)`. Height opcodes are evaluated against `block_height`
+/// (default 0).
+#[napi]
+pub fn execute_script(
+    script_handle: ScriptHandle,
+    stack_inputs: Vec<String>,
+    block_height: Option<i64>,
+) -> Result<JsScriptResult> {
+    let script = get_script(script_handle)?;
+
+    let inputs = stack_inputs
+        .iter()
+        .map(|input| StackItem::parse(input))
+        .collect::<TariResult<Vec<_>>>()?;
+
+    let block_height = u64::try_from(block_height.unwrap_or(0)).map_err(|_| {
+        napi::Error::new(Status::InvalidArg, "Block height cannot be negative".to_string())
+    })?;
+
+    Ok(match script.execute(inputs, block_height) {
+        Ok(stack) => {
+            let success = stack.len() == 1;
+            JsScriptResult {
+                success,
+                final_stack: stack.iter().map(ToString::to_string).collect(),
+                error: if success {
+                    None
+                } else {
+                    Some(format!("Script finished with {} stack items, expected 1", stack.len()))
+                },
+            }
+        }
+        Err(error) => JsScriptResult {
+            success: false,
+            final_stack: Vec::new(),
+            error: Some(error),
+        },
+    })
+}
+
+/// Get summary information about a compiled script
+#[napi]
+pub fn get_script_info(script_handle: ScriptHandle) -> Result<JsScriptInfo> {
+    let script = get_script(script_handle)?;
+
+    Ok(JsScriptInfo {
+        opcode_count: script.opcodes.len() as u32,
+        serialized_size: script.to_bytes().len() as u32,
+        lock_height: script.lock_height().map(|h| h as i64),
+        has_signature_check: script
+            .opcodes
+            .iter()
+            .any(|op| {
+                matches!(
+                    op,
+                    Opcode::CheckSig(_)
+                        | Opcode::CheckSigVerify(_)
+                        | Opcode::CheckMultiSig(_)
+                        | Opcode::CheckMultiSigVerify(_)
+                )
+            }),
+        multisig_threshold: script.multisig().map(|m| m.threshold as u32),
+        multisig_key_count: script.multisig().map(|m| m.public_keys.len() as u32),
+    })
+}
+
+/// Compile an m-of-n multisig spending script
+///
+/// The script succeeds when `threshold` valid signatures over `message`
+/// from distinct keys in `public_keys` are on the input stack. `message`
+/// is 32 bytes of hex and defaults to all zeros.
+#[napi]
+pub fn compile_multisig_script(
+    threshold: u32,
+    public_keys: Vec<String>,
+    message: Option<String>,
+) -> Result<ScriptHandle> {
+    let keys = public_keys
+        .iter()
+        .map(|key| {
+            RistrettoPublicKey::from_hex(key).map_err(|_| {
+                TariWalletError::new(WalletErrorCode::InvalidKey, format!("Invalid public key: {}", key))
+                    .component("script")
+            })
+        })
+        .collect::<TariResult<Vec<_>>>()?;
+
+    let message = match message {
+        Some(message) => hex::from_hex(&message)
+            .ok()
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .ok_or_else(|| invalid_script("Multisig message must be 32 bytes of hex"))?,
+        None => [0u8; 32],
+    };
+
+    let multisig = MultiSig::new(threshold as usize, keys, message)?;
+    let script = TariScript {
+        opcodes: vec![Opcode::CheckMultiSig(Box::new(multisig))],
+    };
+
+    let handle = generate_advanced_handle();
+    SCRIPTS.lock().map_err(|_| lock_error())?.insert(handle, script);
+
+    Ok(handle)
+}
+
+/// Get the serialized script as hex
+#[napi]
+pub fn script_to_hex(script_handle: ScriptHandle) -> Result<String> {
+    Ok(hex::to_hex(&get_script(script_handle)?.to_bytes()))
+}
+
+/// Destroy a script handle
+#[napi]
+pub fn script_destroy(script_handle: ScriptHandle) -> Result<()> {
+    let removed = SCRIPTS
+        .lock()
+        .map_err(|_| lock_error())?
+        .remove(&script_handle);
+
+    match removed {
+        Some(_) => Ok(()),
+        None => Err(napi::Error::new(
+            Status::InvalidArg,
+            format!("Invalid script handle: {}", script_handle),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const HASH: &str = "0101010101010101010101010101010101010101010101010101010101010101";
+
+    #[test]
+    fn test_identity_covenant_serialization() {
+        let filter = CovenantFilter::from_spec(&json!({ "type": "identity" })).unwrap();
+        assert_eq!(filter.to_bytes(), vec![0x20]);
+    }
+
+    #[test]
+    fn test_composite_covenant_serialization() {
+        let spec = json!({
+            "type": "or",
+            "filters": [
+                { "type": "output_hash_eq", "value": HASH },
+                {
+                    "type": "and",
+                    "filters": [
+                        { "type": "field_eq", "field": "features_output_type", "value": "burn" },
+                        { "type": "field_eq", "field": "features_maturity", "value": 300 }
+                    ]
+                }
+            ]
+        });
+
+        let bytes = CovenantFilter::from_spec(&spec).unwrap().to_bytes();
+        let mut expected = vec![0x22, 0x30, 0x01];
+        expected.extend_from_slice(&[0x01; 32]);
+        expected.extend_from_slice(&[0x21, 0x33, 0x07, 0x05, 0x0a, 0x02]);
+        expected.extend_from_slice(&[0x33, 0x07, 0x06, 0x06, 0xac, 0x02]);
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_unknown_filter_type_is_rejected() {
+        let err = CovenantFilter::from_spec(&json!({ "type": "xor" })).unwrap_err();
+        assert_eq!(err.code, WalletErrorCode::SchemaValidationFailed);
+        assert!(err.message.contains("xor"));
+    }
+
+    #[test]
+    fn test_invalid_specs_are_rejected() {
+        assert!(CovenantFilter::from_spec(&json!({})).is_err());
+        assert!(CovenantFilter::from_spec(&json!({ "type": "and", "filters": [{ "type": "identity" }] })).is_err());
+        assert!(CovenantFilter::from_spec(&json!({ "type": "output_hash_eq", "value": "abcd" })).is_err());
+        assert!(CovenantFilter::from_spec(&json!({ "type": "field_eq", "field": "nope", "value": 1 })).is_err());
+    }
+
+    #[test]
+    fn test_covenant_handle_lifecycle() {
+        let handle = create_covenant(json!({ "type": "identity" })).unwrap();
+        assert_eq!(covenant_to_hex(handle).unwrap(), "20");
+
+        covenant_destroy(handle).unwrap();
+        assert!(covenant_to_hex(handle).is_err());
+        assert!(covenant_destroy(handle).is_err());
+    }
+
+    const MESSAGE: &str = "0202020202020202020202020202020202020202020202020202020202020202";
+
+    fn signed_inputs(message: &[u8]) -> (String, String) {
+        use tari_crypto::keys::{PublicKey, SecretKey};
+        use tari_crypto::ristretto::RistrettoSecretKey;
+
+        let secret = RistrettoSecretKey::random(&mut rand::thread_rng());
+        let public = RistrettoPublicKey::from_secret_key(&secret);
+        let signature = RistrettoSchnorr::sign(&secret, message, &mut rand::thread_rng()).unwrap();
+        (
+            format!("Signature({})", signature_to_hex(&signature)),
+            format!("PublicKey({})", public.to_hex()),
+        )
+    }
+
+    #[test]
+    fn test_compile_and_serialize_script() {
+        let script = TariScript::compile("CheckHeightVerify(100)  PushInt(-2)\nNop").unwrap();
+        assert_eq!(script.opcodes.len(), 3);
+        assert_eq!(script.lock_height(), Some(100));
+        assert_eq!(script.to_bytes()[0], 0x66);
+        assert_eq!(script.to_bytes().len(), 9 + 9 + 1);
+    }
+
+    #[test]
+    fn test_script_bytes_round_trip() {
+        let (_, key) = signed_inputs(&[0u8; 32]);
+        let key = &key["PublicKey(".len()..key.len() - 1];
+        let source = format!(
+            "CheckHeight(7) PushInt(-2) Drop Drop PushPubKey({key}) CheckMultiSig(1, 1, {key}, {MESSAGE}) Drop Nop",
+        );
+        let script = TariScript::compile(&source).unwrap();
+        assert_eq!(TariScript::from_bytes(&script.to_bytes()).unwrap(), script);
+
+        assert!(TariScript::from_bytes(&[]).is_err());
+        assert!(TariScript::from_bytes(&[0x01]).is_err());
+        let push = TariScript::compile("PushInt(5)").unwrap().to_bytes();
+        assert!(TariScript::from_bytes(&push[..push.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_compile_rejects_invalid_scripts() {
+        assert!(TariScript::compile("").is_err());
+        assert!(TariScript::compile("Bogus").is_err());
+        assert!(TariScript::compile("CheckHeight(abc)").is_err());
+        assert!(TariScript::compile("CheckHeight(1").is_err());
+        assert!(TariScript::compile("Drop(1)").is_err());
+    }
+
+    #[test]
+    fn test_time_lock_script_execution() {
+        let handle = compile_script("CheckHeightVerify(100) PushOne".to_string()).unwrap();
+
+        let locked = execute_script(handle, vec![], Some(99)).unwrap();
+        assert!(!locked.success);
+        assert!(locked.error.unwrap().contains("below 100"));
+
+        let unlocked = execute_script(handle, vec![], Some(100)).unwrap();
+        assert!(unlocked.success);
+        assert_eq!(unlocked.final_stack, vec!["Number(1)".to_string()]);
+
+        let info = get_script_info(handle).unwrap();
+        assert_eq!(info.lock_height, Some(100));
+        assert!(!info.has_signature_check);
+        script_destroy(handle).unwrap();
+    }
+
+    #[test]
+    fn test_compare_height_execution() {
+        let script = TariScript::compile("CompareHeight").unwrap();
+        let stack = script.execute(vec![StackItem::Number(40)], 100).unwrap();
+        assert_eq!(stack, vec![StackItem::Number(60)]);
+    }
+
+    #[test]
+    fn test_check_sig_execution() {
+        let message = hex::from_hex(MESSAGE).unwrap();
+        let (signature, public_key) = signed_inputs(&message);
+        let handle = compile_script(format!("CheckSigVerify({}) PushOne", MESSAGE)).unwrap();
+
+        let result = execute_script(handle, vec![signature.clone(), public_key], None).unwrap();
+        assert!(result.success, "{:?}", result.error);
+
+        let (_, other_key) = signed_inputs(&message);
+        let result = execute_script(handle, vec![signature, other_key], None).unwrap();
+        assert!(!result.success);
+        assert!(get_script_info(handle).unwrap().has_signature_check);
+    }
+
+    #[test]
+    fn test_return_and_underflow_fail() {
+        let handle = compile_script("PushOne Return".to_string()).unwrap();
+        assert!(!execute_script(handle, vec![], None).unwrap().success);
+
+        let handle = compile_script("Drop".to_string()).unwrap();
+        let result = execute_script(handle, vec![], None).unwrap();
+        assert_eq!(result.error.as_deref(), Some("Stack underflow"));
+
+        assert!(execute_script(handle, vec!["Bogus(1)".to_string()], None).is_err());
+    }
+
+    #[test]
+    fn test_multisig_script_requires_threshold_signatures() {
+        let message = hex::from_hex(MESSAGE).unwrap();
+        let (sig_a, key_a) = signed_inputs(&message);
+        let (sig_b, key_b) = signed_inputs(&message);
+        let (_, key_c) = signed_inputs(&message);
+        let key_hex = |item: &str| item["PublicKey(".len()..item.len() - 1].to_string();
+        let keys = vec![key_hex(&key_a), key_hex(&key_b), key_hex(&key_c)];
+
+        let handle = compile_multisig_script(2, keys, Some(MESSAGE.to_string())).unwrap();
+        let info = get_script_info(handle).unwrap();
+        assert_eq!(info.multisig_threshold, Some(2));
+        assert_eq!(info.multisig_key_count, Some(3));
+        assert_eq!(info.serialized_size, 1 + 2 + 3 * 32 + 32);
+
+        let result = execute_script(handle, vec![sig_a.clone(), sig_b], None).unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.final_stack, vec!["Number(1)".to_string()]);
+
+        let result = execute_script(handle, vec![sig_a.clone(), sig_a], None).unwrap();
+        assert_eq!(result.final_stack, vec!["Number(0)".to_string()]);
+    }
+
+    #[test]
+    fn test_multisig_script_validation() {
+        let (_, key) = signed_inputs(&[0u8; 32]);
+        let key = key["PublicKey(".len()..key.len() - 1].to_string();
+
+        assert!(compile_multisig_script(0, vec![key.clone()], None).is_err());
+        assert!(compile_multisig_script(2, vec![key.clone()], None).is_err());
+        assert!(compile_multisig_script(1, vec!["zz".to_string()], None).is_err());
+        assert!(compile_multisig_script(1, vec![key.clone()], Some("00".to_string())).is_err());
+
+        let source = format!("CheckMultiSigVerify(1, 1, {}, {}) PushOne", key, MESSAGE);
+        let handle = compile_script(source).unwrap();
+        assert_eq!(get_script_info(handle).unwrap().multisig_threshold, Some(1));
+    }
+}
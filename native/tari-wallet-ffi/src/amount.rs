@@ -0,0 +1,149 @@
+/**
+ * Exact conversion between microTari and Tari amounts
+ *
+ * Amounts cross the FFI boundary as decimal strings so that balances
+ * beyond the range JavaScript numbers represent exactly keep their
+ * precision. Conversions are done on integers, never floating point.
+ */
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+
+/// Number of microTari in one Tari
+const MICRO_TARI_PER_TARI: u64 = 1_000_000;
+
+/// Decimal places of a Tari amount
+const TARI_DECIMALS: usize = 6;
+
+fn amount_error(message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(WalletErrorCode::InvalidAmount, message).component("amount")
+}
+
+/// Parse a non-negative string of decimal digits
+fn parse_digits(digits: &str, amount: &str) -> TariResult<u64> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(amount_error(format!("Malformed amount: {:?}", amount)));
+    }
+    digits
+        .parse::<u64>()
+        .map_err(|_| amount_error(format!("Amount is too large: {}", amount)))
+}
+
+fn reject_negative(amount: &str) -> TariResult<()> {
+    if amount.starts_with('-') {
+        return Err(amount_error(format!(
+            "Amount cannot be negative: {}",
+            amount
+        )));
+    }
+    Ok(())
+}
+
+/// Format a microTari amount as Tari, without trailing fractional zeros
+pub(crate) fn format_tari(micro_tari: u64) -> String {
+    let whole = micro_tari / MICRO_TARI_PER_TARI;
+    let fraction = micro_tari % MICRO_TARI_PER_TARI;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{:0width$}", fraction, width = TARI_DECIMALS);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+/// Parse a Tari amount with up to six decimal places into microTari
+pub(crate) fn parse_tari(amount: &str) -> TariResult<u64> {
+    reject_negative(amount)?;
+
+    let (whole, fraction) = match amount.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (amount, None),
+    };
+
+    let whole = parse_digits(whole, amount)?;
+    let fraction = match fraction {
+        Some(fraction) if fraction.len() > TARI_DECIMALS => {
+            return Err(amount_error(format!(
+                "Amount has more than {} decimal places: {}",
+                TARI_DECIMALS, amount
+            )));
+        }
+        Some(fraction) => {
+            parse_digits(fraction, amount)?;
+            let padded = format!("{:0<width$}", fraction, width = TARI_DECIMALS);
+            parse_digits(&padded, amount)?
+        }
+        None => 0,
+    };
+
+    whole
+        .checked_mul(MICRO_TARI_PER_TARI)
+        .and_then(|micro| micro.checked_add(fraction))
+        .ok_or_else(|| amount_error(format!("Amount is too large: {}", amount)))
+}
+
+/// Convert a microTari amount string to Tari, e.g. "1500000" to "1.5"
+#[napi]
+pub fn micro_tari_to_tari(amount: String) -> Result<String> {
+    reject_negative(&amount)?;
+    let micro_tari = parse_digits(&amount, &amount)?;
+    Ok(format_tari(micro_tari))
+}
+
+/// Convert a Tari amount string to microTari, e.g. "1.5" to "1500000"
+#[napi]
+pub fn tari_to_micro_tari(amount: String) -> Result<String> {
+    Ok(parse_tari(&amount)?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_micro_tari_to_tari() {
+        assert_eq!(micro_tari_to_tari("0".to_string()).unwrap(), "0");
+        assert_eq!(micro_tari_to_tari("1".to_string()).unwrap(), "0.000001");
+        assert_eq!(micro_tari_to_tari("1500000".to_string()).unwrap(), "1.5");
+        assert_eq!(micro_tari_to_tari("2000000".to_string()).unwrap(), "2");
+        assert_eq!(
+            micro_tari_to_tari(u64::MAX.to_string()).unwrap(),
+            "18446744073709.551615"
+        );
+
+        assert!(micro_tari_to_tari("-1".to_string()).is_err());
+        assert!(micro_tari_to_tari("1.5".to_string()).is_err());
+        assert!(micro_tari_to_tari("".to_string()).is_err());
+        assert!(micro_tari_to_tari("18446744073709551616".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_tari_to_micro_tari() {
+        assert_eq!(tari_to_micro_tari("1.5".to_string()).unwrap(), "1500000");
+        assert_eq!(tari_to_micro_tari("0.000001".to_string()).unwrap(), "1");
+        assert_eq!(tari_to_micro_tari("42".to_string()).unwrap(), "42000000");
+        assert_eq!(
+            tari_to_micro_tari("18446744073709.551615".to_string()).unwrap(),
+            u64::MAX.to_string()
+        );
+
+        assert!(tari_to_micro_tari("-1.5".to_string()).is_err());
+        assert!(tari_to_micro_tari("1.0000001".to_string()).is_err());
+        assert!(tari_to_micro_tari("1.".to_string()).is_err());
+        assert!(tari_to_micro_tari(".5".to_string()).is_err());
+        assert!(tari_to_micro_tari("1.2.3".to_string()).is_err());
+        assert!(tari_to_micro_tari("1e6".to_string()).is_err());
+        assert!(tari_to_micro_tari("18446744073709.551616".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for micro_tari in [0, 1, 10, 999_999, 1_000_000, 123_456_789] {
+            let tari = format_tari(micro_tari);
+            assert_eq!(parse_tari(&tari).unwrap(), micro_tari);
+        }
+    }
+}
@@ -10,12 +10,25 @@ use napi_derive::napi;
 use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU32, Ordering};
 use once_cell::sync::Lazy;
 use tokio::sync::mpsc;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::TariWalletError;
+use crate::event_bus::WalletEvent;
 use crate::types::WalletHandle;
 
+/// Default limit on registered callbacks of each kind
+const DEFAULT_MAX_CALLBACKS: u32 = 100;
+
+/// Limit on registered callbacks of each kind, set with `set_max_callbacks`
+///
+/// Guards against a caller that keeps registering callbacks for new
+/// wallets without removing them, each of which pins a JS function.
+static MAX_CALLBACKS: AtomicU32 = AtomicU32::new(DEFAULT_MAX_CALLBACKS);
+
 /// Event payload structure sent to JavaScript
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventPayload {
@@ -32,21 +45,164 @@ pub struct EventPayload {
 /// ThreadsafeFunction type alias for wallet events
 pub type WalletEventCallback = ThreadsafeFunction<EventPayload, ErrorStrategy::CalleeHandled>;
 
+/// Event types emitted to wallet event callbacks
+const KNOWN_EVENT_TYPES: &[&str] = &[
+    "tx:received",
+    "tx:broadcast",
+    "tx:mined",
+    "tx:cancelled",
+    "tx:confirmed",
+    "balance:updated",
+    "connectivity:changed",
+    "basenode:connected",
+    "basenode:disconnected",
+    "sync:progress",
+    "sync:reorg",
+    "sync:cancelled",
+    "sync:completed",
+    "sync:failed",
+    "recovery:completed",
+    "wallet:started",
+    "wallet:stopped",
+    "error",
+];
+
+/// A wallet's event callback and the event types it subscribed to
+struct RegisteredCallback {
+    function: WalletEventCallback,
+    /// None when subscribed to every event type
+    event_types: Option<HashSet<String>>,
+}
+
+impl RegisteredCallback {
+    fn wants(&self, event_type: &str) -> bool {
+        self.event_types
+            .as_ref()
+            .is_none_or(|types| types.contains(event_type))
+    }
+}
+
 /// Global storage for wallet event callbacks
 /// Maps wallet handle to its registered callback
-static WALLET_CALLBACKS: Lazy<Arc<Mutex<HashMap<WalletHandle, WalletEventCallback>>>> = 
+static WALLET_CALLBACKS: Lazy<Arc<Mutex<HashMap<WalletHandle, RegisteredCallback>>>> = 
     Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
 
+/// Inbound transaction notification sent to `wallet_on_transaction_received` callbacks
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsReceivedTransaction {
+    pub tx_id: String,
+    /// Amount in µT
+    pub amount: String,
+    pub sender_address: Option<String>,
+    pub message: Option<String>,
+    /// "pending" when first detected, "confirmed" once sufficiently confirmed
+    pub status: String,
+    /// Height from which the received output can be spent, if it is locked
+    pub maturity_height: Option<i64>,
+    /// "standard", "coinbase" or "burn"
+    pub output_type: String,
+}
+
+/// ThreadsafeFunction type alias for inbound transaction notifications
+pub type TransactionReceivedCallback =
+    ThreadsafeFunction<JsReceivedTransaction, ErrorStrategy::Fatal>;
+
+/// Inbound transaction callbacks by wallet handle
+static RECEIVED_CALLBACKS: Lazy<Mutex<HashMap<WalletHandle, TransactionReceivedCallback>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Kind of output manager event; the values are stable across releases
+#[napi]
+pub enum JsOutputEventKind {
+    /// An inbound transaction created an output that is not yet mined
+    OutputReceived = 0,
+    /// Outputs created by a transaction reached the required confirmations
+    OutputConfirmed = 1,
+    /// Outputs were spent by an outbound transaction
+    OutputSpent = 2,
+    /// Outputs changed some other way, such as by a sync, reorg or import
+    BalanceUpdated = 3,
+}
+
+/// Output manager event sent to `wallet_subscribe_output_manager_events` callbacks
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsOutputManagerEvent {
+    /// A `JsOutputEventKind` value
+    pub kind: i32,
+    /// Transaction that changed the outputs, if one did
+    pub tx_id: Option<String>,
+    /// Amount spent in µT, for `OutputSpent`
+    pub amount: Option<String>,
+    /// Height synced to or rolled back to, for `BalanceUpdated`
+    pub height: Option<i64>,
+}
+
+impl JsOutputManagerEvent {
+    fn new(kind: JsOutputEventKind) -> Self {
+        Self {
+            kind: kind as i32,
+            tx_id: None,
+            amount: None,
+            height: None,
+        }
+    }
+}
+
+impl From<WalletEvent> for JsOutputManagerEvent {
+    fn from(event: WalletEvent) -> Self {
+        match event {
+            WalletEvent::TransactionSent { tx_id, amount } => Self {
+                tx_id: Some(tx_id),
+                amount: Some(amount.to_string()),
+                ..Self::new(JsOutputEventKind::OutputSpent)
+            },
+            WalletEvent::TransactionReceived { tx_id } => Self {
+                tx_id: Some(tx_id),
+                ..Self::new(JsOutputEventKind::OutputReceived)
+            },
+            WalletEvent::TransactionConfirmed { tx_id } => Self {
+                tx_id: Some(tx_id),
+                ..Self::new(JsOutputEventKind::OutputConfirmed)
+            },
+            WalletEvent::SyncCompleted { height } | WalletEvent::BlocksRolledBack { height } => Self {
+                height: Some(height as i64),
+                ..Self::new(JsOutputEventKind::BalanceUpdated)
+            },
+            WalletEvent::OutputsChanged => Self::new(JsOutputEventKind::BalanceUpdated),
+        }
+    }
+}
+
+/// ThreadsafeFunction type alias for output manager events
+pub type OutputManagerEventCallback = ThreadsafeFunction<JsOutputManagerEvent, ErrorStrategy::Fatal>;
+
+/// Output manager event callbacks by wallet handle
+static OUTPUT_EVENT_CALLBACKS: Lazy<Mutex<HashMap<WalletHandle, OutputManagerEventCallback>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
 /// Event emitter channel for async event processing
 static EVENT_EMITTER: Lazy<Arc<Mutex<Option<mpsc::UnboundedSender<EventPayload>>>>> = 
     Lazy::new(|| Arc::new(Mutex::new(None)));
 
 /// Register an event callback for a specific wallet
+///
+/// With `event_types`, such as `["tx:received"]`, the callback is only
+/// called for those events; without it, or with an empty list, it is called
+/// for every event. Unknown event types are rejected.
 #[napi]
 pub fn wallet_set_event_callback(
     wallet_handle: WalletHandle,
     callback: JsFunction,
+    event_types: Option<Vec<String>>,
 ) -> Result<()> {
+    let event_types = parse_event_types(event_types)?;
+    let mut callbacks = WALLET_CALLBACKS.lock().map_err(|_| {
+        napi::Error::new(Status::GenericFailure, "Failed to acquire callback lock")
+    })?;
+    ensure_callback_capacity(&callbacks, wallet_handle)?;
+
     // Create ThreadsafeFunction from the JavaScript callback
     let tsfn: WalletEventCallback = callback.create_threadsafe_function(0, |ctx| {
         let payload = &ctx.value;
@@ -65,14 +221,15 @@ pub fn wallet_set_event_callback(
         Ok(vec![js_str])
     })?;
 
-    // Store the callback for this wallet
-    {
-        let mut callbacks = WALLET_CALLBACKS.lock().map_err(|_| {
-            napi::Error::new(Status::GenericFailure, "Failed to acquire callback lock")
-        })?;
-        
-        callbacks.insert(wallet_handle, tsfn);
+    // Store the callback for this wallet, releasing any it replaces
+    let registered = RegisteredCallback {
+        function: tsfn,
+        event_types,
+    };
+    if let Some(previous) = callbacks.insert(wallet_handle, registered) {
+        let _ = previous.function.abort();
     }
+    drop(callbacks);
 
     // Initialize event processing system if not already done
     ensure_event_processor_started()?;
@@ -89,12 +246,147 @@ pub fn wallet_remove_event_callback(wallet_handle: WalletHandle) -> Result<()> {
     
     if let Some(callback) = callbacks.remove(&wallet_handle) {
         // Abort the ThreadsafeFunction to clean up resources
-        callback.abort()?;
+        callback.function.abort()?;
     }
     
     Ok(())
 }
 
+/// Register a callback for inbound transactions
+///
+/// The callback fires as soon as an inbound transaction is detected, before
+/// it is mined, and again when it reaches the required confirmations.
+/// Outputs added with `wallet_import_utxo` are reported once, as confirmed.
+/// Payments from other wallets are not detected until the transaction
+/// service is wired in.
+#[napi]
+pub fn wallet_on_transaction_received(
+    wallet_handle: WalletHandle,
+    callback: TransactionReceivedCallback,
+) -> Result<()> {
+    let mut callbacks = RECEIVED_CALLBACKS.lock().map_err(|_| {
+        napi::Error::new(Status::GenericFailure, "Failed to acquire callback lock")
+    })?;
+    ensure_callback_capacity(&callbacks, wallet_handle)?;
+
+    if let Some(previous) = callbacks.insert(wallet_handle, callback) {
+        let _ = previous.abort();
+    }
+    Ok(())
+}
+
+/// Register a callback for every change to a wallet's outputs
+///
+/// Unlike the coalesced `balance:updated` event, each received, confirmed
+/// or spent output is reported individually as it happens, tagged with a
+/// `JsOutputEventKind`.
+#[napi]
+pub fn wallet_subscribe_output_manager_events(
+    wallet_handle: WalletHandle,
+    callback: OutputManagerEventCallback,
+) -> Result<()> {
+    let mut callbacks = OUTPUT_EVENT_CALLBACKS.lock().map_err(|_| {
+        napi::Error::new(Status::GenericFailure, "Failed to acquire callback lock")
+    })?;
+    ensure_callback_capacity(&callbacks, wallet_handle)?;
+
+    if let Some(previous) = callbacks.insert(wallet_handle, callback) {
+        let _ = previous.abort();
+    }
+    Ok(())
+}
+
+/// Remove the output manager event callback for a wallet
+#[napi]
+pub fn wallet_unsubscribe_output_manager_events(wallet_handle: WalletHandle) {
+    if let Ok(mut callbacks) = OUTPUT_EVENT_CALLBACKS.lock() {
+        if let Some(callback) = callbacks.remove(&wallet_handle) {
+            let _ = callback.abort();
+        }
+    }
+}
+
+/// Set the limit on registered callbacks of each kind
+///
+/// Registrations beyond the limit fail with ResourceExhausted; replacing a
+/// wallet's existing callback always succeeds. Lowering the limit does not
+/// remove callbacks that are already registered.
+#[napi]
+pub fn set_max_callbacks(max: u32) -> Result<()> {
+    if max == 0 {
+        return Err(TariWalletError::new(
+            WalletErrorCode::InvalidConfig,
+            "Callback limit must be at least 1",
+        )
+        .component("callbacks")
+        .into());
+    }
+    MAX_CALLBACKS.store(max, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Validate requested event types; None or an empty list subscribes to all
+fn parse_event_types(
+    event_types: Option<Vec<String>>,
+) -> std::result::Result<Option<HashSet<String>>, TariWalletError> {
+    let Some(event_types) = event_types.filter(|types| !types.is_empty()) else {
+        return Ok(None);
+    };
+
+    if let Some(unknown) = event_types
+        .iter()
+        .find(|event_type| !KNOWN_EVENT_TYPES.contains(&event_type.as_str()))
+    {
+        return Err(TariWalletError::new(
+            WalletErrorCode::InvalidParameter,
+            format!(
+                "Unknown event type: {} (expected one of {})",
+                unknown,
+                KNOWN_EVENT_TYPES.join(", ")
+            ),
+        )
+        .component("callbacks"));
+    }
+    Ok(Some(event_types.into_iter().collect()))
+}
+
+/// Refuse a registration for a new wallet once `callbacks` is at the limit
+fn ensure_callback_capacity<T>(
+    callbacks: &HashMap<WalletHandle, T>,
+    wallet_handle: WalletHandle,
+) -> std::result::Result<(), TariWalletError> {
+    let max = MAX_CALLBACKS.load(Ordering::Relaxed);
+    if callbacks.contains_key(&wallet_handle) || callbacks.len() < max as usize {
+        return Ok(());
+    }
+    Err(TariWalletError::new(
+        WalletErrorCode::ResourceExhausted,
+        format!(
+            "Callback limit of {} reached; remove unused callbacks before registering more",
+            max
+        ),
+    )
+    .component("callbacks"))
+}
+
+/// Remove the inbound transaction callback for a wallet
+pub fn remove_transaction_received_callback(wallet_handle: WalletHandle) {
+    if let Ok(mut callbacks) = RECEIVED_CALLBACKS.lock() {
+        if let Some(callback) = callbacks.remove(&wallet_handle) {
+            let _ = callback.abort();
+        }
+    }
+}
+
+/// Notify a wallet's inbound transaction callback, if one is registered
+pub fn notify_transaction_received(wallet_handle: WalletHandle, transaction: JsReceivedTransaction) {
+    if let Ok(callbacks) = RECEIVED_CALLBACKS.lock() {
+        if let Some(callback) = callbacks.get(&wallet_handle) {
+            callback.call(transaction, ThreadsafeFunctionCallMode::NonBlocking);
+        }
+    }
+}
+
 /// Check if a wallet has a registered event callback
 pub fn has_event_callback(wallet_handle: WalletHandle) -> bool {
     if let Ok(callbacks) = WALLET_CALLBACKS.lock() {
@@ -129,6 +421,48 @@ pub fn emit_wallet_event(
     Ok(())
 }
 
+/// Forward a wallet's bus events to its callbacks until the wallet is dropped
+///
+/// Every event goes to the output manager event callback. Only sends go to
+/// the event callback; receives and syncs already notify it from the
+/// transaction monitor and the sync loop.
+pub(crate) async fn forward_bus_events(
+    wallet_handle: WalletHandle,
+    mut events: tokio::sync::broadcast::Receiver<WalletEvent>,
+) {
+    use tokio::sync::broadcast::error::RecvError;
+
+    loop {
+        let event = match events.recv().await {
+            Ok(event) => event,
+            Err(RecvError::Lagged(missed)) => {
+                tracing::warn!("Wallet {} event callback missed {} events", wallet_handle, missed);
+                continue;
+            }
+            Err(RecvError::Closed) => return,
+        };
+        if let Ok(callbacks) = OUTPUT_EVENT_CALLBACKS.lock() {
+            if let Some(callback) = callbacks.get(&wallet_handle) {
+                callback.call(event.clone().into(), ThreadsafeFunctionCallMode::NonBlocking);
+            }
+        }
+        if !has_event_callback(wallet_handle) {
+            continue;
+        }
+        if let WalletEvent::TransactionSent { tx_id, amount } = event {
+            let data = serde_json::json!({
+                "id": tx_id,
+                "amount": amount.to_string(),
+                "status": "broadcast",
+                "isInbound": false,
+                "timestamp": chrono::Utc::now().timestamp_millis(),
+                "confirmations": 0
+            });
+            let _ = emit_wallet_event(wallet_handle, "tx:broadcast", data);
+        }
+    }
+}
+
 /// Emit event directly to callback (synchronous, for urgent events)
 pub fn emit_wallet_event_direct(
     wallet_handle: WalletHandle,
@@ -147,8 +481,10 @@ pub fn emit_wallet_event_direct(
     })?;
 
     if let Some(callback) = callbacks.get(&wallet_handle) {
-        // Call the JavaScript callback directly (non-blocking)
-        callback.call(Ok(payload), ThreadsafeFunctionCallMode::NonBlocking);
+        if callback.wants(&payload.event_type) {
+            // Call the JavaScript callback directly (non-blocking)
+            callback.function.call(Ok(payload), ThreadsafeFunctionCallMode::NonBlocking);
+        }
     }
 
     Ok(())
@@ -187,8 +523,10 @@ async fn process_event_payload(payload: EventPayload) {
     };
 
     if let Some(callback) = callbacks.get(&payload.wallet_handle) {
-        // Call the JavaScript callback with the event payload
-        callback.call(Ok(payload), ThreadsafeFunctionCallMode::NonBlocking);
+        if callback.wants(&payload.event_type) {
+            // Call the JavaScript callback with the event payload
+            callback.function.call(Ok(payload), ThreadsafeFunctionCallMode::NonBlocking);
+        }
     }
 }
 
@@ -221,7 +559,18 @@ pub fn cleanup_all_callbacks() -> Result<()> {
 
     // Abort all ThreadsafeFunctions
     for (_, callback) in callbacks.drain() {
-        let _ = callback.abort(); // Ignore errors during cleanup
+        let _ = callback.function.abort(); // Ignore errors during cleanup
+    }
+
+    if let Ok(mut received) = RECEIVED_CALLBACKS.lock() {
+        for (_, callback) in received.drain() {
+            let _ = callback.abort();
+        }
+    }
+    if let Ok(mut output_events) = OUTPUT_EVENT_CALLBACKS.lock() {
+        for (_, callback) in output_events.drain() {
+            let _ = callback.abort();
+        }
     }
 
     // Clear event emitter
@@ -254,6 +603,64 @@ mod tests {
         assert_eq!(payload.timestamp, deserialized.timestamp);
     }
 
+    #[test]
+    fn test_registrations_beyond_the_limit_fail() {
+        let mut callbacks: HashMap<WalletHandle, ()> = HashMap::new();
+        for handle in 0..DEFAULT_MAX_CALLBACKS as WalletHandle {
+            ensure_callback_capacity(&callbacks, handle).unwrap();
+            callbacks.insert(handle, ());
+        }
+
+        let error = ensure_callback_capacity(&callbacks, DEFAULT_MAX_CALLBACKS as WalletHandle).unwrap_err();
+        assert_eq!(error.code, WalletErrorCode::ResourceExhausted);
+        // A wallet replacing its own callback does not add to the count
+        assert!(ensure_callback_capacity(&callbacks, 0).is_ok());
+
+        assert!(set_max_callbacks(0).is_err());
+    }
+
+    #[test]
+    fn test_output_manager_events_map_to_stable_kinds() {
+        let spent = JsOutputManagerEvent::from(WalletEvent::TransactionSent {
+            tx_id: "tx_1".to_string(),
+            amount: 1000,
+        });
+        assert_eq!(spent.kind, 2);
+        assert_eq!(spent.tx_id.as_deref(), Some("tx_1"));
+        assert_eq!(spent.amount.as_deref(), Some("1000"));
+
+        let confirmed = JsOutputManagerEvent::from(WalletEvent::TransactionConfirmed {
+            tx_id: "tx_2".to_string(),
+        });
+        assert_eq!(confirmed.kind, JsOutputEventKind::OutputConfirmed as i32);
+        assert_eq!(confirmed.amount, None);
+
+        let rolled_back = JsOutputManagerEvent::from(WalletEvent::BlocksRolledBack { height: 42 });
+        assert_eq!(rolled_back.kind, JsOutputEventKind::BalanceUpdated as i32);
+        assert_eq!(rolled_back.height, Some(42));
+        assert_eq!(
+            JsOutputManagerEvent::from(WalletEvent::OutputsChanged),
+            JsOutputManagerEvent::new(JsOutputEventKind::BalanceUpdated)
+        );
+    }
+
+    #[test]
+    fn test_event_types_are_validated() {
+        assert_eq!(parse_event_types(None).unwrap(), None);
+        assert_eq!(parse_event_types(Some(vec![])).unwrap(), None);
+
+        let types = parse_event_types(Some(vec!["tx:received".to_string()]))
+            .unwrap()
+            .unwrap();
+        assert!(types.contains("tx:received"));
+        assert!(!types.contains("balance:updated"));
+
+        let error = parse_event_types(Some(vec!["tx:received".to_string(), "tx:recieved".to_string()]))
+            .unwrap_err();
+        assert_eq!(error.code, WalletErrorCode::InvalidParameter);
+        assert!(error.message.contains("tx:recieved"));
+    }
+
     #[test]
     fn test_has_event_callback() {
         // Should return false for non-existent wallet
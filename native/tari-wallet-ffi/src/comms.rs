@@ -0,0 +1,235 @@
+/**
+ * Comms stack settings from the wallet config
+ *
+ * Peers are dialled over plain TCP unless the config selects the Tor
+ * transport. Then every connection is made through a SOCKS5 proxy,
+ * normally the local Tor daemon, so the wallet's IP address is not
+ * revealed to the base nodes it talks to.
+ *
+ * The listener for inbound peer connections binds to loopback unless the
+ * config names another address; a desktop wallet has no need to accept
+ * connections from the rest of the network.
+ */
+
+use std::fmt;
+use std::net::SocketAddr;
+
+use crate::connectivity::is_dns_name;
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+use crate::types::JsTorSocksProxy;
+
+/// Proxy used by the Tor transport unless `tor_socks_proxy.address` is set
+pub(crate) const DEFAULT_TOR_SOCKS_ADDRESS: &str = "127.0.0.1:9050";
+
+/// Address the listener binds to unless `listener_address` is set
+pub(crate) const DEFAULT_LISTENER_ADDRESS: &str = "127.0.0.1:18188";
+
+/// Longest SOCKS5 username or password (RFC 1929)
+const MAX_SOCKS_CREDENTIAL_LEN: usize = 255;
+
+fn invalid(message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(WalletErrorCode::InvalidConfig, message).component("comms")
+}
+
+/// How the comms stack reaches peers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Transport {
+    Tcp,
+    Tor(SocksProxy),
+}
+
+/// A SOCKS5 proxy and the credentials to use with it, if it needs them
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct SocksProxy {
+    pub(crate) address: String,
+    pub(crate) auth: Option<(String, String)>,
+}
+
+// Written by hand so the password cannot end up in a log
+impl fmt::Debug for SocksProxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SocksProxy")
+            .field("address", &self.address)
+            .field("username", &self.auth.as_ref().map(|(username, _)| username))
+            .finish()
+    }
+}
+
+impl fmt::Display for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp => write!(f, "tcp"),
+            Self::Tor(proxy) if proxy.auth.is_some() => {
+                write!(f, "tor via {} (authenticated)", proxy.address)
+            }
+            Self::Tor(proxy) => write!(f, "tor via {}", proxy.address),
+        }
+    }
+}
+
+impl Transport {
+    /// The transport named by `transport`, "tcp" if unset, or "tor"
+    ///
+    /// A proxy is only accepted with the Tor transport, so a proxy set
+    /// without it is not silently left unused.
+    pub(crate) fn parse(
+        transport: Option<&str>,
+        proxy: Option<&JsTorSocksProxy>,
+    ) -> TariResult<Self> {
+        match (transport.unwrap_or("tcp"), proxy) {
+            ("tcp", None) => Ok(Self::Tcp),
+            ("tcp", Some(_)) => Err(invalid(
+                "A Tor SOCKS proxy is only used with the tor transport",
+            )),
+            ("tor", proxy) => Ok(Self::Tor(SocksProxy::parse(proxy)?)),
+            (other, _) => Err(invalid(format!(
+                "Invalid transport: {} (expected tcp or tor)",
+                other
+            ))),
+        }
+    }
+
+    /// Whether a peer at `multiaddr` can be dialled over this transport
+    ///
+    /// Onion addresses are only reachable through Tor.
+    pub(crate) fn can_reach(&self, multiaddr: &str) -> bool {
+        matches!(self, Self::Tor(_)) || !multiaddr.starts_with("/onion3/")
+    }
+}
+
+impl SocksProxy {
+    fn parse(proxy: Option<&JsTorSocksProxy>) -> TariResult<Self> {
+        let address = proxy
+            .and_then(|proxy| proxy.address.clone())
+            .unwrap_or_else(|| DEFAULT_TOR_SOCKS_ADDRESS.to_string());
+        validate_proxy_address(&address)?;
+
+        let auth = match proxy.map(|proxy| (&proxy.username, &proxy.password)) {
+            None | Some((None, None)) => None,
+            Some((Some(username), Some(password))) => {
+                for credential in [username, password] {
+                    if !(1..=MAX_SOCKS_CREDENTIAL_LEN).contains(&credential.len()) {
+                        return Err(invalid(format!(
+                            "SOCKS username and password must be 1 to {} bytes",
+                            MAX_SOCKS_CREDENTIAL_LEN
+                        )));
+                    }
+                }
+                Some((username.clone(), password.clone()))
+            }
+            Some(_) => {
+                return Err(invalid(
+                    "SOCKS username and password must be given together",
+                ))
+            }
+        };
+
+        Ok(Self { address, auth })
+    }
+}
+
+/// The socket address the listener binds to, `<ip>:<port>`
+///
+/// Port 0 lets the OS pick a free port.
+pub(crate) fn parse_listener_address(address: Option<&str>) -> TariResult<SocketAddr> {
+    let address = address.unwrap_or(DEFAULT_LISTENER_ADDRESS);
+    address.parse::<SocketAddr>().map_err(|_| {
+        invalid(format!(
+            "Invalid listener address: {} (expected ip:port, e.g. {})",
+            address, DEFAULT_LISTENER_ADDRESS
+        ))
+    })
+}
+
+/// Check a proxy address is `<ip>:<port>` or `<hostname>:<port>`
+fn validate_proxy_address(address: &str) -> TariResult<()> {
+    let valid = match address.parse::<SocketAddr>() {
+        Ok(socket) => socket.port() != 0,
+        Err(_) => address.rsplit_once(':').is_some_and(|(host, port)| {
+            is_dns_name(host) && port.parse::<u16>().is_ok_and(|port| port != 0)
+        }),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(invalid(format!(
+            "Invalid SOCKS proxy address: {} (expected host:port)",
+            address
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proxy(address: Option<&str>, username: Option<&str>, password: Option<&str>) -> JsTorSocksProxy {
+        JsTorSocksProxy {
+            address: address.map(str::to_string),
+            username: username.map(str::to_string),
+            password: password.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_tor_transport_uses_the_socks_proxy() {
+        assert_eq!(Transport::parse(None, None).unwrap(), Transport::Tcp);
+
+        let Transport::Tor(default) = Transport::parse(Some("tor"), None).unwrap() else {
+            panic!("tor transport not selected");
+        };
+        assert_eq!(default.address, DEFAULT_TOR_SOCKS_ADDRESS);
+        assert_eq!(default.auth, None);
+
+        let authenticated = proxy(Some("tor-proxy.local:9150"), Some("wallet"), Some("secret"));
+        let transport = Transport::parse(Some("tor"), Some(&authenticated)).unwrap();
+        assert_eq!(transport.to_string(), "tor via tor-proxy.local:9150 (authenticated)");
+        assert!(!format!("{:?}", transport).contains("secret"));
+
+        let ipv6 = proxy(Some("[::1]:9050"), None, None);
+        assert!(Transport::parse(Some("tor"), Some(&ipv6)).is_ok());
+
+        let onion = format!("/onion3/{}:18141", "a".repeat(56));
+        assert!(transport.can_reach(&onion));
+        assert!(!Transport::Tcp.can_reach(&onion));
+        assert!(Transport::Tcp.can_reach("/ip4/127.0.0.1/tcp/18189"));
+    }
+
+    #[test]
+    fn test_listener_defaults_to_loopback() {
+        let default = parse_listener_address(None).unwrap();
+        assert!(default.ip().is_loopback());
+        assert_eq!(default.port(), 18188);
+
+        assert_eq!(
+            parse_listener_address(Some("[::1]:0")).unwrap(),
+            "[::1]:0".parse::<SocketAddr>().unwrap()
+        );
+        assert!(parse_listener_address(Some("0.0.0.0:18188")).is_ok());
+
+        for address in ["127.0.0.1", "localhost:18188", "/ip4/127.0.0.1/tcp/18188", "127.0.0.1:70000"] {
+            let error = parse_listener_address(Some(address)).unwrap_err();
+            assert!(error.message.contains("Invalid listener address"), "{}", address);
+            assert_eq!(error.code, WalletErrorCode::InvalidConfig);
+        }
+    }
+
+    #[test]
+    fn test_invalid_transports_are_rejected() {
+        let rejected = [
+            (Some("udp"), proxy(None, None, None), "Invalid transport"),
+            (Some("tcp"), proxy(None, None, None), "only used with the tor transport"),
+            (Some("tor"), proxy(Some("127.0.0.1"), None, None), "Invalid SOCKS proxy address"),
+            (Some("tor"), proxy(Some("127.0.0.1:0"), None, None), "Invalid SOCKS proxy address"),
+            (Some("tor"), proxy(Some("bad host:9050"), None, None), "Invalid SOCKS proxy address"),
+            (Some("tor"), proxy(None, Some("wallet"), None), "given together"),
+            (Some("tor"), proxy(None, Some(""), Some("secret")), "1 to 255 bytes"),
+        ];
+        for (transport, proxy, reason) in rejected {
+            let error = Transport::parse(transport, Some(&proxy)).unwrap_err();
+            assert!(error.message.contains(reason), "{}", error.message);
+            assert_eq!(error.code, WalletErrorCode::InvalidConfig);
+        }
+    }
+}
@@ -0,0 +1,1259 @@
+/**
+ * Base node connection tracking for wallet instances
+ *
+ * Each wallet keeps a pool of known base nodes, one of which is the
+ * active node used for chain queries and transaction submission. The
+ * pool summarises its state as an online/connecting/offline status and
+ * notifies a listener whenever that status changes.
+ *
+ * Every node also carries a reputation score, raised by successful
+ * connections and lowered by failures. Scores outlive the connection,
+ * are saved in the wallet's storage directory between sessions, and
+ * are weighed against latency when choosing the active node. A ban
+ * may be temporary; once it expires the node is treated as unbanned,
+ * and a periodic sweep lifts the ban back to the lowest reputation.
+ *
+ * A health sweep dials every known node concurrently, a few at a time,
+ * so one unresponsive node cannot hold up the others. Connecting to a
+ * single node retries failed dials with backoff set by a `RetryPolicy`.
+ */
+
+use napi_derive::napi;
+use rand::Rng;
+use std::collections::HashMap;
+use std::fs;
+use std::future::Future;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tari_crypto::ristretto::RistrettoPublicKey;
+use tari_crypto::tari_utilities::hex::Hex;
+
+use crate::comms::Transport;
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+use crate::types::JsRetryPolicy;
+
+/// Maximum number of base nodes a wallet keeps connections to
+const MAX_BASE_NODE_CONNECTIONS: u32 = 8;
+
+/// Separator between public key and address in a base node peer string
+const PEER_SEPARATOR: &str = "::";
+
+/// Reputation of a base node the wallet has not dealt with before
+const INITIAL_REPUTATION: i32 = 0;
+
+/// Reputation gained for a successful connection
+const SUCCESS_REWARD: i32 = 1;
+
+/// Reputation lost for a failed connection
+const FAILURE_PENALTY: i32 = 5;
+
+const MAX_REPUTATION: i32 = 100;
+
+/// Lowest reputation failures alone can push a node to
+const MIN_REPUTATION: i32 = -50;
+
+/// Reputation of a banned node; banned nodes are never selected
+const BANNED_REPUTATION: i32 = -100;
+
+/// Milliseconds of latency one reputation point is worth when choosing a node
+const REPUTATION_WEIGHT_MS: i64 = 20;
+
+/// Latency assumed for a node that has not been pinged yet
+const UNMEASURED_LATENCY_MS: i64 = 250;
+
+/// File in the wallet's storage directory holding reputations between sessions
+const REPUTATION_FILE: &str = "peer_reputation.json";
+
+/// File holding when temporary bans expire, as Unix seconds by public key
+const BAN_EXPIRY_FILE: &str = "peer_ban_expiry.json";
+
+/// Base nodes dialed at once by a health sweep, bounding open sockets
+const MAX_CONCURRENT_HEALTH_CHECKS: usize = 4;
+
+/// Defaults for the `connection_retry` settings
+const DEFAULT_RETRY_ATTEMPTS: u32 = 3;
+const DEFAULT_RETRY_BASE_DELAY_MS: u32 = 500;
+const DEFAULT_RETRY_MAX_DELAY_MS: u32 = 10_000;
+const DEFAULT_RETRY_MULTIPLIER: f64 = 2.0;
+
+/// Overall connectivity of a wallet to the Tari network
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum NetworkStatus {
+    /// At least one connected base node is synced to the chain tip
+    Online,
+    /// A node is being dialed, or connected nodes are still syncing
+    Connecting,
+    #[default]
+    Offline,
+}
+
+impl NetworkStatus {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            NetworkStatus::Online => "online",
+            NetworkStatus::Connecting => "connecting",
+            NetworkStatus::Offline => "offline",
+        }
+    }
+}
+
+/// How often, and how far apart, failed attempts are retried
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RetryPolicy {
+    /// Including the first attempt
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from_config(None)
+    }
+}
+
+impl RetryPolicy {
+    pub(crate) fn from_config(config: Option<&JsRetryPolicy>) -> Self {
+        let millis =
+            |ms: Option<u32>, default: u32| Duration::from_millis(ms.unwrap_or(default) as u64);
+        Self {
+            max_attempts: config
+                .and_then(|c| c.max_attempts)
+                .unwrap_or(DEFAULT_RETRY_ATTEMPTS),
+            base_delay: millis(
+                config.and_then(|c| c.base_delay_ms),
+                DEFAULT_RETRY_BASE_DELAY_MS,
+            ),
+            max_delay: millis(
+                config.and_then(|c| c.max_delay_ms),
+                DEFAULT_RETRY_MAX_DELAY_MS,
+            ),
+            multiplier: config
+                .and_then(|c| c.multiplier)
+                .unwrap_or(DEFAULT_RETRY_MULTIPLIER),
+            jitter: config.and_then(|c| c.jitter).unwrap_or(true),
+        }
+    }
+
+    /// Wait before retry number `retry`, counting from 1
+    fn delay(&self, retry: u32) -> Duration {
+        let growth = self.multiplier.powi(retry.saturating_sub(1) as i32);
+        let delay = self
+            .base_delay
+            .mul_f64(growth.min(u32::MAX as f64))
+            .min(self.max_delay);
+
+        if self.jitter {
+            delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+        } else {
+            delay
+        }
+    }
+
+    /// Run `attempt` until it succeeds, fails with an unrecoverable error,
+    /// or runs out of attempts
+    pub(crate) async fn run<T, F, Fut>(&self, mut attempt: F) -> TariResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = TariResult<T>>,
+    {
+        let mut retry = 0;
+        loop {
+            match attempt().await {
+                Err(e) if e.code.is_recoverable() && retry + 1 < self.max_attempts => {
+                    retry += 1;
+                    tokio::time::sleep(self.delay(retry)).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Called with the new status whenever a wallet's network status changes
+pub(crate) type StatusListener = Box<dyn Fn(NetworkStatus) + Send + Sync>;
+
+/// A known base node and the state of the connection to it
+struct BaseNodeConnection {
+    public_key: String,
+    address: String,
+    connected: bool,
+    /// Whether the node has caught up with the chain tip
+    synced: bool,
+    /// Last measured round trip, once the node has been pinged
+    latency: Option<Duration>,
+}
+
+impl BaseNodeConnection {
+    /// Higher is better: reputation, less the node's latency
+    fn selection_score(&self, reputation: i32) -> i64 {
+        let latency_ms = self
+            .latency
+            .map_or(UNMEASURED_LATENCY_MS, |latency| latency.as_millis() as i64);
+        reputation as i64 * REPUTATION_WEIGHT_MS - latency_ms
+    }
+}
+
+/// Base nodes known to a single wallet
+#[derive(Default)]
+pub(crate) struct ConnectionPool {
+    nodes: Vec<BaseNodeConnection>,
+    /// Public key of the node currently in use
+    active: Option<String>,
+    /// Connection attempts in progress
+    dialing: u32,
+    status: NetworkStatus,
+    status_listener: Option<StatusListener>,
+    /// Reputation by public key, kept after a node is removed
+    reputations: HashMap<String, i32>,
+    /// When each temporary ban ends; bans without an entry are permanent
+    ban_expiries: HashMap<String, SystemTime>,
+}
+
+impl ConnectionPool {
+    /// Add or update a base node and make it the active node
+    pub(crate) fn set_active(&mut self, public_key: &str, address: &str) {
+        self.add_connected(public_key, address);
+        self.active = Some(public_key.to_string());
+    }
+
+    /// Add configured seed nodes to the pool without connecting to them
+    ///
+    /// Seeds sharing a public key with a known node are skipped; the
+    /// next health sweep dials the rest.
+    pub(crate) fn add_static_seeds(&mut self, seeds: Vec<(String, String)>) {
+        for (public_key, address) in seeds {
+            if self.nodes.iter().any(|n| n.public_key == public_key) {
+                continue;
+            }
+            self.nodes.push(BaseNodeConnection {
+                public_key,
+                address,
+                connected: false,
+                // Placeholder implementation, as for connected nodes
+                synced: true,
+                latency: None,
+            });
+        }
+    }
+
+    /// Record a connection to a base node, returning false if it was already connected
+    ///
+    /// The node becomes active if no other node is.
+    pub(crate) fn add_connected(&mut self, public_key: &str, address: &str) -> bool {
+        let connection = BaseNodeConnection {
+            public_key: public_key.to_string(),
+            address: address.to_string(),
+            connected: true,
+            // Placeholder implementation - would follow the node's chain
+            // metadata and only report synced once it reaches the tip
+            synced: true,
+            latency: None,
+        };
+
+        let newly_connected = match self.nodes.iter_mut().find(|n| n.public_key == public_key) {
+            Some(node) => {
+                let was_connected = node.connected && node.address == address;
+                *node = connection;
+                !was_connected
+            }
+            None => {
+                self.nodes.push(connection);
+                true
+            }
+        };
+
+        if self.active.is_none() && !self.is_banned(public_key) {
+            self.active = Some(public_key.to_string());
+        }
+        self.update_status();
+        newly_connected
+    }
+
+    pub(crate) fn reputation(&self, public_key: &str) -> i32 {
+        self.reputations
+            .get(public_key)
+            .copied()
+            .unwrap_or(INITIAL_REPUTATION)
+    }
+
+    /// Whether a base node is banned and its ban has not expired yet
+    pub(crate) fn is_banned(&self, public_key: &str) -> bool {
+        self.is_banned_at(public_key, SystemTime::now())
+    }
+
+    fn is_banned_at(&self, public_key: &str, now: SystemTime) -> bool {
+        self.reputation(public_key) <= BANNED_REPUTATION
+            && self
+                .ban_expiries
+                .get(public_key)
+                .is_none_or(|&expiry| expiry > now)
+    }
+
+    /// Reward a base node for a successful connection
+    ///
+    /// Banned nodes stay banned.
+    pub(crate) fn record_success(&mut self, public_key: &str) {
+        if !self.is_banned(public_key) {
+            let reputation = self.reputation(public_key);
+            self.reputations.insert(
+                public_key.to_string(),
+                (reputation + SUCCESS_REWARD).min(MAX_REPUTATION),
+            );
+        }
+    }
+
+    /// Penalise a base node for a failed connection
+    pub(crate) fn record_failure(&mut self, public_key: &str) {
+        if !self.is_banned(public_key) {
+            let reputation = self.reputation(public_key);
+            self.reputations.insert(
+                public_key.to_string(),
+                (reputation - FAILURE_PENALTY).max(MIN_REPUTATION),
+            );
+        }
+    }
+
+    /// Drop a base node to the banned reputation and stop using it
+    ///
+    /// The ban lasts for `duration`, or until the node is unbanned by hand
+    /// if that is None.
+    pub(crate) fn ban(&mut self, public_key: &str, duration: Option<Duration>) {
+        self.reputations
+            .insert(public_key.to_string(), BANNED_REPUTATION);
+        match duration {
+            Some(duration) => {
+                self.ban_expiries
+                    .insert(public_key.to_string(), SystemTime::now() + duration);
+            }
+            None => {
+                self.ban_expiries.remove(public_key);
+            }
+        }
+
+        if let Some(node) = self.nodes.iter_mut().find(|n| n.public_key == public_key) {
+            node.connected = false;
+        }
+        if self.active.as_deref() == Some(public_key) {
+            self.active = self.best_node();
+        }
+        self.update_status();
+    }
+
+    /// Lift bans that expired by `now`, returning the public keys unbanned
+    ///
+    /// Unbanned nodes start again from the lowest reputation failures can
+    /// reach, so they are only chosen when no better node is available.
+    pub(crate) fn lift_expired_bans(&mut self, now: SystemTime) -> Vec<String> {
+        let expired: Vec<String> = self
+            .ban_expiries
+            .iter()
+            .filter(|(_, &expiry)| expiry <= now)
+            .map(|(public_key, _)| public_key.clone())
+            .collect();
+
+        for public_key in &expired {
+            self.ban_expiries.remove(public_key);
+            if self.reputation(public_key) <= BANNED_REPUTATION {
+                self.reputations.insert(public_key.clone(), MIN_REPUTATION);
+            }
+        }
+        expired
+    }
+
+    /// Unbanned nodes as `(public key, address)`, for a health sweep
+    pub(crate) fn checkable_nodes(&self) -> Vec<(String, String)> {
+        self.nodes
+            .iter()
+            .filter(|n| !self.is_banned(&n.public_key))
+            .map(|n| (n.public_key.clone(), n.address.clone()))
+            .collect()
+    }
+
+    /// Update a node's connection, latency and reputation from a health check
+    ///
+    /// An unhealthy active node is replaced by the best remaining one.
+    pub(crate) fn record_health(&mut self, health: &JsBaseNodeHealth) {
+        let Some(node) = self
+            .nodes
+            .iter_mut()
+            .find(|n| n.public_key == health.public_key)
+        else {
+            return;
+        };
+        node.connected = health.healthy;
+        if let Some(latency_ms) = health.latency_ms {
+            node.latency = Some(Duration::from_secs_f64(latency_ms / 1000.0));
+        }
+
+        if health.healthy {
+            self.record_success(&health.public_key);
+        } else {
+            self.record_failure(&health.public_key);
+        }
+        if self.active.is_none() || self.active.as_deref() == Some(&health.public_key) {
+            self.active = self.best_node();
+        }
+        self.update_status();
+    }
+
+    /// The connected, unbanned node with the best reputation and latency
+    fn best_node(&self) -> Option<String> {
+        self.nodes
+            .iter()
+            .filter(|n| n.connected && !self.is_banned(&n.public_key))
+            .max_by_key(|n| n.selection_score(self.reputation(&n.public_key)))
+            .map(|n| n.public_key.clone())
+    }
+
+    /// Note that a connection attempt has started
+    pub(crate) fn begin_dial(&mut self) {
+        self.dialing += 1;
+        self.update_status();
+    }
+
+    /// Note that a connection attempt has finished, whether or not it succeeded
+    pub(crate) fn end_dial(&mut self) {
+        self.dialing = self.dialing.saturating_sub(1);
+        self.update_status();
+    }
+
+    pub(crate) fn status(&self) -> NetworkStatus {
+        self.status
+    }
+
+    /// Replace the listener notified of status changes
+    pub(crate) fn set_status_listener(&mut self, listener: StatusListener) {
+        self.status_listener = Some(listener);
+    }
+
+    fn update_status(&mut self) {
+        let status = if self.nodes.iter().any(|n| n.connected && n.synced) {
+            NetworkStatus::Online
+        } else if self.dialing > 0 || self.nodes.iter().any(|n| n.connected) {
+            NetworkStatus::Connecting
+        } else {
+            NetworkStatus::Offline
+        };
+
+        if status != self.status {
+            self.status = status;
+            if let Some(listener) = &self.status_listener {
+                listener(status);
+            }
+        }
+    }
+
+    /// Whether another connection would exceed the connection limit
+    pub(crate) fn is_full(&self, public_key: &str) -> bool {
+        let connected = self.nodes.iter().filter(|n| n.connected).count() as u32;
+        connected >= MAX_BASE_NODE_CONNECTIONS
+            && !self
+                .nodes
+                .iter()
+                .any(|n| n.connected && n.public_key == public_key)
+    }
+
+    /// Drop a base node, moving the active node to the best connected node if needed
+    pub(crate) fn remove(&mut self, public_key: &str) -> TariResult<()> {
+        let index = self
+            .nodes
+            .iter()
+            .position(|n| n.public_key == public_key)
+            .ok_or_else(|| {
+                connectivity_error(
+                    WalletErrorCode::PeerNotFound,
+                    format!("Not connected to base node {}", public_key),
+                )
+            })?;
+        self.nodes.remove(index);
+
+        if self.active.as_deref() == Some(public_key) {
+            self.active = self.best_node();
+        }
+        self.update_status();
+        Ok(())
+    }
+
+    /// Drop every connection, keeping the nodes and their reputations
+    pub(crate) fn disconnect_all(&mut self) {
+        for node in &mut self.nodes {
+            node.connected = false;
+        }
+        self.active = None;
+        self.dialing = 0;
+        self.update_status();
+    }
+
+    /// Reputations of every node scored so far, best first
+    pub(crate) fn peer_reputations(&self) -> Vec<JsPeerReputation> {
+        let mut reputations: Vec<JsPeerReputation> = self
+            .reputations
+            .iter()
+            .map(|(public_key, &score)| {
+                let node = self.nodes.iter().find(|n| &n.public_key == public_key);
+                JsPeerReputation {
+                    public_key: public_key.clone(),
+                    address: node.map(|n| n.address.clone()),
+                    score,
+                    banned: self.is_banned(public_key),
+                    banned_until: self
+                        .ban_expiries
+                        .get(public_key)
+                        .and_then(|expiry| expiry.duration_since(UNIX_EPOCH).ok())
+                        .map(|since_epoch| since_epoch.as_secs_f64()),
+                    connected: node.is_some_and(|n| n.connected),
+                }
+            })
+            .collect();
+        reputations.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.public_key.cmp(&b.public_key))
+        });
+        reputations
+    }
+
+    /// Restore reputations saved by an earlier session
+    ///
+    /// Reputation only guides node selection, so an unreadable file is
+    /// logged and ignored rather than failing wallet creation.
+    pub(crate) fn load_reputations(&mut self, storage_path: &str) {
+        let path = reputation_path(storage_path);
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return;
+        };
+
+        match serde_json::from_str::<HashMap<String, i32>>(&contents) {
+            Ok(reputations) => self.reputations = reputations,
+            Err(e) => tracing::warn!(
+                "Ignoring unreadable peer reputations {}: {}",
+                path.display(),
+                e
+            ),
+        }
+
+        let path = ban_expiry_path(storage_path);
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return;
+        };
+        match serde_json::from_str::<HashMap<String, u64>>(&contents) {
+            Ok(expiries) => {
+                self.ban_expiries = expiries
+                    .into_iter()
+                    .map(|(public_key, secs)| (public_key, UNIX_EPOCH + Duration::from_secs(secs)))
+                    .collect();
+            }
+            Err(e) => tracing::warn!(
+                "Ignoring unreadable peer ban expiries {}: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+
+    /// Save reputations for the next session
+    pub(crate) fn save_reputations(&self, storage_path: &str) -> TariResult<()> {
+        // Rounded up so a ban never ends early after a restart
+        let expiries: HashMap<&String, u64> = self
+            .ban_expiries
+            .iter()
+            .map(|(public_key, expiry)| {
+                let since_epoch = expiry.duration_since(UNIX_EPOCH).unwrap_or_default();
+                (public_key, since_epoch.as_secs() + u64::from(since_epoch.subsec_nanos() > 0))
+            })
+            .collect();
+
+        write_json(&reputation_path(storage_path), &self.reputations, "peer reputations")?;
+        write_json(&ban_expiry_path(storage_path), &expiries, "peer ban expiries")
+    }
+
+    /// Statistics for the pool, reported alongside the comms listener address
+    pub(crate) fn stats(&self, listener_address: SocketAddr) -> JsConnectionStats {
+        let latencies: Vec<f64> = self
+            .nodes
+            .iter()
+            .filter(|n| n.connected)
+            .filter_map(|n| n.latency)
+            .map(|latency| latency.as_secs_f64() * 1000.0)
+            .collect();
+
+        JsConnectionStats {
+            total_nodes: self.nodes.len() as u32,
+            connected_nodes: self.nodes.iter().filter(|n| n.connected).count() as u32,
+            active_node: self
+                .active
+                .as_ref()
+                .and_then(|key| self.nodes.iter().find(|n| &n.public_key == key))
+                .map(|n| n.address.clone()),
+            average_latency_ms: if latencies.is_empty() {
+                None
+            } else {
+                Some(latencies.iter().sum::<f64>() / latencies.len() as f64)
+            },
+            max_connections: MAX_BASE_NODE_CONNECTIONS,
+            listener_address: listener_address.to_string(),
+        }
+    }
+}
+
+fn reputation_path(storage_path: &str) -> PathBuf {
+    Path::new(storage_path).join(REPUTATION_FILE)
+}
+
+fn ban_expiry_path(storage_path: &str) -> PathBuf {
+    Path::new(storage_path).join(BAN_EXPIRY_FILE)
+}
+
+fn write_json(path: &Path, value: &impl serde::Serialize, what: &str) -> TariResult<()> {
+    let contents = serde_json::to_string(value)
+        .map_err(|e| connectivity_error(WalletErrorCode::SerializationError, e.to_string()))?;
+
+    fs::write(path, contents).map_err(|e| {
+        connectivity_error(
+            WalletErrorCode::FileAccessDenied,
+            format!("Cannot write {} {}: {}", what, path.display(), e),
+        )
+    })
+}
+
+fn connectivity_error(code: WalletErrorCode, message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(code, message).component("connectivity")
+}
+
+/// Check a multiaddr is one comms can dial
+fn validate_multiaddr(address: &str) -> bool {
+    let parts: Vec<&str> = address.split('/').collect();
+    let port_is_valid = |port: &str| port.parse::<u16>().is_ok_and(|p| p > 0);
+
+    match parts.as_slice() {
+        ["", "ip4", ip, "tcp", port] => ip.parse::<Ipv4Addr>().is_ok() && port_is_valid(port),
+        ["", "ip6", ip, "tcp", port] => ip.parse::<Ipv6Addr>().is_ok() && port_is_valid(port),
+        ["", "dns" | "dns4" | "dns6", host, "tcp", port] => !host.is_empty() && port_is_valid(port),
+        ["", "onion3", onion] => onion
+            .split_once(':')
+            .is_some_and(|(id, port)| id.len() == 56 && port_is_valid(port)),
+        _ => false,
+    }
+}
+
+/// Check a hostname is a valid DNS name
+pub(crate) fn is_dns_name(host: &str) -> bool {
+    let is_label = |label: &str| {
+        (1..=63).contains(&label.len())
+            && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+    };
+    host.len() <= 253 && host.split('.').all(is_label)
+}
+
+/// Split a `<public key>::<multiaddr>` peer string into its validated parts
+pub(crate) fn parse_base_node_peer(peer: &str) -> TariResult<(String, String)> {
+    let invalid = |reason: &str| {
+        connectivity_error(
+            WalletErrorCode::InvalidPeerAddress,
+            format!("Invalid base node peer {}: {}", peer, reason),
+        )
+    };
+
+    let (public_key, address) = peer
+        .split_once(PEER_SEPARATOR)
+        .ok_or_else(|| invalid("expected <public key>::<multiaddr>"))?;
+
+    RistrettoPublicKey::from_hex(public_key).map_err(|_| invalid("malformed public key"))?;
+    if !validate_multiaddr(address) {
+        return Err(invalid("malformed multiaddr"));
+    }
+
+    Ok((public_key.to_string(), address.to_string()))
+}
+
+/// Open a connection to a base node over `transport`, giving up after `timeout`
+pub(crate) async fn dial_base_node(
+    transport: &Transport,
+    public_key: &str,
+    address: &str,
+    timeout: Duration,
+) -> TariResult<()> {
+    if !transport.can_reach(address) {
+        return Err(connectivity_error(
+            WalletErrorCode::BaseNodeNotReachable,
+            format!("Base node {} is only reachable over the tor transport", address),
+        ));
+    }
+
+    // Placeholder implementation - would dial through the comms connectivity
+    // manager, failing with BaseNodeNotReachable if the peer refuses
+    let dial = async {
+        tracing::debug!("Dialing base node {} at {} over {}", public_key, address, transport);
+        Ok(())
+    };
+
+    with_dial_timeout(address, timeout, dial).await
+}
+
+/// Abandon a connection attempt that has not completed within `timeout`
+///
+/// Dials to unreachable addresses can otherwise hang until the OS gives up.
+async fn with_dial_timeout(
+    address: &str,
+    timeout: Duration,
+    dial: impl Future<Output = TariResult<()>>,
+) -> TariResult<()> {
+    tokio::time::timeout(timeout, dial).await.map_err(|_| {
+        connectivity_error(
+            WalletErrorCode::ConnectionTimeout,
+            format!(
+                "Connecting to base node {} timed out after {:?}",
+                address, timeout
+            ),
+        )
+    })?
+}
+
+/// Dial base nodes concurrently, finishing within `timeout` overall
+///
+/// Results are in the order of `nodes`. Nodes still waiting for a dial
+/// slot when the time runs out are reported as timed out.
+pub(crate) async fn check_base_nodes(
+    transport: Transport,
+    nodes: Vec<(String, String)>,
+    timeout: Duration,
+) -> Vec<JsBaseNodeHealth> {
+    let transport = Arc::new(transport);
+    check_nodes_with(nodes, timeout, move |public_key, address, timeout| {
+        let transport = transport.clone();
+        async move { dial_base_node(&transport, &public_key, &address, timeout).await }
+    })
+    .await
+}
+
+async fn check_nodes_with<F, Fut>(
+    nodes: Vec<(String, String)>,
+    timeout: Duration,
+    dial: F,
+) -> Vec<JsBaseNodeHealth>
+where
+    F: Fn(String, String, Duration) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = TariResult<()>> + Send + 'static,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    let permits = Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT_HEALTH_CHECKS));
+    let dial = Arc::new(dial);
+
+    let mut checks = tokio::task::JoinSet::new();
+    for (index, (public_key, address)) in nodes.iter().cloned().enumerate() {
+        let permits = permits.clone();
+        let dial = dial.clone();
+        checks.spawn(async move {
+            let check = async {
+                let _permit = permits.acquire_owned().await;
+                let started = Instant::now();
+                let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+                dial(public_key.clone(), address.clone(), remaining)
+                    .await
+                    .map(|()| started.elapsed())
+            };
+            let result = tokio::time::timeout_at(deadline, check)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(connectivity_error(
+                        WalletErrorCode::ConnectionTimeout,
+                        format!(
+                            "Health check of base node {} timed out after {:?}",
+                            address, timeout
+                        ),
+                    ))
+                });
+            (index, result)
+        });
+    }
+
+    let mut results: Vec<Option<TariResult<Duration>>> = nodes.iter().map(|_| None).collect();
+    while let Some(joined) = checks.join_next().await {
+        if let Ok((index, result)) = joined {
+            results[index] = Some(result);
+        }
+    }
+
+    nodes
+        .into_iter()
+        .zip(results)
+        .map(|((public_key, address), result)| {
+            let result = result.unwrap_or_else(|| {
+                Err(connectivity_error(
+                    WalletErrorCode::ConnectionFailed,
+                    "Health check did not complete",
+                ))
+            });
+            JsBaseNodeHealth {
+                public_key,
+                address,
+                healthy: result.is_ok(),
+                latency_ms: result
+                    .as_ref()
+                    .ok()
+                    .map(|latency| latency.as_secs_f64() * 1000.0),
+                error: result.err().map(|e| e.message),
+            }
+        })
+        .collect()
+}
+
+/// Base node connection statistics
+#[napi(object)]
+pub struct JsConnectionStats {
+    pub total_nodes: u32,
+    pub connected_nodes: u32,
+    /// Address of the node currently in use
+    pub active_node: Option<String>,
+    /// Unset until at least one connected node has been pinged
+    pub average_latency_ms: Option<f64>,
+    pub max_connections: u32,
+    /// Address the comms listener accepts peer connections on
+    pub listener_address: String,
+}
+
+/// Reputation score of a base node, for debugging node selection
+#[napi(object)]
+pub struct JsPeerReputation {
+    pub public_key: String,
+    /// Unset once the node has been removed from the pool
+    pub address: Option<String>,
+    pub score: i32,
+    pub banned: bool,
+    /// Unix time a temporary ban ends; unset for permanent bans
+    pub banned_until: Option<f64>,
+    pub connected: bool,
+}
+
+/// Outcome of checking one base node in a health sweep
+#[napi(object)]
+pub struct JsBaseNodeHealth {
+    pub public_key: String,
+    pub address: String,
+    pub healthy: bool,
+    /// Time taken to connect, when the check succeeded
+    pub latency_ms: Option<f64>,
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use crate::comms::DEFAULT_LISTENER_ADDRESS;
+
+    fn listener() -> SocketAddr {
+        DEFAULT_LISTENER_ADDRESS.parse().unwrap()
+    }
+
+    #[test]
+    fn test_stats_track_active_node() {
+        let mut pool = ConnectionPool::default();
+        let stats = pool.stats(listener());
+        assert_eq!(stats.total_nodes, 0);
+        assert!(stats.active_node.is_none());
+        assert!(stats.average_latency_ms.is_none());
+
+        pool.set_active("key_a", "/ip4/10.0.0.1/tcp/18189");
+        pool.set_active("key_b", "/ip4/10.0.0.2/tcp/18189");
+        pool.set_active("key_a", "/ip4/10.0.0.3/tcp/18189");
+        pool.nodes[0].latency = Some(Duration::from_millis(40));
+        pool.nodes[1].latency = Some(Duration::from_millis(60));
+
+        let stats = pool.stats(listener());
+        assert_eq!(stats.total_nodes, 2);
+        assert_eq!(stats.connected_nodes, 2);
+        assert_eq!(
+            stats.active_node.as_deref(),
+            Some("/ip4/10.0.0.3/tcp/18189")
+        );
+        assert_eq!(stats.average_latency_ms, Some(50.0));
+        assert_eq!(stats.max_connections, MAX_BASE_NODE_CONNECTIONS);
+    }
+
+    #[test]
+    fn test_status_transitions_notify_listener() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let mut pool = ConnectionPool::default();
+        let recorded = changes.clone();
+        pool.set_status_listener(Box::new(move |status| {
+            recorded.lock().unwrap().push(status);
+        }));
+        assert_eq!(pool.status(), NetworkStatus::Offline);
+
+        pool.begin_dial();
+        pool.end_dial();
+        pool.begin_dial();
+        pool.add_connected("key_a", "/ip4/10.0.0.1/tcp/18189");
+        pool.end_dial();
+        pool.add_connected("key_b", "/ip4/10.0.0.2/tcp/18189");
+        pool.remove("key_a").unwrap();
+        pool.remove("key_b").unwrap();
+
+        use NetworkStatus::*;
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![Connecting, Offline, Connecting, Online, Offline]
+        );
+    }
+
+    #[test]
+    fn test_disconnect_all_goes_offline() {
+        let mut pool = ConnectionPool::default();
+        pool.add_connected("key_a", "/ip4/10.0.0.1/tcp/18189");
+        pool.add_connected("key_b", "/ip4/10.0.0.2/tcp/18189");
+        pool.record_success("key_a");
+        pool.begin_dial();
+
+        pool.disconnect_all();
+        assert_eq!(pool.status(), NetworkStatus::Offline);
+        let stats = pool.stats(listener());
+        assert_eq!(stats.total_nodes, 2);
+        assert_eq!(stats.connected_nodes, 0);
+        assert!(stats.active_node.is_none());
+        assert!(pool.reputation("key_a") > pool.reputation("key_b"));
+    }
+
+    #[test]
+    fn test_parse_base_node_peer() {
+        let key = RistrettoPublicKey::default().to_hex();
+
+        let (public_key, address) =
+            parse_base_node_peer(&format!("{}::/ip4/127.0.0.1/tcp/18189", key)).unwrap();
+        assert_eq!(public_key, key);
+        assert_eq!(address, "/ip4/127.0.0.1/tcp/18189");
+
+        assert!(parse_base_node_peer(&format!("{}::/dns4/node.tari.com/tcp/18189", key)).is_ok());
+        assert!(parse_base_node_peer(&format!("{}::/ip4/300.0.0.1/tcp/18189", key)).is_err());
+        assert!(parse_base_node_peer(&format!("{}::/ip4/127.0.0.1/udp/18189", key)).is_err());
+        assert!(parse_base_node_peer("not_a_key::/ip4/127.0.0.1/tcp/18189").is_err());
+        assert!(parse_base_node_peer("/ip4/127.0.0.1/tcp/18189").is_err());
+    }
+
+    #[test]
+    fn test_remove_moves_active_node() {
+        let mut pool = ConnectionPool::default();
+        assert!(pool.add_connected("key_a", "/ip4/10.0.0.1/tcp/18189"));
+        assert!(pool.add_connected("key_b", "/ip4/10.0.0.2/tcp/18189"));
+        assert!(!pool.add_connected("key_b", "/ip4/10.0.0.2/tcp/18189"));
+
+        pool.remove("key_a").unwrap();
+        assert_eq!(
+            pool.stats(listener()).active_node.as_deref(),
+            Some("/ip4/10.0.0.2/tcp/18189")
+        );
+        assert!(pool.remove("key_a").is_err());
+
+        pool.remove("key_b").unwrap();
+        assert!(pool.stats(listener()).active_node.is_none());
+    }
+
+    #[test]
+    fn test_reputation_rewards_penalties_and_bans() {
+        let mut pool = ConnectionPool::default();
+        assert_eq!(pool.reputation("key_a"), INITIAL_REPUTATION);
+
+        pool.record_success("key_a");
+        pool.record_success("key_a");
+        pool.record_failure("key_a");
+        assert_eq!(
+            pool.reputation("key_a"),
+            2 * SUCCESS_REWARD - FAILURE_PENALTY
+        );
+
+        for _ in 0..100 {
+            pool.record_failure("key_a");
+        }
+        assert_eq!(pool.reputation("key_a"), MIN_REPUTATION);
+        assert!(!pool.is_banned("key_a"));
+
+        pool.ban("key_a", None);
+        pool.record_success("key_a");
+        assert_eq!(pool.reputation("key_a"), BANNED_REPUTATION);
+        assert!(pool.peer_reputations()[0].banned);
+    }
+
+    #[test]
+    fn test_temporary_bans_expire() {
+        let storage_path =
+            std::env::temp_dir().join(format!("tari_ban_expiry_test_{}", std::process::id()));
+        fs::create_dir_all(&storage_path).unwrap();
+        let storage_path = storage_path.to_string_lossy().to_string();
+
+        let mut pool = ConnectionPool::default();
+        pool.ban("key_a", Some(Duration::from_secs(60)));
+        pool.ban("key_b", None);
+        assert!(pool.is_banned("key_a"));
+        assert!(pool.peer_reputations().iter().all(|p| p.banned));
+        assert!(pool.peer_reputations()[0].banned_until.is_some());
+        pool.save_reputations(&storage_path).unwrap();
+
+        // Expired bans stop counting before the sweep gets to them
+        let later = SystemTime::now() + Duration::from_secs(61);
+        assert!(!pool.is_banned_at("key_a", later));
+        assert!(pool.is_banned_at("key_b", later));
+
+        assert_eq!(pool.lift_expired_bans(later), vec!["key_a".to_string()]);
+        assert_eq!(pool.reputation("key_a"), MIN_REPUTATION);
+        assert!(pool.lift_expired_bans(later).is_empty());
+
+        // The expiry survives a restart
+        let mut restored = ConnectionPool::default();
+        restored.load_reputations(&storage_path);
+        assert!(restored.is_banned("key_a"));
+        assert!(!restored.is_banned_at("key_a", later));
+        assert!(restored.is_banned_at("key_b", later));
+
+        fs::remove_dir_all(&storage_path).unwrap();
+    }
+
+    #[test]
+    fn test_best_node_weighs_reputation_against_latency() {
+        let mut pool = ConnectionPool::default();
+        pool.add_connected("key_a", "/ip4/10.0.0.1/tcp/18189");
+        pool.add_connected("key_b", "/ip4/10.0.0.2/tcp/18189");
+        pool.add_connected("key_c", "/ip4/10.0.0.3/tcp/18189");
+        pool.nodes[1].latency = Some(Duration::from_millis(50));
+        pool.nodes[2].latency = Some(Duration::from_millis(400));
+
+        // key_b is fastest
+        assert_eq!(pool.best_node().as_deref(), Some("key_b"));
+
+        // Enough reputation outweighs key_c's extra latency
+        for _ in 0..20 {
+            pool.record_success("key_c");
+        }
+        assert_eq!(pool.best_node().as_deref(), Some("key_c"));
+
+        pool.ban("key_c", None);
+        assert_eq!(pool.best_node().as_deref(), Some("key_b"));
+
+        // Removing the active node falls back to the best remaining node
+        pool.remove("key_a").unwrap();
+        assert_eq!(
+            pool.stats(listener()).active_node.as_deref(),
+            Some("/ip4/10.0.0.2/tcp/18189")
+        );
+    }
+
+    #[test]
+    fn test_reputations_persist_between_sessions() {
+        let storage_path =
+            std::env::temp_dir().join(format!("tari_reputation_test_{}", std::process::id()));
+        fs::create_dir_all(&storage_path).unwrap();
+        let storage_path = storage_path.to_string_lossy().to_string();
+
+        let mut pool = ConnectionPool::default();
+        pool.add_connected("key_a", "/ip4/10.0.0.1/tcp/18189");
+        pool.record_success("key_a");
+        pool.ban("key_b", None);
+        pool.save_reputations(&storage_path).unwrap();
+
+        let mut restored = ConnectionPool::default();
+        restored.load_reputations(&storage_path);
+        assert_eq!(restored.reputation("key_a"), SUCCESS_REWARD);
+        assert!(restored.is_banned("key_b"));
+
+        fs::write(reputation_path(&storage_path), "not json").unwrap();
+        let mut fresh = ConnectionPool::default();
+        fresh.load_reputations(&storage_path);
+        assert!(fresh.peer_reputations().is_empty());
+
+        fs::remove_dir_all(&storage_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dial_times_out_on_unroutable_address() {
+        let address = "/ip4/10.255.255.1/tcp/18189";
+        let timeout = Duration::from_millis(50);
+
+        // Stands in for a dial whose SYN is never answered
+        let started = std::time::Instant::now();
+        let error = with_dial_timeout(address, timeout, std::future::pending())
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.code, WalletErrorCode::ConnectionTimeout);
+        assert!(error.message.contains(address));
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_retry_delays_back_off_up_to_the_limit() {
+        let policy = RetryPolicy::from_config(Some(&JsRetryPolicy {
+            max_attempts: None,
+            base_delay_ms: Some(100),
+            max_delay_ms: Some(350),
+            multiplier: Some(2.0),
+            jitter: Some(false),
+        }));
+        let delays: Vec<u128> = (1..=4)
+            .map(|retry| policy.delay(retry).as_millis())
+            .collect();
+        assert_eq!(delays, vec![100, 200, 350, 350]);
+
+        let jittered = RetryPolicy {
+            jitter: true,
+            ..policy
+        };
+        for _ in 0..20 {
+            let delay = jittered.delay(2);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_retries_recoverable_failures() {
+        let policy = RetryPolicy::from_config(Some(&JsRetryPolicy {
+            max_attempts: Some(3),
+            base_delay_ms: Some(1),
+            max_delay_ms: None,
+            multiplier: None,
+            jitter: None,
+        }));
+        let attempts = Mutex::new(0);
+        let flaky = || {
+            *attempts.lock().unwrap() += 1;
+            let attempt = *attempts.lock().unwrap();
+            async move {
+                if attempt < 3 {
+                    Err(connectivity_error(
+                        WalletErrorCode::ConnectionTimeout,
+                        "timed out",
+                    ))
+                } else {
+                    Ok(attempt)
+                }
+            }
+        };
+        assert_eq!(policy.run(flaky).await.unwrap(), 3);
+
+        // Out of attempts
+        *attempts.lock().unwrap() = -10;
+        assert!(policy.run(flaky).await.is_err());
+        assert_eq!(*attempts.lock().unwrap(), -7);
+
+        // A malformed peer will not improve with retrying
+        *attempts.lock().unwrap() = 0;
+        let invalid = || {
+            *attempts.lock().unwrap() += 1;
+            async {
+                Err::<(), _>(connectivity_error(
+                    WalletErrorCode::InvalidPeerAddress,
+                    "bad",
+                ))
+            }
+        };
+        assert!(policy.run(invalid).await.is_err());
+        assert_eq!(*attempts.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_health_checks_run_concurrently() {
+        let nodes: Vec<(String, String)> = (0..MAX_CONCURRENT_HEALTH_CHECKS)
+            .map(|i| (format!("key{}", i), format!("/ip4/10.0.0.{}/tcp/18189", i)))
+            .collect();
+        let slow_dial = |public_key: String, _: String, _: Duration| async move {
+            if public_key == "key0" {
+                // Never answers
+                std::future::pending::<()>().await;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok(())
+        };
+
+        // Sequential checks would take the hung node's timeout plus 300ms
+        let started = std::time::Instant::now();
+        let results = check_nodes_with(nodes, Duration::from_millis(300), slow_dial).await;
+        let elapsed = started.elapsed();
+
+        assert!(elapsed >= Duration::from_millis(300));
+        assert!(elapsed < Duration::from_millis(550), "took {:?}", elapsed);
+        assert!(!results[0].healthy);
+        assert!(results[0].error.as_ref().unwrap().contains("timed out"));
+        assert!(results[1..]
+            .iter()
+            .all(|r| r.healthy && r.latency_ms.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_health_checks_bound_concurrency() {
+        let nodes: Vec<(String, String)> = (0..MAX_CONCURRENT_HEALTH_CHECKS * 2)
+            .map(|i| (format!("key{}", i), format!("/ip4/10.0.0.{}/tcp/18189", i)))
+            .collect();
+        let in_flight = Arc::new(Mutex::new((0usize, 0usize)));
+        let dial = {
+            let in_flight = in_flight.clone();
+            move |_: String, _: String, _: Duration| {
+                let in_flight = in_flight.clone();
+                async move {
+                    {
+                        let mut counts = in_flight.lock().unwrap();
+                        counts.0 += 1;
+                        counts.1 = counts.1.max(counts.0);
+                    }
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    in_flight.lock().unwrap().0 -= 1;
+                    Ok(())
+                }
+            }
+        };
+
+        let results = check_nodes_with(nodes, Duration::from_secs(5), dial).await;
+
+        assert!(results.iter().all(|r| r.healthy));
+        assert_eq!(in_flight.lock().unwrap().1, MAX_CONCURRENT_HEALTH_CHECKS);
+    }
+
+    #[test]
+    fn test_static_seeds_are_deduplicated_by_public_key() {
+        let mut pool = ConnectionPool::default();
+        pool.set_active("a", "/ip4/10.0.0.1/tcp/18189");
+
+        pool.add_static_seeds(vec![
+            ("a".to_string(), "/ip4/10.0.0.9/tcp/18189".to_string()),
+            ("b".to_string(), "/ip4/10.0.0.2/tcp/18189".to_string()),
+            ("b".to_string(), "/ip4/10.0.0.3/tcp/18189".to_string()),
+        ]);
+
+        assert_eq!(
+            pool.checkable_nodes(),
+            vec![
+                ("a".to_string(), "/ip4/10.0.0.1/tcp/18189".to_string()),
+                ("b".to_string(), "/ip4/10.0.0.2/tcp/18189".to_string()),
+            ]
+        );
+        // Seeds are not connected until dialed
+        assert_eq!(pool.stats(listener()).connected_nodes, 1);
+    }
+
+    #[test]
+    fn test_unhealthy_active_node_is_replaced() {
+        let mut pool = ConnectionPool::default();
+        pool.set_active("a", "/ip4/10.0.0.1/tcp/18189");
+        pool.add_connected("b", "/ip4/10.0.0.2/tcp/18189");
+
+        let health = |public_key: &str, healthy: bool| JsBaseNodeHealth {
+            public_key: public_key.to_string(),
+            address: String::new(),
+            healthy,
+            latency_ms: healthy.then_some(10.0),
+            error: None,
+        };
+        pool.record_health(&health("a", false));
+        pool.record_health(&health("b", true));
+
+        assert_eq!(pool.active.as_deref(), Some("b"));
+        assert!(pool.reputation("a") < pool.reputation("b"));
+        assert_eq!(pool.status(), NetworkStatus::Online);
+    }
+}
@@ -0,0 +1,677 @@
+/**
+ * Cryptographic primitives for the Tari wallet FFI
+ *
+ * Keys are kept on the Rust side behind opaque handles so secret material
+ * only crosses the FFI boundary when it is explicitly exported.
+ */
+
+use blake2::{Blake2b512, Digest};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Mutex;
+use tari_crypto::commitment::HomomorphicCommitmentFactory;
+use tari_crypto::dhke::DiffieHellmanSharedSecret;
+use tari_crypto::errors::RangeProofError;
+use tari_crypto::keys::{PublicKey, SecretKey};
+use tari_crypto::range_proof::RangeProofService;
+use tari_crypto::ristretto::bulletproofs_plus::BulletproofsPlusService;
+use tari_crypto::ristretto::pedersen::extended_commitment_factory::ExtendedPedersenCommitmentFactory;
+use tari_crypto::ristretto::pedersen::PedersenCommitment;
+use tari_crypto::ristretto::{
+    RistrettoComAndPubSig, RistrettoPublicKey, RistrettoSchnorr, RistrettoSecretKey,
+};
+use tari_crypto::tari_utilities::hex::{self, Hex};
+use tari_crypto::tari_utilities::ByteArray;
+
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+
+/// Opaque handle to a private key held by the FFI
+pub type PrivateKeyHandle = i64;
+
+/// Opaque handle to a public key held by the FFI
+pub type PublicKeyHandle = i64;
+
+/// Length of a hex-encoded Schnorr signature (public nonce + signature scalar)
+const SIGNATURE_HEX_LENGTH: usize = 128;
+
+/// Bit length of the Bulletproofs+ range proofs attached to output values
+const RANGE_PROOF_BIT_LENGTH: usize = 64;
+
+/// Length of a hex-encoded metadata signature: ephemeral commitment,
+/// ephemeral public key and the three response scalars
+const METADATA_SIGNATURE_HEX_LENGTH: usize = 320;
+
+/// Domain separator for output metadata signature challenges
+const METADATA_SIGNATURE_DOMAIN: &[u8] = b"com.tari.sdk.output.metadata_signature";
+
+static PRIVATE_KEYS: Lazy<Mutex<HashMap<PrivateKeyHandle, RistrettoSecretKey>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static PUBLIC_KEYS: Lazy<Mutex<HashMap<PublicKeyHandle, RistrettoPublicKey>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Handles are shared between private and public keys so they are never confused
+static NEXT_KEY_HANDLE: AtomicI64 = AtomicI64::new(1);
+
+fn generate_key_handle() -> i64 {
+    NEXT_KEY_HANDLE.fetch_add(1, Ordering::SeqCst)
+}
+
+fn lock_error() -> TariWalletError {
+    TariWalletError::new(WalletErrorCode::ThreadingError, "Failed to acquire key store lock")
+        .component("crypto")
+}
+
+fn invalid_key_handle(handle: i64) -> TariWalletError {
+    TariWalletError::new(
+        WalletErrorCode::InvalidHandle,
+        format!("Invalid key handle: {}", handle),
+    )
+    .component("crypto")
+}
+
+/// Store a private key and return its handle
+pub(crate) fn insert_private_key(key: RistrettoSecretKey) -> TariResult<PrivateKeyHandle> {
+    let handle = generate_key_handle();
+    PRIVATE_KEYS.lock().map_err(|_| lock_error())?.insert(handle, key);
+    Ok(handle)
+}
+
+/// Store a public key and return its handle
+pub(crate) fn insert_public_key(key: RistrettoPublicKey) -> TariResult<PublicKeyHandle> {
+    let handle = generate_key_handle();
+    PUBLIC_KEYS.lock().map_err(|_| lock_error())?.insert(handle, key);
+    Ok(handle)
+}
+
+/// Look up a private key by handle
+pub(crate) fn get_private_key(handle: PrivateKeyHandle) -> TariResult<RistrettoSecretKey> {
+    PRIVATE_KEYS
+        .lock()
+        .map_err(|_| lock_error())?
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| invalid_key_handle(handle))
+}
+
+/// Look up a public key by handle
+pub(crate) fn get_public_key(handle: PublicKeyHandle) -> TariResult<RistrettoPublicKey> {
+    PUBLIC_KEYS
+        .lock()
+        .map_err(|_| lock_error())?
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| invalid_key_handle(handle))
+}
+
+/// Generate a new random private key
+#[napi]
+pub fn private_key_generate() -> Result<PrivateKeyHandle> {
+    let key = RistrettoSecretKey::random(&mut rand::thread_rng());
+    Ok(insert_private_key(key)?)
+}
+
+/// Load a private key from its hex representation
+#[napi]
+pub fn private_key_from_hex(hex: String) -> Result<PrivateKeyHandle> {
+    let key = RistrettoSecretKey::from_hex(&hex).map_err(|e| {
+        TariWalletError::new(WalletErrorCode::InvalidKey, format!("Invalid private key: {}", e))
+            .component("crypto")
+    })?;
+    Ok(insert_private_key(key)?)
+}
+
+/// Destroy a private key handle
+#[napi]
+pub fn private_key_destroy(handle: PrivateKeyHandle) -> Result<()> {
+    PRIVATE_KEYS
+        .lock()
+        .map_err(|_| lock_error())?
+        .remove(&handle)
+        .map(|_| ())
+        .ok_or_else(|| invalid_key_handle(handle).into())
+}
+
+/// Derive the public key for a private key
+#[napi]
+pub fn public_key_from_private_key(private_key_handle: PrivateKeyHandle) -> Result<PublicKeyHandle> {
+    let private_key = get_private_key(private_key_handle)?;
+    Ok(insert_public_key(RistrettoPublicKey::from_secret_key(&private_key))?)
+}
+
+/// Load a public key from its hex representation
+#[napi]
+pub fn public_key_from_hex(hex: String) -> Result<PublicKeyHandle> {
+    let key = RistrettoPublicKey::from_hex(&hex).map_err(|e| {
+        TariWalletError::new(WalletErrorCode::InvalidKey, format!("Invalid public key: {}", e))
+            .component("crypto")
+    })?;
+    Ok(insert_public_key(key)?)
+}
+
+/// Get the hex representation of a public key
+#[napi]
+pub fn public_key_to_hex(handle: PublicKeyHandle) -> Result<String> {
+    Ok(get_public_key(handle)?.to_hex())
+}
+
+/// Destroy a public key handle
+#[napi]
+pub fn public_key_destroy(handle: PublicKeyHandle) -> Result<()> {
+    PUBLIC_KEYS
+        .lock()
+        .map_err(|_| lock_error())?
+        .remove(&handle)
+        .map(|_| ())
+        .ok_or_else(|| invalid_key_handle(handle).into())
+}
+
+/// Sign an arbitrary message with a Ristretto Schnorr signature
+///
+/// The signature is returned as hex: the 32-byte public nonce followed by
+/// the 32-byte signature scalar.
+#[napi]
+pub fn sign_message(private_key_handle: PrivateKeyHandle, message: Vec<u8>) -> Result<String> {
+    let private_key = get_private_key(private_key_handle)?;
+
+    let signature = RistrettoSchnorr::sign(&private_key, &message, &mut rand::thread_rng())
+        .map_err(|e| {
+            TariWalletError::new(WalletErrorCode::SigningFailed, format!("Failed to sign message: {}", e))
+                .component("crypto")
+        })?;
+
+    Ok(signature_to_hex(&signature))
+}
+
+/// Verify a signature produced by `sign_message`
+///
+/// Returns `false` for a well-formed signature that does not match; a
+/// malformed signature string is reported as an error.
+#[napi]
+pub fn verify_message(
+    public_key_handle: PublicKeyHandle,
+    message: Vec<u8>,
+    signature: String,
+) -> Result<bool> {
+    let public_key = get_public_key(public_key_handle)?;
+    let signature = parse_signature(&signature)?;
+
+    Ok(signature.verify(&public_key, &message))
+}
+
+/// Derive the ECDH shared secret between a private key and a public key
+///
+/// This is the raw shared secret used when constructing one-sided payments,
+/// so both parties can independently recompute scan keys from it.
+#[napi]
+pub fn diffie_hellman(
+    private_key_handle: PrivateKeyHandle,
+    public_key_handle: PublicKeyHandle,
+) -> Result<String> {
+    let private_key = get_private_key(private_key_handle)?;
+    let public_key = get_public_key(public_key_handle)?;
+
+    let shared_secret = DiffieHellmanSharedSecret::<RistrettoPublicKey>::new(&private_key, &public_key);
+    Ok(hex::to_hex(shared_secret.as_bytes()))
+}
+
+/// Domain separator for MuSig key aggregation coefficients
+const KEY_AGGREGATION_DOMAIN: &[u8] = b"com.tari.sdk.musig.key_aggregation";
+
+/// Combine the public keys of a multisig group into their joint public key
+///
+/// Uses MuSig key aggregation: each key is weighted by a coefficient
+/// hashed from the whole group, so no member can choose their key to
+/// cancel out the others'. Keys are sorted first, so every member gets the
+/// same joint key whatever order they list the group in.
+#[napi]
+pub fn aggregate_public_keys(handles: Vec<PublicKeyHandle>) -> Result<PublicKeyHandle> {
+    if handles.len() < 2 {
+        return Err(TariWalletError::new(
+            WalletErrorCode::InvalidParameter,
+            format!("Key aggregation needs at least two public keys, got {}", handles.len()),
+        )
+        .component("crypto")
+        .into());
+    }
+
+    let keys = handles
+        .into_iter()
+        .map(get_public_key)
+        .collect::<TariResult<Vec<_>>>()?;
+    Ok(insert_public_key(aggregate_keys(keys)?)?)
+}
+
+/// MuSig joint public key: the sum of each key times its aggregation coefficient
+fn aggregate_keys(mut keys: Vec<RistrettoPublicKey>) -> TariResult<RistrettoPublicKey> {
+    keys.sort();
+    let mut group_hasher = Blake2b512::new();
+    group_hasher.update(KEY_AGGREGATION_DOMAIN);
+    for key in &keys {
+        group_hasher.update(key.as_bytes());
+    }
+    let group_hash = group_hasher.finalize();
+
+    keys.iter().try_fold(RistrettoPublicKey::default(), |joint, key| {
+        let coefficient = aggregation_coefficient(&group_hash, key)?;
+        Ok(&joint + &(&coefficient * key))
+    })
+}
+
+/// MuSig coefficient H(domain || H(group) || key) for one member's key
+fn aggregation_coefficient(
+    group_hash: &[u8],
+    key: &RistrettoPublicKey,
+) -> TariResult<RistrettoSecretKey> {
+    let digest = Blake2b512::new()
+        .chain_update(KEY_AGGREGATION_DOMAIN)
+        .chain_update(group_hash)
+        .chain_update(key.as_bytes())
+        .finalize();
+    RistrettoSecretKey::from_uniform_bytes(&digest).map_err(|e| {
+        TariWalletError::new(
+            WalletErrorCode::CryptoError,
+            format!("Failed to derive key aggregation coefficient: {}", e),
+        )
+        .component("crypto")
+    })
+}
+
+/// Compute the Pedersen commitment to a µT value under a hex blinding factor
+///
+/// Returns the commitment as hex, as it appears in a UTXO.
+#[napi]
+pub fn commitment_open(value: String, blinding_factor: String) -> Result<String> {
+    let value = parse_commitment_value(&value)?;
+    let blinding_factor = parse_blinding_factor(&blinding_factor)?;
+
+    let factory = ExtendedPedersenCommitmentFactory::default();
+    Ok(factory.commit_value(&blinding_factor, value).to_hex())
+}
+
+/// Check that a hex commitment opens to a µT value under a blinding factor
+///
+/// Returns `false` for a well-formed commitment to something else; a
+/// malformed commitment, value or blinding factor is reported as an error.
+#[napi]
+pub fn commitment_verify(commitment: String, value: String, blinding_factor: String) -> Result<bool> {
+    let value = parse_commitment_value(&value)?;
+    Ok(commitment_opens(&commitment, value, &blinding_factor)?)
+}
+
+/// Whether a hex commitment opens to `value` under a hex blinding factor
+pub(crate) fn commitment_opens(commitment: &str, value: u64, blinding_factor: &str) -> TariResult<bool> {
+    let commitment = parse_commitment(commitment)?;
+    let blinding_factor = parse_blinding_factor(blinding_factor)?;
+
+    let factory = ExtendedPedersenCommitmentFactory::default();
+    Ok(factory.open_value(&blinding_factor, value, &commitment))
+}
+
+/// Parse a hex Pedersen commitment, as it appears in a UTXO
+pub(crate) fn parse_commitment(commitment: &str) -> TariResult<PedersenCommitment> {
+    PedersenCommitment::from_hex(commitment).map_err(|e| {
+        TariWalletError::new(WalletErrorCode::InvalidFormat, format!("Invalid commitment: {}", e))
+            .component("crypto")
+    })
+}
+
+fn parse_commitment_value(value: &str) -> TariResult<u64> {
+    value.parse::<u64>().map_err(|_| {
+        TariWalletError::new(WalletErrorCode::InvalidAmount, format!("Invalid value: {}", value))
+            .component("crypto")
+    })
+}
+
+fn parse_blinding_factor(blinding_factor: &str) -> TariResult<RistrettoSecretKey> {
+    RistrettoSecretKey::from_hex(blinding_factor).map_err(|e| {
+        TariWalletError::new(WalletErrorCode::InvalidKey, format!("Invalid blinding factor: {}", e))
+            .component("crypto")
+    })
+}
+
+/// Encode a signature in the `sign_message` hex format
+pub(crate) fn signature_to_hex(signature: &RistrettoSchnorr) -> String {
+    format!(
+        "{}{}",
+        signature.get_public_nonce().to_hex(),
+        signature.get_signature().to_hex()
+    )
+}
+
+/// Commit to a value under a fresh blinding factor and prove it is in range
+///
+/// Returns the blinding factor that opens the Pedersen commitment, the
+/// commitment itself and the serialized Bulletproofs+ proof.
+pub(crate) fn commit_with_range_proof(
+    value: u64,
+) -> TariResult<(RistrettoSecretKey, PedersenCommitment, Vec<u8>)> {
+    let blinding_factor = RistrettoSecretKey::random(&mut rand::thread_rng());
+    let commitment = ExtendedPedersenCommitmentFactory::default().commit_value(&blinding_factor, value);
+    let proof = construct_range_proof(&blinding_factor, value)?;
+
+    Ok((blinding_factor, commitment, proof))
+}
+
+/// Prove that the commitment to `value` under `blinding_factor` is in range
+pub(crate) fn construct_range_proof(
+    blinding_factor: &RistrettoSecretKey,
+    value: u64,
+) -> TariResult<Vec<u8>> {
+    let crypto_error = |e: &dyn std::fmt::Display| {
+        TariWalletError::new(
+            WalletErrorCode::CryptoError,
+            format!("Failed to construct range proof: {}", e),
+        )
+        .component("crypto")
+    };
+
+    range_proof_service()
+        .map_err(|e| crypto_error(&e))?
+        .construct_proof(blinding_factor, value)
+        .map_err(|e| crypto_error(&e))
+}
+
+/// Check a hex Bulletproofs+ range proof against a hex commitment
+///
+/// Returns `false` for a well-formed proof that does not prove the
+/// commitment's value is in range; malformed hex is reported as an error.
+#[napi]
+pub fn verify_range_proof(commitment: String, proof: String) -> Result<bool> {
+    Ok(range_proof_verifies(&commitment, &proof)?)
+}
+
+/// Whether a hex range proof proves a hex commitment's value is in range
+pub(crate) fn range_proof_verifies(commitment: &str, proof: &str) -> TariResult<bool> {
+    let commitment = PedersenCommitment::from_hex(commitment).map_err(|e| {
+        TariWalletError::new(WalletErrorCode::InvalidFormat, format!("Invalid commitment: {}", e))
+            .component("crypto")
+    })?;
+    let proof = hex::from_hex(proof).map_err(|e| {
+        TariWalletError::new(WalletErrorCode::InvalidFormat, format!("Invalid range proof: {}", e))
+            .component("crypto")
+    })?;
+
+    let service = range_proof_service().map_err(|e| {
+        TariWalletError::new(
+            WalletErrorCode::CryptoError,
+            format!("Failed to initialize range proof service: {}", e),
+        )
+        .component("crypto")
+    })?;
+    Ok(service.verify(&proof, &commitment))
+}
+
+/// Whether `signature` is a valid metadata signature for an output
+///
+/// The signature proves knowledge of the output's value and blinding
+/// factor, and of the sender offset key, over a challenge that commits to
+/// the output's script. Changing the script or the sender offset key after
+/// signing invalidates it, as on chain.
+pub(crate) fn metadata_signature_verifies(
+    commitment: &str,
+    sender_offset_public_key: &str,
+    script: &[u8],
+    signature: &str,
+) -> TariResult<bool> {
+    let commitment = parse_commitment(commitment)?;
+    let sender_offset_public_key = RistrettoPublicKey::from_hex(sender_offset_public_key).map_err(|e| {
+        TariWalletError::new(
+            WalletErrorCode::InvalidKey,
+            format!("Invalid sender offset public key: {}", e),
+        )
+        .component("crypto")
+    })?;
+    let signature = parse_metadata_signature(signature)?;
+
+    let challenge = metadata_signature_challenge(
+        signature.ephemeral_commitment(),
+        signature.ephemeral_pubkey(),
+        &sender_offset_public_key,
+        &commitment,
+        script,
+    );
+    Ok(signature.verify_challenge(
+        &commitment,
+        &sender_offset_public_key,
+        &challenge,
+        &ExtendedPedersenCommitmentFactory::default(),
+        &mut rand::thread_rng(),
+    ))
+}
+
+/// Sign an output's metadata with its value, blinding factor and sender offset key
+#[cfg(test)]
+pub(crate) fn sign_output_metadata(
+    value: u64,
+    blinding_factor: &RistrettoSecretKey,
+    sender_offset_key: &RistrettoSecretKey,
+    script: &[u8],
+) -> String {
+    let factory = ExtendedPedersenCommitmentFactory::default();
+    let nonce = || RistrettoSecretKey::random(&mut rand::thread_rng());
+    let (r_a, r_x, r_y) = (nonce(), nonce(), nonce());
+    let challenge = metadata_signature_challenge(
+        &factory.commit(&r_x, &r_a),
+        &RistrettoPublicKey::from_secret_key(&r_y),
+        &RistrettoPublicKey::from_secret_key(sender_offset_key),
+        &factory.commit_value(blinding_factor, value),
+        script,
+    );
+    let signature = RistrettoComAndPubSig::sign(
+        &RistrettoSecretKey::from(value),
+        blinding_factor,
+        sender_offset_key,
+        &r_a,
+        &r_x,
+        &r_y,
+        &challenge,
+        &factory,
+    )
+    .unwrap();
+    hex::to_hex(&signature.to_vec())
+}
+
+/// Challenge H(domain || R_c || R_p || K_O || C || script) a metadata signature answers
+fn metadata_signature_challenge(
+    ephemeral_commitment: &PedersenCommitment,
+    ephemeral_pubkey: &RistrettoPublicKey,
+    sender_offset_public_key: &RistrettoPublicKey,
+    commitment: &PedersenCommitment,
+    script: &[u8],
+) -> [u8; 64] {
+    Blake2b512::new()
+        .chain_update(METADATA_SIGNATURE_DOMAIN)
+        .chain_update(ephemeral_commitment.as_bytes())
+        .chain_update(ephemeral_pubkey.as_bytes())
+        .chain_update(sender_offset_public_key.as_bytes())
+        .chain_update(commitment.as_bytes())
+        .chain_update(script)
+        .finalize()
+        .into()
+}
+
+/// Parse a hex metadata signature, as produced by `RistrettoComAndPubSig::to_vec`
+fn parse_metadata_signature(signature: &str) -> TariResult<RistrettoComAndPubSig> {
+    let invalid = |reason: &str| {
+        TariWalletError::new(
+            WalletErrorCode::InvalidSignature,
+            format!("Invalid metadata signature: {}", reason),
+        )
+        .component("crypto")
+    };
+
+    if signature.len() != METADATA_SIGNATURE_HEX_LENGTH || !signature.is_ascii() {
+        return Err(invalid("expected 320 hex characters"));
+    }
+
+    let part = |index: usize| &signature[index * 64..(index + 1) * 64];
+    let ephemeral_commitment =
+        PedersenCommitment::from_hex(part(0)).map_err(|_| invalid("bad ephemeral commitment"))?;
+    let ephemeral_pubkey =
+        RistrettoPublicKey::from_hex(part(1)).map_err(|_| invalid("bad ephemeral public key"))?;
+    let scalar = |index: usize| {
+        RistrettoSecretKey::from_hex(part(index)).map_err(|_| invalid("bad response scalar"))
+    };
+
+    Ok(RistrettoComAndPubSig::new(
+        ephemeral_commitment,
+        ephemeral_pubkey,
+        scalar(2)?,
+        scalar(3)?,
+        scalar(4)?,
+    ))
+}
+
+fn range_proof_service() -> std::result::Result<BulletproofsPlusService, RangeProofError> {
+    BulletproofsPlusService::init(
+        RANGE_PROOF_BIT_LENGTH,
+        1,
+        ExtendedPedersenCommitmentFactory::default(),
+    )
+}
+
+/// Parse a hex signature in the `sign_message` format
+pub(crate) fn parse_signature(signature: &str) -> TariResult<RistrettoSchnorr> {
+    let invalid = |reason: &str| {
+        TariWalletError::new(
+            WalletErrorCode::InvalidSignature,
+            format!("Invalid signature: {}", reason),
+        )
+        .component("crypto")
+    };
+
+    if signature.len() != SIGNATURE_HEX_LENGTH || !signature.is_ascii() {
+        return Err(invalid("expected 128 hex characters"));
+    }
+
+    let (nonce_hex, scalar_hex) = signature.split_at(SIGNATURE_HEX_LENGTH / 2);
+    let public_nonce =
+        RistrettoPublicKey::from_hex(nonce_hex).map_err(|_| invalid("bad public nonce"))?;
+    let scalar =
+        RistrettoSecretKey::from_hex(scalar_hex).map_err(|_| invalid("bad signature scalar"))?;
+
+    Ok(RistrettoSchnorr::new(public_nonce, scalar))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_A: &str = "1f0d3c5e2bd7a6f0a8a2e4b1c9d0e3f45a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d";
+    const KEY_B: &str = "0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b0c";
+
+    #[test]
+    fn test_signature_verifies_against_signing_key() {
+        let private_a = private_key_from_hex(KEY_A.to_string()).unwrap();
+        let public_a = public_key_from_private_key(private_a).unwrap();
+
+        let signature = sign_message(private_a, b"auth challenge".to_vec()).unwrap();
+        assert_eq!(signature.len(), SIGNATURE_HEX_LENGTH);
+        assert!(verify_message(public_a, b"auth challenge".to_vec(), signature.clone()).unwrap());
+        assert!(!verify_message(public_a, b"other challenge".to_vec(), signature).unwrap());
+    }
+
+    #[test]
+    fn test_signature_fails_for_different_key() {
+        let private_a = private_key_from_hex(KEY_A.to_string()).unwrap();
+        let private_b = private_key_from_hex(KEY_B.to_string()).unwrap();
+        let public_b = public_key_from_private_key(private_b).unwrap();
+
+        let signature = sign_message(private_a, b"auth challenge".to_vec()).unwrap();
+        assert!(!verify_message(public_b, b"auth challenge".to_vec(), signature).unwrap());
+    }
+
+    #[test]
+    fn test_malformed_signature_is_rejected() {
+        let private_a = private_key_from_hex(KEY_A.to_string()).unwrap();
+        let public_a = public_key_from_private_key(private_a).unwrap();
+
+        assert!(verify_message(public_a, b"msg".to_vec(), "abcd".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_diffie_hellman_is_symmetric() {
+        let private_a = private_key_from_hex(KEY_A.to_string()).unwrap();
+        let private_b = private_key_from_hex(KEY_B.to_string()).unwrap();
+        let public_a = public_key_from_private_key(private_a).unwrap();
+        let public_b = public_key_from_private_key(private_b).unwrap();
+
+        let secret_ab = diffie_hellman(private_a, public_b).unwrap();
+        let secret_ba = diffie_hellman(private_b, public_a).unwrap();
+
+        assert_eq!(secret_ab, secret_ba);
+        assert_eq!(secret_ab.len(), 64);
+        assert_ne!(secret_ab, diffie_hellman(private_a, public_a).unwrap());
+    }
+
+    #[test]
+    fn test_aggregated_key_is_order_independent() {
+        let private_a = private_key_from_hex(KEY_A.to_string()).unwrap();
+        let private_b = private_key_from_hex(KEY_B.to_string()).unwrap();
+        let public_a = public_key_from_private_key(private_a).unwrap();
+        let public_b = public_key_from_private_key(private_b).unwrap();
+
+        let joint_ab = aggregate_public_keys(vec![public_a, public_b]).unwrap();
+        let joint_ba = aggregate_public_keys(vec![public_b, public_a]).unwrap();
+        assert_eq!(public_key_to_hex(joint_ab).unwrap(), public_key_to_hex(joint_ba).unwrap());
+
+        // Not the plain sum of the keys, which is open to key cancellation
+        let plain_sum = &get_public_key(public_a).unwrap() + &get_public_key(public_b).unwrap();
+        assert_ne!(public_key_to_hex(joint_ab).unwrap(), plain_sum.to_hex());
+
+        assert!(aggregate_public_keys(vec![public_a]).is_err());
+        public_key_destroy(public_b).unwrap();
+        assert!(aggregate_public_keys(vec![public_a, public_b]).is_err());
+    }
+
+    #[test]
+    fn test_commitment_opens_to_its_value() {
+        // A commitment to zero is the blinding factor's public key, k·G
+        let private_a = private_key_from_hex(KEY_A.to_string()).unwrap();
+        let public_a = public_key_from_private_key(private_a).unwrap();
+        assert_eq!(
+            commitment_open("0".to_string(), KEY_A.to_string()).unwrap(),
+            public_key_to_hex(public_a).unwrap()
+        );
+
+        let commitment = commitment_open("1000".to_string(), KEY_A.to_string()).unwrap();
+        assert_eq!(commitment.len(), 64);
+        assert_ne!(commitment, commitment_open("1000".to_string(), KEY_B.to_string()).unwrap());
+
+        let verify = |value: &str, blinding_factor: &str| {
+            commitment_verify(commitment.clone(), value.to_string(), blinding_factor.to_string())
+        };
+        assert!(verify("1000", KEY_A).unwrap());
+        assert!(!verify("1001", KEY_A).unwrap());
+        assert!(!verify("1000", KEY_B).unwrap());
+        assert!(verify("-1", KEY_A).is_err());
+        assert!(verify("1000", "zz").is_err());
+        let malformed = commitment_verify("abcd".to_string(), "1000".to_string(), KEY_A.to_string());
+        assert!(malformed.is_err());
+    }
+
+    #[test]
+    fn test_range_proof_verifies_against_its_commitment() {
+        let blinding_factor = RistrettoSecretKey::from_hex(KEY_A).unwrap();
+        let commitment = commitment_open("1000".to_string(), KEY_A.to_string()).unwrap();
+        let proof = hex::to_hex(&construct_range_proof(&blinding_factor, 1000).unwrap());
+
+        assert!(verify_range_proof(commitment.clone(), proof).unwrap());
+        assert!(!verify_range_proof(commitment.clone(), "00".repeat(64)).unwrap());
+        assert!(verify_range_proof(commitment, "zz".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_destroyed_handle_is_rejected() {
+        let private_a = private_key_generate().unwrap();
+        private_key_destroy(private_a).unwrap();
+
+        assert!(sign_message(private_a, b"msg".to_vec()).is_err());
+        assert!(private_key_destroy(private_a).is_err());
+    }
+}
@@ -0,0 +1,619 @@
+/**
+ * Wallet database connections
+ *
+ * An open wallet holds one writer connection to its SQLite database until
+ * it shuts down, and a pool of read-only connections for queries that only
+ * read, up to `max_read_connections` of them. In WAL mode, the default,
+ * pooled readers neither wait for the writer nor hold it up.
+ *
+ * With `encrypt_database` set, the database is a SQLCipher database keyed
+ * with the wallet passphrase, so nothing in it can be read from disk
+ * without the passphrase. SQLCipher only notices a wrong key when it first
+ * reads a page, so each connection reads the schema as soon as it is
+ * opened and a bad key is reported by the open itself. The configured
+ * pragmas are then executed on the connection.
+ *
+ * The schema version is the database's `user_version`. Opening a database
+ * runs each migration it has not had yet, one transaction per step, and
+ * refuses a database written by a newer SDK rather than read and then
+ * write a schema this SDK does not understand.
+ *
+ * The network a wallet was created on is recorded in the database, so a
+ * database opened or restored under a config for another network is
+ * refused wherever it ends up. So are the wallet's birthday height and its
+ * seed, enciphered as its seed words encode it, which travel with backups;
+ * a backup of a wallet with another seed is refused.
+ *
+ * Backups and restores of an open wallet go through SQLite's online
+ * backup API on the wallet's own connection, so a backup is a consistent
+ * snapshot that includes changes still in the write-ahead log, and a
+ * restore never swaps the file out from under the connection.
+ */
+
+use std::fs;
+use std::io::Read;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use napi::Result;
+use rusqlite::backup::Backup;
+use rusqlite::{Connection, ErrorCode, OpenFlags, OptionalExtension};
+use tokio::sync::{Semaphore, SemaphorePermit};
+use zeroize::Zeroizing;
+
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+use crate::mnemonic::CipherSeed;
+use crate::types::JsWalletConfig;
+
+/// File name of the wallet database within a wallet's storage path
+pub(crate) const WALLET_DB_FILE: &str = "tari_wallet.sqlite3";
+
+/// Header every unencrypted SQLite database file starts with
+pub(crate) const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// Journal mode of the wallet database unless configured otherwise
+const DEFAULT_JOURNAL_MODE: &str = "WAL";
+
+/// Read-only connections a wallet's pool opens unless configured otherwise
+pub(crate) const DEFAULT_MAX_READ_CONNECTIONS: u32 = 4;
+
+/// Most read-only connections a wallet's pool may be configured to open
+pub(crate) const MAX_READ_CONNECTIONS_LIMIT: u32 = 64;
+
+/// Pages copied per step of a backup or restore
+const BACKUP_PAGES_PER_STEP: std::ffi::c_int = 256;
+
+/// SQL taking the schema from version `i` to `i + 1`, at index `i`
+///
+/// Add a step to change the schema; never edit a released one. Databases
+/// from before schema versioning are at version 0.
+const MIGRATIONS: &[&str] = &[
+    // v1: key-value settings of the wallet
+    "CREATE TABLE wallet_metadata (key TEXT PRIMARY KEY NOT NULL, value TEXT NOT NULL)",
+    // v2: idempotency keys of sends, with what was sent and the Unix time
+    // it went out
+    "CREATE TABLE idempotency_keys (
+        key TEXT PRIMARY KEY NOT NULL,
+        tx_id TEXT NOT NULL,
+        recipient TEXT NOT NULL,
+        amount INTEGER NOT NULL,
+        fee INTEGER NOT NULL,
+        status INTEGER NOT NULL,
+        sent_at INTEGER NOT NULL
+    );
+    CREATE INDEX idempotency_keys_sent_at ON idempotency_keys (sent_at)",
+    // v3: local bookkeeping labels of transactions
+    "CREATE TABLE transaction_labels (tx_id TEXT PRIMARY KEY NOT NULL, label TEXT NOT NULL)",
+];
+
+/// Database schema version this SDK reads and writes
+pub(crate) const WALLET_SCHEMA_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Location of the wallet database for a storage path
+pub(crate) fn wallet_db_path(storage_path: &str) -> PathBuf {
+    Path::new(storage_path).join(WALLET_DB_FILE)
+}
+
+fn database_error(code: WalletErrorCode, message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(code, message).component("database")
+}
+
+/// Whether the file at `path` starts with the plaintext SQLite header
+pub(crate) fn has_plain_header(path: &Path) -> bool {
+    let mut header = [0u8; 16];
+    fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .is_ok()
+        && &header == SQLITE_HEADER
+}
+
+/// The open connections to a wallet's database
+pub(crate) struct WalletDatabase {
+    connection: Mutex<Connection>,
+    read_pool: Arc<ReadPool>,
+}
+
+impl WalletDatabase {
+    /// Open the database under `config.storage_path`, creating it if needed
+    ///
+    /// Fails with AuthenticationFailed when the passphrase does not unlock
+    /// an encrypted database, and with DecryptionFailed when an encrypted
+    /// database is opened without `encrypt_database`.
+    pub(crate) fn open(config: &JsWalletConfig) -> Result<Self> {
+        let path = wallet_db_path(&config.storage_path);
+        let key = database_key(config);
+        let mut connection = open_keyed(&path, key, OpenFlags::default())?;
+        let statements = apply_pragmas(&connection, config)?;
+        migrate(&mut connection)?;
+        check_network(&connection, &config.network)?;
+        warn_unless_wal(&connection);
+
+        // Journal mode belongs to the database, so only the writer sets it
+        let reader_pragmas = statements
+            .into_iter()
+            .filter(|statement| !statement.starts_with("PRAGMA journal_mode"))
+            .collect();
+        let max_readers = config.max_read_connections.unwrap_or(DEFAULT_MAX_READ_CONNECTIONS);
+        Ok(Self {
+            connection: Mutex::new(connection),
+            read_pool: Arc::new(ReadPool::new(path, key, reader_pragmas, max_readers)),
+        })
+    }
+
+    /// The pool of read-only connections, shared with queries that run
+    /// without the wallet's lock
+    pub(crate) fn read_pool(&self) -> Arc<ReadPool> {
+        self.read_pool.clone()
+    }
+
+    /// The wallet's connection, held until the guard is dropped
+    pub(crate) fn connection(&self) -> MutexGuard<'_, Connection> {
+        self.connection.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Write a copy of the database to `destination`
+    ///
+    /// An encrypted database is backed up encrypted under the same key.
+    pub(crate) fn backup(&self, destination: &Path, config: &JsWalletConfig) -> TariResult<()> {
+        let backup_error = |e: rusqlite::Error| {
+            database_error(
+                WalletErrorCode::ConfigBackupFailed,
+                format!("Failed to back up wallet database: {}", e),
+            )
+        };
+        let mut copy = Connection::open(destination).map_err(backup_error)?;
+        if let Some(key) = database_key(config) {
+            copy.pragma_update(None, "key", key).map_err(backup_error)?;
+        }
+
+        let connection = self.connection();
+        Backup::new(&connection, &mut copy)
+            .and_then(|backup| backup.run_to_completion(BACKUP_PAGES_PER_STEP, Duration::ZERO, None))
+            .map_err(backup_error)
+    }
+
+    /// Replace the database's contents with those of the backup at `source`
+    ///
+    /// The backup is checked before anything is overwritten: it has to be
+    /// readable with the wallet's key, from a schema this SDK understands,
+    /// from the wallet's network and of the wallet's seed.
+    pub(crate) fn restore(&self, source: &Path, config: &JsWalletConfig) -> Result<()> {
+        let restore_error = |e: rusqlite::Error| {
+            database_error(
+                WalletErrorCode::ConfigRestoreFailed,
+                format!("Failed to restore wallet database: {}", e),
+            )
+        };
+        let read_only = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+        let backup = open_keyed(source, database_key(config), read_only)?;
+        let version = schema_version(&backup).map_err(restore_error)?;
+        if version > WALLET_SCHEMA_VERSION {
+            return Err(database_error(
+                WalletErrorCode::IncompatibleVersion,
+                format!(
+                    "backup schema newer than this SDK: backup is v{}, SDK supports v{}",
+                    version, WALLET_SCHEMA_VERSION
+                ),
+            )
+            .into());
+        }
+        let mut connection = self.connection();
+        let seed = recorded_seed(&connection)?;
+        if version > 0 {
+            if let Some(recorded) = recorded_network(&backup)? {
+                if recorded != config.network {
+                    return Err(network_mismatch(&recorded, &config.network).into());
+                }
+            }
+            if let (Some(seed), Some(recorded)) = (&seed, recorded_seed(&backup)?) {
+                if recorded != *seed {
+                    return Err(database_error(
+                        WalletErrorCode::WalletExists,
+                        "backup is of another wallet: its seed differs",
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Backup::new(&backup, &mut connection)
+            .and_then(|restore| restore.run_to_completion(BACKUP_PAGES_PER_STEP, Duration::ZERO, None))
+            .map_err(restore_error)?;
+        migrate(&mut connection)?;
+        check_network(&connection, &config.network)?;
+        // A backup taken before the seed was recorded keeps the wallet's
+        if let Some(seed) = seed {
+            if recorded_seed(&connection)?.is_none() {
+                record_seed(&connection, &seed)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The chain height the wallet was created at, if one was recorded
+    pub(crate) fn birthday_height(&self) -> TariResult<Option<u64>> {
+        let value: Option<String> = self
+            .connection()
+            .query_row(
+                "SELECT value FROM wallet_metadata WHERE key = 'birthday_height'",
+                [],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| metadata_error("birthday height", e))?;
+        value
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    database_error(
+                        WalletErrorCode::DatabaseCorrupted,
+                        format!("Recorded wallet birthday height {} is not a height", value),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    /// Record the chain height the wallet was created at
+    ///
+    /// Outputs cannot predate a wallet's creation, so recovery can start
+    /// scanning from this height instead of genesis.
+    pub(crate) fn record_birthday(&self, height: u64) -> TariResult<()> {
+        self.connection()
+            .execute(
+                "INSERT OR REPLACE INTO wallet_metadata (key, value) VALUES ('birthday_height', ?1)",
+                [height.to_string()],
+            )
+            .map_err(|e| metadata_error("birthday height", e))?;
+        Ok(())
+    }
+
+    /// The wallet's seed, if one was recorded
+    pub(crate) fn cipher_seed(&self) -> TariResult<Option<CipherSeed>> {
+        recorded_seed(&self.connection())?
+            .map(|enciphered| CipherSeed::from_hex(&enciphered))
+            .transpose()
+    }
+
+    /// Record the wallet's seed, enciphered as its seed words encode it
+    ///
+    /// Reopening the wallet without seed words loads it again.
+    pub(crate) fn record_cipher_seed(&self, seed: &CipherSeed) -> TariResult<()> {
+        record_seed(&self.connection(), &seed.to_hex()?)
+    }
+
+    /// Close the connections, logging rather than failing if SQLite cannot
+    ///
+    /// Pooled readers still lent out close when they are returned.
+    pub(crate) fn close(self) {
+        self.read_pool.close();
+        let connection = self
+            .connection
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Err((_, e)) = connection.close() {
+            tracing::warn!("Wallet database did not close cleanly: {}", e);
+        }
+    }
+}
+
+/// Read-only connections to a wallet database, opened as queries need them
+///
+/// Up to `max_connections` are open at once; a query that finds them all
+/// lent out waits for one to be returned without blocking its runtime
+/// worker. Returned connections stay open for the next query.
+pub(crate) struct ReadPool {
+    path: PathBuf,
+    key: Option<String>,
+    pragmas: Vec<String>,
+    permits: Semaphore,
+    idle: Mutex<Vec<Connection>>,
+}
+
+impl ReadPool {
+    fn new(path: PathBuf, key: Option<&str>, pragmas: Vec<String>, max_connections: u32) -> Self {
+        Self {
+            path,
+            key: key.map(str::to_string),
+            pragmas,
+            permits: Semaphore::new(max_connections as usize),
+            idle: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Borrow a connection, opening one if none is idle
+    pub(crate) async fn get(&self) -> TariResult<PooledConnection<'_>> {
+        let permit = self.permits.acquire().await.map_err(|_| {
+            database_error(WalletErrorCode::ResourceDestroyed, "Wallet database is closed")
+        })?;
+        let idle = self.idle().pop();
+        let connection = match idle {
+            Some(connection) => connection,
+            None => self.open_reader()?,
+        };
+        Ok(PooledConnection {
+            pool: self,
+            connection: Some(connection),
+            _permit: permit,
+        })
+    }
+
+    fn open_reader(&self) -> TariResult<Connection> {
+        let read_only = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+        let connection = open_keyed(&self.path, self.key.as_deref(), read_only).map_err(|e| {
+            database_error(
+                WalletErrorCode::DatabaseCorrupted,
+                format!("Cannot open a reader on {}: {}", self.path.display(), e.reason),
+            )
+        })?;
+        for statement in &self.pragmas {
+            connection.execute_batch(statement).map_err(|e| {
+                database_error(
+                    WalletErrorCode::DatabaseCorrupted,
+                    format!("Failed to apply {} to a reader: {}", statement, e),
+                )
+            })?;
+        }
+        Ok(connection)
+    }
+
+    /// Close the idle connections and refuse to lend out more
+    fn close(&self) {
+        self.permits.close();
+        self.idle().clear();
+    }
+
+    fn idle(&self) -> MutexGuard<'_, Vec<Connection>> {
+        self.idle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A connection borrowed from a [`ReadPool`], returned to it when dropped
+pub(crate) struct PooledConnection<'a> {
+    pool: &'a ReadPool,
+    connection: Option<Connection>,
+    _permit: SemaphorePermit<'a>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.connection.as_ref().expect("pooled connection is only taken on drop")
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            if !self.pool.permits.is_closed() {
+                self.pool.idle().push(connection);
+            }
+        }
+    }
+}
+
+/// A migrated database that only lives in memory, with no readers
+#[cfg(test)]
+pub(crate) fn in_memory() -> WalletDatabase {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate(&mut connection).unwrap();
+    WalletDatabase {
+        connection: Mutex::new(connection),
+        read_pool: Arc::new(ReadPool::new(PathBuf::new(), None, Vec::new(), 0)),
+    }
+}
+
+/// The key of the database for `config`, if it is encrypted
+fn database_key(config: &JsWalletConfig) -> Option<&str> {
+    config
+        .encrypt_database
+        .unwrap_or(false)
+        .then(|| config.passphrase.as_deref().unwrap_or_default())
+}
+
+/// Open the database at `path` with `key`, if encrypted, checking it is readable
+fn open_keyed(path: &Path, key: Option<&str>, flags: OpenFlags) -> Result<Connection> {
+    let open_error = |e: rusqlite::Error| {
+        database_error(
+            WalletErrorCode::InitializationFailed,
+            format!("Cannot open {}: {}", path.display(), e),
+        )
+    };
+    let connection = Connection::open_with_flags(path, flags).map_err(open_error)?;
+
+    if let Some(key) = key {
+        // The key has to be set before anything reads the database
+        connection.pragma_update(None, "key", key).map_err(open_error)?;
+    }
+
+    match connection.query_row("SELECT count(*) FROM sqlite_master", [], |row| {
+        row.get::<_, i64>(0)
+    }) {
+        Ok(_) => Ok(connection),
+        Err(e) if e.sqlite_error_code() == Some(ErrorCode::NotADatabase) => {
+            Err(unreadable(key.is_some(), path).into())
+        }
+        Err(e) => Err(open_error(e).into()),
+    }
+}
+
+/// Execute the configured SQLite pragmas on the connection, returning them
+///
+/// The journal defaults to WAL, so the pooled readers, backups and other
+/// processes reading the file neither block the wallet's writes nor wait
+/// for them.
+fn apply_pragmas(connection: &Connection, config: &JsWalletConfig) -> Result<Vec<String>> {
+    let pragmas = config.sqlite_pragmas.as_ref();
+    let mut statements = match pragmas {
+        Some(pragmas) => pragmas.statements()?,
+        None => Vec::new(),
+    };
+    if pragmas.is_none_or(|pragmas| pragmas.journal_mode.is_none()) {
+        statements.insert(0, format!("PRAGMA journal_mode = {}", DEFAULT_JOURNAL_MODE));
+    }
+
+    for statement in &statements {
+        connection.execute_batch(statement).map_err(|e| {
+            database_error(
+                WalletErrorCode::InitializationFailed,
+                format!("Failed to apply {}: {}", statement, e),
+            )
+        })?;
+    }
+    tracing::info!("Applied SQLite pragmas: {}", statements.join("; "));
+    Ok(statements)
+}
+
+/// Warn that readers and the writer wait for each other outside WAL mode
+fn warn_unless_wal(connection: &Connection) {
+    let mode = connection.pragma_query_value(None, "journal_mode", |row| row.get::<_, String>(0));
+    match mode {
+        Ok(mode) if mode.eq_ignore_ascii_case("wal") => {}
+        Ok(mode) => tracing::warn!(
+            "Wallet database journal mode is {}; pooled reads and writes will wait for each other",
+            mode
+        ),
+        Err(e) => tracing::warn!("Cannot read the wallet database journal mode: {}", e),
+    }
+}
+
+/// The database's schema version
+pub(crate) fn schema_version(connection: &Connection) -> rusqlite::Result<u32> {
+    connection.pragma_query_value(None, "user_version", |row| row.get(0))
+}
+
+/// Bring the schema up to date
+fn migrate(connection: &mut Connection) -> TariResult<()> {
+    let version = schema_version(connection).map_err(|e| {
+        database_error(
+            WalletErrorCode::DatabaseCorrupted,
+            format!("Unreadable database schema version: {}", e),
+        )
+    })?;
+    if version > WALLET_SCHEMA_VERSION {
+        return Err(database_error(
+            WalletErrorCode::IncompatibleVersion,
+            format!(
+                "db schema newer than this SDK: db is v{}, SDK supports v{}",
+                version, WALLET_SCHEMA_VERSION
+            ),
+        ));
+    }
+
+    for step in version..WALLET_SCHEMA_VERSION {
+        tracing::debug!("Migrating wallet database schema v{} to v{}", step, step + 1);
+        run_migration(connection, step).map_err(|e| {
+            tracing::error!("Wallet database migration from v{} failed: {}", step, e);
+            database_error(
+                WalletErrorCode::MigrationFailed,
+                format!("Database migration from v{} failed: {}", step, e),
+            )
+        })?;
+    }
+    if version < WALLET_SCHEMA_VERSION {
+        tracing::info!(
+            "Migrated wallet database schema from v{} to v{}",
+            version,
+            WALLET_SCHEMA_VERSION
+        );
+    }
+    Ok(())
+}
+
+/// Take the schema from version `from` to the next, all or nothing
+fn run_migration(connection: &mut Connection, from: u32) -> rusqlite::Result<()> {
+    let transaction = connection.transaction()?;
+    transaction.execute_batch(MIGRATIONS[from as usize])?;
+    transaction.pragma_update(None, "user_version", from + 1)?;
+    transaction.commit()
+}
+
+/// Check the database was created on `network`, recording it on first open
+///
+/// Opening a mainnet wallet with a testnet config would otherwise report
+/// balances from the wrong chain.
+fn check_network(connection: &Connection, network: &str) -> TariResult<()> {
+    match recorded_network(connection)? {
+        Some(recorded) if recorded == network => Ok(()),
+        Some(recorded) => Err(network_mismatch(&recorded, network)),
+        None => {
+            connection
+                .execute(
+                    "INSERT INTO wallet_metadata (key, value) VALUES ('network', ?1)",
+                    [network],
+                )
+                .map_err(|e| metadata_error("network", e))?;
+            Ok(())
+        }
+    }
+}
+
+/// The network recorded in the database, if it has one yet
+fn recorded_network(connection: &Connection) -> TariResult<Option<String>> {
+    connection
+        .query_row(
+            "SELECT value FROM wallet_metadata WHERE key = 'network'",
+            [],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| metadata_error("network", e))
+}
+
+/// The enciphered seed recorded in the database, if it has one yet
+fn recorded_seed(connection: &Connection) -> TariResult<Option<Zeroizing<String>>> {
+    connection
+        .query_row(
+            "SELECT value FROM wallet_metadata WHERE key = 'cipher_seed'",
+            [],
+            |row| row.get(0).map(Zeroizing::new),
+        )
+        .optional()
+        .map_err(|e| metadata_error("seed", e))
+}
+
+fn record_seed(connection: &Connection, enciphered: &str) -> TariResult<()> {
+    connection
+        .execute(
+            "INSERT OR REPLACE INTO wallet_metadata (key, value) VALUES ('cipher_seed', ?1)",
+            [enciphered],
+        )
+        .map_err(|e| metadata_error("seed", e))?;
+    Ok(())
+}
+
+fn metadata_error(entry: &str, e: rusqlite::Error) -> TariWalletError {
+    database_error(
+        WalletErrorCode::DatabaseCorrupted,
+        format!("Cannot access the wallet {}: {}", entry, e),
+    )
+}
+
+fn network_mismatch(recorded: &str, network: &str) -> TariWalletError {
+    database_error(
+        WalletErrorCode::InvalidNetworkType,
+        format!("network mismatch: db is {}, config is {}", recorded, network),
+    )
+}
+
+/// Why a database SQLite could not read was refused
+fn unreadable(encrypted: bool, path: &Path) -> TariWalletError {
+    match (encrypted, has_plain_header(path)) {
+        (true, true) => database_error(
+            WalletErrorCode::InvalidConfig,
+            "Database encryption is enabled but the existing wallet database is not encrypted",
+        ),
+        (true, false) => database_error(
+            WalletErrorCode::AuthenticationFailed,
+            "Wrong passphrase for the encrypted wallet database",
+        ),
+        (false, _) => database_error(
+            WalletErrorCode::DecryptionFailed,
+            "Wallet database is encrypted or unreadable; enable encrypt_database and \
+             provide the passphrase if it was encrypted",
+        ),
+    }
+}
@@ -0,0 +1,209 @@
+/**
+ * Emoji IDs for wallet addresses
+ *
+ * An emoji ID spells out the public key of a `tari://<network>/<hex>`
+ * address one emoji per byte from the Tari emoji set, followed by a
+ * DammSum checksum emoji so a mistyped or truncated ID is rejected rather
+ * than resolving to someone else. The network is not part of the ID; it is
+ * supplied by whoever resolves it.
+ */
+
+use tari_crypto::tari_utilities::hex;
+
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::TariWalletError;
+
+/// The Tari emoji set; byte `b` is written as `EMOJI[b]`
+///
+/// Matches `tari_common_types::emoji::EMOJI`, so IDs shown here can be
+/// read by other Tari wallets.
+const EMOJI: [char; 256] = [
+    '🌀', '🌂', '🌈', '🌊', '🌋', '🌍', '🌙', '🌝', '🌞', '🌟', '🌠', '🌰', '🌴', '🌵', '🌷', '🌸',
+    '🌹', '🌻', '🌽', '🍀', '🍁', '🍄', '🍅', '🍆', '🍇', '🍈', '🍉', '🍊', '🍋', '🍌', '🍍', '🍎',
+    '🍐', '🍑', '🍒', '🍓', '🍔', '🍕', '🍗', '🍚', '🍞', '🍟', '🍠', '🍣', '🍦', '🍩', '🍪', '🍫',
+    '🍬', '🍭', '🍯', '🍰', '🍳', '🍴', '🍵', '🍶', '🍷', '🍸', '🍹', '🍺', '🍼', '🎀', '🎁', '🎂',
+    '🎃', '🎄', '🎈', '🎉', '🎒', '🎓', '🎠', '🎡', '🎢', '🎣', '🎤', '🎥', '🎧', '🎨', '🎩', '🎪',
+    '🎬', '🎭', '🎮', '🎰', '🎱', '🎲', '🎳', '🎵', '🎷', '🎸', '🎹', '🎺', '🎻', '🎼', '🎽', '🎾',
+    '🎿', '🏀', '🏁', '🏆', '🏈', '🏉', '🏠', '🏥', '🏦', '🏭', '🏰', '🐀', '🐉', '🐊', '🐌', '🐍',
+    '🐎', '🐐', '🐑', '🐓', '🐖', '🐗', '🐘', '🐙', '🐚', '🐛', '🐜', '🐝', '🐞', '🐢', '🐣', '🐨',
+    '🐩', '🐪', '🐬', '🐭', '🐮', '🐯', '🐰', '🐲', '🐳', '🐴', '🐵', '🐶', '🐷', '🐸', '🐺', '🐻',
+    '🐼', '🐽', '🐾', '👀', '👅', '👑', '👒', '👓', '👔', '👕', '👖', '👗', '👘', '👙', '👚', '👛',
+    '👞', '👟', '👠', '👡', '👢', '👣', '👹', '👻', '👽', '👾', '👿', '💀', '💄', '💈', '💉', '💊',
+    '💋', '💌', '💍', '💎', '💐', '💔', '💕', '💘', '💡', '💣', '💤', '💦', '💨', '💩', '💭', '💯',
+    '💰', '💳', '💸', '💺', '💻', '💼', '📈', '📉', '📌', '📎', '📚', '📝', '📡', '📣', '📱', '📷',
+    '🔋', '🔌', '🔎', '🔑', '🔔', '🔥', '🔦', '🔧', '🔨', '🔩', '🔪', '🔫', '🔬', '🔭', '🔮', '🔱',
+    '🗽', '😂', '😇', '😈', '😉', '😍', '😎', '😱', '😷', '🙈', '🚀', '🚁', '🚂', '🚒', '🚓', '🚗',
+    '🚜', '🚢', '🚦', '🚧', '🚨', '🚪', '🚫', '🚲', '🚽', '🚿', '🧲', '🛁', '💥', '🧐', '🧵', '🧶',
+];
+
+/// Primitive polynomial x^8 + x^4 + x^3 + x + 1 used by the DammSum checksum
+const DAMMSUM_MASK: u8 = 0b0001_1011;
+
+/// Presentation selector some keyboards append to emoji; ignored when parsing
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+
+const ADDRESS_SCHEME: &str = "tari://";
+
+fn emoji_error(code: WalletErrorCode, message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(code, message).component("emoji")
+}
+
+fn emoji_for(byte: u8) -> char {
+    EMOJI[byte as usize]
+}
+
+fn byte_for(emoji: char) -> Option<u8> {
+    EMOJI
+        .iter()
+        .position(|&c| c == emoji)
+        .map(|index| index as u8)
+}
+
+/// DammSum of the bytes, as in `tari_common_types::dammsum`
+///
+/// Catches any single changed byte and any swap of adjacent bytes. The
+/// checksum of data followed by its own checksum is zero.
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |sum, &byte| {
+        let sum = sum ^ byte;
+        let shifted = sum << 1;
+        if sum & 0x80 != 0 {
+            shifted ^ DAMMSUM_MASK
+        } else {
+            shifted
+        }
+    })
+}
+
+/// Whether a string is written entirely in the emoji ID alphabet
+pub(crate) fn is_emoji_id(value: &str) -> bool {
+    let mut emoji = value.chars().filter(|&c| c != VARIATION_SELECTOR).peekable();
+    emoji.peek().is_some() && emoji.all(|c| byte_for(c).is_some())
+}
+
+/// The emoji ID of a `tari://<network>/<hex>` address
+pub(crate) fn address_to_emoji_id(address: &str) -> Result<String, TariWalletError> {
+    let key_hex = address
+        .strip_prefix(ADDRESS_SCHEME)
+        .and_then(|rest| rest.split_once('/'))
+        .map(|(_, key_hex)| key_hex)
+        .ok_or_else(|| {
+            emoji_error(
+                WalletErrorCode::InvalidAddress,
+                format!("{} is not a tari://<network>/<public key> address", address),
+            )
+        })?;
+    let bytes = hex::from_hex(key_hex).map_err(|_| {
+        emoji_error(
+            WalletErrorCode::InvalidHex,
+            format!("Public key of address {} is not hex", address),
+        )
+    })?;
+    if bytes.is_empty() {
+        return Err(emoji_error(WalletErrorCode::InvalidAddress, "Address has no public key"));
+    }
+
+    Ok(bytes
+        .iter()
+        .copied()
+        .chain(std::iter::once(checksum(&bytes)))
+        .map(emoji_for)
+        .collect())
+}
+
+/// The address on `network` that an emoji ID spells out
+pub(crate) fn emoji_id_to_address(emoji_id: &str, network: &str) -> Result<String, TariWalletError> {
+    let mut bytes = emoji_id
+        .chars()
+        .filter(|&c| c != VARIATION_SELECTOR)
+        .map(|c| {
+            byte_for(c).ok_or_else(|| {
+                emoji_error(
+                    WalletErrorCode::InvalidEmojiId,
+                    format!("{} is not part of the emoji ID alphabet", c),
+                )
+            })
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    if bytes.len() < 2 {
+        return Err(emoji_error(WalletErrorCode::InvalidLength, "Emoji ID is too short"));
+    }
+    if checksum(&bytes) != 0 {
+        return Err(emoji_error(
+            WalletErrorCode::InvalidChecksum,
+            "Emoji ID checksum does not match; check it was copied in full",
+        ));
+    }
+    bytes.pop();
+    Ok(format!("{}{}/{}", ADDRESS_SCHEME, network, hex::to_hex(&bytes)))
+}
+
+/// A destination as an address, resolving emoji IDs on `network`
+///
+/// Anything that is not an emoji ID is passed through for the transaction
+/// service to validate.
+pub(crate) fn normalize_destination(destination: &str, network: &str) -> Result<String, TariWalletError> {
+    if is_emoji_id(destination) {
+        emoji_id_to_address(destination, network)
+    } else {
+        Ok(destination.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str =
+        "tari://testnet/e2a5f0bd1d34dbd8d4f48ae11a8f78c9e7bfc6f1d2e3a4b5c6d7e8f9a0b1c2d3";
+
+    #[test]
+    fn test_emoji_ids_round_trip() {
+        let emoji_id = address_to_emoji_id(ADDRESS).unwrap();
+        // One emoji per key byte, and the checksum
+        assert_eq!(emoji_id.chars().count(), 33);
+        assert!(is_emoji_id(&emoji_id));
+        assert_eq!(emoji_id_to_address(&emoji_id, "testnet").unwrap(), ADDRESS);
+
+        // Presentation selectors added by a keyboard are ignored
+        let decorated: String = emoji_id.chars().flat_map(|c| [c, VARIATION_SELECTOR]).collect();
+        assert_eq!(emoji_id_to_address(&decorated, "testnet").unwrap(), ADDRESS);
+
+        assert_eq!(normalize_destination(ADDRESS, "testnet").unwrap(), ADDRESS);
+        assert_eq!(normalize_destination(&emoji_id, "testnet").unwrap(), ADDRESS);
+    }
+
+    #[test]
+    fn test_emoji_set_has_one_emoji_per_byte() {
+        let distinct: std::collections::HashSet<char> = EMOJI.iter().copied().collect();
+        assert_eq!(distinct.len(), 256);
+        assert!((0..=255u8).all(|byte| byte_for(emoji_for(byte)) == Some(byte)));
+
+        let bytes = [0x12, 0x34, 0x56];
+        let checked: Vec<u8> = bytes.iter().copied().chain([checksum(&bytes)]).collect();
+        assert_eq!(checksum(&checked), 0);
+    }
+
+    #[test]
+    fn test_damaged_emoji_ids_are_rejected() {
+        let emoji_id: Vec<char> = address_to_emoji_id(ADDRESS).unwrap().chars().collect();
+
+        let truncated: String = emoji_id[..emoji_id.len() - 1].iter().collect();
+        let error = emoji_id_to_address(&truncated, "testnet").unwrap_err();
+        assert_eq!(error.code, WalletErrorCode::InvalidChecksum);
+
+        let mut swapped = emoji_id.clone();
+        swapped.swap(0, 1);
+        let swapped: String = swapped.into_iter().collect();
+        assert_eq!(
+            emoji_id_to_address(&swapped, "testnet").unwrap_err().code,
+            WalletErrorCode::InvalidChecksum
+        );
+
+        let error = emoji_id_to_address("🐀a", "testnet").unwrap_err();
+        assert_eq!(error.code, WalletErrorCode::InvalidEmojiId);
+        assert!(!is_emoji_id("recipient"));
+        assert!(!is_emoji_id(""));
+    }
+}
@@ -7,7 +7,6 @@
 
 use crate::error_mapping::{TariWalletError as NewTariWalletError};
 use crate::error_codes::WalletErrorCode;
-use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use std::fmt;
 
@@ -69,23 +68,14 @@ impl AsRef<str> for LegacyTariWalletError {
     }
 }
 
+/// Legacy errors cross the NAPI boundary the same way as the new ones,
+/// keeping their original wording
 impl From<LegacyTariWalletError> for napi::Error {
     fn from(err: LegacyTariWalletError) -> Self {
-        let (status, message) = match &err {
-            LegacyTariWalletError::InvalidConfig(_) => (Status::InvalidArg, err.to_string()),
-            LegacyTariWalletError::WalletNotFound => (Status::GenericFailure, err.to_string()),
-            LegacyTariWalletError::WalletAlreadyExists => (Status::InvalidArg, err.to_string()),
-            LegacyTariWalletError::InsufficientFunds => (Status::GenericFailure, err.to_string()),
-            LegacyTariWalletError::InvalidAddress(_) => (Status::InvalidArg, err.to_string()),
-            LegacyTariWalletError::TransactionNotFound(_) => (Status::GenericFailure, err.to_string()),
-            LegacyTariWalletError::NetworkError(_) => (Status::GenericFailure, err.to_string()),
-            LegacyTariWalletError::StorageError(_) => (Status::GenericFailure, err.to_string()),
-            LegacyTariWalletError::FFIError(_) => (Status::GenericFailure, err.to_string()),
-            LegacyTariWalletError::ValidationError(_) => (Status::InvalidArg, err.to_string()),
-            LegacyTariWalletError::TemporaryFailure(_) => (Status::GenericFailure, err.to_string()),
-        };
-
-        napi::Error::new(status, message)
+        let message = err.to_string();
+        let mut mapped = NewTariWalletError::from(err);
+        mapped.message = message;
+        mapped.into()
     }
 }
 
@@ -210,6 +200,7 @@ pub fn storage_error(msg: &str) -> TariWalletError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use napi::Status;
 
     #[test]
     fn test_error_conversion() {
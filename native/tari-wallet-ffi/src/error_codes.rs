@@ -44,6 +44,8 @@ pub enum WalletErrorCode {
     InvalidKernel = 2017,
     InvalidCommitment = 2018,
     InvalidRangeProof = 2019,
+    WatchOnly = 2044,
+    MessageTooLong = 2045,
     
     // Network errors (3000-3099)
     NetworkUnavailable = 3000,
@@ -226,23 +226,41 @@ impl std::error::Error for TariWalletError {
 }
 
 /// Convert TariWalletError to NAPI Error
+///
+/// The message is prefixed with `[code:Kind]`, where the kind is the error
+/// category, so callers can tell a network failure from a bad argument
+/// without parsing the message.
 impl From<TariWalletError> for napi::Error {
     fn from(err: TariWalletError) -> Self {
-        let status = match err.category() {
-            ErrorCategory::Validation => Status::InvalidArg,
-            ErrorCategory::Security => Status::GenericFailure,
-            ErrorCategory::Resource => Status::GenericFailure,
-            ErrorCategory::Network => Status::GenericFailure,
-            _ => Status::GenericFailure,
-        };
+        let status = napi_status(err.code);
+        let enhanced_message = format!("[{}:{}] {}", err.code as i32, err.category(), err.message);
 
-        // Include error code in the message for easier debugging
-        let enhanced_message = format!("[{}] {}", err.code as i32, err.message);
-        
         napi::Error::new(status, enhanced_message)
     }
 }
 
+/// NAPI status for an error code
+///
+/// Errors caused by a bad argument are `InvalidArg` wherever their category
+/// puts them, so JS can reject them without retrying.
+fn napi_status(code: WalletErrorCode) -> Status {
+    match code {
+        WalletErrorCode::OperationCancelled => Status::Cancelled,
+        WalletErrorCode::InvalidConfig
+        | WalletErrorCode::InvalidNetworkType
+        | WalletErrorCode::InvalidAddress
+        | WalletErrorCode::InvalidAmount
+        | WalletErrorCode::InvalidFee
+        | WalletErrorCode::MessageTooLong
+        | WalletErrorCode::InvalidPeerAddress
+        | WalletErrorCode::InvalidParameter
+        | WalletErrorCode::InvalidHandle
+        | WalletErrorCode::InvalidKey => Status::InvalidArg,
+        _ if code.category() == ErrorCategory::Validation => Status::InvalidArg,
+        _ => Status::GenericFailure,
+    }
+}
+
 /// Result type alias for Tari wallet operations
 pub type TariResult<T> = std::result::Result<T, TariWalletError>;
 
@@ -431,6 +449,25 @@ mod tests {
         assert_eq!(error.category(), ErrorCategory::Transaction);
     }
 
+    #[test]
+    fn test_napi_error_keeps_code_and_kind() {
+        let error: napi::Error = invalid_address("invalid_addr").into();
+        assert_eq!(error.status, Status::InvalidArg);
+        let prefix = format!("[{}:Transaction] ", WalletErrorCode::InvalidAddress as i32);
+        assert!(error.reason.starts_with(&prefix));
+
+        let error: napi::Error = TariWalletError::new(WalletErrorCode::InvalidHex, "zz").into();
+        assert_eq!(error.status, Status::InvalidArg);
+        assert!(error.reason.contains(":Validation] "));
+
+        let error: napi::Error = network_unavailable("no peers").into();
+        assert_eq!(error.status, Status::GenericFailure);
+        assert!(error.reason.contains(":Network] "));
+
+        let error: napi::Error = TariWalletError::new(WalletErrorCode::OperationCancelled, "stopped").into();
+        assert_eq!(error.status, Status::Cancelled);
+    }
+
     #[test]
     fn test_js_error_info_conversion() {
         let error = invalid_address("invalid_addr");
@@ -134,6 +134,18 @@ impl EventBridge {
         emit_wallet_event(self.wallet_handle, event_type, data)
     }
 
+    /// Handle an outbound transaction reaching the required confirmations
+    pub fn handle_transaction_confirmed(&self, tx_id: &str, confirmations: u32) -> napi::Result<()> {
+        let data = json!({
+            "id": tx_id,
+            "confirmations": confirmations,
+            "status": "confirmed",
+            "timestamp": chrono::Utc::now().timestamp_millis()
+        });
+
+        emit_wallet_event(self.wallet_handle, "tx:confirmed", data)
+    }
+
     /// Handle a balance update event
     pub fn handle_balance_event(&self, event: BalanceEvent) -> napi::Result<()> {
         let data = json!({
@@ -0,0 +1,122 @@
+/**
+ * Internal event bus between wallet subsystems
+ *
+ * Each wallet owns a broadcast channel that the send, receive and sync paths
+ * publish to when they move funds or change what the wallet has seen of the
+ * chain. Caches and the callbacks module subscribe to it, so a subsystem
+ * changing wallet state does not need to know what depends on that state.
+ *
+ * The bus is internal; JavaScript sees its effects through wallet events.
+ */
+
+use tokio::sync::broadcast;
+
+/// Events a wallet can buffer per subscriber, unless `event_bus_capacity` is set
+pub(crate) const DEFAULT_EVENT_BUS_CAPACITY: u32 = 256;
+
+/// A change to wallet state that other subsystems may depend on
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum WalletEvent {
+    /// An outbound transaction was created and broadcast; its amount and fee
+    /// have left the available balance
+    TransactionSent { tx_id: String, amount: u64 },
+    /// A new inbound transaction was detected
+    TransactionReceived { tx_id: String },
+    /// A transaction of either direction reached the required confirmations,
+    /// confirming the outputs it created
+    TransactionConfirmed { tx_id: String },
+    /// A sync finished scanning the chain up to `height`
+    SyncCompleted { height: u64 },
+    /// Blocks from `height` up were rolled back, unmining their transactions
+    BlocksRolledBack { height: u64 },
+    /// Outputs were added or replaced outside of a send, receive or sync,
+    /// such as by mining, importing a UTXO or restoring the database
+    OutputsChanged,
+}
+
+/// A wallet's event bus
+pub(crate) struct EventBus {
+    sender: broadcast::Sender<WalletEvent>,
+}
+
+impl EventBus {
+    /// A bus buffering up to `capacity` events for each subscriber
+    pub(crate) fn new(capacity: u32) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1) as usize);
+        Self { sender }
+    }
+
+    /// Tell every current subscriber about an event
+    ///
+    /// Publishing without subscribers is not an error; nothing depends on
+    /// the event yet.
+    pub(crate) fn publish(&self, event: WalletEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    /// Receive events published from now on
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<WalletEvent> {
+        self.sender.subscribe()
+    }
+}
+
+/// Tracks whether a cache is out of date with respect to a wallet's events
+///
+/// Any event invalidates the cache, as does missing events because the
+/// subscriber fell more than the bus capacity behind.
+pub(crate) struct CacheInvalidator {
+    events: broadcast::Receiver<WalletEvent>,
+    stale: bool,
+}
+
+impl CacheInvalidator {
+    pub(crate) fn new(bus: &EventBus) -> Self {
+        Self {
+            events: bus.subscribe(),
+            stale: false,
+        }
+    }
+
+    /// Whether an event arrived since the cache was last refreshed
+    pub(crate) fn is_stale(&mut self) -> bool {
+        loop {
+            match self.events.try_recv() {
+                Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => self.stale = true,
+                Err(_) => return self.stale,
+            }
+        }
+    }
+
+    /// Mark the cache as refreshed, discarding events already seen
+    pub(crate) fn refreshed(&mut self) {
+        self.is_stale();
+        self.stale = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_invalidate_caches_until_refreshed() {
+        let bus = EventBus::new(2);
+        let mut cache = CacheInvalidator::new(&bus);
+        assert!(!cache.is_stale());
+
+        bus.publish(WalletEvent::SyncCompleted { height: 10 });
+        assert!(cache.is_stale());
+        // Staleness is sticky until the cache is refreshed
+        assert!(cache.is_stale());
+        cache.refreshed();
+        assert!(!cache.is_stale());
+
+        // A subscriber that falls behind treats the missed events as changes
+        for height in 0..5 {
+            bus.publish(WalletEvent::BlocksRolledBack { height });
+        }
+        assert!(cache.is_stale());
+        cache.refreshed();
+        assert!(!cache.is_stale());
+    }
+}
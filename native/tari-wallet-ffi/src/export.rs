@@ -0,0 +1,217 @@
+/**
+ * Transaction history export for bookkeeping
+ *
+ * Transactions are written as CSV (RFC 4180, with a header row) or as a
+ * JSON array, one entry per transaction with the same fields either way.
+ * Amounts and fees stay in µT as integer strings so no precision is lost,
+ * and times are RFC 3339 in UTC.
+ */
+
+use serde_json::json;
+
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::TariWalletError;
+use crate::types::{JsTransactionInfo, JsTransactionStatus};
+
+const CSV_HEADER: [&str; 9] = [
+    "id", "timestamp", "direction", "amount", "fee", "status", "address", "message", "label",
+];
+
+/// Output format of `wallet_export_transactions`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    /// Parse "csv" or "json", in any case
+    pub(crate) fn parse(format: &str) -> Result<Self, TariWalletError> {
+        match format.to_ascii_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(TariWalletError::new(
+                WalletErrorCode::InvalidParameter,
+                format!("Invalid export format: {} (expected csv or json)", format),
+            )
+            .component("export")),
+        }
+    }
+
+    /// Write `transactions` in this format
+    pub(crate) fn write(self, transactions: &[JsTransactionInfo]) -> String {
+        match self {
+            Self::Csv => to_csv(transactions),
+            Self::Json => to_json(transactions),
+        }
+    }
+}
+
+/// Name of a `JsTransactionStatus` value, as in transaction events
+fn status_name(status: i32) -> &'static str {
+    const STATUSES: [(JsTransactionStatus, &str); 8] = [
+        (JsTransactionStatus::Pending, "pending"),
+        (JsTransactionStatus::Broadcast, "broadcast"),
+        (JsTransactionStatus::MinedUnconfirmed, "mined_unconfirmed"),
+        (JsTransactionStatus::Imported, "imported"),
+        (JsTransactionStatus::MinedConfirmed, "mined_confirmed"),
+        (JsTransactionStatus::Rejected, "rejected"),
+        (JsTransactionStatus::Cancelled, "cancelled"),
+        (JsTransactionStatus::Coinbase, "coinbase"),
+    ];
+    STATUSES
+        .iter()
+        .find(|(value, _)| *value as i32 == status)
+        .map_or("unknown", |(_, name)| name)
+}
+
+fn direction(tx: &JsTransactionInfo) -> &'static str {
+    if tx.is_inbound {
+        "inbound"
+    } else {
+        "outbound"
+    }
+}
+
+fn timestamp(tx: &JsTransactionInfo) -> String {
+    chrono::DateTime::from_timestamp(tx.timestamp as i64, 0)
+        .unwrap_or_default()
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// Characters that make a spreadsheet read a cell as a formula
+const FORMULA_PREFIXES: [char; 4] = ['=', '+', '-', '@'];
+
+/// Quote a CSV field if it holds a separator, quote or line break
+///
+/// A field that a spreadsheet would evaluate as a formula, such as a
+/// message of `=HYPERLINK(..)` from the sender, is prefixed with `'` so it
+/// is shown as text instead.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(FORMULA_PREFIXES) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn to_csv(transactions: &[JsTransactionInfo]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push_str("\r\n");
+    for tx in transactions {
+        let row = [
+            tx.id.as_str(),
+            &timestamp(tx),
+            direction(tx),
+            &tx.amount,
+            &tx.fee,
+            status_name(tx.status),
+            &tx.address,
+            &tx.message,
+            tx.local_label.as_deref().unwrap_or_default(),
+        ];
+        let row: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+fn to_json(transactions: &[JsTransactionInfo]) -> String {
+    let entries: Vec<serde_json::Value> = transactions
+        .iter()
+        .map(|tx| {
+            json!({
+                "id": tx.id,
+                "timestamp": timestamp(tx),
+                "direction": direction(tx),
+                "amount": tx.amount,
+                "fee": tx.fee,
+                "status": status_name(tx.status),
+                "address": tx.address,
+                "message": tx.message,
+                "label": tx.local_label,
+            })
+        })
+        .collect();
+    serde_json::Value::Array(entries).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction(message: &str, label: Option<&str>) -> JsTransactionInfo {
+        JsTransactionInfo {
+            id: "tx_1".to_string(),
+            amount: "1000".to_string(),
+            gross_amount: "1100".to_string(),
+            fee: "100".to_string(),
+            status: JsTransactionStatus::MinedConfirmed as i32,
+            message: message.to_string(),
+            timestamp: 1_700_000_000.0,
+            is_inbound: false,
+            address: "tari://testnet/recipient".to_string(),
+            lock_height: None,
+            confirmations: 3,
+            maturity_height: None,
+            output_type: "standard".to_string(),
+            local_label: label.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_csv_escapes_separators_and_quotes() {
+        let csv = ExportFormat::Csv.write(&[
+            transaction("rent, \"March\"", Some("line one\nline two")),
+            transaction("plain", None),
+        ]);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+
+        assert_eq!(lines[0], "id,timestamp,direction,amount,fee,status,address,message,label");
+        assert_eq!(
+            lines[1],
+            "tx_1,2023-11-14T22:13:20Z,outbound,1000,100,mined_confirmed,tari://testnet/recipient,\
+             \"rent, \"\"March\"\"\",\"line one\nline two\""
+        );
+        assert!(lines[2].ends_with(",plain,"));
+        assert_eq!(lines[3], "");
+    }
+
+    #[test]
+    fn test_csv_neutralises_formulas() {
+        let csv = ExportFormat::Csv.write(&[transaction(
+            "=HYPERLINK(\"http://evil\",\"click\")",
+            Some("@SUM(A1)"),
+        )]);
+        let row = csv.split("\r\n").nth(1).unwrap();
+        assert!(row.ends_with(",\"'=HYPERLINK(\"\"http://evil\"\",\"\"click\"\")\",'@SUM(A1)"));
+
+        for value in ["+1", "-1", "=1", "@1"] {
+            assert_eq!(csv_field(value), format!("'{}", value));
+        }
+        assert_eq!(csv_field("1000"), "1000");
+        assert_eq!(csv_field("a-b"), "a-b");
+    }
+
+    #[test]
+    fn test_json_export_keeps_every_field() {
+        let json = ExportFormat::parse("JSON")
+            .unwrap()
+            .write(&[transaction("rent, \"March\"", Some("bills"))]);
+        let entries: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(entries[0]["message"], "rent, \"March\"");
+        assert_eq!(entries[0]["label"], "bills");
+        assert_eq!(entries[0]["status"], "mined_confirmed");
+        assert_eq!(entries[0]["direction"], "outbound");
+
+        let error = ExportFormat::parse("xlsx").unwrap_err();
+        assert_eq!(error.code, WalletErrorCode::InvalidParameter);
+    }
+}
@@ -0,0 +1,158 @@
+/**
+ * Idempotency keys for outbound payments
+ *
+ * A send may carry a caller-chosen key. Sending again with a key used in
+ * the last day returns the original send instead of paying twice, so
+ * payment processors can retry a send whose outcome they never saw. Keys
+ * are saved in the wallet database's `idempotency_keys` table as soon as
+ * they are used, together with the recipient, amount, fee and status of
+ * the send, and outlive the wallet session. Keys past retention are
+ * deleted when the wallet opens and whenever a new key is recorded.
+ */
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::database::WalletDatabase;
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+
+/// How long a key keeps resolving to the transaction first sent with it
+const IDEMPOTENCY_KEY_RETENTION_SECS: i64 = 24 * 60 * 60;
+
+/// The transaction sent with an idempotency key
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct KeyedTransaction {
+    pub(crate) tx_id: String,
+    pub(crate) recipient: String,
+    /// µT credited to the recipient
+    pub(crate) amount: u64,
+    /// µT charged
+    pub(crate) fee: u64,
+    /// Status the transaction was sent with
+    pub(crate) status: i32,
+    /// Unix time the transaction was sent, in seconds
+    pub(crate) sent_at: i64,
+}
+
+/// Delete the keys that have expired since the wallet was last open
+pub(crate) fn prune_expired(database: &WalletDatabase) -> TariResult<()> {
+    prune(&database.connection(), chrono::Utc::now().timestamp())
+}
+
+/// The transaction sent with `key`, if it is still within the retention window
+pub(crate) fn keyed_transaction(database: &WalletDatabase, key: &str) -> TariResult<Option<KeyedTransaction>> {
+    let now = chrono::Utc::now().timestamp();
+    database
+        .connection()
+        .query_row(
+            "SELECT tx_id, recipient, amount, fee, status, sent_at FROM idempotency_keys
+             WHERE key = ?1 AND sent_at > ?2",
+            params![key, now - IDEMPOTENCY_KEY_RETENTION_SECS],
+            |row| {
+                Ok(KeyedTransaction {
+                    tx_id: row.get(0)?,
+                    recipient: row.get(1)?,
+                    amount: row.get(2)?,
+                    fee: row.get(3)?,
+                    status: row.get(4)?,
+                    sent_at: row.get(5)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(|e| {
+            idempotency_error(
+                WalletErrorCode::DatabaseCorrupted,
+                format!("Cannot read idempotency key {}: {}", key, e),
+            )
+        })
+}
+
+/// Remember the transaction sent with `key`
+pub(crate) fn record(database: &WalletDatabase, key: &str, keyed: &KeyedTransaction) -> TariResult<()> {
+    let connection = database.connection();
+    prune(&connection, chrono::Utc::now().timestamp())?;
+    connection
+        .execute(
+            "INSERT OR REPLACE INTO idempotency_keys (key, tx_id, recipient, amount, fee, status, sent_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                key,
+                keyed.tx_id,
+                keyed.recipient,
+                keyed.amount,
+                keyed.fee,
+                keyed.status,
+                keyed.sent_at
+            ],
+        )
+        .map_err(|e| {
+            idempotency_error(
+                WalletErrorCode::DatabaseCorrupted,
+                format!("Cannot save idempotency key {}: {}", key, e),
+            )
+        })?;
+    Ok(())
+}
+
+/// Delete the keys that are past retention at `now`
+fn prune(connection: &Connection, now: i64) -> TariResult<()> {
+    connection
+        .execute(
+            "DELETE FROM idempotency_keys WHERE sent_at <= ?1",
+            [now - IDEMPOTENCY_KEY_RETENTION_SECS],
+        )
+        .map_err(|e| {
+            idempotency_error(
+                WalletErrorCode::DatabaseCorrupted,
+                format!("Cannot delete expired idempotency keys: {}", e),
+            )
+        })?;
+    Ok(())
+}
+
+fn idempotency_error(code: WalletErrorCode, message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(code, message).component("idempotency")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database;
+
+    fn sent(tx_id: &str) -> KeyedTransaction {
+        KeyedTransaction {
+            tx_id: tx_id.to_string(),
+            recipient: "recipient".to_string(),
+            amount: 1_000,
+            fee: 25,
+            status: 1,
+            sent_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    #[test]
+    fn test_keys_persist_until_they_expire() {
+        let database = database::in_memory();
+        let first = sent("tx_1");
+        record(&database, "order-1", &first).unwrap();
+        assert_eq!(keyed_transaction(&database, "order-1").unwrap(), Some(first));
+
+        database
+            .connection()
+            .execute(
+                "UPDATE idempotency_keys SET sent_at = sent_at - ?1",
+                [IDEMPOTENCY_KEY_RETENTION_SECS],
+            )
+            .unwrap();
+        assert_eq!(keyed_transaction(&database, "order-1").unwrap(), None);
+
+        // Expired keys are deleted on the next write, not only hidden
+        record(&database, "order-2", &sent("tx_2")).unwrap();
+        let remaining: i64 = database
+            .connection()
+            .query_row("SELECT count(*) FROM idempotency_keys", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(remaining, 1);
+    }
+}
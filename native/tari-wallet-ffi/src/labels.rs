@@ -0,0 +1,66 @@
+/**
+ * Local transaction labels
+ *
+ * Users can tag transactions ("rent", "refund") for their own bookkeeping.
+ * Unlike the transaction message, a label never leaves this machine: labels
+ * are kept in the wallet database's `transaction_labels` table and survive
+ * restarts, backups and restores.
+ */
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::database::WalletDatabase;
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+
+/// Longest label accepted, in characters
+pub(crate) const MAX_LABEL_LENGTH: usize = 256;
+
+pub(crate) fn get(connection: &Connection, tx_id: &str) -> TariResult<Option<String>> {
+    connection
+        .query_row(
+            "SELECT label FROM transaction_labels WHERE tx_id = ?1",
+            [tx_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| {
+            labels_error(
+                WalletErrorCode::DatabaseCorrupted,
+                format!("Cannot read the label of {}: {}", tx_id, e),
+            )
+        })
+}
+
+/// Label a transaction
+///
+/// A missing or blank label removes the transaction's label.
+pub(crate) fn set(database: &WalletDatabase, tx_id: &str, label: Option<&str>) -> TariResult<()> {
+    let connection = database.connection();
+    let written = match label.map(str::trim).filter(|label| !label.is_empty()) {
+        Some(label) if label.chars().count() > MAX_LABEL_LENGTH => {
+            return Err(labels_error(
+                WalletErrorCode::InvalidFormat,
+                format!("Label is longer than {} characters", MAX_LABEL_LENGTH),
+            ));
+        }
+        Some(label) => connection.execute(
+            "INSERT OR REPLACE INTO transaction_labels (tx_id, label) VALUES (?1, ?2)",
+            params![tx_id, label],
+        ),
+        None => connection.execute("DELETE FROM transaction_labels WHERE tx_id = ?1", [tx_id]),
+    };
+    written.map_err(|e| write_error(tx_id, e))?;
+    Ok(())
+}
+
+fn write_error(tx_id: &str, e: rusqlite::Error) -> TariWalletError {
+    labels_error(
+        WalletErrorCode::DatabaseCorrupted,
+        format!("Cannot save the label of {}: {}", tx_id, e),
+    )
+}
+
+fn labels_error(code: WalletErrorCode, message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(code, message).component("labels")
+}
@@ -21,8 +21,25 @@ mod error_codes;
 mod error_mapping;
 mod types;
 mod wallet;
+mod database;
 mod callbacks;
 mod event_bridge;
+mod event_bus;
+mod emoji;
+mod export;
+mod crypto;
+mod advanced;
+mod runtime_pool;
+mod recovery;
+mod connectivity;
+mod comms;
+mod amount;
+mod logging;
+mod mnemonic;
+mod sync;
+mod offline;
+mod idempotency;
+mod labels;
 
 // Export legacy error types for backward compatibility
 pub use error::{LegacyTariWalletError, TariResult, JsErrorCode, JsErrorInfo};
@@ -36,17 +53,50 @@ pub use types::*;
 pub use wallet::*;
 pub use callbacks::*;
 pub use event_bridge::*;
+pub use crypto::*;
+pub use advanced::*;
+pub use runtime_pool::*;
+pub use recovery::*;
+pub use connectivity::*;
+pub use amount::*;
+pub use logging::*;
+pub use sync::*;
+pub use offline::*;
 
 /// Initialize logging for the FFI module
+///
+/// `level` uses the `JsWalletConfig.log_level` scale and replaces any
+/// previously configured level.
 #[napi]
 pub fn init_logging(level: Option<i32>) -> napi::Result<()> {
     let log_level = level.unwrap_or(2); // Default to Info level
-    
-    // Initialize logging system
-    // In a real implementation, this would set up the Tari logging system
-    println!("FFI logging initialized at level: {}", log_level);
-    
-    Ok(())
+    logging::apply_log_level(logging::level_from_config(log_level))
+}
+
+/// Versions and build settings of the native module, for bug reports
+#[napi(object)]
+pub struct JsBuildInfo {
+    pub sdk_version: String,
+    /// Tari release the wallet FFI was built against
+    pub tari_core_version: String,
+    /// Network the module was built for
+    pub network: String,
+    /// Short commit hash, or "unknown" when built outside a git checkout
+    pub git_commit: String,
+    /// "debug" or "release"
+    pub build_profile: String,
+}
+
+/// Get the versions and build settings of the native module
+#[napi]
+pub fn get_build_info() -> JsBuildInfo {
+    JsBuildInfo {
+        sdk_version: env!("CARGO_PKG_VERSION").to_string(),
+        tari_core_version: env!("TARI_CORE_VERSION").to_string(),
+        network: env!("BUILD_NETWORK").to_string(),
+        git_commit: env!("GIT_COMMIT").to_string(),
+        build_profile: env!("BUILD_PROFILE").to_string(),
+    }
 }
 
 /// Validate a Tari address
@@ -89,8 +139,7 @@ pub fn emoji_id_to_address(emoji_id: String, network: String) -> napi::Result<St
         }
     }
 
-    // Placeholder implementation - real implementation would parse emoji ID
-    Ok(format!("tari://{}/converted_from_emoji", network))
+    Ok(emoji::emoji_id_to_address(&emoji_id, &network)?)
 }
 
 /// Convert Tari address to emoji ID
@@ -103,14 +152,5 @@ pub fn address_to_emoji_id(address: String) -> napi::Result<String> {
         ));
     }
 
-    // Validate address format
-    if !address.starts_with("tari://") {
-        return Err(napi::Error::new(
-            napi::Status::InvalidArg,
-            "Invalid Tari address format".to_string(),
-        ));
-    }
-
-    // Placeholder implementation - real implementation would convert to emoji ID
-    Ok("🎯🚀💎🌟🔥✨🎭🎪🎨🎸🎪🚀".to_string())
+    Ok(emoji::address_to_emoji_id(&address)?)
 }
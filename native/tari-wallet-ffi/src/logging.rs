@@ -0,0 +1,306 @@
+/**
+ * Wallet logging: the global subscriber and its JavaScript callback
+ *
+ * The FFI installs one global `tracing` subscriber whose level filter can
+ * be reloaded at runtime, so the level from the first wallet config or
+ * `set_log_level` takes effect without reinitializing it. Besides writing
+ * to stderr, a layer turns each log event into a structured record and
+ * hands it to the callback registered with `set_log_callback`, so host
+ * applications can show wallet logs in their own logging UI.
+ */
+
+use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi_derive::napi;
+use once_cell::sync::{Lazy, OnceCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, Registry};
+
+/// Level used when logging starts before any level is configured
+const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::INFO;
+
+/// Handle for changing the installed subscriber's level filter
+static LEVEL_HANDLE: OnceCell<reload::Handle<LevelFilter, Registry>> = OnceCell::new();
+
+static LOG_CALLBACK: Lazy<Mutex<Option<LogCallback>>> = Lazy::new(|| Mutex::new(None));
+
+/// A structured log record delivered to JavaScript
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct JsLogRecord {
+    /// "error", "warn", "info", "debug" or "trace"
+    pub level: String,
+    /// Module path the record was logged from
+    pub target: String,
+    pub message: String,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: f64,
+    /// Structured fields attached to the record, formatted as strings
+    pub fields: HashMap<String, String>,
+}
+
+/// ThreadsafeFunction type alias for log record callbacks
+pub type LogCallback = ThreadsafeFunction<JsLogRecord, ErrorStrategy::Fatal>;
+
+/// Map a `JsWalletConfig.log_level` (0 error through 4 trace) to a filter
+pub(crate) fn level_from_config(level: i32) -> LevelFilter {
+    match level {
+        i32::MIN..=0 => LevelFilter::ERROR,
+        1 => LevelFilter::WARN,
+        2 => LevelFilter::INFO,
+        3 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Parse a level name: "off", "error", "warn", "info", "debug" or "trace"
+fn parse_log_level(level: &str) -> Result<LevelFilter> {
+    match level.to_ascii_lowercase().as_str() {
+        "off" => Ok(LevelFilter::OFF),
+        "error" => Ok(LevelFilter::ERROR),
+        "warn" => Ok(LevelFilter::WARN),
+        "info" => Ok(LevelFilter::INFO),
+        "debug" => Ok(LevelFilter::DEBUG),
+        "trace" => Ok(LevelFilter::TRACE),
+        _ => Err(Error::new(
+            Status::InvalidArg,
+            format!(
+                "Invalid log level: {} (expected off, error, warn, info, debug or trace)",
+                level
+            ),
+        )),
+    }
+}
+
+/// Install the global subscriber with a reloadable level filter
+fn install_subscriber(level: LevelFilter) -> Result<reload::Handle<LevelFilter, Registry>> {
+    let (filter, handle) = reload::Layer::new(level);
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_ansi(false),
+        )
+        .with(CallbackLayer {
+            sink: dispatch_to_callback,
+        })
+        .try_init()
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to install log subscriber: {}", e),
+            )
+        })?;
+    Ok(handle)
+}
+
+/// Install the subscriber at a `JsWalletConfig.log_level` unless it is
+/// already installed
+///
+/// Once installed, the level only changes through `apply_log_level`, so
+/// the first wallet config's level sticks.
+pub(crate) fn init_log_level(level: Option<i32>) -> Result<()> {
+    let level = level.map_or(DEFAULT_LOG_LEVEL, level_from_config);
+    LEVEL_HANDLE.get_or_try_init(|| install_subscriber(level))?;
+    Ok(())
+}
+
+/// Change the level of the installed subscriber, installing it if needed
+pub(crate) fn apply_log_level(level: LevelFilter) -> Result<()> {
+    let mut installed_now = false;
+    let handle = LEVEL_HANDLE.get_or_try_init(|| {
+        installed_now = true;
+        install_subscriber(level)
+    })?;
+    if installed_now {
+        return Ok(());
+    }
+
+    handle.reload(level).map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to change log level: {}", e),
+        )
+    })
+}
+
+/// Set the FFI log level without reinitializing the subscriber
+#[napi]
+pub fn set_log_level(level: String) -> Result<()> {
+    apply_log_level(parse_log_level(&level)?)
+}
+
+/// Collects an event's message and fields
+#[derive(Default)]
+struct RecordVisitor {
+    message: String,
+    fields: HashMap<String, String>,
+}
+
+impl RecordVisitor {
+    fn record(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = value;
+        } else {
+            self.fields.insert(field.name().to_string(), value);
+        }
+    }
+}
+
+impl Visit for RecordVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, format!("{:?}", value));
+    }
+}
+
+/// Layer that turns events into `JsLogRecord`s and passes them to a sink
+struct CallbackLayer<F> {
+    sink: F,
+}
+
+impl<S, F> Layer<S> for CallbackLayer<F>
+where
+    S: Subscriber,
+    F: Fn(JsLogRecord) + Send + Sync + 'static,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = RecordVisitor::default();
+        event.record(&mut visitor);
+
+        (self.sink)(JsLogRecord {
+            level: metadata.level().as_str().to_lowercase(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            timestamp: chrono::Utc::now().timestamp_millis() as f64,
+            fields: visitor.fields,
+        });
+    }
+}
+
+/// Send a record to the registered log callback, if any
+fn dispatch_to_callback(record: JsLogRecord) {
+    if let Ok(callback) = LOG_CALLBACK.lock() {
+        if let Some(callback) = callback.as_ref() {
+            callback.call(record, ThreadsafeFunctionCallMode::NonBlocking);
+        }
+    }
+}
+
+/// Route wallet log records to a JavaScript callback
+///
+/// Replaces any previously registered callback. Records are filtered by
+/// the current log level before they are dispatched.
+#[napi]
+pub fn set_log_callback(callback: LogCallback) -> Result<()> {
+    init_log_level(None)?;
+
+    let mut current = LOG_CALLBACK.lock().map_err(|_| {
+        Error::new(
+            Status::GenericFailure,
+            "Failed to acquire log callback lock",
+        )
+    })?;
+    if let Some(previous) = current.replace(callback) {
+        let _ = previous.abort();
+    }
+    Ok(())
+}
+
+/// Stop routing log records to JavaScript
+#[napi]
+pub fn clear_log_callback() -> Result<()> {
+    let mut current = LOG_CALLBACK.lock().map_err(|_| {
+        Error::new(
+            Status::GenericFailure,
+            "Failed to acquire log callback lock",
+        )
+    })?;
+    if let Some(previous) = current.take() {
+        let _ = previous.abort();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn collecting_subscriber(
+        level: LevelFilter,
+    ) -> (
+        impl Subscriber + Send + Sync,
+        reload::Handle<LevelFilter, Registry>,
+        Arc<Mutex<Vec<JsLogRecord>>>,
+    ) {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink_records = records.clone();
+        let (filter, handle) = reload::Layer::new(level);
+        let subscriber = tracing_subscriber::registry()
+            .with(filter)
+            .with(CallbackLayer {
+                sink: move |record| sink_records.lock().unwrap().push(record),
+            });
+        (subscriber, handle, records)
+    }
+
+    #[test]
+    fn test_records_are_structured_and_filtered() {
+        let (subscriber, _handle, records) = collecting_subscriber(LevelFilter::INFO);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(handle = 7, peer = "node", "Connected to base node");
+            tracing::debug!("Dropped at info level");
+        });
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.level, "info");
+        assert_eq!(record.message, "Connected to base node");
+        assert_eq!(record.target, module_path!());
+        assert_eq!(record.fields.get("handle").map(String::as_str), Some("7"));
+        assert_eq!(record.fields.get("peer").map(String::as_str), Some("node"));
+        assert!(record.timestamp > 0.0);
+    }
+
+    #[test]
+    fn test_level_reloads_without_reinstalling() {
+        let (subscriber, handle, records) = collecting_subscriber(LevelFilter::INFO);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("Before reload");
+            handle.reload(parse_log_level("DEBUG").unwrap()).unwrap();
+            tracing::debug!("After reload");
+        });
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message, "After reload");
+    }
+
+    #[test]
+    fn test_log_level_parsing() {
+        assert_eq!(parse_log_level("warn").unwrap(), LevelFilter::WARN);
+        assert_eq!(parse_log_level("off").unwrap(), LevelFilter::OFF);
+        assert!(parse_log_level("verbose").is_err());
+        assert!(parse_log_level("").is_err());
+
+        assert_eq!(level_from_config(0), LevelFilter::ERROR);
+        assert_eq!(level_from_config(3), LevelFilter::DEBUG);
+        assert_eq!(level_from_config(5), LevelFilter::TRACE);
+    }
+}
@@ -0,0 +1,709 @@
+/**
+ * Wallet cipher seed and its mnemonic encoding
+ *
+ * The cipher seed holds the wallet's root entropy and birthday, in the
+ * layout of Tari's `key_manager::cipher_seed` (version 2): the birthday,
+ * entropy and a MAC are enciphered with ChaCha20 under keys stretched
+ * from the default passphrase with Argon2, then framed by the version
+ * byte, the salt and a CRC-32 checksum. Those 33 bytes are written as 24
+ * words of 11 bits each from the BIP39 wordlist of the chosen language,
+ * so a mnemonic exported here restores in other Tari wallets and the
+ * other way round. When restoring without a language, it is detected
+ * from the words themselves.
+ *
+ * Keys are derived from the entropy as Tari's key manager derives them,
+ * with a domain-separated Blake2b hash of the entropy, branch and index.
+ */
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use blake2::digest::consts::{U32, U64};
+use blake2::Blake2b;
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use chacha20::ChaCha20;
+use napi::Result;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use tari_crypto::hash_domain;
+use tari_crypto::hashing::DomainSeparatedHasher;
+use tari_crypto::keys::SecretKey;
+use tari_crypto::ristretto::RistrettoSecretKey;
+use tari_crypto::tari_utilities::hex;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+
+/// Version byte of the current cipher seed layout
+const CIPHER_SEED_VERSION: u8 = 2;
+
+/// Bytes of root entropy in a cipher seed
+const ENTROPY_LEN: usize = 16;
+
+const BIRTHDAY_LEN: usize = 2;
+const MAC_LEN: usize = 5;
+const SALT_LEN: usize = 5;
+const CHECKSUM_LEN: usize = 4;
+
+/// Enciphered part of the seed: birthday, entropy and MAC
+const ENCIPHERED_LEN: usize = BIRTHDAY_LEN + ENTROPY_LEN + MAC_LEN;
+
+/// Serialized size: version, enciphered part, salt and checksum
+const CIPHER_SEED_LEN: usize = 1 + ENCIPHERED_LEN + SALT_LEN + CHECKSUM_LEN;
+
+/// Passphrase the seed is enciphered under when the user sets none
+const DEFAULT_CIPHER_SEED_PASSPHRASE: &str = "TARI_CIPHER_SEED";
+
+/// Argon2 memory cost in KiB, and the bytes of the salt it is given
+const ARGON2_MEMORY_KIB: u32 = 46 * 1024;
+const ARGON2_SALT_LEN: usize = 16;
+
+hash_domain!(KeyManagerDomain, "com.tari.base_layer.key_manager", 1);
+
+const HASHER_LABEL_PBKDF_SALT: &str = "cipher_seed_pbkdf_salt";
+const HASHER_LABEL_ENCRYPTION_NONCE: &str = "cipher_seed_encryption_nonce";
+const HASHER_LABEL_MAC: &str = "cipher_seed_mac";
+const HASHER_LABEL_DERIVE_KEY: &str = "derive_key";
+
+/// Key manager branch the wallet's spend key is derived on
+const SPEND_KEY_BRANCH: &str = "comms";
+
+/// Key manager branch the keys behind fresh one-sided addresses are derived on
+const ADDRESS_KEY_BRANCH: &str = "one_sided_address";
+
+/// Number of words in a wallet mnemonic
+pub(crate) const MNEMONIC_WORDS: usize = CIPHER_SEED_LEN * 8 / BITS_PER_WORD;
+
+const BITS_PER_WORD: usize = 11;
+
+/// Unix time of the day wallet birthdays are counted from (2022-01-01)
+const BIRTHDAY_GENESIS_FROM_UNIX_EPOCH: i64 = 1_640_995_200;
+
+/// The BIP39 wordlists, in Unicode NFKD as the BIP39 spec writes them
+static CHINESE_SIMPLIFIED_WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlists/chinese_simplified.txt").lines().collect());
+static CHINESE_TRADITIONAL_WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlists/chinese_traditional.txt").lines().collect());
+static ENGLISH_WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlists/english.txt").lines().collect());
+static FRENCH_WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlists/french.txt").lines().collect());
+static ITALIAN_WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlists/italian.txt").lines().collect());
+static JAPANESE_WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlists/japanese.txt").lines().collect());
+static KOREAN_WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlists/korean.txt").lines().collect());
+static SPANISH_WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlists/spanish.txt").lines().collect());
+
+/// A mnemonic wordlist and its language
+type Wordlist<'a> = (MnemonicLanguage, &'a [&'a str]);
+
+/// Languages Tari mnemonics can be written in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum MnemonicLanguage {
+    ChineseSimplified,
+    ChineseTraditional,
+    #[default]
+    English,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Spanish,
+}
+
+impl MnemonicLanguage {
+    const ALL: [Self; 8] = [
+        Self::ChineseSimplified,
+        Self::ChineseTraditional,
+        Self::English,
+        Self::French,
+        Self::Italian,
+        Self::Japanese,
+        Self::Korean,
+        Self::Spanish,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::ChineseSimplified => "chinese_simplified",
+            Self::ChineseTraditional => "chinese_traditional",
+            Self::English => "english",
+            Self::French => "french",
+            Self::Italian => "italian",
+            Self::Japanese => "japanese",
+            Self::Korean => "korean",
+            Self::Spanish => "spanish",
+        }
+    }
+
+    /// Parse a language name such as "english" or "spanish"
+    pub(crate) fn parse(name: &str) -> TariResult<Self> {
+        let normalized = name.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|language| language.name() == normalized)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|language| language.name()).collect();
+                mnemonic_error(
+                    WalletErrorCode::InvalidFormat,
+                    format!(
+                        "Unknown mnemonic language: {} (expected one of {})",
+                        name,
+                        names.join(", ")
+                    ),
+                )
+            })
+    }
+
+    fn wordlist(self) -> &'static [&'static str] {
+        match self {
+            Self::ChineseSimplified => &CHINESE_SIMPLIFIED_WORDLIST,
+            Self::ChineseTraditional => &CHINESE_TRADITIONAL_WORDLIST,
+            Self::English => &ENGLISH_WORDLIST,
+            Self::French => &FRENCH_WORDLIST,
+            Self::Italian => &ITALIAN_WORDLIST,
+            Self::Japanese => &JAPANESE_WORDLIST,
+            Self::Korean => &KOREAN_WORDLIST,
+            Self::Spanish => &SPANISH_WORDLIST,
+        }
+    }
+}
+
+/// The wordlists of every language
+fn all_wordlists() -> Vec<Wordlist<'static>> {
+    MnemonicLanguage::ALL
+        .into_iter()
+        .map(|language| (language, language.wordlist()))
+        .collect()
+}
+
+/// A word as typed, in the NFKD form the wordlists use
+fn normalize_word(word: &str) -> String {
+    word.trim().nfkd().collect::<String>().to_lowercase()
+}
+
+// Not every wordlist is sorted by code point, so the lookup is linear
+fn word_index(wordlist: &[&str], word: &str) -> Option<usize> {
+    let word = normalize_word(word);
+    wordlist.iter().position(|candidate| *candidate == word)
+}
+
+/// Wordlists containing every one of `words`, in `wordlists` order
+///
+/// Fails when a word is in no wordlist, or when each word is in some
+/// wordlist but no single wordlist has them all.
+fn candidate_wordlists<'a>(
+    words: &[String],
+    wordlists: &[Wordlist<'a>],
+) -> TariResult<Vec<Wordlist<'a>>> {
+    let candidates: Vec<_> = wordlists
+        .iter()
+        .filter(|(_, wordlist)| {
+            words
+                .iter()
+                .all(|word| word_index(wordlist, word).is_some())
+        })
+        .copied()
+        .collect();
+    if !candidates.is_empty() {
+        return Ok(candidates);
+    }
+
+    if let Some(word) = words.iter().find(|word| {
+        wordlists
+            .iter()
+            .all(|(_, wordlist)| word_index(wordlist, word).is_none())
+    }) {
+        return Err(mnemonic_error(
+            WalletErrorCode::InvalidFormat,
+            format!("Not a seed word: {}", word),
+        ));
+    }
+
+    let languages: Vec<_> = wordlists
+        .iter()
+        .filter(|(_, wordlist)| {
+            words
+                .iter()
+                .any(|word| word_index(wordlist, word).is_some())
+        })
+        .map(|(language, _)| language.name())
+        .collect();
+    Err(mnemonic_error(
+        WalletErrorCode::InvalidFormat,
+        format!(
+            "Seed words mix languages ({}); all words must come from one wordlist",
+            languages.join(", ")
+        ),
+    ))
+}
+
+fn mnemonic_error(code: WalletErrorCode, message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(code, message).component("mnemonic")
+}
+
+/// CRC-32 (IEEE) of `bytes`
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Root secret of a wallet, recoverable from its mnemonic
+pub(crate) struct CipherSeed {
+    /// Days since 2022-01-01 when the wallet was created
+    birthday: u16,
+    entropy: [u8; ENTROPY_LEN],
+    salt: [u8; SALT_LEN],
+}
+
+impl CipherSeed {
+    /// Generate a seed with fresh entropy and salt and today's birthday
+    pub(crate) fn new() -> Self {
+        let days = (chrono::Utc::now().timestamp() - BIRTHDAY_GENESIS_FROM_UNIX_EPOCH) / 86_400;
+        let mut entropy = [0u8; ENTROPY_LEN];
+        let mut salt = [0u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut entropy);
+        rand::thread_rng().fill_bytes(&mut salt);
+
+        Self {
+            birthday: days.clamp(0, u16::MAX as i64) as u16,
+            entropy,
+            salt,
+        }
+    }
+
+    /// The wallet's spend key
+    pub(crate) fn spend_key(&self) -> TariResult<RistrettoSecretKey> {
+        self.derive_key(SPEND_KEY_BRANCH, 0)
+    }
+
+    /// The key behind the one-sided address issued `index`-th
+    ///
+    /// Being derived from the seed, it is recovered with the seed words.
+    pub(crate) fn address_key(&self, index: u64) -> TariResult<RistrettoSecretKey> {
+        self.derive_key(ADDRESS_KEY_BRANCH, index)
+    }
+
+    /// The secret key at `index` on a key manager `branch`
+    fn derive_key(&self, branch: &str, index: u64) -> TariResult<RistrettoSecretKey> {
+        let hash = DomainSeparatedHasher::<Blake2b<U64>, KeyManagerDomain>::new_with_label(
+            HASHER_LABEL_DERIVE_KEY,
+        )
+        .chain(self.entropy)
+        .chain(branch.as_bytes())
+        .chain(index.to_le_bytes())
+        .finalize();
+        RistrettoSecretKey::from_uniform_bytes(hash.as_ref()).map_err(|e| {
+            mnemonic_error(
+                WalletErrorCode::CryptoError,
+                format!("Failed to derive a {} key: {}", branch, e),
+            )
+        })
+    }
+
+    /// ChaCha20 and MAC keys for a seed with `salt`, under the default passphrase
+    fn derive_cipher_keys(salt: &[u8]) -> TariResult<Zeroizing<[u8; 64]>> {
+        let argon2_salt = DomainSeparatedHasher::<Blake2b<U32>, KeyManagerDomain>::new_with_label(
+            HASHER_LABEL_PBKDF_SALT,
+        )
+        .chain(salt)
+        .finalize();
+
+        let mut keys = Zeroizing::new([0u8; 64]);
+        Params::new(ARGON2_MEMORY_KIB, 1, 1, Some(keys.len()))
+            .and_then(|params| {
+                Argon2::new(Algorithm::Argon2d, Version::V0x13, params).hash_password_into(
+                    DEFAULT_CIPHER_SEED_PASSPHRASE.as_bytes(),
+                    &argon2_salt.as_ref()[..ARGON2_SALT_LEN],
+                    keys.as_mut(),
+                )
+            })
+            .map_err(|e| {
+                mnemonic_error(
+                    WalletErrorCode::CryptoError,
+                    format!("Failed to derive the seed cipher keys: {}", e),
+                )
+            })?;
+        Ok(keys)
+    }
+
+    fn apply_stream_cipher(data: &mut [u8], encryption_key: &[u8], salt: &[u8]) {
+        let nonce = DomainSeparatedHasher::<Blake2b<U64>, KeyManagerDomain>::new_with_label(
+            HASHER_LABEL_ENCRYPTION_NONCE,
+        )
+        .chain(salt)
+        .finalize();
+        ChaCha20::new(encryption_key.into(), nonce.as_ref()[..12].into()).apply_keystream(data);
+    }
+
+    fn mac(birthday: &[u8], entropy: &[u8], salt: &[u8], mac_key: &[u8]) -> [u8; MAC_LEN] {
+        let hash = DomainSeparatedHasher::<Blake2b<U32>, KeyManagerDomain>::new_with_label(HASHER_LABEL_MAC)
+            .chain(birthday)
+            .chain(entropy)
+            .chain([CIPHER_SEED_VERSION])
+            .chain(salt)
+            .chain(mac_key)
+            .finalize();
+        let mut mac = [0u8; MAC_LEN];
+        mac.copy_from_slice(&hash.as_ref()[..MAC_LEN]);
+        mac
+    }
+
+    fn to_bytes(&self) -> TariResult<Zeroizing<[u8; CIPHER_SEED_LEN]>> {
+        let keys = Self::derive_cipher_keys(&self.salt)?;
+        let (encryption_key, mac_key) = keys.split_at(32);
+        let birthday = self.birthday.to_le_bytes();
+
+        let mut bytes = Zeroizing::new([0u8; CIPHER_SEED_LEN]);
+        bytes[0] = CIPHER_SEED_VERSION;
+        let enciphered = &mut bytes[1..1 + ENCIPHERED_LEN];
+        enciphered[..BIRTHDAY_LEN].copy_from_slice(&birthday);
+        enciphered[BIRTHDAY_LEN..BIRTHDAY_LEN + ENTROPY_LEN].copy_from_slice(&self.entropy);
+        enciphered[BIRTHDAY_LEN + ENTROPY_LEN..]
+            .copy_from_slice(&Self::mac(&birthday, &self.entropy, &self.salt, mac_key));
+        Self::apply_stream_cipher(enciphered, encryption_key, &self.salt);
+
+        bytes[1 + ENCIPHERED_LEN..CIPHER_SEED_LEN - CHECKSUM_LEN].copy_from_slice(&self.salt);
+        let checksum = crc32(&bytes[..CIPHER_SEED_LEN - CHECKSUM_LEN]);
+        bytes[CIPHER_SEED_LEN - CHECKSUM_LEN..].copy_from_slice(&checksum.to_le_bytes());
+        Ok(bytes)
+    }
+
+    fn from_bytes(bytes: &[u8; CIPHER_SEED_LEN]) -> TariResult<Self> {
+        let (body, checksum) = bytes.split_at(CIPHER_SEED_LEN - CHECKSUM_LEN);
+        if crc32(body).to_le_bytes() != checksum {
+            return Err(mnemonic_error(
+                WalletErrorCode::InvalidChecksum,
+                "Seed words checksum does not match; check for mistyped words",
+            ));
+        }
+        if body[0] != CIPHER_SEED_VERSION {
+            return Err(mnemonic_error(
+                WalletErrorCode::InvalidFormat,
+                format!("Unsupported seed version: {}", body[0]),
+            ));
+        }
+
+        let mut salt = [0u8; SALT_LEN];
+        salt.copy_from_slice(&body[1 + ENCIPHERED_LEN..]);
+        let keys = Self::derive_cipher_keys(&salt)?;
+        let (encryption_key, mac_key) = keys.split_at(32);
+        let mut plaintext = Zeroizing::new([0u8; ENCIPHERED_LEN]);
+        plaintext.copy_from_slice(&body[1..1 + ENCIPHERED_LEN]);
+        Self::apply_stream_cipher(plaintext.as_mut(), encryption_key, &salt);
+
+        let (birthday, rest) = plaintext.split_at(BIRTHDAY_LEN);
+        let (entropy, mac) = rest.split_at(ENTROPY_LEN);
+        if Self::mac(birthday, entropy, &salt, mac_key) != mac {
+            return Err(mnemonic_error(
+                WalletErrorCode::DecryptionFailed,
+                "Seed words could not be deciphered; they were not written with the default passphrase",
+            ));
+        }
+
+        let mut seed = Self {
+            birthday: u16::from_le_bytes([birthday[0], birthday[1]]),
+            entropy: [0u8; ENTROPY_LEN],
+            salt,
+        };
+        seed.entropy.copy_from_slice(entropy);
+        Ok(seed)
+    }
+
+    /// The enciphered seed as hex, the form the wallet database stores
+    pub(crate) fn to_hex(&self) -> TariResult<Zeroizing<String>> {
+        Ok(Zeroizing::new(hex::to_hex(&*self.to_bytes()?)))
+    }
+
+    /// Decipher a seed stored with `to_hex`
+    pub(crate) fn from_hex(enciphered: &str) -> TariResult<Self> {
+        let invalid = || {
+            mnemonic_error(
+                WalletErrorCode::InvalidFormat,
+                "Stored seed is not an enciphered cipher seed",
+            )
+        };
+        let bytes = Zeroizing::new(hex::from_hex(enciphered).map_err(|_| invalid())?);
+        let bytes: &[u8; CIPHER_SEED_LEN] = bytes.as_slice().try_into().map_err(|_| invalid())?;
+        Self::from_bytes(bytes)
+    }
+
+    /// Encode the seed as its mnemonic words in `language`
+    pub(crate) fn to_mnemonic(&self, language: MnemonicLanguage) -> TariResult<Vec<String>> {
+        Ok(encode_words(&*self.to_bytes()?, language.wordlist()))
+    }
+
+    /// Decode a seed from its mnemonic words
+    ///
+    /// Without a `language`, the words are tried against every
+    /// wordlist that contains them all, and the language that decodes is
+    /// returned alongside the seed.
+    pub(crate) fn from_mnemonic(
+        words: &[String],
+        language: Option<MnemonicLanguage>,
+    ) -> TariResult<(Self, MnemonicLanguage)> {
+        if words.len() != MNEMONIC_WORDS {
+            return Err(mnemonic_error(
+                WalletErrorCode::InvalidLength,
+                format!("Seed words must contain exactly {} words", MNEMONIC_WORDS),
+            ));
+        }
+
+        let candidates = match language {
+            Some(language) => vec![(language, language.wordlist())],
+            None => candidate_wordlists(words, &all_wordlists())?,
+        };
+
+        let mut first_error = None;
+        for (language, wordlist) in candidates {
+            match Self::from_words(words, wordlist) {
+                Ok(seed) => return Ok((seed, language)),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        Err(first_error.unwrap_or_else(|| {
+            mnemonic_error(
+                WalletErrorCode::InvalidFormat,
+                "No wordlist contains the seed words",
+            )
+        }))
+    }
+
+    fn from_words(words: &[String], wordlist: &[&str]) -> TariResult<Self> {
+        Self::from_bytes(&*decode_words(words, wordlist)?)
+    }
+}
+
+/// Check seed words decode as a Tari cipher seed, returning their language
+///
+/// Without a `language`, it is detected from the words. Tari seed words are
+/// not a BIP39 mnemonic, so a BIP39 checksum check rejects valid ones.
+#[napi]
+pub async fn validate_seed_words(words: Vec<String>, language: Option<String>) -> Result<String> {
+    let language = language.as_deref().map(MnemonicLanguage::parse).transpose()?;
+    let (_, language) = CipherSeed::from_mnemonic(&words, language)?;
+    Ok(language.name().to_string())
+}
+
+/// Write a serialized seed as words of 11 bits each
+fn encode_words(bytes: &[u8; CIPHER_SEED_LEN], wordlist: &[&str]) -> Vec<String> {
+    let (mut buffer, mut buffered_bits) = (0u32, 0);
+    let mut words = Vec::with_capacity(MNEMONIC_WORDS);
+
+    // Bits are taken least significant first, as Tari's mnemonic encoding does
+    for byte in bytes {
+        buffer |= (*byte as u32) << buffered_bits;
+        buffered_bits += 8;
+        if buffered_bits >= BITS_PER_WORD {
+            words.push(wordlist[buffer as usize & 0x7ff].to_string());
+            buffer >>= BITS_PER_WORD;
+            buffered_bits -= BITS_PER_WORD;
+        }
+    }
+    buffer.zeroize();
+    words
+}
+
+/// Read a serialized seed back from its words
+fn decode_words(words: &[String], wordlist: &[&str]) -> TariResult<Zeroizing<[u8; CIPHER_SEED_LEN]>> {
+    let mut bytes = Zeroizing::new([0u8; CIPHER_SEED_LEN]);
+    let (mut buffer, mut buffered_bits, mut next) = (0u32, 0, 0);
+    for word in words {
+        let index = word_index(wordlist, word).ok_or_else(|| {
+            mnemonic_error(
+                WalletErrorCode::InvalidFormat,
+                format!("Not a seed word: {}", word),
+            )
+        })?;
+
+        buffer |= (index as u32) << buffered_bits;
+        buffered_bits += BITS_PER_WORD;
+        while buffered_bits >= 8 {
+            bytes[next] = buffer as u8;
+            buffer >>= 8;
+            buffered_bits -= 8;
+            next += 1;
+        }
+    }
+    buffer.zeroize();
+    Ok(bytes)
+}
+
+impl Drop for CipherSeed {
+    fn drop(&mut self) {
+        self.entropy.zeroize();
+        self.salt.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wordlist_is_sorted_bip39_english() {
+        assert_eq!(ENGLISH_WORDLIST.len(), 2048);
+        assert!(ENGLISH_WORDLIST.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(ENGLISH_WORDLIST[0], "abandon");
+        assert_eq!(ENGLISH_WORDLIST[2047], "zoo");
+
+        for (language, wordlist) in all_wordlists() {
+            let distinct: std::collections::HashSet<_> = wordlist.iter().collect();
+            assert_eq!(distinct.len(), 2048, "{}", language.name());
+        }
+    }
+
+    #[test]
+    fn test_mnemonic_round_trip() {
+        let seed = CipherSeed::new();
+        let words = seed.to_mnemonic(MnemonicLanguage::English).unwrap();
+        assert_eq!(words.len(), MNEMONIC_WORDS);
+
+        let (restored, language) = CipherSeed::from_mnemonic(&words, None).unwrap();
+        assert_eq!(language, MnemonicLanguage::English);
+        assert_eq!(restored.birthday, seed.birthday);
+        assert_eq!(restored.entropy, seed.entropy);
+        assert_eq!(restored.salt, seed.salt);
+        assert_eq!(restored.spend_key().unwrap(), seed.spend_key().unwrap());
+        assert_ne!(CipherSeed::new().spend_key().unwrap(), seed.spend_key().unwrap());
+
+        let stored = CipherSeed::from_hex(&seed.to_hex().unwrap()).unwrap();
+        assert_eq!(stored.spend_key().unwrap(), seed.spend_key().unwrap());
+        assert!(CipherSeed::from_hex("00").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_validate_seed_words_reports_their_language() {
+        let words = CipherSeed::new().to_mnemonic(MnemonicLanguage::Spanish).unwrap();
+        assert_eq!(validate_seed_words(words.clone(), None).await.unwrap(), "spanish");
+        assert!(validate_seed_words(words.clone(), Some("english".to_string())).await.is_err());
+        assert!(validate_seed_words(words[..12].to_vec(), None).await.is_err());
+    }
+
+    #[test]
+    fn test_seed_is_enciphered() {
+        let seed = CipherSeed::new();
+        let bytes = seed.to_bytes().unwrap();
+        assert_eq!(bytes[0], CIPHER_SEED_VERSION);
+        assert!(!bytes.windows(ENTROPY_LEN).any(|window| window == seed.entropy));
+        assert_eq!(&bytes[1 + ENCIPHERED_LEN..CIPHER_SEED_LEN - CHECKSUM_LEN], &seed.salt);
+
+        // A seed whose checksum holds but whose MAC does not is rejected
+        let mut tampered = *bytes;
+        tampered[1 + ENCIPHERED_LEN] ^= 1;
+        let checksum = crc32(&tampered[..CIPHER_SEED_LEN - CHECKSUM_LEN]);
+        tampered[CIPHER_SEED_LEN - CHECKSUM_LEN..].copy_from_slice(&checksum.to_le_bytes());
+        let error = CipherSeed::from_bytes(&tampered).err().unwrap();
+        assert_eq!(error.code, WalletErrorCode::DecryptionFailed);
+    }
+
+    #[test]
+    fn test_keys_are_derived_per_branch_and_index() {
+        let seed = CipherSeed::new();
+        let spend_key = seed.spend_key().unwrap();
+        assert_eq!(seed.derive_key(SPEND_KEY_BRANCH, 0).unwrap(), spend_key);
+        assert_ne!(seed.derive_key(SPEND_KEY_BRANCH, 1).unwrap(), spend_key);
+        assert_ne!(seed.derive_key("other", 0).unwrap(), spend_key);
+        assert_ne!(seed.address_key(0).unwrap(), spend_key);
+        assert_ne!(seed.address_key(0).unwrap(), seed.address_key(1).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_mnemonics_are_rejected() {
+        let words = CipherSeed::new()
+            .to_mnemonic(MnemonicLanguage::English)
+            .unwrap();
+
+        let mut unknown = words.clone();
+        unknown[3] = "notaword".to_string();
+        let error = CipherSeed::from_mnemonic(&unknown, None).err().unwrap();
+        assert_eq!(error.code, WalletErrorCode::InvalidFormat);
+
+        let mut mistyped = words.clone();
+        mistyped[5] = if mistyped[5] == "zoo" { "zone" } else { "zoo" }.to_string();
+        let error = CipherSeed::from_mnemonic(&mistyped, None).err().unwrap();
+        assert_eq!(error.code, WalletErrorCode::InvalidChecksum);
+
+        let error = CipherSeed::from_mnemonic(&words[..12], None).err().unwrap();
+        assert_eq!(error.code, WalletErrorCode::InvalidLength);
+    }
+
+    #[test]
+    fn test_language_names() {
+        for language in MnemonicLanguage::ALL {
+            assert_eq!(MnemonicLanguage::parse(language.name()).unwrap(), language);
+        }
+        assert_eq!(
+            MnemonicLanguage::parse(" Spanish ").unwrap(),
+            MnemonicLanguage::Spanish
+        );
+        assert!(MnemonicLanguage::parse("klingon").is_err());
+
+        let bytes = CipherSeed::new().to_bytes().unwrap();
+        for (language, wordlist) in all_wordlists() {
+            let words = encode_words(&bytes, wordlist);
+            assert_eq!(*decode_words(&words, wordlist).unwrap(), *bytes);
+            let candidates = candidate_wordlists(&words, &all_wordlists()).unwrap();
+            assert!(candidates.iter().any(|(candidate, _)| *candidate == language));
+        }
+
+        let seed = CipherSeed::new();
+        let words = seed.to_mnemonic(MnemonicLanguage::Korean).unwrap();
+        let (restored, language) = CipherSeed::from_mnemonic(&words, None).unwrap();
+        assert_eq!(language, MnemonicLanguage::Korean);
+        assert_eq!(restored.entropy, seed.entropy);
+        assert!(CipherSeed::from_mnemonic(&words, Some(MnemonicLanguage::English)).is_err());
+    }
+
+    #[test]
+    fn test_words_match_in_any_unicode_form() {
+        let spanish = MnemonicLanguage::Spanish.wordlist();
+        // "ábaco" with a precomposed á, as most keyboards type it
+        assert_eq!(word_index(spanish, "\u{e1}baco"), Some(0));
+        assert_eq!(word_index(spanish, " A\u{301}BACO "), Some(0));
+        assert_eq!(word_index(spanish, "abaco"), None);
+    }
+
+    #[test]
+    fn test_language_detection() {
+        let english = ENGLISH_WORDLIST.as_slice();
+        let accented: Vec<String> = english.iter().map(|word| format!("e\u{301}{}", word)).collect();
+        let accented: Vec<&str> = accented.iter().map(String::as_str).collect();
+        let wordlists = [
+            (MnemonicLanguage::English, english),
+            (MnemonicLanguage::French, accented.as_slice()),
+        ];
+
+        let mut words = CipherSeed::new()
+            .to_mnemonic(MnemonicLanguage::English)
+            .unwrap();
+        let candidates = candidate_wordlists(&words, &wordlists).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].0, MnemonicLanguage::English);
+
+        words[2] = format!("é{}", words[2]);
+        let error = candidate_wordlists(&words, &wordlists).err().unwrap();
+        assert_eq!(error.code, WalletErrorCode::InvalidFormat);
+        assert!(error.message.contains("mix languages (english, french)"));
+
+        words[4] = "notaword".to_string();
+        let error = candidate_wordlists(&words, &wordlists).err().unwrap();
+        assert!(error.message.contains("Not a seed word: notaword"));
+    }
+}
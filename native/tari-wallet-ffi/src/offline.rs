@@ -0,0 +1,366 @@
+/**
+ * Offline signing for cold-storage wallets
+ *
+ * An online wallet, which may be watch-only, builds an unsigned transaction,
+ * the key-holding wallet signs it on an air-gapped machine, and the online
+ * wallet broadcasts the signed result. Both documents are versioned JSON so they can be moved
+ * between machines by file or QR code. The signature covers the unsigned
+ * transaction exactly as serialized, fields in declaration order.
+ */
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use tari_crypto::keys::PublicKey;
+use tari_crypto::ristretto::{RistrettoPublicKey, RistrettoSchnorr};
+use tari_crypto::tari_utilities::hex::{self, Hex};
+
+use crate::crypto::{parse_signature, signature_to_hex};
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+use crate::types::WalletHandle;
+use crate::wallet::{
+    broadcast_offline_transaction, ensure_spendable, estimate_fee, parse_amount,
+    parse_fee_per_gram, wallet_signing_key, wallet_view_key,
+};
+
+/// Identifies an offline transaction document
+const OFFLINE_TX_FORMAT: &str = "tari-offline-tx";
+
+/// Bumped whenever the serialized layout changes
+const OFFLINE_TX_VERSION: u32 = 1;
+
+/// Random bytes making each unsigned transaction unique
+const NONCE_LENGTH: usize = 16;
+
+/// A payment waiting to be signed by the wallet holding the spend key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnsignedTransaction {
+    format: String,
+    version: u32,
+    network: String,
+    /// Hex public spend key of the wallet that has to sign
+    sender_public_key: String,
+    recipient_address: String,
+    /// µT, as a decimal string
+    amount: String,
+    fee_per_gram: String,
+    /// µT, as a decimal string
+    fee: String,
+    nonce: String,
+}
+
+impl UnsignedTransaction {
+    /// Check the document is one this version understands, for `network`
+    fn validate(&self, network: &str) -> TariResult<()> {
+        if self.format != OFFLINE_TX_FORMAT {
+            return Err(offline_error(
+                WalletErrorCode::InvalidJson,
+                format!("Not an offline transaction: format is {}", self.format),
+            ));
+        }
+        if self.version != OFFLINE_TX_VERSION {
+            return Err(offline_error(
+                WalletErrorCode::IncompatibleVersion,
+                format!(
+                    "Unsupported offline transaction version {}, expected {}",
+                    self.version, OFFLINE_TX_VERSION
+                ),
+            ));
+        }
+        if self.network != network {
+            return Err(offline_error(
+                WalletErrorCode::InvalidNetworkType,
+                format!(
+                    "Transaction is for {} but the wallet is on {}",
+                    self.network, network
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check the transaction spends from the wallet with `public_key`
+    fn ensure_sender(&self, public_key: &RistrettoPublicKey) -> TariResult<()> {
+        if self.sender_public_key != public_key.to_hex() {
+            return Err(offline_error(
+                WalletErrorCode::InvalidKey,
+                format!(
+                    "Transaction spends from {}, not this wallet",
+                    self.sender_public_key
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// The bytes the signature commits to
+    fn signing_message(&self) -> TariResult<Vec<u8>> {
+        serde_json::to_vec(self)
+            .map_err(|e| offline_error(WalletErrorCode::SerializationError, e.to_string()))
+    }
+}
+
+/// An unsigned transaction with the sender's signature over it
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedTransaction {
+    transaction: UnsignedTransaction,
+    /// In the `sign_message` hex format
+    signature: String,
+}
+
+fn offline_error(code: WalletErrorCode, message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(code, message).component("offline")
+}
+
+fn to_json<T: Serialize>(value: &T) -> TariResult<String> {
+    serde_json::to_string(value)
+        .map_err(|e| offline_error(WalletErrorCode::SerializationError, e.to_string()))
+}
+
+fn from_json<'a, T: Deserialize<'a>>(serialized: &'a str, what: &str) -> TariResult<T> {
+    serde_json::from_str(serialized).map_err(|e| {
+        offline_error(
+            WalletErrorCode::InvalidJson,
+            format!("Malformed {}: {}", what, e),
+        )
+    })
+}
+
+/// Build a payment for the key-holding wallet to sign offline
+///
+/// Resolves to the serialized unsigned transaction. The balance is checked
+/// but nothing is spent until the signed transaction is broadcast.
+#[napi]
+pub async fn wallet_build_unsigned_transaction(
+    handle: WalletHandle,
+    recipient_address: String,
+    amount: String,
+    fee_per_gram: Option<String>,
+) -> Result<String> {
+    if recipient_address.is_empty() {
+        return Err(offline_error(
+            WalletErrorCode::InvalidAddress,
+            "Recipient address cannot be empty",
+        )
+        .into());
+    }
+
+    let value = parse_amount(&amount)?;
+    let fee_per_gram = parse_fee_per_gram(fee_per_gram.as_deref())?;
+    // Same single input, recipient and change shape as a regular send
+    let fee = estimate_fee(fee_per_gram, 1, 2);
+    ensure_spendable(handle, value, fee).await?;
+
+    let (network, view_key) = wallet_view_key(handle).await?;
+    let mut nonce = [0u8; NONCE_LENGTH];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    Ok(to_json(&UnsignedTransaction {
+        format: OFFLINE_TX_FORMAT.to_string(),
+        version: OFFLINE_TX_VERSION,
+        network,
+        sender_public_key: view_key.to_hex(),
+        recipient_address,
+        amount: value.to_string(),
+        fee_per_gram: fee_per_gram.to_string(),
+        fee: fee.to_string(),
+        nonce: hex::to_hex(&nonce),
+    })?)
+}
+
+/// Sign a serialized unsigned transaction with this wallet's spend key
+///
+/// Needs no network access, so it can run on an air-gapped machine.
+/// Resolves to the serialized signed transaction.
+#[napi]
+pub async fn wallet_sign_transaction(
+    handle: WalletHandle,
+    unsigned_transaction: String,
+) -> Result<String> {
+    let transaction: UnsignedTransaction =
+        from_json(&unsigned_transaction, "unsigned transaction")?;
+    let (network, spend_key) = wallet_signing_key(handle).await?;
+
+    transaction.validate(&network)?;
+    transaction.ensure_sender(&RistrettoPublicKey::from_secret_key(&spend_key))?;
+
+    let message = transaction.signing_message()?;
+    let signature =
+        RistrettoSchnorr::sign(&spend_key, &message, &mut rand::thread_rng()).map_err(|e| {
+            offline_error(
+                WalletErrorCode::SigningFailed,
+                format!("Failed to sign transaction: {}", e),
+            )
+        })?;
+
+    Ok(to_json(&SignedTransaction {
+        transaction,
+        signature: signature_to_hex(&signature),
+    })?)
+}
+
+/// Verify and broadcast a serialized signed transaction, resolving to its id
+///
+/// Each signed transaction can only be broadcast once.
+#[napi]
+pub async fn wallet_broadcast_signed_transaction(
+    handle: WalletHandle,
+    signed_transaction: String,
+) -> Result<String> {
+    let signed: SignedTransaction = from_json(&signed_transaction, "signed transaction")?;
+    let transaction = &signed.transaction;
+    let (network, view_key) = wallet_view_key(handle).await?;
+
+    transaction.validate(&network)?;
+    transaction.ensure_sender(&view_key)?;
+
+    let signature = parse_signature(&signed.signature)?;
+    if !signature.verify(&view_key, transaction.signing_message()?) {
+        return Err(offline_error(
+            WalletErrorCode::VerificationFailed,
+            "Transaction signature does not match its contents",
+        )
+        .into());
+    }
+
+    let amount = parse_amount(&transaction.amount)?;
+    let fee = transaction.fee.parse::<u64>().map_err(|_| {
+        offline_error(
+            WalletErrorCode::InvalidFee,
+            format!("Invalid fee: {}", transaction.fee),
+        )
+    })?;
+
+    broadcast_offline_transaction(handle, &transaction.nonce, amount, fee).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::JsWalletConfig;
+    use crate::wallet::{wallet_create, wallet_destroy, wallet_get_balance};
+
+    async fn create_wallet(name: &str) -> WalletHandle {
+        let storage_path =
+            std::env::temp_dir().join(format!("tari_offline_test_{}_{}", name, std::process::id()));
+
+        wallet_create(JsWalletConfig {
+            network: "testnet".to_string(),
+            storage_path: storage_path.to_string_lossy().to_string(),
+            log_path: None,
+            log_level: None,
+            passphrase: None,
+            seed_words: None,
+            mnemonic_language: None,
+            view_key: None,
+            num_rolling_log_files: None,
+            rolling_log_file_size: None,
+            balance_cache_ttl_ms: None,
+            encrypt_database: None,
+            max_broadcast_retries: None,
+            broadcast_retry_delay_seconds: None,
+            connection_timeout_ms: None,
+            required_confirmations: None,
+            max_fee_per_gram: None,
+            operation_timeouts: None,
+            peer_seeds: None,
+            connection_retry: None,
+            sqlite_pragmas: None,
+            max_read_connections: None,
+            network_overrides: None,
+            event_bus_capacity: None,
+            auto_connect_on_start: None,
+            transport: None,
+            tor_socks_proxy: None,
+            listener_address: None,
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_build_sign_and_broadcast() {
+        let online = create_wallet("online").await;
+        let other = create_wallet("other").await;
+        let (_, spend_key) = wallet_signing_key(online).await.unwrap();
+
+        let unsigned = wallet_build_unsigned_transaction(
+            online,
+            "tari://testnet/recipient".to_string(),
+            "1000".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+        let parsed: UnsignedTransaction = serde_json::from_str(&unsigned).unwrap();
+        assert_eq!(parsed.sender_public_key, RistrettoPublicKey::from_secret_key(&spend_key).to_hex());
+
+        // Only the wallet holding the spend key can sign
+        assert!(wallet_sign_transaction(other, unsigned.clone())
+            .await
+            .is_err());
+        let signed = wallet_sign_transaction(online, unsigned).await.unwrap();
+
+        // Tampering with the amount invalidates the signature
+        let mut tampered: SignedTransaction = serde_json::from_str(&signed).unwrap();
+        tampered.transaction.amount = "999999".to_string();
+        let error = wallet_broadcast_signed_transaction(online, to_json(&tampered).unwrap())
+            .await
+            .unwrap_err();
+        assert!(error.reason.contains("signature does not match"));
+
+        let before = wallet_get_balance(online).await.unwrap().available;
+        let tx_id = wallet_broadcast_signed_transaction(online, signed.clone())
+            .await
+            .unwrap();
+        assert!(tx_id.starts_with("tx_"));
+        assert_ne!(wallet_get_balance(online).await.unwrap().available, before);
+
+        // Replaying the same signed transaction is refused
+        assert!(wallet_broadcast_signed_transaction(online, signed)
+            .await
+            .is_err());
+
+        wallet_destroy(online).await.unwrap();
+        wallet_destroy(other).await.unwrap();
+    }
+
+    #[test]
+    fn test_validate_rejects_other_formats() {
+        let transaction = UnsignedTransaction {
+            format: OFFLINE_TX_FORMAT.to_string(),
+            version: OFFLINE_TX_VERSION,
+            network: "testnet".to_string(),
+            sender_public_key: String::new(),
+            recipient_address: "tari://testnet/recipient".to_string(),
+            amount: "1000".to_string(),
+            fee_per_gram: "5".to_string(),
+            fee: "635".to_string(),
+            nonce: "00".to_string(),
+        };
+        assert!(transaction.validate("testnet").is_ok());
+
+        let error = transaction.validate("mainnet").unwrap_err();
+        assert_eq!(error.code, WalletErrorCode::InvalidNetworkType);
+
+        let newer = UnsignedTransaction {
+            version: OFFLINE_TX_VERSION + 1,
+            ..transaction.clone()
+        };
+        let error = newer.validate("testnet").unwrap_err();
+        assert_eq!(error.code, WalletErrorCode::IncompatibleVersion);
+
+        let psbt = UnsignedTransaction {
+            format: "psbt".to_string(),
+            ..transaction
+        };
+        assert_eq!(
+            psbt.validate("testnet").unwrap_err().code,
+            WalletErrorCode::InvalidJson
+        );
+    }
+}
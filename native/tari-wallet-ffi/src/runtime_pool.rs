@@ -0,0 +1,251 @@
+/**
+ * Dedicated Tokio runtime for long-running wallet operations
+ *
+ * Wallet work is spawned onto this pool rather than the shared NAPI
+ * runtime so embedders can cap the number of worker threads and size
+ * their stacks. Reconfiguring swaps in a new runtime and drains the old
+ * one in the background.
+ */
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::runtime::{Builder, Runtime};
+
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::{TariResult, TariWalletError};
+
+/// Upper bound on worker threads accepted by `configure_runtime_pool`
+const MAX_WORKER_THREADS: u32 = 64;
+
+/// Stack size of each worker thread unless configured otherwise
+const DEFAULT_STACK_SIZE_BYTES: u32 = 3 * 1024 * 1024;
+
+/// Smallest worker stack accepted by `configure_runtime_pool`
+const MIN_STACK_SIZE_BYTES: u32 = 512 * 1024;
+
+/// Largest worker stack accepted by `configure_runtime_pool`
+const MAX_STACK_SIZE_BYTES: u32 = 256 * 1024 * 1024;
+
+/// How long a replaced runtime may take to finish its in-flight tasks
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+static POOL: Lazy<RwLock<RuntimePool>> = Lazy::new(|| {
+    let worker_threads = std::thread::available_parallelism()
+        .map(|n| n.get() as u32)
+        .unwrap_or(2)
+        .min(MAX_WORKER_THREADS);
+    RwLock::new(
+        RuntimePool::new(worker_threads, DEFAULT_STACK_SIZE_BYTES)
+            .expect("failed to start wallet runtime pool"),
+    )
+});
+
+static QUEUED_TASKS: AtomicUsize = AtomicUsize::new(0);
+static ACTIVE_TASKS: AtomicUsize = AtomicUsize::new(0);
+static COMPLETED_TASKS: AtomicU64 = AtomicU64::new(0);
+
+struct RuntimePool {
+    runtime: Runtime,
+    worker_threads: u32,
+    stack_size_bytes: u32,
+    /// Tasks spawned on this particular runtime that have not finished
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl RuntimePool {
+    fn new(worker_threads: u32, stack_size_bytes: u32) -> TariResult<Self> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(worker_threads as usize)
+            .thread_stack_size(stack_size_bytes as usize)
+            .thread_name("tari-wallet-worker")
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                TariWalletError::new(
+                    WalletErrorCode::ResourceExhausted,
+                    format!("Failed to start runtime pool: {}", e),
+                )
+                .component("runtime_pool")
+            })?;
+
+        Ok(Self {
+            runtime,
+            worker_threads,
+            stack_size_bytes,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Shut the runtime down once its in-flight tasks finish or the drain timeout passes
+    fn drain(self) {
+        std::thread::spawn(move || {
+            let deadline = Instant::now() + DRAIN_TIMEOUT;
+            while self.in_flight.load(Ordering::SeqCst) > 0 && Instant::now() < deadline {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            self.runtime.shutdown_timeout(Duration::from_secs(1));
+        });
+    }
+}
+
+fn lock_error() -> TariWalletError {
+    TariWalletError::new(WalletErrorCode::ThreadingError, "Failed to acquire runtime pool lock")
+        .component("runtime_pool")
+}
+
+/// Run a wallet operation on the runtime pool and wait for its result
+pub(crate) async fn run_on_pool<F, T>(future: F) -> Result<T>
+where
+    F: Future<Output = Result<T>> + Send + 'static,
+    T: Send + 'static,
+{
+    let join_handle = {
+        let pool = POOL.read().map_err(|_| lock_error())?;
+        let in_flight = pool.in_flight.clone();
+
+        QUEUED_TASKS.fetch_add(1, Ordering::SeqCst);
+        in_flight.fetch_add(1, Ordering::SeqCst);
+
+        pool.runtime.spawn(async move {
+            QUEUED_TASKS.fetch_sub(1, Ordering::SeqCst);
+            ACTIVE_TASKS.fetch_add(1, Ordering::SeqCst);
+
+            let result = future.await;
+
+            ACTIVE_TASKS.fetch_sub(1, Ordering::SeqCst);
+            COMPLETED_TASKS.fetch_add(1, Ordering::SeqCst);
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            result
+        })
+    };
+
+    join_handle.await.map_err(|e| {
+        TariWalletError::new(
+            WalletErrorCode::ThreadingError,
+            format!("Wallet task failed: {}", e),
+        )
+        .component("runtime_pool")
+    })?
+}
+
+/// Runtime pool statistics
+#[napi(object)]
+pub struct JsRuntimePoolStats {
+    pub worker_threads: u32,
+    pub stack_size_bytes: u32,
+    /// Worker threads currently running a wallet task
+    pub active_threads: u32,
+    pub queued_tasks: u32,
+    pub completed_tasks: i64,
+}
+
+/// Get statistics for the wallet runtime pool
+#[napi]
+pub fn get_runtime_pool_stats() -> Result<JsRuntimePoolStats> {
+    let (worker_threads, stack_size_bytes) = {
+        let pool = POOL.read().map_err(|_| lock_error())?;
+        (pool.worker_threads, pool.stack_size_bytes)
+    };
+    let active_tasks = ACTIVE_TASKS.load(Ordering::SeqCst) as u32;
+
+    Ok(JsRuntimePoolStats {
+        worker_threads,
+        stack_size_bytes,
+        active_threads: active_tasks.min(worker_threads),
+        queued_tasks: QUEUED_TASKS.load(Ordering::SeqCst) as u32,
+        completed_tasks: COMPLETED_TASKS.load(Ordering::SeqCst) as i64,
+    })
+}
+
+/// Resize the wallet runtime pool
+///
+/// `stack_size_bytes` sets each worker thread's stack, e.g. for validation
+/// workloads that recurse deeply; the current size is kept if unset.
+/// New work goes to the resized pool immediately; tasks already running
+/// on the previous pool are allowed to finish before it shuts down.
+#[napi]
+pub fn configure_runtime_pool(worker_threads: u32, stack_size_bytes: Option<u32>) -> Result<()> {
+    if worker_threads == 0 || worker_threads > MAX_WORKER_THREADS {
+        return Err(TariWalletError::new(
+            WalletErrorCode::ValueOutOfRange,
+            format!("Worker threads must be between 1 and {}", MAX_WORKER_THREADS),
+        )
+        .component("runtime_pool")
+        .into());
+    }
+
+    if let Some(size) = stack_size_bytes {
+        if !(MIN_STACK_SIZE_BYTES..=MAX_STACK_SIZE_BYTES).contains(&size) {
+            return Err(TariWalletError::new(
+                WalletErrorCode::ValueOutOfRange,
+                format!(
+                    "Worker stack size must be between {} and {} bytes",
+                    MIN_STACK_SIZE_BYTES, MAX_STACK_SIZE_BYTES
+                ),
+            )
+            .component("runtime_pool")
+            .into());
+        }
+    }
+
+    let stack_size_bytes = {
+        let pool = POOL.read().map_err(|_| lock_error())?;
+        let stack_size_bytes = stack_size_bytes.unwrap_or(pool.stack_size_bytes);
+        if (pool.worker_threads, pool.stack_size_bytes) == (worker_threads, stack_size_bytes) {
+            return Ok(());
+        }
+        stack_size_bytes
+    };
+
+    // Runtimes are never dropped on the calling thread, which may itself be async
+    let replacement = RuntimePool::new(worker_threads, stack_size_bytes)?;
+    let previous = std::mem::replace(&mut *POOL.write().map_err(|_| lock_error())?, replacement);
+
+    previous.drain();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resize_drains_in_flight_tasks() {
+        let completed_before = get_runtime_pool_stats().unwrap().completed_tasks;
+
+        let task = tokio::spawn(run_on_pool(async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok(42)
+        }));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let current = get_runtime_pool_stats().unwrap().worker_threads;
+        let target = if current == 2 { 1 } else { 2 };
+        configure_runtime_pool(target, None).unwrap();
+        assert_eq!(get_runtime_pool_stats().unwrap().worker_threads, target);
+
+        assert_eq!(task.await.unwrap().unwrap(), 42);
+        assert_eq!(run_on_pool(async { Ok("after resize") }).await.unwrap(), "after resize");
+        assert!(get_runtime_pool_stats().unwrap().completed_tasks >= completed_before + 2);
+
+        // Changing only the stack size also swaps the runtime
+        configure_runtime_pool(target, Some(MIN_STACK_SIZE_BYTES * 2)).unwrap();
+        let stats = get_runtime_pool_stats().unwrap();
+        assert_eq!((stats.worker_threads, stats.stack_size_bytes), (target, MIN_STACK_SIZE_BYTES * 2));
+        assert_eq!(run_on_pool(async { Ok(7) }).await.unwrap(), 7);
+        configure_runtime_pool(target, Some(DEFAULT_STACK_SIZE_BYTES)).unwrap();
+    }
+
+    #[test]
+    fn test_configure_rejects_invalid_sizes() {
+        assert!(configure_runtime_pool(0, None).is_err());
+        assert!(configure_runtime_pool(MAX_WORKER_THREADS + 1, None).is_err());
+        assert!(configure_runtime_pool(1, Some(MIN_STACK_SIZE_BYTES - 1)).is_err());
+        assert!(configure_runtime_pool(1, Some(MAX_STACK_SIZE_BYTES + 1)).is_err());
+    }
+}
@@ -0,0 +1,556 @@
+/**
+ * Syncing a wallet to the chain tip on demand
+ *
+ * `wallet_sync_to_tip` scans from the height the wallet last synced to up
+ * to the base node's current tip and resolves once it gets there, so apps
+ * resuming from the background can wait for an up-to-date wallet. Progress
+ * is reported as `sync:progress` wallet events, and a running sync can be
+ * cancelled with `wallet_cancel_sync`. Cancelling keeps the blocks scanned
+ * so far, so the next sync resumes where the cancelled one stopped.
+ *
+ * Hashes of recently synced blocks are kept so that a chain reorganization
+ * is noticed when the peer's chain no longer matches the local tip. The
+ * wallet is then rolled back to the last common block and resynced. A
+ * reorg deeper than the kept hashes has no known common block, so the
+ * wallet is rescanned from its birthday, or from genesis without one.
+ */
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::callbacks::emit_wallet_event;
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::TariWalletError;
+use crate::event_bridge::{create_event_bridge, SyncProgressEvent};
+use crate::runtime_pool::run_on_pool;
+use crate::types::WalletHandle;
+use crate::wallet::{
+    connect_if_offline, rollback_to_height, set_synced_height, wallet_sync_target,
+    with_wallet_timeout, TimedOperation,
+};
+
+/// Number of blocks requested from the base node per sync step
+const SYNC_BATCH_SIZE: u64 = 100;
+
+/// Number of recent block hashes kept for finding where a reorg forked
+const MAX_REORG_DEPTH: usize = 1_000;
+
+/// Minimum time between `sync:progress` events
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Status of each wallet's current or last sync by wallet handle
+static SYNCS: Lazy<Mutex<HashMap<WalletHandle, SyncEntry>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// State of a wallet's sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SyncStatus {
+    /// No sync has run, or the last one failed
+    Idle,
+    Syncing,
+    /// The last sync reached the chain tip
+    Synced,
+    /// The last sync was cancelled before reaching the tip
+    Cancelled,
+}
+
+impl SyncStatus {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Syncing => "syncing",
+            Self::Synced => "synced",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+struct SyncEntry {
+    status: SyncStatus,
+    /// Cancellation token checked between batches
+    cancelled: Arc<AtomicBool>,
+    /// Kept across syncs so the next one can detect a reorg at its start
+    headers: Arc<Mutex<LocalHeaders>>,
+}
+
+/// Outcome of a completed sync
+#[napi(object)]
+pub struct JsSyncResult {
+    pub start_height: i64,
+    pub end_height: i64,
+    pub duration_ms: f64,
+    /// Chain reorganizations rolled back and rescanned during the sync
+    pub reorgs_handled: u32,
+    pub blocks_rolled_back: i64,
+}
+
+/// A block header hash
+type BlockHash = [u8; 32];
+
+/// Hashes of the most recently synced blocks, oldest first
+///
+/// Only the last `MAX_REORG_DEPTH` blocks are kept; a reorg deeper than
+/// that rescans from the wallet's birthday.
+#[derive(Default)]
+struct LocalHeaders {
+    hashes: VecDeque<(u64, BlockHash)>,
+}
+
+/// Where to resume syncing after a chain reorganization
+#[derive(Debug, PartialEq, Eq)]
+struct Reorg {
+    /// First block that is no longer on the local chain
+    resume_height: u64,
+    blocks_rolled_back: u64,
+}
+
+impl LocalHeaders {
+    fn push(&mut self, height: u64, hash: BlockHash) {
+        if self.hashes.len() == MAX_REORG_DEPTH {
+            self.hashes.pop_front();
+        }
+        self.hashes.push_back((height, hash));
+    }
+
+    /// Compare the local tip against the peer's chain, rolling back to the
+    /// last common ancestor if they have diverged
+    ///
+    /// If none of the kept blocks is on the peer's chain the common
+    /// ancestor is unknown, and the wallet is rolled back to `rescan_height`.
+    fn detect_reorg(
+        &mut self,
+        peer_hash: impl Fn(u64) -> BlockHash,
+        rescan_height: u64,
+    ) -> Option<Reorg> {
+        let (tip_height, tip_hash) = *self.hashes.back()?;
+        if peer_hash(tip_height) == tip_hash {
+            return None;
+        }
+
+        while let Some((height, hash)) = self.hashes.back() {
+            if peer_hash(*height) == *hash {
+                break;
+            }
+            self.hashes.pop_back();
+        }
+        let resume_height = self
+            .hashes
+            .back()
+            .map_or(rescan_height.min(tip_height), |(height, _)| height + 1);
+
+        Some(Reorg {
+            resume_height,
+            blocks_rolled_back: tip_height + 1 - resume_height,
+        })
+    }
+}
+
+/// Hash of the peer's header at `height`
+fn peer_header_hash(_handle: WalletHandle, height: u64) -> BlockHash {
+    // Placeholder implementation - would request the header from the
+    // connected base node
+    let mut hash = [0u8; 32];
+    hash[..8].copy_from_slice(&height.to_le_bytes());
+    hash
+}
+
+fn sync_error(code: WalletErrorCode, message: impl Into<String>) -> TariWalletError {
+    TariWalletError::new(code, message).component("sync")
+}
+
+fn lock_error() -> TariWalletError {
+    sync_error(
+        WalletErrorCode::ThreadingError,
+        "Failed to acquire sync lock",
+    )
+}
+
+fn set_status(handle: WalletHandle, status: SyncStatus) {
+    if let Ok(mut syncs) = SYNCS.lock() {
+        if let Some(entry) = syncs.get_mut(&handle) {
+            entry.status = status;
+        }
+    }
+}
+
+/// Marks a wallet as syncing until finished, or idle if dropped unfinished
+struct SyncRegistration {
+    handle: WalletHandle,
+    cancelled: Arc<AtomicBool>,
+    headers: Arc<Mutex<LocalHeaders>>,
+    finished: bool,
+}
+
+impl SyncRegistration {
+    fn register(handle: WalletHandle) -> Result<Self> {
+        let mut syncs = SYNCS.lock().map_err(|_| lock_error())?;
+        if syncs
+            .get(&handle)
+            .is_some_and(|entry| entry.status == SyncStatus::Syncing)
+        {
+            return Err(sync_error(
+                WalletErrorCode::InvalidState,
+                format!("A sync is already running for wallet {}", handle),
+            )
+            .into());
+        }
+
+        let headers = syncs
+            .remove(&handle)
+            .map(|entry| entry.headers)
+            .unwrap_or_default();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        syncs.insert(
+            handle,
+            SyncEntry {
+                status: SyncStatus::Syncing,
+                cancelled: cancelled.clone(),
+                headers: headers.clone(),
+            },
+        );
+        Ok(Self {
+            handle,
+            cancelled,
+            headers,
+            finished: false,
+        })
+    }
+
+    fn finish(mut self, status: SyncStatus) {
+        self.finished = true;
+        set_status(self.handle, status);
+    }
+}
+
+impl Drop for SyncRegistration {
+    fn drop(&mut self) {
+        if !self.finished {
+            set_status(self.handle, SyncStatus::Idle);
+        }
+    }
+}
+
+/// Where a scan stopped and the reorgs it handled on the way
+struct ScanOutcome {
+    height: u64,
+    reorgs_handled: u32,
+    blocks_rolled_back: u64,
+}
+
+/// Scan blocks up to `tip_height`, rolling back on reorgs
+///
+/// Before each batch the local tip is checked against the peer's chain;
+/// on divergence the wallet is rolled back to the last common ancestor
+/// and rescanned from there. Stops early when the sync is cancelled; the
+/// height reached is still returned so the partial progress can be kept.
+async fn scan_to(
+    registration: &SyncRegistration,
+    start_height: u64,
+    tip_height: u64,
+    rescan_height: u64,
+) -> Result<ScanOutcome> {
+    let handle = registration.handle;
+    let bridge = create_event_bridge(handle);
+    let report = |height| {
+        let _ = bridge.handle_sync_progress(SyncProgressEvent {
+            current: height,
+            total: tip_height,
+        });
+    };
+
+    let mut outcome = ScanOutcome {
+        height: start_height,
+        reorgs_handled: 0,
+        blocks_rolled_back: 0,
+    };
+    let mut last_reported = Instant::now();
+    while outcome.height < tip_height {
+        if registration.cancelled.load(Ordering::SeqCst) {
+            return Ok(outcome);
+        }
+
+        let reorg = registration
+            .headers
+            .lock()
+            .map_err(|_| lock_error())?
+            .detect_reorg(|height| peer_header_hash(handle, height), rescan_height);
+        if let Some(reorg) = reorg {
+            rollback_to_height(handle, reorg.resume_height).await?;
+            let _ = emit_wallet_event(
+                handle,
+                "sync:reorg",
+                serde_json::json!({
+                    "blocksRolledBack": reorg.blocks_rolled_back,
+                    "resumeHeight": reorg.resume_height,
+                }),
+            );
+            outcome.height = reorg.resume_height;
+            outcome.reorgs_handled += 1;
+            outcome.blocks_rolled_back += reorg.blocks_rolled_back;
+            continue;
+        }
+
+        // Placeholder implementation - would fetch the batch from the base
+        // node and scan it for the wallet's outputs
+        tokio::task::yield_now().await;
+        let batch_end = (outcome.height + SYNC_BATCH_SIZE).min(tip_height);
+        {
+            let mut headers = registration.headers.lock().map_err(|_| lock_error())?;
+            for height in outcome.height..batch_end {
+                headers.push(height, peer_header_hash(handle, height));
+            }
+        }
+        outcome.height = batch_end;
+
+        if last_reported.elapsed() >= PROGRESS_INTERVAL {
+            report(outcome.height);
+            last_reported = Instant::now();
+        }
+    }
+
+    report(outcome.height);
+    Ok(outcome)
+}
+
+/// Sync the wallet to the current chain tip, resolving once it is reached
+///
+/// A wallet not connected to any base node dials the known ones first.
+/// Only one sync runs per wallet at a time. Chain reorganizations found
+/// along the way are rolled back and rescanned, and reported as
+/// `sync:reorg` events. If the sync is cancelled with `wallet_cancel_sync`,
+/// the blocks scanned so far are kept and the promise rejects with an
+/// operation-cancelled error. A sync still running after
+/// `operation_timeouts.sync_ms` is abandoned and rejects with an
+/// operation-timeout error.
+#[napi]
+pub async fn wallet_sync_to_tip(handle: WalletHandle) -> Result<JsSyncResult> {
+    connect_if_offline(handle).await?;
+    let (start_height, tip_height, rescan_height) = wallet_sync_target(handle).await?;
+    let registration = SyncRegistration::register(handle)?;
+
+    run_on_pool(async move {
+        let started = Instant::now();
+        let scan = scan_to(&registration, start_height, tip_height, rescan_height);
+        let outcome = with_wallet_timeout(handle, TimedOperation::Sync, scan).await?;
+        set_synced_height(handle, outcome.height).await?;
+
+        if outcome.height < tip_height {
+            registration.finish(SyncStatus::Cancelled);
+            let _ = emit_wallet_event(
+                handle,
+                "sync:cancelled",
+                serde_json::json!({ "height": outcome.height, "tipHeight": tip_height }),
+            );
+            return Err(sync_error(
+                WalletErrorCode::OperationCancelled,
+                format!(
+                    "Sync of wallet {} was cancelled at height {}",
+                    handle, outcome.height
+                ),
+            )
+            .into());
+        }
+
+        registration.finish(SyncStatus::Synced);
+        Ok(JsSyncResult {
+            start_height: start_height as i64,
+            end_height: outcome.height as i64,
+            duration_ms: started.elapsed().as_secs_f64() * 1000.0,
+            reorgs_handled: outcome.reorgs_handled,
+            blocks_rolled_back: outcome.blocks_rolled_back as i64,
+        })
+    })
+    .await
+}
+
+/// Cancel a wallet's running sync, returning whether one was running
+///
+/// The sync stops after its current batch of blocks.
+#[napi]
+pub fn wallet_cancel_sync(handle: WalletHandle) -> Result<bool> {
+    let syncs = SYNCS.lock().map_err(|_| lock_error())?;
+    Ok(match syncs.get(&handle) {
+        Some(entry) if entry.status == SyncStatus::Syncing => {
+            entry.cancelled.store(true, Ordering::SeqCst);
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Get a wallet's sync status: "idle", "syncing", "synced" or "cancelled"
+#[napi]
+pub fn wallet_get_sync_status(handle: WalletHandle) -> Result<String> {
+    let syncs = SYNCS.lock().map_err(|_| lock_error())?;
+    let status = syncs
+        .get(&handle)
+        .map_or(SyncStatus::Idle, |entry| entry.status);
+    Ok(status.name().to_string())
+}
+
+/// Stop a wallet's running sync, if any, and forget its status
+pub(crate) fn stop_sync(handle: WalletHandle) {
+    if let Ok(mut syncs) = SYNCS.lock() {
+        if let Some(entry) = syncs.remove(&handle) {
+            entry.cancelled.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::JsWalletConfig;
+    use crate::wallet::{set_tip_height, wallet_create, wallet_destroy, wallet_refresh_balance};
+    use std::path::PathBuf;
+
+    async fn create_wallet(name: &str, tip_height: u64) -> (WalletHandle, PathBuf) {
+        let storage_path =
+            std::env::temp_dir().join(format!("tari-sync-{}-{}", name, std::process::id()));
+        let handle = wallet_create(JsWalletConfig {
+            network: "testnet".to_string(),
+            storage_path: storage_path.to_string_lossy().to_string(),
+            log_path: None,
+            log_level: None,
+            passphrase: None,
+            seed_words: None,
+            mnemonic_language: None,
+            view_key: None,
+            num_rolling_log_files: None,
+            rolling_log_file_size: None,
+            balance_cache_ttl_ms: None,
+            encrypt_database: None,
+            max_broadcast_retries: None,
+            broadcast_retry_delay_seconds: None,
+            connection_timeout_ms: None,
+            required_confirmations: None,
+            max_fee_per_gram: None,
+            operation_timeouts: None,
+            peer_seeds: None,
+            connection_retry: None,
+            sqlite_pragmas: None,
+            max_read_connections: None,
+            network_overrides: None,
+            event_bus_capacity: None,
+            auto_connect_on_start: None,
+            transport: None,
+            tor_socks_proxy: None,
+            listener_address: None,
+        })
+        .await
+        .unwrap();
+        set_tip_height(handle, tip_height).await;
+        (handle, storage_path)
+    }
+
+    fn hash(height: u64, fork: u8) -> BlockHash {
+        let mut hash = [fork; 32];
+        hash[..8].copy_from_slice(&height.to_le_bytes());
+        hash
+    }
+
+    #[test]
+    fn test_reorg_rolls_back_to_common_ancestor() {
+        let mut headers = LocalHeaders::default();
+        for height in 0..10 {
+            headers.push(height, hash(height, 0));
+        }
+
+        assert_eq!(headers.detect_reorg(|height| hash(height, 0), 0), None);
+
+        // The peer's chain forked after block 6
+        let peer = |height| hash(height, if height > 6 { 1 } else { 0 });
+        assert_eq!(
+            headers.detect_reorg(peer, 0),
+            Some(Reorg {
+                resume_height: 7,
+                blocks_rolled_back: 3,
+            })
+        );
+        assert_eq!(headers.hashes.back().unwrap().0, 6);
+        assert_eq!(headers.detect_reorg(peer, 0), None);
+    }
+
+    #[test]
+    fn test_reorg_deeper_than_kept_headers_rescans_from_birthday() {
+        let mut headers = LocalHeaders::default();
+        for height in 0..MAX_REORG_DEPTH as u64 + 50 {
+            headers.push(height, hash(height, 0));
+        }
+        assert_eq!(headers.hashes.len(), MAX_REORG_DEPTH);
+
+        // The oldest kept block is 50, but the fork may be older than that
+        let reorg = headers.detect_reorg(|height| hash(height, 1), 20).unwrap();
+        assert_eq!(reorg.resume_height, 20);
+        assert_eq!(reorg.blocks_rolled_back, MAX_REORG_DEPTH as u64 + 30);
+        assert!(headers.hashes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync_resolves_at_tip_and_resumes_from_it() {
+        let (handle, dir) = create_wallet("tip", 1_050).await;
+
+        let result = wallet_sync_to_tip(handle).await.unwrap();
+        assert_eq!(result.start_height, 0);
+        assert_eq!(result.end_height, 1_050);
+        assert_eq!(result.reorgs_handled, 0);
+        assert_eq!(result.blocks_rolled_back, 0);
+
+        set_tip_height(handle, 1_200).await;
+        let result = wallet_sync_to_tip(handle).await.unwrap();
+        assert_eq!(result.start_height, 1_050);
+        assert_eq!(result.end_height, 1_200);
+        assert!(!wallet_cancel_sync(handle).unwrap());
+        assert_eq!(wallet_get_sync_status(handle).unwrap(), "synced");
+
+        wallet_destroy(handle).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_balance_reports_when_it_was_last_synced() {
+        let (handle, dir) = create_wallet("balance", 500).await;
+
+        let balance = wallet_refresh_balance(handle).await.unwrap();
+        assert_eq!(balance.last_updated_at, None);
+        assert!(!balance.synced);
+
+        wallet_sync_to_tip(handle).await.unwrap();
+        let balance = wallet_refresh_balance(handle).await.unwrap();
+        assert!(balance.last_updated_at.is_some());
+        assert!(balance.synced);
+
+        // A new block leaves the wallet behind until the next sync
+        set_tip_height(handle, 501).await;
+        assert!(!wallet_refresh_balance(handle).await.unwrap().synced);
+
+        wallet_destroy(handle).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sync_can_be_cancelled() {
+        let (handle, dir) = create_wallet("cancel", u64::MAX / 2).await;
+
+        assert_eq!(wallet_get_sync_status(handle).unwrap(), "idle");
+        let sync = tokio::spawn(wallet_sync_to_tip(handle));
+        while wallet_get_sync_status(handle).unwrap() != "syncing" {
+            tokio::task::yield_now().await;
+        }
+        assert!(wallet_sync_to_tip(handle).await.is_err());
+        assert!(wallet_cancel_sync(handle).unwrap());
+
+        let error = sync.await.unwrap().err().unwrap();
+        assert!(error.reason.contains("cancelled"));
+        assert_eq!(wallet_get_sync_status(handle).unwrap(), "cancelled");
+        assert!(!wallet_cancel_sync(handle).unwrap());
+
+        wallet_destroy(handle).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -5,6 +5,19 @@
 
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
+use std::fs;
+use std::path::Path;
+use tari_crypto::ristretto::RistrettoPublicKey;
+use tari_crypto::tari_utilities::hex::Hex;
+
+use crate::comms::{parse_listener_address, Transport};
+use crate::connectivity::parse_base_node_peer;
+use crate::database::MAX_READ_CONNECTIONS_LIMIT;
+use crate::mnemonic::MnemonicLanguage;
+
+/// Local development network, backed by a base node the tests control
+#[cfg(any(test, feature = "localnet"))]
+pub(crate) const LOCALNET: &str = "localnet";
 
 /// Wallet configuration for creating or restoring wallets
 #[napi(object)]
@@ -15,8 +28,71 @@ pub struct JsWalletConfig {
     pub log_level: Option<i32>,
     pub passphrase: Option<String>,
     pub seed_words: Option<Vec<String>>,
+    pub mnemonic_language: Option<String>, // e.g. "english"; detected from seed_words if unset
+    pub view_key: Option<String>, // Hex public key; creates a watch-only wallet instead of using seed words
     pub num_rolling_log_files: Option<u32>,
     pub rolling_log_file_size: Option<u32>,
+    pub balance_cache_ttl_ms: Option<u32>, // 0 disables balance caching
+    pub encrypt_database: Option<bool>, // Key the wallet database with the passphrase
+    pub max_broadcast_retries: Option<u32>, // 0 disables rebroadcasting
+    pub broadcast_retry_delay_seconds: Option<u32>,
+    pub connection_timeout_ms: Option<u32>, // Limit on dialing a base node
+    pub required_confirmations: Option<u32>, // Before a mined transaction is confirmed
+    pub max_fee_per_gram: Option<u32>, // Sends above it are refused; defaults to 10x the normal fee
+    pub operation_timeouts: Option<JsOperationTimeouts>,
+    pub peer_seeds: Option<Vec<String>>, // "<public key>::<multiaddr>" base nodes, e.g. for private networks
+    pub connection_retry: Option<JsRetryPolicy>, // Backoff between attempts to dial a base node
+    pub sqlite_pragmas: Option<JsSqlitePragmas>, // Tune the wallet database for its storage medium
+    pub max_read_connections: Option<u32>, // Read-only database connections for queries; defaults to 4
+    pub network_overrides: Option<JsNetworkOverrides>, // Replace the network's built-in peers
+    pub event_bus_capacity: Option<u32>, // Wallet events buffered for each internal subscriber
+    pub auto_connect_on_start: Option<bool>, // Dial configured base nodes on open; defaults to true
+    pub transport: Option<String>, // "tcp" (default) or "tor"
+    pub tor_socks_proxy: Option<JsTorSocksProxy>, // Only with the tor transport
+    pub listener_address: Option<String>, // "<ip>:<port>" for inbound peer connections; defaults to 127.0.0.1:18188
+}
+
+/// SOCKS5 proxy the tor transport connects through, normally the local Tor daemon
+#[napi(object)]
+pub struct JsTorSocksProxy {
+    pub address: Option<String>, // "<host>:<port>"; defaults to 127.0.0.1:9050
+    pub username: Option<String>, // Set with password if the proxy needs authentication
+    pub password: Option<String>,
+}
+
+/// Peers for a private Tari network, used instead of the network's built-in ones
+#[napi(object)]
+pub struct JsNetworkOverrides {
+    pub base_node_addresses: Option<Vec<String>>, // "<public key>::<multiaddr>"
+}
+
+/// SQLite settings for the wallet database, each left at SQLite's default if unset
+#[napi(object)]
+pub struct JsSqlitePragmas {
+    pub journal_mode: Option<String>, // "WAL", "DELETE" or "MEMORY"
+    pub synchronous: Option<String>, // "OFF", "NORMAL", "FULL" or "EXTRA"
+    pub cache_size: Option<i64>, // Pages if positive, KiB if negative
+    pub temp_store: Option<String>, // "DEFAULT", "FILE" or "MEMORY"
+    pub mmap_size: Option<i64>, // Bytes of the database to memory map; 0 disables it
+}
+
+/// Retry behaviour for failed attempts, each setting with a default if unset
+#[napi(object)]
+pub struct JsRetryPolicy {
+    pub max_attempts: Option<u32>, // Including the first attempt; 1 disables retrying
+    pub base_delay_ms: Option<u32>, // Before the first retry
+    pub max_delay_ms: Option<u32>,
+    pub multiplier: Option<f64>, // Applied to the delay after each retry
+    pub jitter: Option<bool>, // Randomise each delay to between half and all of it
+}
+
+/// Limits in milliseconds on wallet operations, each with a default if unset
+#[napi(object)]
+pub struct JsOperationTimeouts {
+    pub create_ms: Option<u32>,
+    pub balance_ms: Option<u32>,
+    pub send_ms: Option<u32>,
+    pub sync_ms: Option<u32>,
 }
 
 /// Balance information with all wallet balance components
@@ -26,19 +102,48 @@ pub struct JsBalance {
     pub pending_incoming: String,
     pub pending_outgoing: String,
     pub timelocked: String,
+    pub stale: bool, // Last known balance, served because the base node could not be reached
+    pub last_updated_at: Option<f64>, // Unix timestamp of the sync the balance reflects
+    pub synced: bool, // Whether the wallet had scanned up to the chain tip
 }
 
 /// Transaction information structure
 #[napi(object)]
 pub struct JsTransactionInfo {
     pub id: String, // Transaction ID as string
-    pub amount: String,
-    pub fee: String,
+    pub amount: String, // Credited to the recipient, net of the fee
+    pub gross_amount: String, // Debited from the sender: amount plus fee outbound, amount inbound
+    pub fee: String, // Paid by this wallet; "0" for inbound transactions
     pub status: i32, // Transaction status enum value
     pub message: String,
     pub timestamp: f64, // Unix timestamp
     pub is_inbound: bool,
     pub address: String, // Tari address as string
+    pub lock_height: Option<i64>, // Block height before which the output cannot be spent
+    pub confirmations: u32,
+    pub maturity_height: Option<i64>, // From the output features; set for coinbase and time-locked outputs
+    pub output_type: String, // "standard", "coinbase" or "burn"
+    pub local_label: Option<String>, // Set with wallet_set_transaction_label; never broadcast
+}
+
+/// Criteria for searching transactions; unset criteria match everything
+#[napi(object)]
+pub struct JsTxFilter {
+    pub min_amount: Option<String>, // µT, inclusive
+    pub max_amount: Option<String>, // µT, inclusive
+    pub from_timestamp: Option<f64>, // Unix seconds, inclusive
+    pub to_timestamp: Option<f64>, // Unix seconds, inclusive
+    pub address: Option<String>, // Counterparty address or emoji ID
+    pub direction: Option<String>, // "inbound" or "outbound"
+}
+
+/// One page of transaction search results, newest first
+#[napi(object)]
+pub struct JsTransactionPage {
+    pub transactions: Vec<JsTransactionInfo>,
+    pub total: u32, // Matching transactions across all pages
+    pub offset: u32,
+    pub has_more: bool,
 }
 
 /// Contact information
@@ -60,12 +165,127 @@ pub struct JsUtxoInfo {
     pub status: i32, // UTXO status enum
 }
 
+/// Output count and value totals, in µT
+#[napi(object)]
+pub struct JsUtxoSummary {
+    pub count: u32,
+    pub total_value: String,
+    pub unspent_value: String,
+    pub spendable_count: u32, // Unspent outputs past their maturity height
+}
+
 /// Transaction sending options
 #[napi(object)]
 pub struct JsSendTransactionOptions {
     pub fee_per_gram: Option<String>,
     pub message: Option<String>,
     pub is_one_sided: Option<bool>,
+    pub allow_high_fee: Option<bool>, // Send even if fee_per_gram exceeds the wallet's max_fee_per_gram
+    pub idempotency_key: Option<String>, // Repeating a send with the same key returns the original transaction id
+    pub covenant: Option<i64>, // Handle from create_covenant, attached to the recipient's output
+}
+
+/// A single payment in a batch send
+#[napi(object)]
+pub struct JsPayment {
+    pub destination: String,
+    pub amount: String,
+    pub message: Option<String>,
+}
+
+/// An existing output to add to the wallet, e.g. from a paper wallet
+#[napi(object)]
+pub struct JsImportUtxo {
+    pub amount: String, // µT
+    pub spending_key: String, // Hex blinding factor the commitment was made with
+    pub commitment: String, // Hex Pedersen commitment, as on chain
+    pub range_proof: String, // Hex Bulletproofs+ proof for the commitment
+    pub sender_offset_public_key: String, // Hex key the metadata signature is made with
+    pub metadata_signature: String, // Hex commitment and public key signature over the output metadata
+    pub script: Option<String>, // Hex serialized TariScript
+    pub input_data: Option<Vec<String>>, // Script input stack, bottom first, e.g. "PublicKey(<hex>)"
+    pub source_address: Option<String>,
+    pub message: Option<String>,
+}
+
+/// Per-payment outcome of a batch send
+#[napi(object)]
+pub struct JsBatchResult {
+    pub tx_id: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Summary of a transaction assembled in dry-run mode
+#[napi(object)]
+pub struct JsDryRunResult {
+    pub fee_estimate: String,
+    pub input_count: u32,
+    pub output_count: u32,
+    pub would_succeed: bool,
+}
+
+/// Outcome of a send, so callers need no follow-up query for the fee
+#[napi(object)]
+pub struct JsSendResult {
+    pub tx_id: String,
+    pub amount: String, // µT credited to the recipient
+    pub fee: String, // µT actually charged
+    pub status: i32, // Transaction status enum value
+    pub timestamp: f64, // Unix timestamp of the first broadcast
+}
+
+/// Result of burning funds, used to later prove the burn
+#[napi(object)]
+pub struct JsBurnResult {
+    pub tx_id: String,
+    pub commitment: String, // Hex Pedersen commitment of the burnt value
+    pub range_proof: String, // Hex Bulletproofs+ range proof for the commitment
+    pub blinding_factor: String, // Hex blinding factor opening the commitment; keep it secret until the claim
+}
+
+/// Broadcast state of an outbound transaction
+#[napi(object)]
+pub struct JsBroadcastStatus {
+    pub retry_count: u32,
+    pub last_broadcast_at: f64, // Unix timestamp
+    pub seen_by_network: bool,
+}
+
+/// Outcome of rebroadcasting a wallet's pending transactions
+///
+/// Every attempted transaction counts as exactly one of succeeded, failed
+/// or unacknowledged.
+#[napi(object)]
+pub struct JsRebroadcastSummary {
+    pub attempted: u32,
+    pub succeeded: u32, // Accepted by a base node
+    pub failed: u32,
+    pub unacknowledged: u32, // Submitted, but no base node confirmed receiving it
+}
+
+/// Structure of a transaction, for detailed transaction views
+#[napi(object)]
+pub struct JsTransactionDetails {
+    pub tx_id: String,
+    pub is_inbound: bool,
+    pub kernels: u32,
+    pub inputs: Option<u32>, // Unknown for inbound transactions, whose inputs are the sender's
+    pub outputs: Option<u32>, // Unknown for inbound transactions, which may include the sender's change
+    pub maturity_height: Option<i64>, // Height from which the outputs can be spent, if they are locked
+    pub confirmations: u32,
+    pub block_height: Option<i64>, // Unset until mined
+    pub kernel_signature: Option<String>, // Hex excess signature; unset until mined
+    pub message: Option<String>, // Sent with the transaction, if it had one
+    pub local_label: Option<String>,
+}
+
+/// Recommended fees per gram in µT for different confirmation times
+#[napi(object)]
+pub struct JsFeeRecommendations {
+    pub slow: String,
+    pub normal: String,
+    pub fast: String,
+    pub source: String, // Always "fallback" defaults; mempool stats are not queried yet
 }
 
 /// Base node peer information
@@ -132,10 +352,13 @@ impl JsWalletConfig {
                 "Storage path is required".to_string(),
             ));
         }
+        validate_storage_path(&self.storage_path)?;
 
         // Validate network type
         match self.network.as_str() {
             "mainnet" | "testnet" | "nextnet" => {}
+            #[cfg(any(test, feature = "localnet"))]
+            LOCALNET => {}
             _ => {
                 return Err(Error::new(
                     Status::InvalidArg,
@@ -154,6 +377,67 @@ impl JsWalletConfig {
             }
         }
 
+        if self.required_confirmations == Some(0) {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "Required confirmations must be at least one".to_string(),
+            ));
+        }
+
+        if let Some(timeouts) = &self.operation_timeouts {
+            let limits = [
+                timeouts.create_ms,
+                timeouts.balance_ms,
+                timeouts.send_ms,
+                timeouts.sync_ms,
+            ];
+            if limits.contains(&Some(0)) {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    "Operation timeouts must be at least one millisecond".to_string(),
+                ));
+            }
+        }
+
+        if let Some(retry) = &self.connection_retry {
+            if retry.max_attempts == Some(0) {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    "Retry policy needs at least one attempt".to_string(),
+                ));
+            }
+            if retry.multiplier.is_some_and(|multiplier| !multiplier.is_finite() || multiplier < 1.0) {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    "Retry multiplier must be at least 1".to_string(),
+                ));
+            }
+        }
+
+        if self.max_fee_per_gram == Some(0) {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "Max fee per gram must be at least one".to_string(),
+            ));
+        }
+
+        if self.broadcast_retry_delay_seconds == Some(0) {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "Broadcast retry delay must be at least one second".to_string(),
+            ));
+        }
+
+        // An encrypted database is keyed with the passphrase
+        if self.encrypt_database.unwrap_or(false)
+            && self.passphrase.as_deref().unwrap_or("").is_empty()
+        {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "A passphrase is required when database encryption is enabled".to_string(),
+            ));
+        }
+
         // Validate seed words if provided
         if let Some(ref words) = self.seed_words {
             if words.len() != 24 {
@@ -164,10 +448,168 @@ impl JsWalletConfig {
             }
         }
 
+        if let Some(ref language) = self.mnemonic_language {
+            MnemonicLanguage::parse(language)?;
+        }
+
+        if let Some(ref pragmas) = self.sqlite_pragmas {
+            pragmas.statements()?;
+        }
+
+        if self
+            .max_read_connections
+            .is_some_and(|max| max == 0 || max > MAX_READ_CONNECTIONS_LIMIT)
+        {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!(
+                    "Max read connections must be between 1 and {}",
+                    MAX_READ_CONNECTIONS_LIMIT
+                ),
+            ));
+        }
+
+        if let Some(ref overrides) = self.network_overrides {
+            overrides.validate(&self.network)?;
+        }
+
+        Transport::parse(self.transport.as_deref(), self.tor_socks_proxy.as_ref())?;
+        parse_listener_address(self.listener_address.as_deref())?;
+
+        if let Some(ref view_key) = self.view_key {
+            if self.seed_words.is_some() {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    "Seed words and a view key cannot both be given".to_string(),
+                ));
+            }
+            parse_view_key(view_key)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl JsNetworkOverrides {
+    /// Check every peer is well formed and, except on localnet, that there is
+    /// a base node to dial
+    pub(crate) fn validate(&self, network: &str) -> Result<()> {
+        for address in self.base_node_addresses.iter().flatten() {
+            parse_base_node_peer(address)?;
+        }
+
+        let has_peers = self
+            .base_node_addresses
+            .as_ref()
+            .is_some_and(|peers| !peers.is_empty());
+        if !has_peers && network != "localnet" {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("Network overrides for {} need at least one base node address", network),
+            ));
+        }
+
         Ok(())
     }
 }
 
+impl JsSqlitePragmas {
+    /// The `PRAGMA` statements for the settings that are set
+    ///
+    /// Fails on a value SQLite would not accept.
+    pub(crate) fn statements(&self) -> Result<Vec<String>> {
+        let mut statements = Vec::new();
+        let choices = [
+            ("journal_mode", &self.journal_mode, &["WAL", "DELETE", "MEMORY"][..]),
+            ("synchronous", &self.synchronous, &["OFF", "NORMAL", "FULL", "EXTRA"][..]),
+            ("temp_store", &self.temp_store, &["DEFAULT", "FILE", "MEMORY"][..]),
+        ];
+        for (name, value, allowed) in choices {
+            let Some(value) = value else {
+                continue;
+            };
+            let value = value.to_uppercase();
+            if !allowed.contains(&value.as_str()) {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("SQLite {} must be one of {}", name, allowed.join(", ")),
+                ));
+            }
+            statements.push(format!("PRAGMA {} = {}", name, value));
+        }
+
+        if let Some(cache_size) = self.cache_size {
+            statements.push(format!("PRAGMA cache_size = {}", cache_size));
+        }
+
+        if let Some(mmap_size) = self.mmap_size {
+            if mmap_size < 0 {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    "SQLite mmap_size cannot be negative".to_string(),
+                ));
+            }
+            statements.push(format!("PRAGMA mmap_size = {}", mmap_size));
+        }
+
+        Ok(statements)
+    }
+}
+
+/// File briefly created to check a storage directory is writable
+const WRITE_CHECK_FILE: &str = ".tari-write-check";
+
+/// Check a wallet can keep its files under `storage_path`
+///
+/// The directory need not exist yet, but then its nearest existing ancestor
+/// must be a directory the wallet can create it in.
+pub(crate) fn validate_storage_path(storage_path: &str) -> Result<()> {
+    let invalid = |message: String| Err(Error::new(Status::InvalidArg, message));
+    let path = Path::new(storage_path);
+    if !path.is_absolute() {
+        return invalid(format!("Storage path must be absolute: {}", storage_path));
+    }
+
+    let Some(existing) = path.ancestors().find(|ancestor| ancestor.exists()) else {
+        return invalid(format!("Storage path has no existing parent: {}", storage_path));
+    };
+    if existing == path && !existing.is_dir() {
+        return invalid(format!("Storage path is a file, not a directory: {}", storage_path));
+    }
+    if !existing.is_dir() {
+        return invalid(format!(
+            "Storage path {} is blocked by a file at {}",
+            storage_path,
+            existing.display()
+        ));
+    }
+
+    // Permission bits do not account for ownership, ACLs or read-only
+    // mounts, so check by actually writing
+    let probe = existing.join(WRITE_CHECK_FILE);
+    if let Err(e) = fs::write(&probe, b"") {
+        return invalid(format!(
+            "Storage path {} is not writable: cannot create files in {}: {}",
+            storage_path,
+            existing.display(),
+            e
+        ));
+    }
+    let _ = fs::remove_file(&probe);
+
+    Ok(())
+}
+
+/// Parse the hex public key a watch-only wallet is created from
+pub(crate) fn parse_view_key(view_key: &str) -> Result<RistrettoPublicKey> {
+    RistrettoPublicKey::from_hex(view_key).map_err(|_| {
+        Error::new(
+            Status::InvalidArg,
+            "View key must be a hex encoded public key".to_string(),
+        )
+    })
+}
+
 impl JsBalance {
     /// Create a new balance with zero values
     pub fn zero() -> Self {
@@ -176,6 +618,9 @@ impl JsBalance {
             pending_incoming: "0".to_string(),
             pending_outgoing: "0".to_string(),
             timelocked: "0".to_string(),
+            stale: false,
+            last_updated_at: None,
+            synced: false,
         }
     }
 
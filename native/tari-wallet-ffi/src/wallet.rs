@@ -4,36 +4,448 @@
  */
 
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
-use tokio::sync::RwLock;
+use once_cell::sync::Lazy;
+use rusqlite::Connection;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use zeroize::Zeroizing;
 
+use tari_crypto::keys::PublicKey;
+use tari_crypto::ristretto::{RistrettoPublicKey, RistrettoSecretKey};
+use tari_crypto::tari_utilities::hex::Hex;
+
+use crate::advanced::{get_covenant, StackItem, TariScript};
+use crate::callbacks::{
+    forward_bus_events, notify_transaction_received, remove_transaction_received_callback,
+    wallet_remove_event_callback, wallet_unsubscribe_output_manager_events, JsReceivedTransaction,
+};
+use crate::comms::{parse_listener_address, Transport};
+use crate::connectivity::{
+    check_base_nodes, dial_base_node, parse_base_node_peer, ConnectionPool, JsBaseNodeHealth,
+    JsConnectionStats, JsPeerReputation, NetworkStatus, RetryPolicy,
+};
+use crate::crypto::{
+    commit_with_range_proof, commitment_opens, metadata_signature_verifies, range_proof_verifies,
+};
+use crate::database::{has_plain_header, wallet_db_path, ReadPool, WalletDatabase};
+use crate::emoji::normalize_destination;
 use crate::error::{TariResult, TariWalletError};
+use crate::error_codes::WalletErrorCode;
+use crate::error_mapping::TariWalletError as MappedWalletError;
+use crate::event_bridge::create_event_bridge;
+use crate::event_bus::{CacheInvalidator, EventBus, WalletEvent, DEFAULT_EVENT_BUS_CAPACITY};
+use crate::export::ExportFormat;
+use crate::idempotency;
+use crate::labels;
+use crate::logging::init_log_level;
+use crate::mnemonic::{CipherSeed, MnemonicLanguage};
+use crate::recovery::stop_recovery;
+use crate::runtime_pool::run_on_pool;
+use crate::sync::stop_sync;
 use crate::types::*;
 
+/// Placeholder starting balance in µT until wallets are backed by Tari
+const PLACEHOLDER_BALANCE: u64 = 1_000_000;
+
+/// Default time a balance snapshot is served from cache
+const DEFAULT_BALANCE_CACHE_TTL_MS: u32 = 2_000;
+
+/// Maximum time shutdown waits for a wallet's in-flight operation to finish
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default number of times an unseen transaction is rebroadcast
+const DEFAULT_MAX_BROADCAST_RETRIES: u32 = 3;
+
+/// Default wait before rebroadcasting a transaction the network has not seen
+const DEFAULT_BROADCAST_RETRY_DELAY_SECS: u32 = 30;
+
+/// How often outbound transactions are checked for retries and confirmations
+const TRANSACTION_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often expired base node bans are lifted
+const BAN_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Default confirmations after which a mined transaction counts as confirmed
+const DEFAULT_REQUIRED_CONFIRMATIONS: u32 = 3;
+
+/// Transactions per page of search results, unless a limit is given
+const DEFAULT_SEARCH_LIMIT: u32 = 50;
+
+/// Most transactions a single page of search results can hold
+const MAX_SEARCH_LIMIT: u32 = 500;
+
+/// Default limit on dialing a base node
+const DEFAULT_CONNECTION_TIMEOUT_MS: u32 = 30_000;
+
+/// Default limits on the operations bounded by `operation_timeouts`
+const DEFAULT_CREATE_TIMEOUT_MS: u32 = 60_000;
+const DEFAULT_BALANCE_TIMEOUT_MS: u32 = 30_000;
+const DEFAULT_SEND_TIMEOUT_MS: u32 = 60_000;
+const DEFAULT_SYNC_TIMEOUT_MS: u32 = 600_000;
+
+/// Page size of SQLCipher databases, whose files have no plaintext header
+const SQLCIPHER_PAGE_SIZE: u64 = 4096;
+
+/// Default fee per gram in µT when none is supplied
+const DEFAULT_FEE_PER_GRAM: u64 = 5;
+
+/// Lowest fee per gram in µT a base node will relay
+const MIN_FEE_PER_GRAM: u64 = 1;
+
+/// Default safety cap on the fee per gram, as a multiple of the normal
+/// recommendation
+const FEE_CAP_MULTIPLIER: u64 = 10;
+
+/// Decimal places a fee per gram can be given to
+const FEE_PER_GRAM_DECIMALS: u32 = 6;
+const FEE_PER_GRAM_SCALE: u128 = 10u128.pow(FEE_PER_GRAM_DECIMALS);
+
+/// `JsUtxoInfo.status` values, matching the output manager's output status
+const UTXO_STATUS_UNSPENT: i32 = 0;
+const UTXO_STATUS_SPENT: i32 = 1;
+const UTXO_STATUS_ENCUMBERED_TO_BE_RECEIVED: i32 = 2;
+const UTXO_STATUS_ENCUMBERED_TO_BE_SPENT: i32 = 3;
+
+/// Output feature output types, as encoded on chain
+const OUTPUT_TYPE_STANDARD: u8 = 0;
+const OUTPUT_TYPE_COINBASE: u8 = 1;
+const OUTPUT_TYPE_BURN: u8 = 2;
+
+/// Transaction weights in grams, matching Tari's default transaction weighting
+const KERNEL_WEIGHT: u64 = 10;
+const INPUT_WEIGHT: u64 = 8;
+const OUTPUT_WEIGHT: u64 = 53;
+
+/// Largest transaction message the protocol carries, in UTF-8 bytes
+const MAX_MESSAGE_BYTES: usize = 512;
+
 /// Global wallet storage - maps handles to wallet instances
-type WalletStorage = Arc<RwLock<HashMap<WalletHandle, Arc<Mutex<WalletInstance>>>>>;
+///
+/// The map lock is only held long enough to look up or modify entries;
+/// each wallet is guarded by its own lock so operations on different
+/// wallets never serialize against each other. Read-only queries, such as
+/// history and UTXO listings, share a wallet's lock with each other and
+/// only wait for operations that change the wallet. Wallet locks are tokio
+/// locks, so an operation waiting for one yields its runtime worker to the
+/// holder rather than blocking it.
+///
+/// Note that a send holds the wallet's lock exclusively until it completes.
+/// Reads of the wallet database, such as transaction labels, go through the
+/// wallet's pool of read-only connections without the wallet's lock, so
+/// they never wait behind a send; the in-memory balance, UTXO and
+/// transaction state still does.
+type WalletStorage = RwLock<HashMap<WalletHandle, Arc<WalletLock>>>;
+
+type WalletLock = RwLock<WalletInstance>;
+
+static WALLET_STORAGE: Lazy<WalletStorage> = Lazy::new(|| RwLock::new(HashMap::new()));
+static NEXT_HANDLE: AtomicI64 = AtomicI64::new(1);
+
+/// Tombstones of destroyed wallets, so later calls with their handles fail
+/// as destroyed rather than unknown, and destroying again is a no-op
+///
+/// A handle is tombstoned in the same critical section that removes it
+/// from `WALLET_STORAGE`, so every handle is always either live or
+/// tombstoned. Operations that looked the wallet up before it was
+/// destroyed keep their `Arc` and finish against the shut-down instance.
+static DESTROYED_HANDLES: Lazy<Mutex<HashSet<WalletHandle>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Last balance each wallet queried successfully, served when a later query
+/// times out. Kept outside the wallets so a hung query holding the wallet
+/// lock cannot block it.
+static LAST_KNOWN_BALANCES: Lazy<Mutex<HashMap<WalletHandle, BalanceSnapshot>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Each wallet's limit on its timed operations, looked up before the
+/// operation starts. Kept outside the wallets so the timer starts even
+/// while another operation holds the wallet lock.
+static OPERATION_TIMEOUTS: Lazy<Mutex<HashMap<WalletHandle, HashMap<TimedOperation, Duration>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Each wallet's pool of read-only database connections. Kept outside the
+/// wallets so database reads do not wait for the wallet lock.
+static READ_POOLS: Lazy<Mutex<HashMap<WalletHandle, Arc<ReadPool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Balance snapshot in µT, cached to avoid re-querying on every call
+#[derive(Clone, Copy)]
+struct BalanceSnapshot {
+    available: u64,
+    pending_incoming: u64,
+    pending_outgoing: u64,
+    timelocked: u64,
+    taken_at: Instant,
+    /// Unix time of the sync the balance reflects
+    last_synced_at: Option<f64>,
+    /// Whether the wallet had scanned up to the chain tip
+    synced: bool,
+}
+
+impl BalanceSnapshot {
+    fn to_js(self) -> JsBalance {
+        JsBalance {
+            available: self.available.to_string(),
+            pending_incoming: self.pending_incoming.to_string(),
+            pending_outgoing: self.pending_outgoing.to_string(),
+            timelocked: self.timelocked.to_string(),
+            stale: false,
+            last_updated_at: self.last_synced_at,
+            synced: self.synced,
+        }
+    }
+}
+
+/// Broadcast and confirmation bookkeeping for an outbound transaction
+struct OutboundTransaction {
+    /// Destination address; unset for burns and offline-signed sends
+    recipient: Option<String>,
+    amount: u64,
+    fee: u64,
+    /// Unix time the transaction was first broadcast
+    created_at: f64,
+    retry_count: u32,
+    last_broadcast: Instant,
+    last_broadcast_at: f64,
+    /// Would be set once the transaction service sees it in a mempool or block
+    seen_by_network: bool,
+    /// Would be set from the transaction service's mined event
+    mined_height: Option<u64>,
+    /// Whether the confirmation event has been emitted
+    confirmed: bool,
+    input_count: u32,
+    output_count: u32,
+    /// Height from which the outputs can be spent, for time-locked sends
+    maturity_height: Option<u64>,
+    /// Serialized covenant on the recipient's output, if the send had one
+    covenant: Option<Vec<u8>>,
+    message: Option<String>,
+}
+
+impl OutboundTransaction {
+    fn confirmations(&self, tip_height: u64) -> u32 {
+        confirmations_at(self.mined_height, tip_height)
+    }
+
+    fn status(&self) -> JsTransactionStatus {
+        if self.confirmed {
+            JsTransactionStatus::MinedConfirmed
+        } else if self.mined_height.is_some() {
+            JsTransactionStatus::MinedUnconfirmed
+        } else if self.seen_by_network {
+            JsTransactionStatus::Broadcast
+        } else {
+            JsTransactionStatus::Pending
+        }
+    }
+
+    fn send_result(&self, tx_id: &str) -> JsSendResult {
+        JsSendResult {
+            tx_id: tx_id.to_string(),
+            amount: self.amount.to_string(),
+            fee: self.fee.to_string(),
+            status: self.status() as i32,
+            timestamp: self.last_broadcast_at,
+        }
+    }
+}
+
+/// Name of an output feature output type, as reported to JavaScript
+fn output_type_name(output_type: u8) -> &'static str {
+    match output_type {
+        OUTPUT_TYPE_COINBASE => "coinbase",
+        OUTPUT_TYPE_BURN => "burn",
+        _ => "standard",
+    }
+}
 
-static mut WALLET_STORAGE: Option<WalletStorage> = None;
-static mut NEXT_HANDLE: WalletHandle = 1;
+/// An inbound transaction detected by the transaction service
+struct InboundTransaction {
+    amount: u64,
+    /// Unix time the transaction was detected
+    received_at: f64,
+    sender_address: Option<String>,
+    message: Option<String>,
+    /// Would be set from the transaction service's mined event
+    mined_height: Option<u64>,
+    /// Whether the confirmed notification has been sent
+    confirmed: bool,
+    /// One of the `OUTPUT_TYPE_*` values
+    output_type: u8,
+    /// Height from which the received output can be spent, if it is locked
+    maturity_height: Option<u64>,
+}
+
+impl InboundTransaction {
+    fn status(&self) -> JsTransactionStatus {
+        if self.confirmed {
+            JsTransactionStatus::MinedConfirmed
+        } else if self.mined_height.is_some() {
+            JsTransactionStatus::MinedUnconfirmed
+        } else {
+            JsTransactionStatus::Pending
+        }
+    }
+
+    fn notification(&self, tx_id: &str, status: &str) -> JsReceivedTransaction {
+        JsReceivedTransaction {
+            tx_id: tx_id.to_string(),
+            amount: self.amount.to_string(),
+            sender_address: self.sender_address.clone(),
+            message: self.message.clone(),
+            status: status.to_string(),
+            maturity_height: self.maturity_height.map(|height| height as i64),
+            output_type: output_type_name(self.output_type).to_string(),
+        }
+    }
+
+    /// Whether the transaction is mined into an output that is still locked
+    fn is_immature(&self, tip_height: u64) -> bool {
+        self.mined_height.is_some() && self.maturity_height.is_some_and(|height| height > tip_height)
+    }
+}
+
+/// Confirmations of a transaction mined at `mined_height`, counting its own block
+fn confirmations_at(mined_height: Option<u64>, tip_height: u64) -> u32 {
+    mined_height
+        .filter(|mined| *mined <= tip_height)
+        .map_or(0, |mined| (tip_height - mined + 1).min(u32::MAX as u64) as u32)
+}
+
+/// Fee-per-gram statistics of transactions waiting in a base node's mempool
+#[derive(Clone, Copy)]
+struct MempoolFeeStats {
+    min_fee_per_gram: u64,
+    avg_fee_per_gram: u64,
+    max_fee_per_gram: u64,
+}
 
 /// Internal wallet instance structure
 /// This will be replaced with actual Tari wallet in Phase 3 integration
 struct WalletInstance {
     handle: WalletHandle,
     config: JsWalletConfig,
+    /// Unset for watch-only wallets, which cannot spend
+    seed: Option<CipherSeed>,
+    /// Language seed words are exported in unless another is requested
+    mnemonic_language: MnemonicLanguage,
+    /// Public key outputs belonging to the wallet are detected with
+    view_key: RistrettoPublicKey,
+    /// Address derived from the view key
+    address: String,
     initialized: bool,
     destroyed: bool,
+    /// Last known chain tip height; would be updated from the base node
+    tip_height: u64,
+    /// Height the wallet has scanned the chain up to
+    synced_height: u64,
+    /// Unix time a sync last finished or was cancelled
+    last_synced_at: Option<f64>,
+    /// Chain tip height when the wallet was created; unknown for restored wallets
+    birthday_height: Option<u64>,
+    /// Spendable balance in µT; would come from the output manager
+    available_balance: u64,
+    /// Last balance snapshot, invalidated whenever funds move
+    balance_cache: Option<BalanceSnapshot>,
+    balance_cache_ttl: Duration,
+    /// Subscribed to `events`; marks the balance snapshot out of date
+    balance_cache_events: Mutex<CacheInvalidator>,
+    /// Changes to wallet state, for caches and callbacks to react to
+    events: EventBus,
+    /// Outbound transactions by id
+    outbound_transactions: HashMap<String, OutboundTransaction>,
+    max_broadcast_retries: u32,
+    broadcast_retry_delay: Duration,
+    /// Confirmations after which a mined transaction counts as confirmed
+    required_confirmations: u32,
+    base_nodes: ConnectionPool,
+    /// Inbound transactions by id
+    inbound_transactions: HashMap<String, InboundTransaction>,
+    /// Fresh receiving addresses handed out, by key manager index
+    issued_addresses: HashMap<String, u64>,
+    /// Nonces of offline-signed transactions already broadcast
+    offline_nonces: HashSet<String>,
+    /// Commitments of outputs added with `wallet_import_utxo`
+    imported_commitments: HashSet<String>,
+    /// Held from creation until shutdown
+    data_dir_lock: Option<DataDirLock>,
+    /// Connection to the wallet database, open from creation until shutdown
+    database: Option<WalletDatabase>,
+    /// How base nodes are dialled, directly or through a Tor SOCKS proxy
+    transport: Transport,
+    /// Address the comms listener binds to for inbound peer connections
+    listener_address: SocketAddr,
 }
 
 impl WalletInstance {
-    fn new(handle: WalletHandle, config: JsWalletConfig) -> Self {
+    fn new(
+        handle: WalletHandle,
+        config: JsWalletConfig,
+        seed: Option<CipherSeed>,
+        view_key: RistrettoPublicKey,
+        mnemonic_language: MnemonicLanguage,
+        transport: Transport,
+        listener_address: SocketAddr,
+    ) -> Self {
+        let cache_ttl_ms = config
+            .balance_cache_ttl_ms
+            .unwrap_or(DEFAULT_BALANCE_CACHE_TTL_MS);
+        let max_broadcast_retries = config
+            .max_broadcast_retries
+            .unwrap_or(DEFAULT_MAX_BROADCAST_RETRIES);
+        let broadcast_retry_delay_secs = config
+            .broadcast_retry_delay_seconds
+            .unwrap_or(DEFAULT_BROADCAST_RETRY_DELAY_SECS);
+        let required_confirmations = config
+            .required_confirmations
+            .unwrap_or(DEFAULT_REQUIRED_CONFIRMATIONS);
+        let address = format!("tari://{}/{}", config.network, view_key.to_hex());
+        let events = EventBus::new(
+            config
+                .event_bus_capacity
+                .unwrap_or(DEFAULT_EVENT_BUS_CAPACITY),
+        );
+
         Self {
             handle,
             config,
+            seed,
+            mnemonic_language,
+            view_key,
+            address,
             initialized: false,
             destroyed: false,
+            tip_height: 0,
+            synced_height: 0,
+            last_synced_at: None,
+            birthday_height: None,
+            available_balance: PLACEHOLDER_BALANCE,
+            balance_cache: None,
+            balance_cache_ttl: Duration::from_millis(cache_ttl_ms as u64),
+            balance_cache_events: Mutex::new(CacheInvalidator::new(&events)),
+            events,
+            outbound_transactions: HashMap::new(),
+            max_broadcast_retries,
+            broadcast_retry_delay: Duration::from_secs(broadcast_retry_delay_secs as u64),
+            required_confirmations,
+            base_nodes: ConnectionPool::default(),
+            inbound_transactions: HashMap::new(),
+            issued_addresses: HashMap::new(),
+            offline_nonces: HashSet::new(),
+            imported_commitments: HashSet::new(),
+            data_dir_lock: None,
+            database: None,
+            transport,
+            listener_address,
         }
     }
 
@@ -45,338 +457,5402 @@ impl WalletInstance {
         }
         Ok(())
     }
-}
 
-/// Initialize the wallet storage system
-fn ensure_storage_initialized() -> &'static WalletStorage {
-    unsafe {
-        if WALLET_STORAGE.is_none() {
-            WALLET_STORAGE = Some(Arc::new(RwLock::new(HashMap::new())));
-        }
-        WALLET_STORAGE.as_ref().unwrap()
+    /// The wallet's seed, failing with WatchOnly if it has none
+    fn seed(&self) -> Result<&CipherSeed> {
+        self.seed.as_ref().ok_or_else(|| {
+            MappedWalletError::new(
+                WalletErrorCode::WatchOnly,
+                "Watch-only wallets hold no spending keys",
+            )
+            .component("wallet")
+            .into()
+        })
     }
-}
 
-/// Generate next wallet handle
-fn generate_handle() -> WalletHandle {
-    unsafe {
-        let handle = NEXT_HANDLE;
-        NEXT_HANDLE += 1;
-        handle
+    /// Check the wallet holds the keys to send funds
+    fn ensure_can_spend(&self) -> Result<()> {
+        self.seed().map(|_| ())
     }
-}
-
-/// Create a new wallet instance
-#[napi]
-pub async fn wallet_create(config: JsWalletConfig) -> Result<WalletHandle> {
-    // Validate configuration
-    config.validate()?;
-
-    let handle = generate_handle();
-    let storage = ensure_storage_initialized();
 
-    // Create wallet instance
-    let wallet = WalletInstance::new(handle, config);
-    let wallet_arc = Arc::new(Mutex::new(wallet));
+    /// Highest fee per gram a send may pay without an explicit override
+    fn max_fee_per_gram(&self) -> u64 {
+        match self.config.max_fee_per_gram {
+            Some(cap) => cap as u64,
+            None => self
+                .mempool_fee_stats()
+                .map_or(DEFAULT_FEE_PER_GRAM, |stats| stats.avg_fee_per_gram)
+                .max(MIN_FEE_PER_GRAM)
+                .saturating_mul(FEE_CAP_MULTIPLIER),
+        }
+    }
 
-    // Store in global storage
-    {
-        let mut storage_lock = storage.write().await;
-        storage_lock.insert(handle, wallet_arc.clone());
+    /// How long a single base node dial may take
+    fn connection_timeout(&self) -> Duration {
+        Duration::from_millis(
+            self.config
+                .connection_timeout_ms
+                .unwrap_or(DEFAULT_CONNECTION_TIMEOUT_MS) as u64,
+        )
     }
 
-    // Initialize wallet (placeholder for actual Tari wallet creation)
-    {
-        let mut wallet_lock = wallet_arc.lock().map_err(|e| {
-            napi::Error::new(
-                Status::GenericFailure,
-                format!("Failed to acquire wallet lock: {}", e),
+    /// Refuse a fee per gram above the safety cap unless `allow_high_fee`
+    fn check_fee_per_gram(&self, fee_per_gram: FeePerGram, allow_high_fee: bool) -> Result<()> {
+        let cap = self.max_fee_per_gram();
+        if fee_per_gram > cap.into() && !allow_high_fee {
+            return Err(MappedWalletError::new(
+                WalletErrorCode::InvalidFee,
+                format!(
+                    "Fee per gram {} exceeds the safety cap of {}",
+                    fee_per_gram, cap
+                ),
             )
-        })?;
-        wallet_lock.initialized = true;
+            .component("wallet")
+            .into());
+        }
+        Ok(())
     }
 
-    Ok(handle)
-}
+    /// Check a passphrase against the wallet's, in constant time
+    fn verify_passphrase(&self, passphrase: &str) -> Result<()> {
+        let expected = self.config.passphrase.as_deref().unwrap_or("").as_bytes();
+        let given = passphrase.as_bytes();
+        let mismatch = expected.len() != given.len()
+            || expected
+                .iter()
+                .zip(given)
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                != 0;
 
-/// Destroy a wallet instance and clean up resources
-#[napi]
-pub async fn wallet_destroy(handle: WalletHandle) -> Result<()> {
-    let storage = ensure_storage_initialized();
+        if mismatch {
+            return Err(MappedWalletError::new(WalletErrorCode::AuthenticationFailed, "Invalid passphrase")
+                .component("wallet")
+                .into());
+        }
+        Ok(())
+    }
 
-    // Remove from storage
-    let wallet_arc = {
-        let mut storage_lock = storage.write().await;
-        storage_lock.remove(&handle)
-    };
+    /// Tear down wallet services; later calls are no-ops
+    fn shutdown(&mut self) {
+        if self.destroyed {
+            return;
+        }
 
-    if let Some(wallet_arc) = wallet_arc {
-        // Mark as destroyed
-        let mut wallet_lock = wallet_arc.lock().map_err(|e| {
-            napi::Error::new(
-                Status::GenericFailure,
-                format!("Failed to acquire wallet lock: {}", e),
-            )
-        })?;
+        // The caller has already cancelled any running sync
+        if let Err(e) = self.base_nodes.save_reputations(&self.config.storage_path) {
+            tracing::warn!("Peer reputations were not saved: {}", e.message);
+        }
+        self.base_nodes.disconnect_all();
+        self.balance_cache = None;
+        if let Some(database) = self.database.take() {
+            database.close();
+        }
+        self.data_dir_lock = None;
+        self.initialized = false;
+        self.destroyed = true;
+    }
 
-        wallet_lock.destroyed = true;
-        wallet_lock.initialized = false;
+    /// Deduct an amount plus fee from the available balance
+    ///
+    /// Sends that could only be covered with immature coinbase or
+    /// time-locked outputs are rejected with the height they unlock at.
+    fn spend(&mut self, amount: u64, fee: u64) -> TariResult<()> {
+        let total = total_with_fee(amount, fee)?;
 
-        // Cleanup would happen here in real implementation
+        if total > self.available_balance {
+            let (immature, matures_at) = self.immature_funds();
+            if immature > 0 && total <= self.available_balance.saturating_add(immature) {
+                return Err(TariWalletError::ValidationError(format!(
+                    "Only {} µT is spendable; {} µT is in immature coinbase or time-locked \
+                     outputs that cannot be spent until height {}",
+                    self.available_balance, immature, matures_at
+                )));
+            }
+            return Err(TariWalletError::InsufficientFunds);
+        }
+        self.available_balance -= total;
         Ok(())
-    } else {
-        Err(napi::Error::new(
-            Status::InvalidArg,
-            format!("Invalid wallet handle: {}", handle),
-        ))
     }
-}
 
-/// Get wallet balance
-#[napi]
-pub async fn wallet_get_balance(handle: WalletHandle) -> Result<JsBalance> {
-    let storage = ensure_storage_initialized();
-    let storage_lock = storage.read().await;
-
-    if let Some(wallet_arc) = storage_lock.get(&handle) {
-        let wallet_lock = wallet_arc.lock().map_err(|e| {
-            napi::Error::new(
-                Status::GenericFailure,
-                format!("Failed to acquire wallet lock: {}", e),
-            )
-        })?;
+    /// Total of received outputs that are still locked, and the height the last of them unlocks at
+    fn immature_funds(&self) -> (u64, u64) {
+        self.inbound_transactions
+            .values()
+            .filter(|tx| tx.is_immature(self.tip_height))
+            .fold((0, 0), |(total, matures_at), tx| {
+                (
+                    total.saturating_add(tx.amount),
+                    matures_at.max(tx.maturity_height.unwrap_or(0)),
+                )
+            })
+    }
 
-        wallet_lock.ensure_not_destroyed()?;
+    /// The cached balance, while the snapshot is fresh
+    fn cached_balance(&self) -> Option<JsBalance> {
+        let stale = self
+            .balance_cache_events
+            .lock()
+            .map_or(true, |mut events| events.is_stale());
+        if stale {
+            return None;
+        }
+        self.balance_cache
+            .filter(|snapshot| snapshot.taken_at.elapsed() < self.balance_cache_ttl)
+            .map(BalanceSnapshot::to_js)
+    }
 
-        // Placeholder implementation - would call actual Tari wallet
-        Ok(JsBalance {
-            available: "1000000".to_string(), // 1 Tari in µT
-            pending_incoming: "0".to_string(),
-            pending_outgoing: "0".to_string(),
-            timelocked: "0".to_string(),
-        })
-    } else {
-        Err(napi::Error::new(
-            Status::InvalidArg,
-            format!("Invalid wallet handle: {}", handle),
-        ))
+    /// Current balance, served from cache while the snapshot is fresh
+    fn balance(&mut self) -> JsBalance {
+        self.cached_balance().unwrap_or_else(|| self.refresh_balance())
     }
-}
 
-/// Get wallet address
-#[napi]
-pub async fn wallet_get_address(handle: WalletHandle) -> Result<String> {
-    let storage = ensure_storage_initialized();
-    let storage_lock = storage.read().await;
-
-    if let Some(wallet_arc) = storage_lock.get(&handle) {
-        let wallet_lock = wallet_arc.lock().map_err(|e| {
-            napi::Error::new(
-                Status::GenericFailure,
-                format!("Failed to acquire wallet lock: {}", e),
-            )
-        })?;
+    /// Query the balance, bypassing and repopulating the cache
+    fn refresh_balance(&mut self) -> JsBalance {
+        // Placeholder implementation - would query the output manager service
+        let snapshot = BalanceSnapshot {
+            available: self.available_balance,
+            pending_incoming: 0,
+            pending_outgoing: 0,
+            timelocked: 0,
+            taken_at: Instant::now(),
+            last_synced_at: self.last_synced_at,
+            synced: self.synced_height >= self.tip_height,
+        };
+        if let Ok(mut events) = self.balance_cache_events.lock() {
+            events.refreshed();
+        }
+        self.balance_cache = Some(snapshot);
+        if let Ok(mut last_known) = LAST_KNOWN_BALANCES.lock() {
+            last_known.insert(self.handle, snapshot);
+        }
+        snapshot.to_js()
+    }
 
-        wallet_lock.ensure_not_destroyed()?;
+    /// Issue a one-sided address that has not been handed out before
+    ///
+    /// Its key is derived from the seed at the address's index, so funds
+    /// sent to it can be spent after restoring from the seed words.
+    fn issue_address(&mut self) -> Result<String> {
+        let index = self.issued_addresses.len() as u64;
+        let public_key = RistrettoPublicKey::from_secret_key(&self.seed()?.address_key(index)?);
+        let address = format!("tari://{}/{}", self.config.network, public_key.to_hex());
 
-        // Placeholder implementation - would call actual Tari wallet
-        Ok("tari://testnet/placeholder_address".to_string())
-    } else {
-        Err(napi::Error::new(
-            Status::InvalidArg,
-            format!("Invalid wallet handle: {}", handle),
-        ))
+        self.issued_addresses.insert(address.clone(), index);
+        Ok(address)
     }
-}
 
-/// Send a transaction
-#[napi]
-pub async fn wallet_send_transaction(
-    handle: WalletHandle,
-    recipient_address: String,
-    amount: String,
-    _options: Option<JsSendTransactionOptions>,
-) -> Result<String> {
-    let storage = ensure_storage_initialized();
-    let storage_lock = storage.read().await;
-
-    if let Some(wallet_arc) = storage_lock.get(&handle) {
-        let wallet_lock = wallet_arc.lock().map_err(|e| {
-            napi::Error::new(
-                Status::GenericFailure,
-                format!("Failed to acquire wallet lock: {}", e),
-            )
-        })?;
+    /// Whether payments to `address` belong to this wallet
+    fn owns_address(&self, address: &str) -> bool {
+        address == self.address || self.issued_addresses.contains_key(address)
+    }
 
-        wallet_lock.ensure_not_destroyed()?;
+    /// Assign an id to a new outbound transaction and broadcast it
+    fn broadcast_new_transaction(&mut self, recipient: Option<String>, amount: u64, fee: u64) -> String {
+        let tx_id = format!("tx_{}", generate_handle());
+        let now = chrono::Utc::now().timestamp() as f64;
+
+        // Placeholder implementation - would submit via the transaction service
+        self.outbound_transactions.insert(
+            tx_id.clone(),
+            OutboundTransaction {
+                recipient,
+                amount,
+                fee,
+                created_at: now,
+                retry_count: 0,
+                last_broadcast: Instant::now(),
+                last_broadcast_at: now,
+                seen_by_network: false,
+                mined_height: None,
+                confirmed: false,
+                // Placeholder UTXO selection, as for fee estimates
+                input_count: 1,
+                output_count: 2,
+                maturity_height: None,
+                covenant: None,
+                message: None,
+            },
+        );
+        self.events.publish(WalletEvent::TransactionSent {
+            tx_id: tx_id.clone(),
+            amount,
+        });
+        tx_id
+    }
 
-        // Validate inputs
-        if recipient_address.is_empty() {
-            return Err(TariWalletError::InvalidAddress(
-                "Recipient address cannot be empty".to_string(),
+    /// The send already made with idempotency key `key`, if any
+    ///
+    /// A repeated key has to come with the recipient and amount it was
+    /// first used for. A send from this session reports its current status;
+    /// one from an earlier session is no longer tracked, so it is reported
+    /// as it was recorded.
+    fn repeated_send(&self, key: &str, recipient: &str, amount: u64) -> Result<Option<JsSendResult>> {
+        let Some(keyed) = idempotency::keyed_transaction(self.database()?, key)? else {
+            return Ok(None);
+        };
+        if keyed.recipient != recipient || keyed.amount != amount {
+            return Err(MappedWalletError::new(
+                WalletErrorCode::DuplicateTransaction,
+                format!(
+                    "Idempotency key {} was used to send {} µT to {}, not {} µT to {}",
+                    key, keyed.amount, keyed.recipient, amount, recipient
+                ),
             )
+            .component("wallet")
             .into());
         }
 
-        if amount.is_empty() || amount == "0" {
-            return Err(TariWalletError::ValidationError(
-                "Amount must be greater than zero".to_string(),
-            )
-            .into());
+        if let Some(tx) = self.outbound_transactions.get(&keyed.tx_id) {
+            return Ok(Some(tx.send_result(&keyed.tx_id)));
         }
+        Ok(Some(JsSendResult {
+            amount: keyed.amount.to_string(),
+            fee: keyed.fee.to_string(),
+            status: keyed.status,
+            timestamp: keyed.sent_at as f64,
+            tx_id: keyed.tx_id,
+        }))
+    }
 
-        // Placeholder implementation - would call actual Tari wallet
-        let transaction_id = format!("tx_{}", generate_handle());
-        Ok(transaction_id)
-    } else {
-        Err(napi::Error::new(
-            Status::InvalidArg,
-            format!("Invalid wallet handle: {}", handle),
-        ))
+    /// The wallet's database connection, which is open until shutdown
+    fn database(&self) -> std::result::Result<&WalletDatabase, TariWalletError> {
+        self.database
+            .as_ref()
+            .ok_or_else(|| TariWalletError::StorageError("Wallet database is not open".to_string()))
     }
-}
 
-/// Get wallet seed words
-#[napi]
-pub async fn wallet_get_seed_words(handle: WalletHandle) -> Result<Vec<String>> {
-    let storage = ensure_storage_initialized();
-    let storage_lock = storage.read().await;
+    /// Rebroadcast unseen transactions whose retry delay has passed
+    ///
+    /// Returns the ids that were rebroadcast. Transactions that have used
+    /// up their retries are left pending for the caller to cancel.
+    fn rebroadcast_due(&mut self, now: Instant) -> Vec<String> {
+        let (max_retries, delay) = (self.max_broadcast_retries, self.broadcast_retry_delay);
+        let mut rebroadcast = Vec::new();
 
-    if let Some(wallet_arc) = storage_lock.get(&handle) {
-        let wallet_lock = wallet_arc.lock().map_err(|e| {
-            napi::Error::new(
-                Status::GenericFailure,
-                format!("Failed to acquire wallet lock: {}", e),
-            )
-        })?;
+        for (tx_id, state) in self.outbound_transactions.iter_mut() {
+            if state.seen_by_network
+                || state.mined_height.is_some()
+                || state.retry_count >= max_retries
+                || now.saturating_duration_since(state.last_broadcast) < delay
+            {
+                continue;
+            }
 
-        wallet_lock.ensure_not_destroyed()?;
+            // Placeholder implementation - would resubmit via the transaction service
+            state.retry_count += 1;
+            state.last_broadcast = now;
+            state.last_broadcast_at = chrono::Utc::now().timestamp() as f64;
+            rebroadcast.push(tx_id.clone());
+        }
+        rebroadcast
+    }
 
-        // Placeholder implementation - would return actual seed words
-        Ok(vec![
-            "abandon".to_string(),
-            "ability".to_string(),
-            "able".to_string(),
-            "about".to_string(),
-            "above".to_string(),
-            "absent".to_string(),
-            "absorb".to_string(),
-            "abstract".to_string(),
-            "absurd".to_string(),
-            "abuse".to_string(),
-            "access".to_string(),
-            "accident".to_string(),
-            "account".to_string(),
-            "accuse".to_string(),
-            "achieve".to_string(),
-            "acid".to_string(),
-            "acoustic".to_string(),
-            "acquire".to_string(),
-            "across".to_string(),
-            "act".to_string(),
-            "action".to_string(),
-            "actor".to_string(),
-            "actress".to_string(),
-            "actual".to_string(),
-        ])
-    } else {
-        Err(napi::Error::new(
-            Status::InvalidArg,
-            format!("Invalid wallet handle: {}", handle),
-        ))
+    /// Resubmit every outbound transaction that has not been mined yet
+    ///
+    /// Unlike `rebroadcast_due` this ignores the retry delay and does not
+    /// use up retries, so it can be repeated as often as the caller likes.
+    fn rebroadcast_pending(&mut self) -> JsRebroadcastSummary {
+        let now = Instant::now();
+        let mut summary = JsRebroadcastSummary {
+            attempted: 0,
+            succeeded: 0,
+            failed: 0,
+            unacknowledged: 0,
+        };
+
+        for (tx_id, state) in self.outbound_transactions.iter_mut() {
+            if state.mined_height.is_some() {
+                continue;
+            }
+
+            summary.attempted += 1;
+            match submit_transaction(tx_id) {
+                Ok(acknowledged) => {
+                    state.last_broadcast = now;
+                    state.last_broadcast_at = chrono::Utc::now().timestamp() as f64;
+                    if acknowledged {
+                        summary.succeeded += 1;
+                    } else {
+                        summary.unacknowledged += 1;
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to rebroadcast transaction {}: {}", tx_id, e);
+                    summary.failed += 1;
+                }
+            }
+        }
+        summary
     }
-}
 
-/// Set base node peer for the wallet
-#[napi]
-pub async fn wallet_set_base_node(
-    handle: WalletHandle,
-    base_node: JsBaseNodePeer,
-) -> Result<()> {
-    let storage = ensure_storage_initialized();
-    let storage_lock = storage.read().await;
-
-    if let Some(wallet_arc) = storage_lock.get(&handle) {
-        let wallet_lock = wallet_arc.lock().map_err(|e| {
-            napi::Error::new(
-                Status::GenericFailure,
-                format!("Failed to acquire wallet lock: {}", e),
-            )
-        })?;
+    /// Mark mined transactions that have reached the required confirmations
+    ///
+    /// Returns each newly confirmed transaction id with its confirmation
+    /// count; a transaction is only returned once.
+    fn newly_confirmed(&mut self) -> Vec<(String, u32)> {
+        let (tip_height, required) = (self.tip_height, self.required_confirmations);
 
-        wallet_lock.ensure_not_destroyed()?;
+        let confirmed: Vec<(String, u32)> = self
+            .outbound_transactions
+            .iter_mut()
+            .filter(|(_, tx)| !tx.confirmed && tx.confirmations(tip_height) >= required)
+            .map(|(tx_id, tx)| {
+                tx.confirmed = true;
+                (tx_id.clone(), tx.confirmations(tip_height))
+            })
+            .collect();
+        for (tx_id, _) in &confirmed {
+            self.events.publish(WalletEvent::TransactionConfirmed { tx_id: tx_id.clone() });
+        }
+        confirmed
+    }
 
-        // Validate base node info
-        if base_node.public_key.is_empty() {
-            return Err(TariWalletError::ValidationError(
-                "Base node public key cannot be empty".to_string(),
-            )
-            .into());
+    /// Inbound transactions detected since the last poll
+    ///
+    /// Always empty until the transaction service is wired in, so payments
+    /// from other wallets are not detected yet; imported outputs are
+    /// reported through `receive_inbound` instead.
+    fn poll_inbound(&mut self) -> Vec<(String, InboundTransaction)> {
+        // Placeholder implementation - would drain new inbound transactions
+        // from the transaction service event stream, including faux ones
+        Vec::new()
+    }
+
+    /// Track a newly detected inbound transaction and notify the
+    /// `wallet_on_transaction_received` callback
+    fn receive_inbound(&mut self, tx_id: String, tx: InboundTransaction) {
+        if let Some(notification) = self.record_inbound(tx_id, tx) {
+            notify_transaction_received(self.handle, notification);
         }
+    }
 
-        if base_node.address.is_empty() {
-            return Err(TariWalletError::ValidationError(
-                "Base node address cannot be empty".to_string(),
-            )
-            .into());
+    /// Track a newly detected inbound transaction
+    ///
+    /// Returns the notification for it, pending unless the transaction is
+    /// already confirmed, or None if it was already known.
+    fn record_inbound(&mut self, tx_id: String, tx: InboundTransaction) -> Option<JsReceivedTransaction> {
+        if self.inbound_transactions.contains_key(&tx_id) {
+            return None;
         }
+        let status = if tx.confirmed { "confirmed" } else { "pending" };
+        let notification = tx.notification(&tx_id, status);
+        self.events.publish(WalletEvent::TransactionReceived { tx_id: tx_id.clone() });
+        self.inbound_transactions.insert(tx_id, tx);
+        Some(notification)
+    }
 
-        // Placeholder implementation - would configure actual base node
-        Ok(())
-    } else {
-        Err(napi::Error::new(
-            Status::InvalidArg,
-            format!("Invalid wallet handle: {}", handle),
-        ))
+    /// Confirmed notifications for inbound transactions that reached the
+    /// required confirmations; each transaction is only returned once
+    fn newly_confirmed_inbound(&mut self) -> Vec<JsReceivedTransaction> {
+        let (tip_height, required) = (self.tip_height, self.required_confirmations);
+
+        let confirmed: Vec<JsReceivedTransaction> = self
+            .inbound_transactions
+            .iter_mut()
+            .filter(|(_, tx)| !tx.confirmed && confirmations_at(tx.mined_height, tip_height) >= required)
+            .map(|(tx_id, tx)| {
+                tx.confirmed = true;
+                tx.notification(tx_id, "confirmed")
+            })
+            .collect();
+        for tx in &confirmed {
+            self.events.publish(WalletEvent::TransactionConfirmed { tx_id: tx.tx_id.clone() });
+        }
+        confirmed
+    }
+
+    /// Extend a localnet chain by `count` blocks, returning the new tip height
+    ///
+    /// Unmined outbound transactions are mined into the first new block.
+    #[cfg(any(test, feature = "localnet"))]
+    fn mine_blocks(&mut self, count: u32) -> u64 {
+        if count == 0 {
+            return self.tip_height;
+        }
+
+        // Placeholder implementation - would ask the local base node to mine
+        // `count` blocks from its mempool and wait for the wallet to see them
+        let first_block = self.tip_height + 1;
+        for tx in self.outbound_transactions.values_mut() {
+            if tx.mined_height.is_none() {
+                tx.seen_by_network = true;
+                tx.mined_height = Some(first_block);
+            }
+        }
+        self.tip_height += count as u64;
+        self.events.publish(WalletEvent::OutputsChanged);
+        self.tip_height
+    }
+
+    /// Forget blocks from `height` up, unmining their transactions
+    fn roll_back(&mut self, height: u64) {
+        for tx in self.outbound_transactions.values_mut() {
+            if tx.mined_height.is_some_and(|mined| mined >= height) {
+                tx.mined_height = None;
+                tx.confirmed = false;
+            }
+        }
+        for tx in self.inbound_transactions.values_mut() {
+            if tx.mined_height.is_some_and(|mined| mined >= height) {
+                tx.mined_height = None;
+                tx.confirmed = false;
+            }
+        }
+        self.synced_height = self.synced_height.min(height);
+        self.events.publish(WalletEvent::BlocksRolledBack { height });
+    }
+
+    /// The chain tip height a base node has reported, if any
+    fn known_tip_height(&self) -> Option<u64> {
+        // Placeholder implementation - would return the height from the
+        // connected base node's chain metadata; `tip_height` is only a
+        // local default until then
+        None
+    }
+
+    /// Always None for now, so fee recommendations are always "fallback"
+    fn mempool_fee_stats(&self) -> Option<MempoolFeeStats> {
+        // Placeholder implementation - would query the connected base node's
+        // mempool fee-per-gram stats, returning None while offline
+        None
+    }
+
+    /// All of the wallet's outputs
+    fn outputs(&self) -> Vec<WalletOutput> {
+        // Placeholder implementation - would query the output manager; the
+        // available balance is reported as one output and pending inbound
+        // payments as outputs still to be received
+        let unspent = (self.available_balance > 0).then_some(WalletOutput {
+            status: UTXO_STATUS_UNSPENT,
+            amount: self.available_balance,
+            maturity: 0,
+        });
+        let unconfirmed = self
+            .inbound_transactions
+            .values()
+            .filter(|tx| !tx.confirmed)
+            .map(|tx| WalletOutput {
+                status: UTXO_STATUS_ENCUMBERED_TO_BE_RECEIVED,
+                amount: tx.amount,
+                maturity: 0,
+            });
+
+        unspent.into_iter().chain(unconfirmed).collect()
+    }
+
+    /// The fields of every transaction that searches filter on
+    fn transaction_records(&self) -> impl Iterator<Item = TransactionRecord<'_>> {
+        let outbound = self.outbound_transactions.iter().map(|(tx_id, tx)| TransactionRecord {
+            tx_id,
+            inbound: false,
+            amount: tx.amount,
+            timestamp: tx.created_at,
+            address: tx.recipient.as_deref(),
+        });
+        let inbound = self.inbound_transactions.iter().map(|(tx_id, tx)| TransactionRecord {
+            tx_id,
+            inbound: true,
+            amount: tx.amount,
+            timestamp: tx.received_at,
+            address: tx.sender_address.as_deref(),
+        });
+        outbound.chain(inbound)
+    }
+
+    /// Full information on a transaction of either direction
+    fn transaction_info(&self, tx_id: &str) -> Option<JsTransactionInfo> {
+        let tip_height = self.tip_height;
+
+        if let Some(tx) = self.outbound_transactions.get(tx_id) {
+            return Some(JsTransactionInfo {
+                id: tx_id.to_string(),
+                amount: tx.amount.to_string(),
+                gross_amount: tx.amount.saturating_add(tx.fee).to_string(),
+                fee: tx.fee.to_string(),
+                status: tx.status() as i32,
+                message: tx.message.clone().unwrap_or_default(),
+                timestamp: tx.created_at,
+                is_inbound: false,
+                address: tx.recipient.clone().unwrap_or_default(),
+                lock_height: tx.maturity_height.map(|height| height as i64),
+                confirmations: tx.confirmations(tip_height),
+                maturity_height: tx.maturity_height.map(|height| height as i64),
+                output_type: output_type_name(OUTPUT_TYPE_STANDARD).to_string(),
+                local_label: None,
+            });
+        }
+        let tx = self.inbound_transactions.get(tx_id)?;
+        Some(JsTransactionInfo {
+            id: tx_id.to_string(),
+            amount: tx.amount.to_string(),
+            gross_amount: tx.amount.to_string(),
+            fee: "0".to_string(),
+            status: tx.status() as i32,
+            message: tx.message.clone().unwrap_or_default(),
+            timestamp: tx.received_at,
+            is_inbound: true,
+            address: tx.sender_address.clone().unwrap_or_default(),
+            lock_height: None,
+            confirmations: confirmations_at(tx.mined_height, tip_height),
+            maturity_height: tx.maturity_height.map(|height| height as i64),
+            output_type: output_type_name(tx.output_type).to_string(),
+            local_label: None,
+        })
+    }
+
+    /// One page of the transactions matching `filter`, newest first
+    fn search_transactions(&self, filter: &TransactionFilter, offset: u32, limit: u32) -> JsTransactionPage {
+        // Placeholder implementation - would run as a query on the
+        // transaction database, filtered, ordered and paged in SQL
+        let mut matching: Vec<TransactionRecord> = self
+            .transaction_records()
+            .filter(|record| filter.matches(record))
+            .collect();
+        matching.sort_by(|a, b| {
+            b.timestamp
+                .total_cmp(&a.timestamp)
+                .then_with(|| a.tx_id.cmp(b.tx_id))
+        });
+
+        let total = matching.len() as u32;
+        let transactions = matching
+            .iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .filter_map(|record| self.transaction_info(record.tx_id))
+            .collect::<Vec<_>>();
+        JsTransactionPage {
+            has_more: (offset as usize).saturating_add(transactions.len()) < total as usize,
+            transactions,
+            total,
+            offset,
+        }
+    }
+
+    /// The wallet's outputs whose status matches `filter`
+    fn utxos(&self, filter: UtxoStatusFilter) -> Vec<JsUtxoInfo> {
+        self.outputs()
+            .into_iter()
+            .filter(|output| filter.matches(output.status))
+            .enumerate()
+            .map(|(index, output)| JsUtxoInfo {
+                amount: output.amount.to_string(),
+                commitment: format!("{:064x}", index),
+                features: 0,
+                maturity: output.maturity.to_string(),
+                status: output.status,
+            })
+            .collect()
+    }
+
+    /// Unspent outputs worth no more than the fee to spend them
+    fn dust_utxos(&self) -> Vec<JsUtxoInfo> {
+        let threshold = dust_threshold(self.mempool_fee_stats());
+        self.utxos(UtxoStatusFilter::Unspent)
+            .into_iter()
+            .filter(|utxo| utxo.amount.parse::<u64>().is_ok_and(|amount| amount <= threshold))
+            .collect()
+    }
+
+    /// Largest amount one transaction can send at `fee_per_gram`, net of its fee
+    ///
+    /// Every spendable output worth more than the cost of its own input is
+    /// selected, and the transaction has no change output since nothing is
+    /// left over.
+    fn max_spendable(&self, fee_per_gram: FeePerGram) -> u64 {
+        let input_cost = fee_per_gram.fee_for(INPUT_WEIGHT);
+        let (input_count, total) = self
+            .outputs()
+            .into_iter()
+            .filter(|output| output.status == UTXO_STATUS_UNSPENT)
+            .filter(|output| output.maturity <= self.tip_height && output.amount > input_cost)
+            .fold((0u64, 0u64), |(count, total), output| {
+                (count + 1, total.saturating_add(output.amount))
+            });
+
+        if input_count == 0 {
+            return 0;
+        }
+        total.saturating_sub(estimate_fee(fee_per_gram, input_count, 1))
+    }
+
+    /// Count and total the wallet's outputs in a single pass
+    fn utxo_summary(&self) -> JsUtxoSummary {
+        let (mut count, mut spendable_count) = (0u32, 0u32);
+        let (mut total_value, mut unspent_value) = (0u64, 0u64);
+        for output in self.outputs() {
+            count += 1;
+            total_value = total_value.saturating_add(output.amount);
+            if output.status == UTXO_STATUS_UNSPENT {
+                unspent_value = unspent_value.saturating_add(output.amount);
+                if output.maturity <= self.tip_height {
+                    spendable_count += 1;
+                }
+            }
+        }
+
+        JsUtxoSummary {
+            count,
+            total_value: total_value.to_string(),
+            unspent_value: unspent_value.to_string(),
+            spendable_count,
+        }
     }
 }
 
-/// Get number of active wallet handles (for debugging)
-#[napi]
-pub async fn wallet_get_active_handle_count() -> Result<i32> {
-    let storage = ensure_storage_initialized();
-    let storage_lock = storage.read().await;
-    Ok(storage_lock.len() as i32)
+/// An output owned by the wallet; amounts in µT
+struct WalletOutput {
+    status: i32,
+    amount: u64,
+    /// Block height from which the output can be spent
+    maturity: u64,
 }
 
-/// Validate a wallet handle without accessing the wallet
-#[napi]
-pub async fn wallet_validate_handle(handle: WalletHandle) -> Result<bool> {
-    let storage = ensure_storage_initialized();
-    let storage_lock = storage.read().await;
-
-    if let Some(wallet_arc) = storage_lock.get(&handle) {
-        let wallet_lock = wallet_arc.lock().map_err(|e| {
-            napi::Error::new(
-                Status::GenericFailure,
-                format!("Failed to acquire wallet lock: {}", e),
-            )
-        })?;
+/// Which outputs `wallet_get_utxos` returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UtxoStatusFilter {
+    Unspent,
+    Spent,
+    /// Outputs waiting on a transaction to be mined, in either direction
+    Unconfirmed,
+    All,
+}
 
-        Ok(!wallet_lock.destroyed && wallet_lock.initialized)
-    } else {
-        Ok(false)
+impl UtxoStatusFilter {
+    /// Parse "unspent", "spent", "unconfirmed" or "all", defaulting to unspent
+    fn parse(filter: Option<&str>) -> TariResult<Self> {
+        match filter.map(str::to_ascii_lowercase).as_deref() {
+            None | Some("unspent") => Ok(Self::Unspent),
+            Some("spent") => Ok(Self::Spent),
+            Some("unconfirmed") => Ok(Self::Unconfirmed),
+            Some("all") => Ok(Self::All),
+            Some(_) => Err(TariWalletError::ValidationError(format!(
+                "Invalid UTXO status filter: {} (expected unspent, spent, unconfirmed or all)",
+                filter.unwrap_or_default()
+            ))),
+        }
+    }
+
+    fn matches(self, status: i32) -> bool {
+        match self {
+            Self::Unspent => status == UTXO_STATUS_UNSPENT,
+            Self::Spent => status == UTXO_STATUS_SPENT,
+            Self::Unconfirmed => matches!(
+                status,
+                UTXO_STATUS_ENCUMBERED_TO_BE_RECEIVED | UTXO_STATUS_ENCUMBERED_TO_BE_SPENT
+            ),
+            Self::All => true,
+        }
     }
 }
 
-/// Cleanup all wallet handles (for testing and shutdown)
-#[napi]
-pub async fn wallet_cleanup_all() -> Result<i32> {
-    let storage = ensure_storage_initialized();
-    let mut storage_lock = storage.write().await;
+/// The fields transactions are searched by, for either direction
+struct TransactionRecord<'a> {
+    tx_id: &'a str,
+    inbound: bool,
+    amount: u64,
+    timestamp: f64,
+    /// Recipient of an outbound transaction, or sender of an inbound one
+    address: Option<&'a str>,
+}
 
-    let count = storage_lock.len() as i32;
+/// Which transactions `wallet_search_transactions` returns
+#[derive(Debug, Default)]
+struct TransactionFilter {
+    min_amount: Option<u64>,
+    max_amount: Option<u64>,
+    from_timestamp: Option<f64>,
+    to_timestamp: Option<f64>,
+    address: Option<String>,
+    inbound: Option<bool>,
+}
 
-    // Mark all wallets as destroyed
-    for (_, wallet_arc) in storage_lock.iter() {
-        if let Ok(mut wallet_lock) = wallet_arc.lock() {
-            wallet_lock.destroyed = true;
-            wallet_lock.initialized = false;
-        }
+impl TransactionFilter {
+    /// Check a filter from JavaScript, resolving an emoji ID address on `network`
+    fn parse(filter: JsTxFilter, network: &str) -> std::result::Result<Self, MappedWalletError> {
+        let amount = |amount: Option<String>| {
+            amount
+                .map(|amount| {
+                    amount.parse::<u64>().map_err(|_| {
+                        MappedWalletError::new(
+                            WalletErrorCode::InvalidAmount,
+                            format!("Invalid amount filter: {}", amount),
+                        )
+                        .component("wallet")
+                    })
+                })
+                .transpose()
+        };
+        let inbound = match filter.direction.map(|d| d.to_ascii_lowercase()).as_deref() {
+            None => None,
+            Some("inbound") => Some(true),
+            Some("outbound") => Some(false),
+            Some(direction) => {
+                return Err(MappedWalletError::new(
+                    WalletErrorCode::InvalidParameter,
+                    format!("Invalid direction filter: {} (expected inbound or outbound)", direction),
+                )
+                .component("wallet"))
+            }
+        };
+
+        Ok(Self {
+            min_amount: amount(filter.min_amount)?,
+            max_amount: amount(filter.max_amount)?,
+            from_timestamp: filter.from_timestamp,
+            to_timestamp: filter.to_timestamp,
+            address: filter
+                .address
+                .map(|address| normalize_destination(&address, network))
+                .transpose()?,
+            inbound,
+        })
+    }
+
+    fn matches(&self, record: &TransactionRecord) -> bool {
+        self.min_amount.is_none_or(|min| record.amount >= min)
+            && self.max_amount.is_none_or(|max| record.amount <= max)
+            && self.from_timestamp.is_none_or(|from| record.timestamp >= from)
+            && self.to_timestamp.is_none_or(|to| record.timestamp <= to)
+            && self.inbound.is_none_or(|inbound| record.inbound == inbound)
+            && self
+                .address
+                .as_deref()
+                .is_none_or(|address| record.address == Some(address))
     }
+}
 
-    // Clear storage
-    storage_lock.clear();
+/// Parse a µT amount string, rejecting zero and non-numeric values
+pub(crate) fn parse_amount(amount: &str) -> TariResult<u64> {
+    match amount.parse::<u64>() {
+        Ok(0) | Err(_) => Err(TariWalletError::ValidationError(
+            "Amount must be greater than zero".to_string(),
+        )),
+        Ok(value) => Ok(value),
+    }
+}
 
-    Ok(count)
+/// Check a transaction message fits the protocol's memo field
+///
+/// Messages are measured in UTF-8 bytes, not characters. Node replaces
+/// unpaired surrogates in JS strings with U+FFFD on the way in, which
+/// cannot be told apart from a real U+FFFD here, so they are rejected by
+/// the TypeScript bindings instead.
+pub(crate) fn validate_message(message: &str) -> std::result::Result<(), MappedWalletError> {
+    if message.len() > MAX_MESSAGE_BYTES {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::MessageTooLong,
+            format!(
+                "Transaction message is {} bytes; the maximum is {} bytes",
+                message.len(),
+                MAX_MESSAGE_BYTES
+            ),
+        )
+        .component("wallet"));
+    }
+    Ok(())
+}
+
+/// A fee rate in µT per gram, held exactly to `FEE_PER_GRAM_DECIMALS` places
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct FeePerGram(u128); // In 10^-FEE_PER_GRAM_DECIMALS µT per gram
+
+impl FeePerGram {
+    /// Fee in µT for `weight` grams, rounded up to the next whole µT
+    ///
+    /// Rounding up means a fractional rate never underpays. The fee
+    /// saturates at `u64::MAX` rather than wrapping.
+    pub(crate) fn fee_for(self, weight: u64) -> u64 {
+        let fee = self.0.saturating_mul(weight as u128).div_ceil(FEE_PER_GRAM_SCALE);
+        u64::try_from(fee).unwrap_or(u64::MAX)
+    }
+}
+
+impl From<u64> for FeePerGram {
+    fn from(fee_per_gram: u64) -> Self {
+        Self(fee_per_gram as u128 * FEE_PER_GRAM_SCALE)
+    }
+}
+
+impl std::fmt::Display for FeePerGram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (whole, fraction) = (self.0 / FEE_PER_GRAM_SCALE, self.0 % FEE_PER_GRAM_SCALE);
+        if fraction == 0 {
+            return write!(f, "{}", whole);
+        }
+        let digits = format!("{:0width$}", fraction, width = FEE_PER_GRAM_DECIMALS as usize);
+        write!(f, "{}.{}", whole, digits.trim_end_matches('0'))
+    }
+}
+
+/// Parse an optional fee per gram, falling back to the default
+///
+/// The fee per gram is a decimal string of µT such as "5" or "0.25". It is
+/// converted exactly, so more than `FEE_PER_GRAM_DECIMALS` decimal places
+/// are refused rather than rounded.
+pub(crate) fn parse_fee_per_gram(fee_per_gram: Option<&str>) -> TariResult<FeePerGram> {
+    let Some(fee) = fee_per_gram else {
+        return Ok(DEFAULT_FEE_PER_GRAM.into());
+    };
+    let invalid = || TariWalletError::ValidationError(format!("Invalid fee per gram: {}", fee));
+
+    let (whole, fraction) = fee.split_once('.').unwrap_or((fee, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) || fee.ends_with('.') {
+        return Err(invalid());
+    }
+    if fraction.len() > FEE_PER_GRAM_DECIMALS as usize {
+        return Err(TariWalletError::ValidationError(format!(
+            "Fee per gram {} has more than {} decimal places",
+            fee, FEE_PER_GRAM_DECIMALS
+        )));
+    }
+
+    let whole = whole.parse::<u64>().map_err(|_| invalid())?;
+    let fraction = format!("{:0<width$}", fraction, width = FEE_PER_GRAM_DECIMALS as usize);
+    let fraction = fraction.parse::<u128>().map_err(|_| invalid())?;
+    Ok(FeePerGram(FeePerGram::from(whole).0 + fraction))
+}
+
+/// Estimate a transaction fee from its input and output counts
+///
+/// The fee is the fee per gram times the transaction's weight, rounded up
+/// to the next whole µT so transactions are never underpaid. It saturates
+/// rather than wrapping, so an absurd fee per gram is caught by
+/// `total_with_fee` instead of producing a tiny fee.
+pub(crate) fn estimate_fee(
+    fee_per_gram: impl Into<FeePerGram>,
+    input_count: u64,
+    output_count: u64,
+) -> u64 {
+    let weight = KERNEL_WEIGHT
+        .saturating_add(input_count.saturating_mul(INPUT_WEIGHT))
+        .saturating_add(output_count.saturating_mul(OUTPUT_WEIGHT));
+    fee_per_gram.into().fee_for(weight)
+}
+
+/// Amount plus fee in µT, refusing totals that do not fit in a u64
+pub(crate) fn total_with_fee(amount: u64, fee: u64) -> TariResult<u64> {
+    amount.checked_add(fee).ok_or_else(|| {
+        TariWalletError::ValidationError(format!(
+            "amount overflow: {} µT plus a {} µT fee",
+            amount, fee
+        ))
+    })
+}
+
+/// Derive slow, normal and fast fee tiers from mempool stats
+///
+/// The slow tier pays the cheapest waiting transaction's rate, the normal
+/// tier the average, and the fast tier outbids the most expensive one.
+/// Without stats the tiers are spread around the default fee per gram.
+fn recommend_fees(stats: Option<MempoolFeeStats>) -> JsFeeRecommendations {
+    let (slow, normal, fast, source) = fee_tiers(stats);
+
+    JsFeeRecommendations {
+        slow: slow.to_string(),
+        normal: normal.to_string(),
+        fast: fast.to_string(),
+        source: source.to_string(),
+    }
+}
+
+/// Slow, normal and fast fees per gram, and where they came from
+fn fee_tiers(stats: Option<MempoolFeeStats>) -> (u64, u64, u64, &'static str) {
+    let (slow, normal, fast, source) = match stats {
+        Some(stats) => (
+            stats.min_fee_per_gram,
+            stats.avg_fee_per_gram,
+            stats.max_fee_per_gram.saturating_add(1),
+            "live",
+        ),
+        None => (
+            DEFAULT_FEE_PER_GRAM / 2,
+            DEFAULT_FEE_PER_GRAM,
+            DEFAULT_FEE_PER_GRAM * 2,
+            "fallback",
+        ),
+    };
+
+    (
+        slow.max(MIN_FEE_PER_GRAM),
+        normal.max(MIN_FEE_PER_GRAM),
+        fast.max(MIN_FEE_PER_GRAM),
+        source,
+    )
+}
+
+/// Largest output value in µT that costs at least as much to spend as it is worth
+///
+/// Spending an output adds one input's weight to a transaction, paid for
+/// at the normal recommended fee per gram.
+fn dust_threshold(stats: Option<MempoolFeeStats>) -> u64 {
+    let (_, normal, _, _) = fee_tiers(stats);
+    normal.saturating_mul(INPUT_WEIGHT)
+}
+
+/// Lock file marking a data directory as owned by an open wallet
+const DATA_DIR_LOCK_FILE: &str = "wallet.lock";
+
+/// Exclusive claim on a wallet data directory, released on drop
+///
+/// Two wallets sharing one database would silently corrupt it, so a
+/// directory can only be opened by one wallet at a time across processes.
+struct DataDirLock {
+    path: PathBuf,
+}
+
+impl DataDirLock {
+    fn acquire(storage_path: &str) -> Result<Self> {
+        let storage_error = |e: std::io::Error| {
+            TariWalletError::StorageError(format!("Failed to lock data directory: {}", e))
+        };
+        fs::create_dir_all(storage_path).map_err(storage_error)?;
+
+        let path = Path::new(storage_path).join(DATA_DIR_LOCK_FILE);
+        if Self::is_stale(&path) {
+            let _ = fs::remove_file(&path);
+        }
+
+        match fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                use std::io::Write;
+                file.write_all(std::process::id().to_string().as_bytes())
+                    .map_err(storage_error)?;
+                Ok(Self { path })
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Err(MappedWalletError::new(
+                WalletErrorCode::FileLocked,
+                format!("data directory is in use: {}", storage_path),
+            )
+            .component("database")
+            .into()),
+            Err(e) => Err(storage_error(e).into()),
+        }
+    }
+
+    /// Whether the lock was left behind by a process that no longer runs
+    fn is_stale(path: &Path) -> bool {
+        let Some(pid) = fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| contents.trim().parse::<u32>().ok())
+        else {
+            return false;
+        };
+
+        if cfg!(target_os = "linux") {
+            !Path::new("/proc").join(pid.to_string()).exists()
+        } else {
+            // Without a portable liveness check, only trust the lock's owner
+            false
+        }
+    }
+}
+
+impl Drop for DataDirLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Check that a file looks like a wallet database before it is swapped in
+///
+/// SQLCipher databases have no plaintext header, so those are recognised
+/// by being made of whole pages.
+fn validate_wallet_db(path: &Path) -> TariResult<()> {
+    let length = fs::metadata(path)
+        .map_err(|e| TariWalletError::StorageError(format!("Cannot read {}: {}", path.display(), e)))?
+        .len();
+
+    if has_plain_header(path) || (length > 0 && length % SQLCIPHER_PAGE_SIZE == 0) {
+        Ok(())
+    } else {
+        Err(TariWalletError::StorageError(format!(
+            "{} is not a Tari wallet database",
+            path.display()
+        )))
+    }
+}
+
+/// Generate next wallet handle
+fn generate_handle() -> WalletHandle {
+    NEXT_HANDLE.fetch_add(1, Ordering::SeqCst)
+}
+
+/// Look up a wallet instance, releasing the storage lock before returning
+async fn get_wallet(handle: WalletHandle) -> Result<Arc<WalletLock>> {
+    WALLET_STORAGE
+        .read()
+        .await
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| unknown_handle(handle))
+}
+
+/// Record that `handle` has been destroyed; call while removing it from storage
+fn tombstone(handle: WalletHandle) {
+    if let Ok(mut destroyed) = DESTROYED_HANDLES.lock() {
+        destroyed.insert(handle);
+    }
+}
+
+fn is_tombstoned(handle: WalletHandle) -> bool {
+    DESTROYED_HANDLES
+        .lock()
+        .map(|destroyed| destroyed.contains(&handle))
+        .unwrap_or(false)
+}
+
+/// Error for a handle that is not in wallet storage
+fn unknown_handle(handle: WalletHandle) -> napi::Error {
+    let message = if is_tombstoned(handle) {
+        format!("Wallet handle {} has been destroyed", handle)
+    } else {
+        format!("Invalid wallet handle: {}", handle)
+    };
+    MappedWalletError::new(WalletErrorCode::InvalidHandle, message)
+        .component("wallet")
+        .into()
+}
+
+/// Storage path and chain tip height of a wallet, as used by recovery scanning
+pub(crate) async fn wallet_scan_target(handle: WalletHandle) -> Result<(String, u64)> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok((wallet_lock.config.storage_path.clone(), wallet_lock.tip_height))
+}
+
+/// Height a wallet has synced to, the chain tip to sync up to, and the
+/// height to rescan from after a reorg deeper than the kept block hashes:
+/// the wallet's birthday, or genesis if it has none
+pub(crate) async fn wallet_sync_target(handle: WalletHandle) -> Result<(u64, u64, u64)> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok((
+        wallet_lock.synced_height,
+        wallet_lock.tip_height,
+        wallet_lock.birthday_height.unwrap_or(0),
+    ))
+}
+
+/// Record the height a wallet has synced to
+pub(crate) async fn set_synced_height(handle: WalletHandle, height: u64) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+    wallet_lock.synced_height = height;
+    wallet_lock.last_synced_at = Some(chrono::Utc::now().timestamp() as f64);
+    wallet_lock.events.publish(WalletEvent::SyncCompleted { height });
+    Ok(())
+}
+
+/// Network and spend key of a wallet, for building and signing offline transactions
+pub(crate) async fn wallet_signing_key(handle: WalletHandle) -> Result<(String, RistrettoSecretKey)> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok((wallet_lock.config.network.clone(), wallet_lock.seed()?.spend_key()?))
+}
+
+/// Network and view key of a wallet; available to watch-only wallets too
+pub(crate) async fn wallet_view_key(handle: WalletHandle) -> Result<(String, RistrettoPublicKey)> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok((wallet_lock.config.network.clone(), wallet_lock.view_key.clone()))
+}
+
+/// Check a wallet's available balance covers an amount plus fee
+pub(crate) async fn ensure_spendable(handle: WalletHandle, amount: u64, fee: u64) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    if total_with_fee(amount, fee)? > wallet_lock.available_balance {
+        return Err(TariWalletError::InsufficientFunds.into());
+    }
+    Ok(())
+}
+
+/// Spend and broadcast a transaction signed offline, returning its id
+///
+/// A signed transaction is identified by its nonce and only broadcast once.
+pub(crate) async fn broadcast_offline_transaction(
+    handle: WalletHandle,
+    nonce: &str,
+    amount: u64,
+    fee: u64,
+) -> Result<String> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    if wallet_lock.offline_nonces.contains(nonce) {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::DuplicateTransaction,
+            "Signed transaction has already been broadcast",
+        )
+        .component("offline")
+        .into());
+    }
+
+    wallet_lock.spend(amount, fee)?;
+    wallet_lock.offline_nonces.insert(nonce.to_string());
+
+    // Placeholder implementation - would finalize the signed transaction and
+    // submit it via the transaction service
+    Ok(wallet_lock.broadcast_new_transaction(None, amount, fee))
+}
+
+/// Check an output to import really is spendable with the key it came with
+///
+/// Returns the amount in µT once the commitment is shown to open to it, its
+/// range proof and metadata signature verify, and any script resolves to
+/// the spending key's public key with the given input data.
+fn validate_imported_utxo(utxo: &JsImportUtxo) -> Result<u64> {
+    let amount = parse_amount(&utxo.amount)?;
+    if !commitment_opens(&utxo.commitment, amount, &utxo.spending_key)? {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::InvalidCommitment,
+            format!(
+                "Commitment {} does not open to {} µT under the given spending key",
+                utxo.commitment, amount
+            ),
+        )
+        .component("import")
+        .into());
+    }
+    // The network rejects spends of outputs without a valid range proof
+    if !range_proof_verifies(&utxo.commitment, &utxo.range_proof)? {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::InvalidRangeProof,
+            format!("Range proof does not verify for commitment {}", utxo.commitment),
+        )
+        .component("import")
+        .into());
+    }
+
+    let script = utxo
+        .script
+        .as_deref()
+        .map(|script| {
+            let bytes = tari_crypto::tari_utilities::hex::from_hex(script).map_err(|e| {
+                MappedWalletError::new(WalletErrorCode::InvalidFormat, format!("Invalid script: {}", e))
+                    .component("import")
+            })?;
+            Ok::<_, napi::Error>((TariScript::from_bytes(&bytes)?, bytes))
+        })
+        .transpose()?;
+
+    let script_bytes = script.as_ref().map_or(&[][..], |(_, bytes)| bytes);
+    if !metadata_signature_verifies(
+        &utxo.commitment,
+        &utxo.sender_offset_public_key,
+        script_bytes,
+        &utxo.metadata_signature,
+    )? {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::InvalidSignature,
+            format!(
+                "Metadata signature does not verify for commitment {} under sender offset key {}",
+                utxo.commitment, utxo.sender_offset_public_key
+            ),
+        )
+        .component("import")
+        .into());
+    }
+
+    // The wallet spends the output with its spending key as the script key,
+    // so the script run on the input data has to resolve to that key
+    match (script, &utxo.input_data) {
+        (Some((script, _)), input_data) => {
+            let input_data = input_data
+                .iter()
+                .flatten()
+                .map(|item| StackItem::parse(item))
+                .collect::<std::result::Result<Vec<_>, MappedWalletError>>()?;
+            let script_key = script.script_public_key(input_data).map_err(|e| {
+                MappedWalletError::new(
+                    WalletErrorCode::InvalidFormat,
+                    format!("Script does not run on the given input data: {}", e),
+                )
+                .component("import")
+            })?;
+            let spending_key = RistrettoSecretKey::from_hex(&utxo.spending_key).map_err(|e| {
+                MappedWalletError::new(WalletErrorCode::InvalidKey, format!("Invalid spending key: {}", e))
+                    .component("import")
+            })?;
+            if script_key != RistrettoPublicKey::from_secret_key(&spending_key) {
+                return Err(MappedWalletError::new(
+                    WalletErrorCode::InvalidFormat,
+                    format!(
+                        "Script resolves to key {} with the given input data, not the spending key's public key",
+                        script_key.to_hex()
+                    ),
+                )
+                .component("import")
+                .into());
+            }
+        }
+        (None, Some(_)) => {
+            return Err(MappedWalletError::new(
+                WalletErrorCode::InvalidParameter,
+                "Input data was given without the script it is for",
+            )
+            .component("import")
+            .into());
+        }
+        (None, None) => {}
+    }
+
+    Ok(amount)
+}
+
+/// Undo a wallet's state from `height` up after a chain reorganization
+///
+/// Transactions mined in the rolled-back blocks go back to unmined so they
+/// are confirmed again once they reappear on the new chain.
+pub(crate) async fn rollback_to_height(handle: WalletHandle, height: u64) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+    wallet_lock.roll_back(height);
+    Ok(())
+}
+
+/// Mine `count` blocks on a localnet wallet's base node, resolving to the new tip height
+///
+/// Lets integration tests confirm transactions without waiting on real
+/// miners. Fails with Unsupported on any other network.
+#[cfg(any(test, feature = "localnet"))]
+#[napi]
+pub async fn wallet_mine_blocks(handle: WalletHandle, count: u32) -> Result<i64> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    if wallet_lock.config.network != LOCALNET {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::Unsupported,
+            format!(
+                "Blocks can only be mined on localnet, not {}",
+                wallet_lock.config.network
+            ),
+        )
+        .component("wallet")
+        .into());
+    }
+
+    Ok(wallet_lock.mine_blocks(count) as i64)
+}
+
+#[cfg(test)]
+pub(crate) async fn set_tip_height(handle: WalletHandle, height: u64) {
+    let wallet = get_wallet(handle).await.unwrap();
+    wallet.write().await.tip_height = height;
+}
+
+/// Submit an outbound transaction to the connected base nodes
+///
+/// Returns whether a base node acknowledged the transaction.
+fn submit_transaction(_tx_id: &str) -> TariResult<bool> {
+    // Placeholder implementation - would resubmit via the transaction
+    // service; nothing is sent, so nothing can be acknowledged
+    Ok(false)
+}
+
+/// Watch a wallet's outbound transactions until it is destroyed
+///
+/// Unseen transactions are rebroadcast, and a `tx:confirmed` event is
+/// emitted once a mined transaction reaches the required confirmations.
+async fn run_transaction_monitor(wallet: Weak<WalletLock>) {
+    let mut interval = tokio::time::interval(TRANSACTION_CHECK_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let Some(wallet) = wallet.upgrade() else {
+            break;
+        };
+        // Skip this tick rather than park a runtime worker behind a
+        // long-running operation on the same wallet
+        let Ok(mut wallet) = wallet.try_write() else {
+            continue;
+        };
+        if wallet.destroyed {
+            break;
+        }
+
+        if wallet.max_broadcast_retries > 0 {
+            for tx_id in wallet.rebroadcast_due(Instant::now()) {
+                tracing::debug!("Rebroadcast transaction {} from wallet {}", tx_id, wallet.handle);
+            }
+        }
+
+        // Placeholder implementation - tip_height would track the base
+        // node's chain metadata as new blocks arrive
+        let bridge = create_event_bridge(wallet.handle);
+        for (tx_id, confirmations) in wallet.newly_confirmed() {
+            let _ = bridge.handle_transaction_confirmed(&tx_id, confirmations);
+        }
+
+        for (tx_id, tx) in wallet.poll_inbound() {
+            wallet.receive_inbound(tx_id, tx);
+        }
+        for notification in wallet.newly_confirmed_inbound() {
+            notify_transaction_received(wallet.handle, notification);
+        }
+    }
+}
+
+/// Periodically lift expired base node bans until the wallet is destroyed
+///
+/// Lifted bans are saved straight away, so a restart does not bring them back.
+async fn run_ban_sweep(wallet: Weak<WalletLock>) {
+    let mut interval = tokio::time::interval(BAN_SWEEP_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let Some(wallet) = wallet.upgrade() else {
+            break;
+        };
+        let Ok(mut wallet) = wallet.try_write() else {
+            continue;
+        };
+        if wallet.destroyed {
+            break;
+        }
+
+        let lifted = wallet.base_nodes.lift_expired_bans(SystemTime::now());
+        if lifted.is_empty() {
+            continue;
+        }
+        tracing::info!("Bans on base nodes {:?} expired for wallet {}", lifted, wallet.handle);
+        if let Err(e) = wallet.base_nodes.save_reputations(&wallet.config.storage_path) {
+            tracing::warn!("Peer reputations were not saved: {}", e.message);
+        }
+    }
+}
+
+/// Acquire the exclusive lock on a single wallet instance, for changing it
+async fn lock_wallet(wallet: &WalletLock) -> RwLockWriteGuard<'_, WalletInstance> {
+    wallet.write().await
+}
+
+/// Acquire a shared lock on a single wallet instance, for read-only queries
+async fn read_wallet(wallet: &WalletLock) -> RwLockReadGuard<'_, WalletInstance> {
+    wallet.read().await
+}
+
+/// Wallet operations bounded by the configured `operation_timeouts`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum TimedOperation {
+    Create,
+    Balance,
+    Send,
+    Sync,
+}
+
+impl TimedOperation {
+    const ALL: [Self; 4] = [Self::Create, Self::Balance, Self::Send, Self::Sync];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Balance => "balance",
+            Self::Send => "send",
+            Self::Sync => "sync",
+        }
+    }
+
+    /// Time the operation may take under `timeouts`, or its default
+    fn timeout(self, timeouts: Option<&JsOperationTimeouts>) -> Duration {
+        let configured = timeouts.and_then(|t| match self {
+            Self::Create => t.create_ms,
+            Self::Balance => t.balance_ms,
+            Self::Send => t.send_ms,
+            Self::Sync => t.sync_ms,
+        });
+        let default = match self {
+            Self::Create => DEFAULT_CREATE_TIMEOUT_MS,
+            Self::Balance => DEFAULT_BALANCE_TIMEOUT_MS,
+            Self::Send => DEFAULT_SEND_TIMEOUT_MS,
+            Self::Sync => DEFAULT_SYNC_TIMEOUT_MS,
+        };
+        Duration::from_millis(configured.unwrap_or(default) as u64)
+    }
+}
+
+/// Fail with OperationTimeout if `future` has not finished within `timeout`
+async fn with_operation_timeout<T>(
+    operation: TimedOperation,
+    timeout: Duration,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    tokio::time::timeout(timeout, future)
+        .await
+        .map_err(|_| operation_timed_out(operation, timeout))?
+}
+
+fn operation_timed_out(operation: TimedOperation, timeout: Duration) -> napi::Error {
+    MappedWalletError::new(
+        WalletErrorCode::OperationTimeout,
+        format!("Wallet {} timed out after {:?}", operation.name(), timeout),
+    )
+    .component("wallet")
+    .into()
+}
+
+/// Time the wallet allows for `operation`
+///
+/// Does not take the wallet lock, so an operation queued behind one that
+/// holds the lock is still timed.
+fn wallet_timeout(handle: WalletHandle, operation: TimedOperation) -> Result<Duration> {
+    OPERATION_TIMEOUTS
+        .lock()
+        .ok()
+        .and_then(|timeouts| timeouts.get(&handle)?.get(&operation).copied())
+        .ok_or_else(|| unknown_handle(handle))
+}
+
+/// A wallet's pool of read-only database connections
+fn wallet_read_pool(handle: WalletHandle) -> Result<Arc<ReadPool>> {
+    READ_POOLS
+        .lock()
+        .ok()
+        .and_then(|pools| pools.get(&handle).cloned())
+        .ok_or_else(|| unknown_handle(handle))
+}
+
+/// A transaction's local label, read on a pooled connection
+///
+/// A label that cannot be read is logged and left out, so listings do not
+/// fail over local bookkeeping.
+fn local_label(connection: &Connection, tx_id: &str) -> Option<String> {
+    labels::get(connection, tx_id).unwrap_or_else(|e| {
+        tracing::warn!("Label of {} was not read: {}", tx_id, e.message);
+        None
+    })
+}
+
+/// Fill in the local labels of transactions without the wallet's lock
+async fn add_local_labels(handle: WalletHandle, transactions: &mut [JsTransactionInfo]) -> Result<()> {
+    let pool = wallet_read_pool(handle)?;
+    let connection = pool.get().await?;
+    for tx in transactions {
+        tx.local_label = local_label(&connection, &tx.id);
+    }
+    Ok(())
+}
+
+/// Run one of a wallet's operations, bounded by the wallet's timeout for it
+pub(crate) async fn with_wallet_timeout<T>(
+    handle: WalletHandle,
+    operation: TimedOperation,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let timeout = wallet_timeout(handle, operation)?;
+    with_operation_timeout(operation, timeout, future).await
+}
+
+/// Run a balance query, falling back to the last known balance if it times out
+///
+/// The fallback is marked stale. Without an earlier balance the timeout is
+/// returned instead: a made-up balance would show funds that may not exist.
+async fn query_balance(
+    handle: WalletHandle,
+    query: impl Future<Output = Result<JsBalance>>,
+) -> Result<JsBalance> {
+    let timeout = wallet_timeout(handle, TimedOperation::Balance)?;
+    let Ok(balance) = tokio::time::timeout(timeout, query).await else {
+        let last_known = LAST_KNOWN_BALANCES
+            .lock()
+            .ok()
+            .and_then(|balances| balances.get(&handle).copied());
+        let Some(snapshot) = last_known else {
+            return Err(operation_timed_out(TimedOperation::Balance, timeout));
+        };
+
+        tracing::warn!(
+            "Balance query for wallet {} timed out; serving the balance from {:?} ago",
+            handle,
+            snapshot.taken_at.elapsed()
+        );
+        return Ok(JsBalance {
+            stale: true,
+            ..snapshot.to_js()
+        });
+    };
+    balance
+}
+
+/// Shut a wallet down, giving up on in-flight work after `SHUTDOWN_TIMEOUT`
+async fn shutdown_wallet(handle: WalletHandle, wallet: Arc<WalletLock>) {
+    if let Ok(mut last_known) = LAST_KNOWN_BALANCES.lock() {
+        last_known.remove(&handle);
+    }
+    if let Ok(mut timeouts) = OPERATION_TIMEOUTS.lock() {
+        timeouts.remove(&handle);
+    }
+    if let Ok(mut pools) = READ_POOLS.lock() {
+        pools.remove(&handle);
+    }
+
+    let _ = create_event_bridge(handle).handle_wallet_stopped();
+
+    // Recovery keeps its on-disk checkpoint so it can be resumed on next open
+    stop_recovery(handle);
+    stop_sync(handle);
+
+    // Taking the wallet lock waits for any operation still using the wallet
+    let shutdown = tokio::spawn(async move {
+        wallet.write().await.shutdown();
+    });
+
+    if tokio::time::timeout(SHUTDOWN_TIMEOUT, shutdown).await.is_err() {
+        tracing::warn!(
+            "Wallet {} did not shut down within {:?}; releasing handle anyway",
+            handle,
+            SHUTDOWN_TIMEOUT
+        );
+    }
+
+    let _ = wallet_remove_event_callback(handle);
+    remove_transaction_received_callback(handle);
+    wallet_unsubscribe_output_manager_events(handle);
+}
+
+/// Create a new wallet instance
+///
+/// Without seed words or a view key, the wallet loads the seed recorded in
+/// its database; a new seed is only generated when there is no database
+/// yet. Seed words must be those of the recorded seed, if there is one.
+///
+/// Fails with OperationTimeout if creation takes longer than
+/// `operation_timeouts.create_ms`.
+#[napi]
+pub async fn wallet_create(config: JsWalletConfig) -> Result<WalletHandle> {
+    // Validate configuration
+    config.validate()?;
+    let timeout = TimedOperation::Create.timeout(config.operation_timeouts.as_ref());
+    with_operation_timeout(TimedOperation::Create, timeout, open_wallet(config)).await
+}
+
+/// Open and register a wallet for a validated config
+async fn open_wallet(config: JsWalletConfig) -> Result<WalletHandle> {
+    let language = config
+        .mnemonic_language
+        .as_deref()
+        .map(MnemonicLanguage::parse)
+        .transpose()?;
+    let (seed, language) = match &config.seed_words {
+        Some(words) => {
+            let (seed, language) = CipherSeed::from_mnemonic(words, language)?;
+            (Some(seed), language)
+        }
+        None => (None, language.unwrap_or_default()),
+    };
+    let view_key = config.view_key.as_deref().map(parse_view_key).transpose()?;
+    let overrides = config.network_overrides.as_ref();
+    let override_base_nodes = overrides.and_then(|o| o.base_node_addresses.as_ref());
+    let peer_seeds = config
+        .peer_seeds
+        .iter()
+        .chain(override_base_nodes)
+        .flatten()
+        .map(|seed| parse_base_node_peer(seed))
+        .collect::<std::result::Result<Vec<_>, MappedWalletError>>()?;
+    let transport = Transport::parse(config.transport.as_deref(), config.tor_socks_proxy.as_ref())?;
+    let listener_address = parse_listener_address(config.listener_address.as_deref())?;
+    if listener_address.ip().is_unspecified() {
+        tracing::warn!("Listening on {} accepts peer connections on every interface", listener_address);
+    }
+    // Placeholder implementation - would bind the comms listener to
+    // `listener_address`
+    tracing::info!("Connecting to peers over {}, listening on {}", transport, listener_address);
+    let data_dir_lock = DataDirLock::acquire(&config.storage_path)?;
+    let is_new_database = !wallet_db_path(&config.storage_path).exists();
+    let database = WalletDatabase::open(&config)?;
+    let stored_seed = database.cipher_seed()?;
+    let stored_view_key = stored_seed
+        .as_ref()
+        .map(|seed| seed.spend_key().map(|key| RistrettoPublicKey::from_secret_key(&key)))
+        .transpose()?;
+    let is_new_wallet = is_new_database && seed.is_none() && view_key.is_none();
+    let seed = match (seed, stored_seed) {
+        (Some(seed), _) => Some(seed),
+        (None, _) if view_key.is_some() => None,
+        (None, Some(stored)) => Some(stored),
+        (None, None) if is_new_wallet => Some(CipherSeed::new()),
+        (None, None) => {
+            return Err(MappedWalletError::new(
+                WalletErrorCode::WalletNotFound,
+                format!(
+                    "Wallet at {} has no seed recorded; restore it from its seed words",
+                    config.storage_path
+                ),
+            )
+            .component("wallet")
+            .into());
+        }
+    };
+    let view_key = match (&seed, view_key) {
+        (Some(seed), _) => RistrettoPublicKey::from_secret_key(&seed.spend_key()?),
+        (None, view_key) => view_key.unwrap_or_default(),
+    };
+    if let Some(stored) = stored_view_key {
+        if stored != view_key {
+            return Err(MappedWalletError::new(
+                WalletErrorCode::WalletExists,
+                format!("Wallet at {} was created from another seed", config.storage_path),
+            )
+            .component("wallet")
+            .into());
+        }
+    } else if let Some(seed) = &seed {
+        database.record_cipher_seed(seed)?;
+    }
+    // Logging is best effort; the first config's level sticks until set_log_level
+    let _ = init_log_level(config.log_level);
+
+    let handle = generate_handle();
+    let auto_connect = config.auto_connect_on_start.unwrap_or(true);
+
+    // Create wallet instance
+    let mut wallet = WalletInstance::new(
+        handle,
+        config,
+        seed,
+        view_key,
+        language,
+        transport,
+        listener_address,
+    );
+    wallet.data_dir_lock = Some(data_dir_lock);
+    wallet.database = Some(database);
+    wallet.birthday_height = wallet.database()?.birthday_height()?;
+    if wallet.birthday_height.is_none() && is_new_wallet {
+        // Without a known tip a new wallet gets no birthday, so recovery
+        // scans from genesis rather than from a height it may have missed
+        if let Some(tip_height) = wallet.known_tip_height() {
+            wallet.birthday_height = Some(tip_height);
+            if let Err(e) = wallet.database()?.record_birthday(tip_height) {
+                tracing::warn!("Wallet birthday was not saved: {}", e.message);
+            }
+        }
+    }
+    wallet.base_nodes.load_reputations(&wallet.config.storage_path);
+    wallet.base_nodes.add_static_seeds(peer_seeds);
+    if let Err(e) = idempotency::prune_expired(wallet.database()?) {
+        tracing::warn!("Expired idempotency keys were not cleared: {}", e.message);
+    }
+    let events = wallet.events.subscribe();
+    let timeouts = TimedOperation::ALL
+        .map(|operation| (operation, operation.timeout(wallet.config.operation_timeouts.as_ref())));
+    if let Ok(mut operation_timeouts) = OPERATION_TIMEOUTS.lock() {
+        operation_timeouts.insert(handle, timeouts.into());
+    }
+    if let Ok(mut pools) = READ_POOLS.lock() {
+        pools.insert(handle, wallet.database()?.read_pool());
+    }
+    let wallet_arc = Arc::new(WalletLock::new(wallet));
+
+    // Store in global storage
+    WALLET_STORAGE.write().await.insert(handle, wallet_arc.clone());
+
+    // Initialize wallet (placeholder for actual Tari wallet creation)
+    lock_wallet(&wallet_arc).await.initialized = true;
+
+    tokio::spawn(run_transaction_monitor(Arc::downgrade(&wallet_arc)));
+    tokio::spawn(run_ban_sweep(Arc::downgrade(&wallet_arc)));
+    tokio::spawn(forward_bus_events(handle, events));
+    // Otherwise nothing is dialed until the app connects or syncs
+    if auto_connect {
+        tokio::spawn(wallet_check_base_node_health(handle));
+    }
+
+    Ok(handle)
+}
+
+/// Destroy a wallet instance and clean up resources
+///
+/// Destroying an already destroyed wallet succeeds without doing anything.
+#[napi]
+pub async fn wallet_destroy(handle: WalletHandle) -> Result<()> {
+    let wallet_arc = {
+        let mut storage = WALLET_STORAGE.write().await;
+        let wallet_arc = storage.remove(&handle);
+        if wallet_arc.is_some() {
+            tombstone(handle);
+        }
+        wallet_arc
+    };
+
+    if let Some(wallet_arc) = wallet_arc {
+        shutdown_wallet(handle, wallet_arc).await;
+        Ok(())
+    } else if is_tombstoned(handle) {
+        Ok(())
+    } else {
+        Err(unknown_handle(handle))
+    }
+}
+
+/// Get wallet balance
+///
+/// If the base node stops responding for `operation_timeouts.balance_ms`,
+/// resolves to the last known balance with `stale` set, or fails with
+/// OperationTimeout when no balance has been fetched yet.
+#[napi]
+pub async fn wallet_get_balance(handle: WalletHandle) -> Result<JsBalance> {
+    query_balance(handle, async move {
+        let wallet_arc = get_wallet(handle).await?;
+        {
+            // A fresh cached balance only needs the shared lock
+            let wallet_lock = read_wallet(&wallet_arc).await;
+            wallet_lock.ensure_not_destroyed()?;
+            if let Some(balance) = wallet_lock.cached_balance() {
+                return Ok(balance);
+            }
+        }
+
+        let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+        wallet_lock.ensure_not_destroyed()?;
+
+        Ok(wallet_lock.balance())
+    })
+    .await
+}
+
+/// Get wallet balance, bypassing the balance cache
+///
+/// Falls back like `wallet_get_balance` when the base node stops responding.
+#[napi]
+pub async fn wallet_refresh_balance(handle: WalletHandle) -> Result<JsBalance> {
+    query_balance(handle, async move {
+        let wallet_arc = get_wallet(handle).await?;
+        let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+        wallet_lock.ensure_not_destroyed()?;
+
+        Ok(wallet_lock.refresh_balance())
+    })
+    .await
+}
+
+/// Get wallet address
+#[napi]
+pub async fn wallet_get_address(handle: WalletHandle) -> Result<String> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.address.clone())
+}
+
+/// Get a fresh one-sided payment address, distinct on every call
+///
+/// Unlike `wallet_get_address`, payments received on different addresses
+/// cannot be linked to each other. Watch-only wallets cannot issue them.
+#[napi]
+pub async fn wallet_get_new_address(handle: WalletHandle) -> Result<String> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    wallet_lock.issue_address()
+}
+
+/// Check whether an address, or an emoji ID, was issued by this wallet
+#[napi]
+pub async fn wallet_is_own_address(handle: WalletHandle, address: String) -> Result<bool> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(normalize_destination(&address, &wallet_lock.config.network)
+        .is_ok_and(|address| wallet_lock.owns_address(&address)))
+}
+
+/// Send a transaction
+///
+/// With `dry_run` set, inputs are selected and the fee computed but nothing
+/// is broadcast; a summary of the assembled transaction is returned instead
+/// of a transaction id. Validation errors such as insufficient funds are
+/// reported exactly as for a real send.
+///
+/// A fee per gram above the wallet's `max_fee_per_gram` is refused unless
+/// `allow_high_fee` is set. The send fails with OperationTimeout after
+/// `operation_timeouts.send_ms`.
+#[napi]
+pub async fn wallet_send_transaction(
+    handle: WalletHandle,
+    recipient_address: String,
+    amount: String,
+    options: Option<JsSendTransactionOptions>,
+    dry_run: Option<bool>,
+) -> Result<Either<String, JsDryRunResult>> {
+    let send = send_transaction(handle, recipient_address, amount, options, dry_run);
+    Ok(match with_wallet_timeout(handle, TimedOperation::Send, send).await? {
+        Either::A(sent) => Either::A(sent.tx_id),
+        Either::B(dry_run) => Either::B(dry_run),
+    })
+}
+
+/// Send a transaction, returning the fee charged and its initial status
+///
+/// Behaves as `wallet_send_transaction` without a dry run. A send repeated
+/// with the same idempotency key reports the original transaction.
+#[napi]
+pub async fn wallet_send_transaction_detailed(
+    handle: WalletHandle,
+    recipient_address: String,
+    amount: String,
+    options: Option<JsSendTransactionOptions>,
+) -> Result<JsSendResult> {
+    let send = send_transaction(handle, recipient_address, amount, options, None);
+    match with_wallet_timeout(handle, TimedOperation::Send, send).await? {
+        Either::A(sent) => Ok(sent),
+        Either::B(_) => Err(MappedWalletError::new(
+            WalletErrorCode::InternalError,
+            "Send without a dry run returned a dry run summary",
+        )
+        .component("wallet")
+        .into()),
+    }
+}
+
+async fn send_transaction(
+    handle: WalletHandle,
+    recipient_address: String,
+    amount: String,
+    options: Option<JsSendTransactionOptions>,
+    dry_run: Option<bool>,
+) -> Result<Either<JsSendResult, JsDryRunResult>> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+    wallet_lock.ensure_can_spend()?;
+
+    // Validate inputs
+    if recipient_address.is_empty() {
+        return Err(TariWalletError::InvalidAddress(
+            "Recipient address cannot be empty".to_string(),
+        )
+        .into());
+    }
+    let recipient_address = normalize_destination(&recipient_address, &wallet_lock.config.network)?;
+
+    let dry_run = dry_run.unwrap_or(false);
+    let value = parse_amount(&amount)?;
+    let fee_per_gram =
+        parse_fee_per_gram(options.as_ref().and_then(|o| o.fee_per_gram.as_deref()))?;
+
+    let message = options.as_ref().and_then(|o| o.message.clone());
+    if let Some(message) = message.as_deref() {
+        validate_message(message)?;
+    }
+    let allow_high_fee = options.as_ref().and_then(|o| o.allow_high_fee);
+    wallet_lock.check_fee_per_gram(fee_per_gram, allow_high_fee.unwrap_or(false))?;
+    let covenant = options
+        .as_ref()
+        .and_then(|o| o.covenant)
+        .map(|covenant| get_covenant(covenant).map(|filter| filter.to_bytes()))
+        .transpose()?;
+
+    // Checked only once the request is valid, and before the balance, which
+    // the original send has already spent
+    let idempotency_key = options.as_ref().and_then(|o| o.idempotency_key.as_deref());
+    if let Some(key) = idempotency_key.filter(|_| !dry_run) {
+        if let Some(sent) = wallet_lock.repeated_send(key, &recipient_address, value)? {
+            return Ok(Either::A(sent));
+        }
+    }
+
+    // Placeholder UTXO selection - a single input covering the amount,
+    // with the recipient output and change. Sending everything leaves no
+    // change, which saves the change output's fee.
+    let input_count = 1;
+    let output_count = if value == wallet_lock.max_spendable(fee_per_gram) { 1 } else { 2 };
+    let fee = estimate_fee(fee_per_gram, input_count, output_count);
+
+    if dry_run {
+        if total_with_fee(value, fee)? > wallet_lock.available_balance {
+            return Err(TariWalletError::InsufficientFunds.into());
+        }
+
+        return Ok(Either::B(JsDryRunResult {
+            fee_estimate: fee.to_string(),
+            input_count: input_count as u32,
+            output_count: output_count as u32,
+            would_succeed: true,
+        }));
+    }
+
+    wallet_lock.spend(value, fee)?;
+
+    // Placeholder implementation - would call actual Tari wallet
+    let transaction_id =
+        wallet_lock.broadcast_new_transaction(Some(recipient_address.clone()), value, fee);
+    if let Some(tx) = wallet_lock.outbound_transactions.get_mut(&transaction_id) {
+        tx.covenant = covenant;
+        tx.message = message;
+    }
+    let sent = wallet_lock.outbound_transactions[&transaction_id].send_result(&transaction_id);
+    if let Some(key) = idempotency_key {
+        let keyed = idempotency::KeyedTransaction {
+            tx_id: transaction_id.clone(),
+            recipient: recipient_address,
+            amount: value,
+            fee,
+            status: sent.status,
+            sent_at: sent.timestamp as i64,
+        };
+        // The payment has gone out, so a failed save must not fail the send
+        if let Err(e) = idempotency::record(wallet_lock.database()?, key, &keyed) {
+            tracing::warn!("Idempotency key {} was not saved: {}", key, e.message);
+        }
+    }
+    Ok(Either::A(sent))
+}
+
+/// Send several payments under a single wallet lock
+///
+/// Each payment succeeds or fails independently; a failed payment is
+/// reported in its result entry and does not abort the rest of the batch.
+/// Inputs are selected once for the whole batch, which pays a single fee
+/// for one output per payment and one change output, shared between the
+/// payments. The batch runs on the wallet runtime pool.
+#[napi]
+pub async fn wallet_send_batch(
+    handle: WalletHandle,
+    payments: Vec<JsPayment>,
+    fee_per_gram: Option<String>,
+) -> Result<Vec<JsBatchResult>> {
+    run_on_pool(async move {
+        let wallet_arc = get_wallet(handle).await?;
+        let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+        wallet_lock.ensure_not_destroyed()?;
+        wallet_lock.ensure_can_spend()?;
+
+        let fee_per_gram = parse_fee_per_gram(fee_per_gram.as_deref())?;
+        wallet_lock.check_fee_per_gram(fee_per_gram, false)?;
+        let network = wallet_lock.config.network.clone();
+        // Placeholder UTXO selection, as for fee estimates
+        let input_count = 1;
+
+        let mut checked: Vec<std::result::Result<(String, u64), String>> = payments
+            .iter()
+            .map(|payment| {
+                if payment.destination.is_empty() {
+                    return Err(TariWalletError::InvalidAddress(
+                        "Recipient address cannot be empty".to_string(),
+                    )
+                    .to_string());
+                }
+                if let Some(message) = payment.message.as_deref() {
+                    validate_message(message).map_err(|e| e.to_string())?;
+                }
+                let destination = normalize_destination(&payment.destination, &network)
+                    .map_err(|e| e.to_string())?;
+                let value = parse_amount(&payment.amount).map_err(|e| e.to_string())?;
+                Ok((destination, value))
+            })
+            .collect();
+
+        // Payments join the batch in order while the funds cover them and
+        // the fee for the outputs taken so far
+        let mut total = 0u64;
+        let mut accepted = 0u64;
+        for payment in checked.iter_mut() {
+            let Ok((_, value)) = payment else { continue };
+            let fee = estimate_fee(fee_per_gram, input_count, accepted + 2);
+            let covered = total
+                .checked_add(*value)
+                .and_then(|amount| total_with_fee(amount, fee).ok())
+                .is_some_and(|needed| needed <= wallet_lock.available_balance);
+            if covered {
+                total += *value;
+                accepted += 1;
+            } else {
+                *payment = Err(TariWalletError::InsufficientFunds.to_string());
+            }
+        }
+        let fee = estimate_fee(fee_per_gram, input_count, accepted + 1);
+        if accepted > 0 {
+            wallet_lock.spend(total, fee)?;
+        }
+
+        // Placeholder implementation - would send one transaction via the
+        // actual Tari wallet; each payment is tracked with its share of the fee
+        let mut fee_shares =
+            (0..accepted).map(|index| fee / accepted + u64::from(index < fee % accepted));
+        let results = checked
+            .into_iter()
+            .zip(payments)
+            .map(|(outcome, payment)| match outcome {
+                Ok((destination, value)) => {
+                    let fee_share = fee_shares.next().unwrap_or_default();
+                    let tx_id =
+                        wallet_lock.broadcast_new_transaction(Some(destination), value, fee_share);
+                    if let Some(tx) = wallet_lock.outbound_transactions.get_mut(&tx_id) {
+                        tx.input_count = input_count as u32;
+                        tx.output_count = accepted as u32 + 1;
+                        tx.message = payment.message;
+                    }
+                    JsBatchResult {
+                        tx_id: Some(tx_id),
+                        error: None,
+                    }
+                }
+                Err(error) => JsBatchResult {
+                    tx_id: None,
+                    error: Some(error),
+                },
+            })
+            .collect();
+
+        Ok(results)
+    })
+    .await
+}
+
+/// Send a transaction whose output cannot be spent before `lock_height`
+#[napi]
+pub async fn wallet_send_time_locked_transaction(
+    handle: WalletHandle,
+    recipient_address: String,
+    amount: String,
+    lock_height: i64,
+    fee_per_gram: Option<String>,
+) -> Result<JsTransactionInfo> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+    wallet_lock.ensure_can_spend()?;
+
+    if recipient_address.is_empty() {
+        return Err(TariWalletError::InvalidAddress(
+            "Recipient address cannot be empty".to_string(),
+        )
+        .into());
+    }
+    let recipient_address = normalize_destination(&recipient_address, &wallet_lock.config.network)?;
+
+    let value = parse_amount(&amount)?;
+    let fee_per_gram = parse_fee_per_gram(fee_per_gram.as_deref())?;
+    wallet_lock.check_fee_per_gram(fee_per_gram, false)?;
+
+    if lock_height < 0 || (lock_height as u64) < wallet_lock.tip_height {
+        return Err(TariWalletError::ValidationError(format!(
+            "Lock height {} is below the current chain tip {}",
+            lock_height, wallet_lock.tip_height
+        ))
+        .into());
+    }
+
+    let fee = estimate_fee(fee_per_gram, 1, 2);
+    let gross_amount = total_with_fee(value, fee)?;
+    wallet_lock.spend(value, fee)?;
+
+    // Placeholder implementation - would build output features with
+    // maturity set to lock_height and send via the actual Tari wallet
+    let tx_id = wallet_lock.broadcast_new_transaction(Some(recipient_address.clone()), value, fee);
+    if let Some(tx) = wallet_lock.outbound_transactions.get_mut(&tx_id) {
+        tx.maturity_height = Some(lock_height as u64);
+    }
+    Ok(JsTransactionInfo {
+        id: tx_id,
+        amount: value.to_string(),
+        gross_amount: gross_amount.to_string(),
+        fee: fee.to_string(),
+        status: JsTransactionStatus::Pending as i32,
+        message: String::new(),
+        timestamp: chrono::Utc::now().timestamp() as f64,
+        is_inbound: false,
+        address: recipient_address,
+        lock_height: Some(lock_height),
+        confirmations: 0,
+        maturity_height: Some(lock_height),
+        output_type: output_type_name(OUTPUT_TYPE_STANDARD).to_string(),
+        local_label: None,
+    })
+}
+
+/// Add an existing output to the wallet, returning the id it is recorded under
+///
+/// The commitment must open to the amount under the spending key, its range
+/// proof and metadata signature must verify, and its script must resolve to
+/// the spending key with the given input data, so an output the wallet
+/// could not spend is refused rather than counted in the balance. Each
+/// output can only be imported once.
+#[napi]
+pub async fn wallet_import_utxo(handle: WalletHandle, utxo: JsImportUtxo) -> Result<String> {
+    let amount = validate_imported_utxo(&utxo)?;
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    let commitment = utxo.commitment.to_ascii_lowercase();
+    if wallet_lock.imported_commitments.contains(&commitment) {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::DuplicateTransaction,
+            format!("Output {} has already been imported", commitment),
+        )
+        .component("import")
+        .into());
+    }
+    let balance = wallet_lock
+        .available_balance
+        .checked_add(amount)
+        .ok_or_else(|| TariWalletError::ValidationError("amount overflow".to_string()))?;
+
+    // Placeholder implementation - would add the output to the output
+    // manager, which scans for it on chain before it becomes spendable
+    let tx_id = format!("tx_{}", generate_handle());
+    let tip_height = wallet_lock.tip_height;
+    wallet_lock.receive_inbound(
+        tx_id.clone(),
+        InboundTransaction {
+            amount,
+            received_at: chrono::Utc::now().timestamp() as f64,
+            sender_address: utxo.source_address,
+            message: utxo.message,
+            mined_height: Some(tip_height),
+            confirmed: true,
+            output_type: OUTPUT_TYPE_STANDARD,
+            maturity_height: None,
+        },
+    );
+    wallet_lock.imported_commitments.insert(commitment);
+    wallet_lock.available_balance = balance;
+    wallet_lock.events.publish(WalletEvent::OutputsChanged);
+
+    Ok(tx_id)
+}
+
+/// Burn funds, provably removing them from circulation
+///
+/// The returned commitment, range proof and the blinding factor opening
+/// the commitment let the caller later prove the burn, e.g. when claiming
+/// the value on another layer. The message is kept with the transaction.
+#[napi]
+pub async fn wallet_burn_funds(
+    handle: WalletHandle,
+    amount: String,
+    fee_per_gram: Option<String>,
+    message: Option<String>,
+) -> Result<JsBurnResult> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+    wallet_lock.ensure_can_spend()?;
+
+    let value = parse_amount(&amount)?;
+    let fee_per_gram = parse_fee_per_gram(fee_per_gram.as_deref())?;
+    wallet_lock.check_fee_per_gram(fee_per_gram, false)?;
+    if let Some(message) = message.as_deref() {
+        validate_message(message)?;
+    }
+
+    // One input spent into the burnt output plus change
+    let fee = estimate_fee(fee_per_gram, 1, 2);
+    wallet_lock.spend(value, fee)?;
+
+    let (blinding_factor, commitment, range_proof) = commit_with_range_proof(value)?;
+
+    // Placeholder implementation - would submit the burn via the actual Tari wallet
+    let tx_id = wallet_lock.broadcast_new_transaction(None, value, fee);
+    if let Some(tx) = wallet_lock.outbound_transactions.get_mut(&tx_id) {
+        tx.message = message;
+    }
+    Ok(JsBurnResult {
+        tx_id,
+        commitment: commitment.to_hex(),
+        range_proof: tari_crypto::tari_utilities::hex::to_hex(&range_proof),
+        blinding_factor: blinding_factor.to_hex(),
+    })
+}
+
+/// Connect to a base node given as `<public key>::<multiaddr>`
+///
+/// Resolves to false if the wallet was already connected to that node.
+/// Each attempt is abandoned after the configured `connection_timeout_ms`,
+/// and failed attempts are retried as set by `connection_retry`.
+#[napi]
+pub async fn wallet_connect_to_base_node(handle: WalletHandle, address: String) -> Result<bool> {
+    let (public_key, multiaddr) = parse_base_node_peer(&address)?;
+
+    let (transport, timeout, retry_policy) = {
+        let wallet_arc = get_wallet(handle).await?;
+        let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+        wallet_lock.ensure_not_destroyed()?;
+
+        if wallet_lock.base_nodes.is_banned(&public_key) {
+            return Err(MappedWalletError::new(
+                WalletErrorCode::ConnectionFailed,
+                format!("Base node {} is banned", public_key),
+            )
+            .component("connectivity")
+            .into());
+        }
+
+        if wallet_lock.base_nodes.is_full(&public_key) {
+            return Err(MappedWalletError::new(
+                WalletErrorCode::ResourceExhausted,
+                "Already connected to the maximum number of base nodes",
+            )
+            .component("connectivity")
+            .into());
+        }
+
+        wallet_lock.base_nodes.begin_dial();
+        (
+            wallet_lock.transport.clone(),
+            wallet_lock.connection_timeout(),
+            RetryPolicy::from_config(wallet_lock.config.connection_retry.as_ref()),
+        )
+    };
+
+    // The wallet is not locked while dialing
+    let dialed = retry_policy
+        .run(|| dial_base_node(&transport, &public_key, &multiaddr, timeout))
+        .await;
+
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    // Record the connection before ending the dial so the status does not
+    // briefly drop to offline
+    let newly_connected = match dialed {
+        Ok(()) => {
+            wallet_lock.base_nodes.record_success(&public_key);
+            Ok(wallet_lock
+                .base_nodes
+                .add_connected(&public_key, &multiaddr))
+        }
+        Err(e) => {
+            wallet_lock.base_nodes.record_failure(&public_key);
+            Err(e)
+        }
+    };
+    wallet_lock.base_nodes.end_dial();
+
+    Ok(newly_connected?)
+}
+
+/// Check every known base node, resolving to one result per node
+///
+/// Nodes are dialed concurrently, a few at a time, and the whole sweep
+/// finishes within the configured `connection_timeout_ms`. Reputations
+/// and the active node are updated from the results.
+#[napi]
+pub async fn wallet_check_base_node_health(handle: WalletHandle) -> Result<Vec<JsBaseNodeHealth>> {
+    let (transport, nodes, timeout) = {
+        let wallet_arc = get_wallet(handle).await?;
+        let wallet_lock = read_wallet(&wallet_arc).await;
+
+        wallet_lock.ensure_not_destroyed()?;
+        (
+            wallet_lock.transport.clone(),
+            wallet_lock.base_nodes.checkable_nodes(),
+            wallet_lock.connection_timeout(),
+        )
+    };
+
+    // The wallet is not locked while dialing
+    let results = check_base_nodes(transport, nodes, timeout).await;
+
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+    for health in &results {
+        wallet_lock.base_nodes.record_health(health);
+    }
+
+    Ok(results)
+}
+
+/// Dial the known base nodes if the wallet is not connected to any
+///
+/// Lets a sync start networking for wallets opened without
+/// `auto_connect_on_start`.
+pub(crate) async fn connect_if_offline(handle: WalletHandle) -> Result<()> {
+    let offline = {
+        let wallet_arc = get_wallet(handle).await?;
+        let wallet_lock = read_wallet(&wallet_arc).await;
+        wallet_lock.ensure_not_destroyed()?;
+        wallet_lock.base_nodes.status() == NetworkStatus::Offline
+    };
+    if offline {
+        wallet_check_base_node_health(handle).await?;
+    }
+    Ok(())
+}
+
+/// Disconnect from a base node by its public key
+#[napi]
+pub async fn wallet_disconnect_from_base_node(handle: WalletHandle, public_key: String) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    // Placeholder implementation - would also close the comms connection
+    Ok(wallet_lock.base_nodes.remove(&public_key)?)
+}
+
+/// Ban a base node by its public key
+///
+/// The node is disconnected, drops to the lowest reputation and is refused
+/// on later connection attempts. With `duration_seconds` the ban expires
+/// after that long; otherwise it is permanent.
+#[napi]
+pub async fn wallet_ban_base_node(
+    handle: WalletHandle,
+    public_key: String,
+    duration_seconds: Option<u32>,
+) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    let duration = duration_seconds.map(|secs| Duration::from_secs(secs as u64));
+    wallet_lock.base_nodes.ban(&public_key, duration);
+    Ok(())
+}
+
+/// Check whether a base node is banned, ignoring bans that have expired
+#[napi]
+pub async fn wallet_is_peer_banned(handle: WalletHandle, public_key: String) -> Result<bool> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.base_nodes.is_banned(&public_key))
+}
+
+/// Get the reputation score of every base node the wallet has dialed, best first
+#[napi]
+pub async fn wallet_get_peer_reputations(handle: WalletHandle) -> Result<Vec<JsPeerReputation>> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.base_nodes.peer_reputations())
+}
+
+/// Get the wallet's network status: "online", "connecting" or "offline"
+///
+/// A wallet is online once at least one connected base node is synced.
+#[napi]
+pub async fn wallet_get_network_status(handle: WalletHandle) -> Result<String> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.base_nodes.status().as_str().to_string())
+}
+
+/// Register a callback invoked with the new network status whenever it changes
+///
+/// Replaces any callback registered earlier for the wallet.
+#[napi]
+pub async fn wallet_on_connectivity_changed(
+    handle: WalletHandle,
+    callback: ThreadsafeFunction<String, ErrorStrategy::Fatal>,
+) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    wallet_lock
+        .base_nodes
+        .set_status_listener(Box::new(move |status| {
+            callback.call(
+                status.as_str().to_string(),
+                ThreadsafeFunctionCallMode::NonBlocking,
+            );
+        }));
+    Ok(())
+}
+
+/// Get statistics for the wallet's base node connections
+///
+/// Includes the address the comms listener accepts peer connections on.
+#[napi]
+pub async fn wallet_get_connection_stats(handle: WalletHandle) -> Result<JsConnectionStats> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.base_nodes.stats(wallet_lock.listener_address))
+}
+
+/// Get the chain height the wallet was created at
+///
+/// Recorded when a wallet is created with fresh seed words while the chain
+/// tip is known; otherwise there is no birthday and recovery scans from
+/// genesis. Restored and watch-only wallets may own outputs from before
+/// they were opened here, so they have no birthday unless one was
+/// recorded earlier.
+#[napi]
+pub async fn wallet_get_birthday(handle: WalletHandle) -> Result<Option<i64>> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.birthday_height.map(|height| height as i64))
+}
+
+/// Get the height of the best block known to the connected base nodes
+///
+/// For working out confirmations or lock heights directly; this is the tip
+/// that sync, recovery and confirmation counts use. Fails if no base node
+/// is connected rather than returning a height that may be stale.
+#[napi]
+pub async fn wallet_get_chain_tip(handle: WalletHandle) -> Result<i64> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    if wallet_lock.base_nodes.status() == NetworkStatus::Offline {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::BaseNodeNotReachable,
+            "No base node is connected to report the chain tip",
+        )
+        .component("wallet")
+        .into());
+    }
+
+    Ok(wallet_lock.tip_height as i64)
+}
+
+/// Get the confirmations of an outbound transaction
+///
+/// Returns JSON `{ confirmations, required }`, where `required` is the
+/// configured confirmation threshold. Unmined transactions have zero
+/// confirmations.
+#[napi]
+pub async fn wallet_get_transaction_confirmations(
+    handle: WalletHandle,
+    transaction_id: String,
+) -> Result<String> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    let transaction = wallet_lock
+        .outbound_transactions
+        .get(&transaction_id)
+        .ok_or_else(|| TariWalletError::TransactionNotFound(transaction_id.clone()))?;
+
+    Ok(serde_json::json!({
+        "confirmations": transaction.confirmations(wallet_lock.tip_height),
+        "required": wallet_lock.required_confirmations,
+    })
+    .to_string())
+}
+
+/// Get the kernel, input and output counts and mining state of a transaction
+///
+/// Block height and kernel signature are unset until the transaction is mined.
+#[napi]
+pub async fn wallet_get_transaction_details(
+    handle: WalletHandle,
+    transaction_id: String,
+) -> Result<JsTransactionDetails> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    let tip_height = wallet_lock.tip_height;
+    let details = if let Some(tx) = wallet_lock.outbound_transactions.get(&transaction_id) {
+        JsTransactionDetails {
+            tx_id: transaction_id,
+            is_inbound: false,
+            kernels: 1,
+            inputs: Some(tx.input_count),
+            outputs: Some(tx.output_count),
+            maturity_height: tx.maturity_height.map(|height| height as i64),
+            confirmations: tx.confirmations(tip_height),
+            block_height: tx.mined_height.map(|height| height as i64),
+            // Placeholder implementation - would come from the kernel of the
+            // completed transaction once the transaction service sees it mined
+            kernel_signature: None,
+            message: tx.message.clone(),
+            local_label: None,
+        }
+    } else if let Some(tx) = wallet_lock.inbound_transactions.get(&transaction_id) {
+        JsTransactionDetails {
+            tx_id: transaction_id,
+            is_inbound: true,
+            kernels: 1,
+            inputs: None,
+            outputs: None,
+            maturity_height: tx.maturity_height.map(|height| height as i64),
+            confirmations: confirmations_at(tx.mined_height, tip_height),
+            block_height: tx.mined_height.map(|height| height as i64),
+            kernel_signature: None,
+            message: tx.message.clone(),
+            local_label: None,
+        }
+    } else {
+        return Err(TariWalletError::TransactionNotFound(transaction_id).into());
+    };
+
+    drop(wallet_lock);
+
+    let pool = wallet_read_pool(handle)?;
+    let connection = pool.get().await?;
+    Ok(JsTransactionDetails {
+        local_label: local_label(&connection, &details.tx_id),
+        ..details
+    })
+}
+
+/// Label a transaction for local bookkeeping, or clear its label
+///
+/// Labels are stored in the wallet database, never broadcast, and survive
+/// restarts. A missing or blank label clears the existing one.
+#[napi]
+pub async fn wallet_set_transaction_label(
+    handle: WalletHandle,
+    transaction_id: String,
+    label: Option<String>,
+) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    if !wallet_lock.outbound_transactions.contains_key(&transaction_id)
+        && !wallet_lock.inbound_transactions.contains_key(&transaction_id)
+    {
+        return Err(TariWalletError::TransactionNotFound(transaction_id).into());
+    }
+
+    labels::set(wallet_lock.database()?, &transaction_id, label.as_deref())?;
+    Ok(())
+}
+
+/// Get a transaction's local label, if it has one
+///
+/// Read on a pooled connection, so it does not wait for a send in progress.
+#[napi]
+pub async fn wallet_get_transaction_label(
+    handle: WalletHandle,
+    transaction_id: String,
+) -> Result<Option<String>> {
+    get_wallet(handle).await?;
+
+    let pool = wallet_read_pool(handle)?;
+    let connection = pool.get().await?;
+    Ok(labels::get(&connection, &transaction_id)?)
+}
+
+/// Search the wallet's transactions of both directions, newest first
+///
+/// Every criterion of `filter` that is set must match. Results are paged:
+/// `offset` transactions are skipped and at most `limit` returned, 50 by
+/// default and 500 at most.
+#[napi]
+pub async fn wallet_search_transactions(
+    handle: WalletHandle,
+    filter: JsTxFilter,
+    offset: Option<u32>,
+    limit: Option<u32>,
+) -> Result<JsTransactionPage> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    let filter = TransactionFilter::parse(filter, &wallet_lock.config.network)?;
+    let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT).clamp(1, MAX_SEARCH_LIMIT);
+    let mut page = wallet_lock.search_transactions(&filter, offset.unwrap_or(0), limit);
+    drop(wallet_lock);
+
+    add_local_labels(handle, &mut page.transactions).await?;
+    Ok(page)
+}
+
+/// Export the wallet's transaction history as "csv" or "json", newest first
+///
+/// Every transaction matching `filter` is exported, with its id, time,
+/// direction, amount, fee, status, counterparty address, message and local
+/// label.
+#[napi]
+pub async fn wallet_export_transactions(
+    handle: WalletHandle,
+    format: String,
+    filter: Option<JsTxFilter>,
+) -> Result<String> {
+    let format = ExportFormat::parse(&format)?;
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    let filter = filter
+        .map(|filter| TransactionFilter::parse(filter, &wallet_lock.config.network))
+        .transpose()?
+        .unwrap_or_default();
+    let mut page = wallet_lock.search_transactions(&filter, 0, u32::MAX);
+    drop(wallet_lock);
+
+    add_local_labels(handle, &mut page.transactions).await?;
+    Ok(format.write(&page.transactions))
+}
+
+/// Get the broadcast retry state of an outbound transaction
+#[napi]
+pub async fn wallet_get_broadcast_status(
+    handle: WalletHandle,
+    transaction_id: String,
+) -> Result<JsBroadcastStatus> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    let state = wallet_lock
+        .outbound_transactions
+        .get(&transaction_id)
+        .ok_or_else(|| TariWalletError::TransactionNotFound(transaction_id.clone()))?;
+
+    Ok(JsBroadcastStatus {
+        retry_count: state.retry_count,
+        last_broadcast_at: state.last_broadcast_at,
+        seen_by_network: state.seen_by_network,
+    })
+}
+
+/// Rebroadcast every pending transaction to the connected base nodes
+///
+/// Meant for reconnecting after a long time offline. Transactions already
+/// mined are skipped, and the automatic rebroadcast retries are left alone,
+/// so this is safe to call repeatedly. Fails if no base node is connected.
+/// Transactions no base node acknowledged are counted as unacknowledged,
+/// not as succeeded.
+#[napi]
+pub async fn wallet_rebroadcast_pending(handle: WalletHandle) -> Result<JsRebroadcastSummary> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    if wallet_lock.base_nodes.status() == NetworkStatus::Offline {
+        return Err(MappedWalletError::new(
+            WalletErrorCode::BaseNodeNotReachable,
+            "No base node is connected to rebroadcast to",
+        )
+        .component("wallet")
+        .into());
+    }
+
+    Ok(wallet_lock.rebroadcast_pending())
+}
+
+/// Get recommended fees per gram
+///
+/// The wallet does not query base node mempool stats yet, so the result is
+/// always a guess based on the default fee per gram and `source` is always
+/// "fallback".
+#[napi]
+pub async fn wallet_get_fee_recommendations(handle: WalletHandle) -> Result<JsFeeRecommendations> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(recommend_fees(wallet_lock.mempool_fee_stats()))
+}
+
+/// Get the wallet's outputs, optionally filtered by status
+///
+/// `status_filter` is "unspent" (the default), "spent", "unconfirmed" or
+/// "all".
+#[napi]
+pub async fn wallet_get_utxos(
+    handle: WalletHandle,
+    status_filter: Option<String>,
+) -> Result<Vec<JsUtxoInfo>> {
+    let filter = UtxoStatusFilter::parse(status_filter.as_deref())?;
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.utxos(filter))
+}
+
+/// Get the largest amount in µT a single send can pay at `fee_per_gram`
+///
+/// For "send max": the fee for spending every worthwhile output, with no
+/// change output, is already taken off. Sending exactly this amount
+/// empties the wallet's spendable balance.
+#[napi]
+pub async fn wallet_get_max_spendable(
+    handle: WalletHandle,
+    fee_per_gram: Option<String>,
+) -> Result<String> {
+    let fee_per_gram = parse_fee_per_gram(fee_per_gram.as_deref())?;
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.max_spendable(fee_per_gram).to_string())
+}
+
+/// Get the number and total value of the wallet's outputs
+///
+/// Cheaper than fetching every output with `wallet_get_utxos` when only
+/// totals are needed.
+#[napi]
+pub async fn wallet_get_utxo_summary(handle: WalletHandle) -> Result<JsUtxoSummary> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.utxo_summary())
+}
+
+/// Get unspent outputs that would cost more in fees to spend than they hold
+///
+/// The threshold follows the normal fee recommendation, so outputs can move
+/// in and out of the dust set as mempool fees change. Candidates for
+/// consolidating into a larger output.
+#[napi]
+pub async fn wallet_get_dust_utxos(handle: WalletHandle) -> Result<Vec<JsUtxoInfo>> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    Ok(wallet_lock.dust_utxos())
+}
+
+/// Get the total value in µT of the outputs `wallet_get_dust_utxos` returns
+#[napi]
+pub async fn wallet_get_total_dust_value(handle: WalletHandle) -> Result<String> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    let total = wallet_lock
+        .dust_utxos()
+        .iter()
+        .filter_map(|utxo| utxo.amount.parse::<u64>().ok())
+        .fold(0u64, u64::saturating_add);
+    Ok(total.to_string())
+}
+
+/// Get wallet seed words after verifying the wallet passphrase
+///
+/// Wallets created without a passphrase are verified against an empty one.
+/// Words are in `language` if given, otherwise in the wallet's mnemonic
+/// language.
+#[napi]
+pub async fn wallet_get_seed_words(
+    handle: WalletHandle,
+    passphrase: String,
+    language: Option<String>,
+) -> Result<Vec<String>> {
+    let passphrase = Zeroizing::new(passphrase);
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+    wallet_lock.verify_passphrase(&passphrase)?;
+
+    let language = match language {
+        Some(name) => MnemonicLanguage::parse(&name)?,
+        None => wallet_lock.mnemonic_language,
+    };
+    Ok(wallet_lock.seed()?.to_mnemonic(language)?)
+}
+
+/// Set base node peer for the wallet
+#[napi]
+pub async fn wallet_set_base_node(
+    handle: WalletHandle,
+    base_node: JsBaseNodePeer,
+) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let mut wallet_lock = lock_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    // Validate base node info
+    if base_node.public_key.is_empty() {
+        return Err(TariWalletError::ValidationError(
+            "Base node public key cannot be empty".to_string(),
+        )
+        .into());
+    }
+
+    if base_node.address.is_empty() {
+        return Err(TariWalletError::ValidationError(
+            "Base node address cannot be empty".to_string(),
+        )
+        .into());
+    }
+
+    wallet_lock
+        .base_nodes
+        .set_active(&base_node.public_key, &base_node.address);
+    Ok(())
+}
+
+/// Back up the wallet database to a file
+///
+/// Works while the wallet is open. The copy is taken through SQLite's online
+/// backup API on the wallet's connection, so it is a consistent snapshot that
+/// includes changes not yet checkpointed out of the write-ahead log.
+#[napi]
+pub async fn wallet_backup_database(handle: WalletHandle, destination_path: String) -> Result<()> {
+    let wallet_arc = get_wallet(handle).await?;
+    let wallet_lock = read_wallet(&wallet_arc).await;
+
+    wallet_lock.ensure_not_destroyed()?;
+
+    wallet_lock.database()?.backup(Path::new(&destination_path), &wallet_lock.config)?;
+
+    Ok(())
+}
+
+/// Restore the wallet database for a storage path from a backup file
+///
+/// Refuses to replace the database of an open wallet unless `force` is set.
+/// An open wallet is restored through its own connection, after the backup
+/// is checked against its key, schema and network; otherwise the file is
+/// swapped in while the data directory is locked against other processes.
+#[napi]
+pub async fn wallet_restore_database(
+    storage_path: String,
+    source_path: String,
+    force: Option<bool>,
+) -> Result<()> {
+    validate_storage_path(&storage_path)?;
+    validate_wallet_db(Path::new(&source_path))?;
+
+    // Release the storage lock before taking any wallet's lock, so a wallet
+    // busy with a send cannot hold up opening and looking up other wallets
+    let wallets: Vec<_> = WALLET_STORAGE.read().await.values().cloned().collect();
+    let mut open_wallets = Vec::new();
+    for wallet in wallets {
+        if read_wallet(&wallet).await.config.storage_path == storage_path {
+            open_wallets.push(wallet);
+        }
+    }
+
+    if !open_wallets.is_empty() && !force.unwrap_or(false) {
+        return Err(TariWalletError::ValidationError(format!(
+            "Wallet at {} is open; pass force to overwrite its database",
+            storage_path
+        ))
+        .into());
+    }
+
+    if open_wallets.is_empty() {
+        return restore_database_file(&storage_path, Path::new(&source_path));
+    }
+
+    for wallet in open_wallets {
+        let mut wallet_lock = lock_wallet(&wallet).await;
+        wallet_lock.database()?.restore(Path::new(&source_path), &wallet_lock.config)?;
+        // What the wallet read from its database on open came from the
+        // replaced one
+        wallet_lock.birthday_height = wallet_lock.database()?.birthday_height()?;
+        wallet_lock.events.publish(WalletEvent::OutputsChanged);
+    }
+
+    Ok(())
+}
+
+/// Swap a backup in as the database of a wallet that is not open
+fn restore_database_file(storage_path: &str, source: &Path) -> Result<()> {
+    let storage_error = |e: std::io::Error| {
+        TariWalletError::StorageError(format!("Failed to restore wallet database: {}", e))
+    };
+
+    // Fails if another process has the wallet open
+    let _data_dir_lock = DataDirLock::acquire(storage_path)?;
+
+    // Copy next to the target first so the swap itself is a single rename
+    let destination = wallet_db_path(storage_path);
+    let staging = destination.with_extension("sqlite3.restore");
+    fs::copy(source, &staging).map_err(storage_error)?;
+
+    // A log left by the replaced database would be replayed into the backup
+    for suffix in ["-wal", "-shm"] {
+        let mut sidecar = destination.clone().into_os_string();
+        sidecar.push(suffix);
+        match fs::remove_file(&sidecar) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(storage_error(e).into()),
+            _ => {}
+        }
+    }
+    fs::rename(&staging, &destination).map_err(storage_error)?;
+
+    Ok(())
+}
+
+/// Check whether a wallet database exists at a storage path
+///
+/// Only inspects the filesystem; nothing is opened, locked or created.
+#[napi]
+pub fn wallet_exists(storage_path: String) -> Result<bool> {
+    Ok(wallet_db_path(&storage_path).is_file())
+}
+
+/// Get number of active wallet handles (for debugging)
+#[napi]
+pub async fn wallet_get_active_handle_count() -> Result<i32> {
+    Ok(WALLET_STORAGE.read().await.len() as i32)
+}
+
+/// Validate a wallet handle without accessing the wallet
+#[napi]
+pub async fn wallet_validate_handle(handle: WalletHandle) -> Result<bool> {
+    let wallet_arc = WALLET_STORAGE.read().await.get(&handle).cloned();
+
+    match wallet_arc {
+        Some(wallet_arc) => {
+            let wallet_lock = read_wallet(&wallet_arc).await;
+            Ok(!wallet_lock.destroyed && wallet_lock.initialized)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Cleanup all wallet handles (for testing and shutdown)
+#[napi]
+pub async fn wallet_cleanup_all() -> Result<i32> {
+    let wallets: Vec<_> = {
+        let mut storage = WALLET_STORAGE.write().await;
+        storage.keys().copied().for_each(tombstone);
+        storage.drain().collect()
+    };
+    let count = wallets.len() as i32;
+
+    // Shut wallets down concurrently so one slow wallet doesn't delay the rest
+    let mut shutdowns = tokio::task::JoinSet::new();
+    for (handle, wallet_arc) in wallets {
+        shutdowns.spawn(shutdown_wallet(handle, wallet_arc));
+    }
+    while shutdowns.join_next().await.is_some() {}
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comms::DEFAULT_LISTENER_ADDRESS;
+    use crate::database::{schema_version, SQLITE_HEADER, WALLET_SCHEMA_VERSION};
+    use tari_crypto::keys::SecretKey;
+
+    /// Seed words of a fresh seed, for opening a database that holds none
+    fn fresh_seed_words() -> Vec<String> {
+        CipherSeed::new().to_mnemonic(MnemonicLanguage::English).unwrap()
+    }
+
+    /// Config with its own data directory, so tests don't contend for its lock
+    fn test_config() -> JsWalletConfig {
+        static NEXT_DIR: AtomicI64 = AtomicI64::new(0);
+        let storage_path = std::env::temp_dir().join(format!(
+            "tari-wallet-test-{}-{}",
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, Ordering::SeqCst)
+        ));
+
+        JsWalletConfig {
+            network: "testnet".to_string(),
+            storage_path: storage_path.to_string_lossy().to_string(),
+            log_path: None,
+            log_level: None,
+            passphrase: None,
+            seed_words: None,
+            mnemonic_language: None,
+            view_key: None,
+            num_rolling_log_files: None,
+            rolling_log_file_size: None,
+            balance_cache_ttl_ms: None,
+            encrypt_database: None,
+            max_broadcast_retries: None,
+            broadcast_retry_delay_seconds: None,
+            connection_timeout_ms: None,
+            required_confirmations: None,
+            max_fee_per_gram: None,
+            operation_timeouts: None,
+            peer_seeds: None,
+            connection_retry: None,
+            sqlite_pragmas: None,
+            max_read_connections: None,
+            network_overrides: None,
+            event_bus_capacity: None,
+            auto_connect_on_start: None,
+            transport: None,
+            tor_socks_proxy: None,
+            listener_address: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_destroy_is_idempotent() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let wallet = get_wallet(handle).await.unwrap();
+
+        wallet_destroy(handle).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+
+        assert!(wallet.write().await.destroyed);
+        assert!(!wallet_validate_handle(handle).await.unwrap());
+        assert!(wallet_get_balance(handle).await.is_err());
+        assert!(wallet_destroy(-1).await.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_destroy_during_balance_queries() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        // An operation that looked the wallet up before the destroy
+        let wallet = get_wallet(handle).await.unwrap();
+
+        let queries: Vec<_> = (0..8)
+            .map(|_| tokio::spawn(wallet_get_balance(handle)))
+            .collect();
+        wallet_destroy(handle).await.unwrap();
+        for query in queries {
+            if let Err(error) = query.await.unwrap() {
+                assert!(error.reason.contains("destroyed"));
+            }
+        }
+
+        // The instance outlives its handle, and refuses further work
+        assert!(wallet.read().await.ensure_not_destroyed().is_err());
+
+        let Err(error) = wallet_get_balance(handle).await else {
+            panic!("destroyed handle still usable");
+        };
+        let prefix = format!("[{}:", WalletErrorCode::InvalidHandle as i32);
+        assert!(error.reason.starts_with(&prefix));
+        assert!(error.reason.contains("has been destroyed"));
+    }
+
+    #[tokio::test]
+    async fn test_backup_and_restore_database() {
+        let dir = std::env::temp_dir().join(format!("tari-wallet-backup-{}", std::process::id()));
+        let storage_path = dir.join("wallet");
+        fs::create_dir_all(&storage_path).unwrap();
+
+        let db_path = wallet_db_path(storage_path.to_str().unwrap());
+        let set_note = |note: &str| {
+            let connection = rusqlite::Connection::open(&db_path).unwrap();
+            connection
+                .execute_batch("CREATE TABLE IF NOT EXISTS notes (note TEXT); DELETE FROM notes")
+                .unwrap();
+            connection.execute("INSERT INTO notes VALUES (?1)", [note]).unwrap();
+        };
+        let note_in = |path: &Path| {
+            let connection = rusqlite::Connection::open(path).unwrap();
+            connection
+                .query_row("SELECT note FROM notes", [], |row| row.get::<_, String>(0))
+                .unwrap()
+        };
+        let note = || note_in(&db_path);
+        set_note("original");
+
+        let config = JsWalletConfig {
+            storage_path: storage_path.to_string_lossy().to_string(),
+            seed_words: Some(fresh_seed_words()),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        assert_eq!(wallet_get_birthday(handle).await.unwrap(), None);
+        let record_birthday = |height| async move {
+            let wallet = get_wallet(handle).await.unwrap();
+            let wallet_lock = read_wallet(&wallet).await;
+            wallet_lock.database().unwrap().record_birthday(height).unwrap();
+        };
+        record_birthday(1_500).await;
+
+        let backup_path = dir.join("backup.sqlite3").to_string_lossy().to_string();
+        wallet_backup_database(handle, backup_path.clone()).await.unwrap();
+        record_birthday(2_000).await;
+
+        // The wallet's connection keeps this write in the write-ahead log
+        set_note("modified");
+        let mut wal = db_path.clone().into_os_string();
+        wal.push("-wal");
+        assert!(fs::metadata(&wal).unwrap().len() > 0);
+        let later_backup = dir.join("later.sqlite3");
+        wallet_backup_database(handle, later_backup.to_string_lossy().to_string())
+            .await
+            .unwrap();
+        assert_eq!(note_in(&later_backup), "modified");
+
+        let storage = storage_path.to_string_lossy().to_string();
+        let refused = wallet_restore_database(storage.clone(), backup_path.clone(), None).await;
+        assert!(refused.err().unwrap().reason.contains("is open"));
+
+        wallet_restore_database(storage.clone(), backup_path.clone(), Some(true))
+            .await
+            .unwrap();
+        assert_eq!(note(), "original");
+        // The open wallet reports the restored database's birthday, not the
+        // one it read on open
+        assert_eq!(wallet_get_birthday(handle).await.unwrap(), Some(1_500));
+
+        let stranger = wallet_create(test_config()).await.unwrap();
+        let stranger_backup = dir.join("stranger.sqlite3").to_string_lossy().to_string();
+        wallet_backup_database(stranger, stranger_backup.clone()).await.unwrap();
+        wallet_destroy(stranger).await.unwrap();
+        let refused = wallet_restore_database(storage.clone(), stranger_backup, Some(true)).await;
+        assert!(refused.err().unwrap().reason.contains("backup is of another wallet"));
+        assert_eq!(note(), "original");
+
+        wallet_destroy(handle).await.unwrap();
+        wallet_restore_database(storage.clone(), later_backup.to_string_lossy().to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(note(), "modified");
+
+        let not_a_db = dir.join("not-a-db");
+        fs::write(&not_a_db, b"just a plain text file").unwrap();
+        let result = wallet_restore_database(storage, not_a_db.to_string_lossy().to_string(), Some(true)).await;
+        assert!(result.err().unwrap().reason.contains("not a Tari wallet database"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_wallet_exists_has_no_side_effects() {
+        let dir = std::env::temp_dir().join(format!("tari-wallet-exists-{}", std::process::id()));
+        let storage_path = dir.to_string_lossy().to_string();
+
+        assert!(!wallet_exists(storage_path.clone()).unwrap());
+        assert!(!dir.exists());
+
+        fs::create_dir_all(&dir).unwrap();
+        assert!(!wallet_exists(storage_path.clone()).unwrap());
+        fs::write(wallet_db_path(&storage_path), SQLITE_HEADER).unwrap();
+        assert!(wallet_exists(storage_path).unwrap());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_storage_path_is_checked_up_front() {
+        let create = |storage_path: String| {
+            wallet_create(JsWalletConfig {
+                storage_path,
+                ..test_config()
+            })
+        };
+
+        let Err(error) = create("relative/wallet".to_string()).await else {
+            panic!("relative storage path accepted");
+        };
+        assert_eq!(error.status, Status::InvalidArg);
+        assert!(error.reason.contains("must be absolute"));
+
+        let file = std::env::temp_dir().join(format!("tari_storage_file_{}", std::process::id()));
+        fs::write(&file, b"not a directory").unwrap();
+        let Err(error) = create(file.to_string_lossy().to_string()).await else {
+            panic!("file accepted as storage path");
+        };
+        assert!(error.reason.contains("is a file, not a directory"));
+
+        let Err(error) = create(file.join("wallet").to_string_lossy().to_string()).await else {
+            panic!("storage path inside a file accepted");
+        };
+        assert!(error.reason.contains("is blocked by a file"));
+
+        fs::remove_file(&file).unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_reads_share_the_wallet_lock() {
+        // A queued writer, such as the startup health check, would hold
+        // back new readers until the held read lock is released
+        let config = JsWalletConfig {
+            balance_cache_ttl_ms: Some(60_000),
+            auto_connect_on_start: Some(false),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        wallet_get_balance(handle).await.unwrap();
+
+        // Another reader, such as a long history export, holds the wallet
+        let wallet = get_wallet(handle).await.unwrap();
+        let guard = wallet.read().await;
+
+        let reads = tokio::spawn(async move {
+            wallet_get_address(handle).await.unwrap();
+            wallet_get_balance(handle).await.unwrap();
+            wallet_get_utxos(handle, None).await.unwrap();
+        });
+        assert!(tokio::time::timeout(Duration::from_secs(1), reads).await.is_ok());
+        drop(guard);
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_database_reads_do_not_wait_for_the_wallet_lock() {
+        let handle = wallet_create(JsWalletConfig {
+            max_read_connections: Some(1),
+            ..test_config()
+        })
+        .await
+        .unwrap();
+        let tx_id = match wallet_send_transaction(handle, "recipient".to_string(), "1000".to_string(), None, None)
+            .await
+            .unwrap()
+        {
+            Either::A(sent) => sent,
+            Either::B(_) => panic!("send returned a dry run summary"),
+        };
+        wallet_set_transaction_label(handle, tx_id.clone(), Some("rent".to_string()))
+            .await
+            .unwrap();
+
+        // A writer that never finishes, such as a send stuck on a dead node
+        let wallet = get_wallet(handle).await.unwrap();
+        let guard = wallet.write().await;
+        let label = tokio::time::timeout(Duration::from_secs(1), wallet_get_transaction_label(handle, tx_id.clone()));
+        assert_eq!(label.await.expect("label read waited for the writer").unwrap().as_deref(), Some("rent"));
+        drop(guard);
+
+        // The pool never lends out more connections than configured
+        let pool = wallet_read_pool(handle).unwrap();
+        let lent = pool.get().await.unwrap();
+        assert!(tokio::time::timeout(Duration::from_millis(50), pool.get()).await.is_err());
+        drop(lent);
+        assert!(tokio::time::timeout(Duration::from_secs(1), pool.get()).await.is_ok());
+
+        wallet_destroy(handle).await.unwrap();
+        assert!(wallet_read_pool(handle).is_err());
+        assert!(pool.get().await.is_err());
+
+        for max_read_connections in [0, crate::database::MAX_READ_CONNECTIONS_LIMIT + 1] {
+            let config = JsWalletConfig {
+                max_read_connections: Some(max_read_connections),
+                ..test_config()
+            };
+            assert!(config.validate().is_err());
+        }
+    }
+
+    /// Concurrent label reads through the one writer connection, as before
+    /// the read pool, against reads through the pool
+    ///
+    /// Run with `cargo test --release -- --ignored --nocapture
+    /// bench_concurrent_reads`.
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    #[ignore]
+    async fn bench_concurrent_reads() {
+        const READERS: usize = 8;
+        const READS_PER_READER: usize = 2_000;
+        const LABELS: usize = 1_000;
+
+        let config = JsWalletConfig {
+            max_read_connections: Some(READERS as u32),
+            ..test_config()
+        };
+        fs::create_dir_all(&config.storage_path).unwrap();
+        let database = Arc::new(WalletDatabase::open(&config).unwrap());
+        for i in 0..LABELS {
+            labels::set(&database, &format!("tx_{}", i), Some("rent")).unwrap();
+        }
+
+        let single_connection = Instant::now();
+        let readers = (0..READERS).map(|reader| {
+            let database = database.clone();
+            tokio::spawn(async move {
+                for i in 0..READS_PER_READER {
+                    let tx_id = format!("tx_{}", (reader * READS_PER_READER + i) % LABELS);
+                    labels::get(&database.connection(), &tx_id).unwrap().unwrap();
+                }
+            })
+        });
+        for reader in readers.collect::<Vec<_>>() {
+            reader.await.unwrap();
+        }
+        let single_connection = single_connection.elapsed();
+
+        let pool = database.read_pool();
+        let pooled = Instant::now();
+        let readers = (0..READERS).map(|reader| {
+            let pool = pool.clone();
+            tokio::spawn(async move {
+                for i in 0..READS_PER_READER {
+                    let tx_id = format!("tx_{}", (reader * READS_PER_READER + i) % LABELS);
+                    let connection = pool.get().await.unwrap();
+                    labels::get(&connection, &tx_id).unwrap().unwrap();
+                }
+            })
+        });
+        for reader in readers.collect::<Vec<_>>() {
+            reader.await.unwrap();
+        }
+        let pooled = pooled.elapsed();
+
+        let reads = (READERS * READS_PER_READER) as f64;
+        println!(
+            "{} concurrent readers: single connection {:.0} reads/s, pool of {} {:.0} reads/s",
+            READERS,
+            reads / single_connection.as_secs_f64(),
+            READERS,
+            reads / pooled.as_secs_f64()
+        );
+
+        drop(pool);
+        Arc::into_inner(database).unwrap().close();
+        fs::remove_dir_all(&config.storage_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_pragmas_are_validated_and_applied() {
+        let pragmas = || JsSqlitePragmas {
+            journal_mode: Some("wal".to_string()),
+            synchronous: Some("NORMAL".to_string()),
+            cache_size: Some(-64_000),
+            temp_store: None,
+            mmap_size: Some(268_435_456),
+        };
+        assert_eq!(
+            pragmas().statements().unwrap(),
+            vec![
+                "PRAGMA journal_mode = WAL",
+                "PRAGMA synchronous = NORMAL",
+                "PRAGMA cache_size = -64000",
+                "PRAGMA mmap_size = 268435456",
+            ]
+        );
+
+        let journal_mode = |pragmas: Option<JsSqlitePragmas>| async {
+            let config = JsWalletConfig {
+                sqlite_pragmas: pragmas,
+                ..test_config()
+            };
+            let db_path = wallet_db_path(&config.storage_path);
+            let handle = wallet_create(config).await.unwrap();
+            wallet_destroy(handle).await.unwrap();
+            // The journal mode is stored in the database file itself
+            rusqlite::Connection::open(db_path)
+                .unwrap()
+                .pragma_query_value(None, "journal_mode", |row| row.get::<_, String>(0))
+                .unwrap()
+        };
+        assert_eq!(journal_mode(Some(pragmas())).await, "wal");
+        assert_eq!(journal_mode(None).await, "wal");
+        let rollback_journal = JsSqlitePragmas {
+            journal_mode: Some("DELETE".to_string()),
+            ..pragmas()
+        };
+        assert_eq!(journal_mode(Some(rollback_journal)).await, "delete");
+
+        let config = JsWalletConfig {
+            sqlite_pragmas: Some(JsSqlitePragmas {
+                journal_mode: Some("TRUNCATE".to_string()),
+                ..pragmas()
+            }),
+            ..test_config()
+        };
+        let Err(error) = wallet_create(config).await else {
+            panic!("unsupported journal mode accepted");
+        };
+        assert!(error.reason.contains("journal_mode must be one of WAL, DELETE, MEMORY"));
+
+        let negative_mmap = JsSqlitePragmas {
+            mmap_size: Some(-1),
+            ..pragmas()
+        };
+        assert!(negative_mmap.statements().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_database_encryption_must_match_config() {
+        let dir = std::env::temp_dir().join(format!("tari-wallet-encryption-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let storage_path = dir.to_string_lossy().to_string();
+        let db_path = wallet_db_path(&storage_path);
+
+        let words = fresh_seed_words();
+        let encrypted_config = || JsWalletConfig {
+            storage_path: storage_path.clone(),
+            passphrase: Some("correct horse".to_string()),
+            encrypt_database: Some(true),
+            seed_words: Some(words.clone()),
+            ..test_config()
+        };
+        let plain_config = || JsWalletConfig {
+            storage_path: storage_path.clone(),
+            seed_words: Some(words.clone()),
+            ..test_config()
+        };
+
+        let write_database = |key: Option<&str>| {
+            let _ = fs::remove_file(&db_path);
+            let connection = rusqlite::Connection::open(&db_path).unwrap();
+            if let Some(key) = key {
+                connection.pragma_update(None, "key", key).unwrap();
+            }
+            connection.execute_batch("CREATE TABLE notes (note TEXT)").unwrap();
+        };
+
+        write_database(Some("correct horse"));
+        // Nothing in the file is readable without the passphrase
+        assert!(!has_plain_header(&db_path));
+        let unkeyed = rusqlite::Connection::open(&db_path).unwrap();
+        assert!(unkeyed.query_row("SELECT count(*) FROM sqlite_master", [], |_| Ok(())).is_err());
+        drop(unkeyed);
+
+        let handle = wallet_create(encrypted_config()).await.unwrap();
+        // Backups stay encrypted under the same passphrase
+        let backup_path = Path::new(&storage_path).with_extension("backup");
+        wallet_backup_database(handle, backup_path.to_string_lossy().to_string())
+            .await
+            .unwrap();
+        assert!(!has_plain_header(&backup_path));
+        let keyed = rusqlite::Connection::open(&backup_path).unwrap();
+        keyed.pragma_update(None, "key", "correct horse").unwrap();
+        assert!(keyed.query_row("SELECT count(*) FROM notes", [], |_| Ok(())).is_ok());
+        drop(keyed);
+        fs::remove_file(&backup_path).unwrap();
+        wallet_destroy(handle).await.unwrap();
+        let result = wallet_create(plain_config()).await;
+        assert!(result.err().unwrap().reason.contains("Wallet database is encrypted"));
+
+        let wrong_passphrase = JsWalletConfig {
+            passphrase: Some("battery staple".to_string()),
+            ..encrypted_config()
+        };
+        let error = wallet_create(wrong_passphrase).await.err().unwrap();
+        let prefix = format!("[{}:", WalletErrorCode::AuthenticationFailed as i32);
+        assert!(error.reason.starts_with(&prefix), "{}", error.reason);
+        assert!(error.reason.contains("Wrong passphrase"));
+
+        write_database(None);
+        let handle = wallet_create(plain_config()).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+        let result = wallet_create(encrypted_config()).await;
+        assert!(result.err().unwrap().reason.contains("is not encrypted"));
+
+        let mut no_passphrase = encrypted_config();
+        no_passphrase.passphrase = None;
+        assert!(wallet_create(no_passphrase).await.is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_data_directory_is_locked_while_open() {
+        let storage_path = test_config().storage_path;
+        let config = || JsWalletConfig {
+            storage_path: storage_path.clone(),
+            ..test_config()
+        };
+        let lock_path = Path::new(&storage_path).join(DATA_DIR_LOCK_FILE);
+
+        let handle = wallet_create(config()).await.unwrap();
+        assert!(lock_path.exists());
+        let result = wallet_create(config()).await;
+        assert!(result.err().unwrap().reason.contains("data directory is in use"));
+
+        wallet_destroy(handle).await.unwrap();
+        assert!(!lock_path.exists());
+        let handle = wallet_create(config()).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+
+        fs::remove_dir_all(&storage_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_network_must_match_data_directory() {
+        let storage_path = test_config().storage_path;
+        let config = |network: &str| JsWalletConfig {
+            network: network.to_string(),
+            storage_path: storage_path.clone(),
+            ..test_config()
+        };
+
+        let handle = wallet_create(config("testnet")).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+
+        let result = wallet_create(config("mainnet")).await;
+        assert!(result
+            .err()
+            .unwrap()
+            .reason
+            .contains("network mismatch: db is testnet, config is mainnet"));
+        // The refused open releases the directory again
+        let handle = wallet_create(config("testnet")).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+
+        // The network travels with the database
+        let moved = JsWalletConfig {
+            network: "mainnet".to_string(),
+            ..test_config()
+        };
+        fs::create_dir_all(&moved.storage_path).unwrap();
+        fs::copy(wallet_db_path(&storage_path), wallet_db_path(&moved.storage_path)).unwrap();
+        let moved_path = moved.storage_path.clone();
+        let error = wallet_create(moved).await.err().unwrap();
+        assert!(error.reason.contains("network mismatch: db is testnet, config is mainnet"));
+
+        fs::remove_dir_all(&storage_path).unwrap();
+        fs::remove_dir_all(&moved_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_database_schema_is_version_gated() {
+        let storage_path = test_config().storage_path;
+        let db_path = wallet_db_path(&storage_path);
+        let words = fresh_seed_words();
+        let config = || JsWalletConfig {
+            storage_path: storage_path.clone(),
+            seed_words: Some(words.clone()),
+            ..test_config()
+        };
+        let version = || schema_version(&rusqlite::Connection::open(&db_path).unwrap()).unwrap();
+        let legacy_database = |sql: &str| {
+            let _ = fs::remove_file(&db_path);
+            rusqlite::Connection::open(&db_path).unwrap().execute_batch(sql).unwrap();
+        };
+
+        let handle = wallet_create(config()).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+        assert_eq!(version(), WALLET_SCHEMA_VERSION);
+
+        // A database from before schema versioning is migrated forward
+        legacy_database("CREATE TABLE legacy (id INTEGER)");
+        let handle = wallet_create(config()).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+        assert_eq!(version(), WALLET_SCHEMA_VERSION);
+        let connection = rusqlite::Connection::open(&db_path).unwrap();
+        let tables: i64 = connection
+            .query_row(
+                "SELECT count(*) FROM sqlite_master WHERE name IN ('legacy', 'wallet_metadata')",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(tables, 2);
+        drop(connection);
+
+        // A failed step leaves the database as it was
+        legacy_database("CREATE TABLE wallet_metadata (id INTEGER)");
+        let error = wallet_create(config()).await.err().unwrap();
+        assert!(error.reason.contains("Database migration from v0 failed"), "{}", error.reason);
+        assert_eq!(version(), 0);
+
+        rusqlite::Connection::open(&db_path)
+            .unwrap()
+            .pragma_update(None, "user_version", WALLET_SCHEMA_VERSION + 1)
+            .unwrap();
+        let error = wallet_create(config()).await.err().unwrap();
+        assert!(error.reason.contains("db schema newer than this SDK"));
+        // The newer version is left for the SDK that wrote it
+        assert_eq!(version(), WALLET_SCHEMA_VERSION + 1);
+
+        fs::remove_dir_all(&storage_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_time_locked_send_reports_lock_height() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        set_tip_height(handle, 100).await;
+
+        let tx = wallet_send_time_locked_transaction(
+            handle,
+            "recipient".to_string(),
+            "5000".to_string(),
+            150,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(tx.lock_height, Some(150));
+        assert_eq!(tx.amount, "5000");
+        assert_eq!(
+            tx.gross_amount.parse::<u64>().unwrap(),
+            5000 + tx.fee.parse::<u64>().unwrap()
+        );
+        assert!(!tx.is_inbound);
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_time_locked_send_rejects_past_height() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        set_tip_height(handle, 100).await;
+
+        let result = wallet_send_time_locked_transaction(
+            handle,
+            "recipient".to_string(),
+            "5000".to_string(),
+            99,
+            None,
+        )
+        .await;
+        assert!(result.err().unwrap().reason.contains("below the current chain tip"));
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_balance_cache_invalidated_after_send() {
+        let config = JsWalletConfig {
+            balance_cache_ttl_ms: Some(60_000),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        let wallet = get_wallet(handle).await.unwrap();
+
+        let initial = wallet_get_balance(handle).await.unwrap();
+        assert_eq!(initial.available, PLACEHOLDER_BALANCE.to_string());
+
+        // A change the cache has not seen is hidden until a refresh
+        wallet.write().await.available_balance -= 1;
+        assert_eq!(wallet_get_balance(handle).await.unwrap().available, initial.available);
+        let refreshed = wallet_refresh_balance(handle).await.unwrap();
+        assert_eq!(refreshed.available, (PLACEHOLDER_BALANCE - 1).to_string());
+
+        wallet_send_transaction(handle, "recipient".to_string(), "1000".to_string(), None, None)
+            .await
+            .unwrap();
+
+        let expected = PLACEHOLDER_BALANCE - 1 - 1000 - estimate_fee(DEFAULT_FEE_PER_GRAM, 1, 2);
+        assert_eq!(wallet_get_balance(handle).await.unwrap().available, expected.to_string());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bus_events_invalidate_the_balance_cache() {
+        let config = JsWalletConfig {
+            balance_cache_ttl_ms: Some(60_000),
+            event_bus_capacity: Some(1),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        let wallet = get_wallet(handle).await.unwrap();
+        let mut events = wallet.read().await.events.subscribe();
+
+        wallet_get_balance(handle).await.unwrap();
+        wallet.write().await.available_balance -= 1;
+        set_synced_height(handle, 0).await.unwrap();
+        assert_eq!(events.try_recv().unwrap(), WalletEvent::SyncCompleted { height: 0 });
+        assert_eq!(
+            wallet_get_balance(handle).await.unwrap().available,
+            (PLACEHOLDER_BALANCE - 1).to_string()
+        );
+
+        // More events than the bus holds still invalidate the cache
+        wallet.write().await.available_balance -= 1;
+        {
+            let mut wallet_lock = wallet.write().await;
+            wallet_lock.roll_back(0);
+            wallet_lock.roll_back(0);
+        }
+        assert_eq!(
+            wallet_get_balance(handle).await.unwrap().available,
+            (PLACEHOLDER_BALANCE - 2).to_string()
+        );
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_batch_reports_partial_failures() {
+        let handle = wallet_create(test_config()).await.unwrap();
+
+        let payment = |destination: &str, amount: &str| JsPayment {
+            destination: destination.to_string(),
+            amount: amount.to_string(),
+            message: None,
+        };
+        let results = wallet_send_batch(
+            handle,
+            vec![
+                payment("alice", "1000"),
+                payment("", "1000"),
+                payment("bob", "0"),
+                payment("carol", &PLACEHOLDER_BALANCE.to_string()),
+                payment("dave", "2000"),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+
+        let succeeded: Vec<bool> = results.iter().map(|r| r.tx_id.is_some()).collect();
+        assert_eq!(succeeded, vec![true, false, false, false, true]);
+        assert!(results[1].error.as_deref().unwrap().contains("cannot be empty"));
+        assert!(results[3].error.as_deref().unwrap().contains("Insufficient funds"));
+
+        // One fee for two payments and the change
+        let fee = estimate_fee(DEFAULT_FEE_PER_GRAM, 1, 3);
+        let balance = wallet_get_balance(handle).await.unwrap();
+        assert_eq!(balance.available, (PLACEHOLDER_BALANCE - 3000 - fee).to_string());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_transaction_message_is_limited_in_bytes() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let send = |message: String| {
+            let options = JsSendTransactionOptions {
+                fee_per_gram: None,
+                message: Some(message),
+                is_one_sided: None,
+                allow_high_fee: None,
+                idempotency_key: None,
+                covenant: None,
+            };
+            wallet_send_transaction(
+                handle,
+                "recipient".to_string(),
+                "1000".to_string(),
+                Some(options),
+                None,
+            )
+        };
+
+        let Either::A(tx_id) = send("a".repeat(MAX_MESSAGE_BYTES)).await.unwrap() else {
+            panic!("send returned a dry run summary");
+        };
+        // The message travels with the transaction rather than being dropped
+        let everything = JsTxFilter {
+            min_amount: None,
+            max_amount: None,
+            from_timestamp: None,
+            to_timestamp: None,
+            address: None,
+            direction: None,
+        };
+        let history = wallet_search_transactions(handle, everything, None, None).await.unwrap();
+        let sent = history.transactions.iter().find(|tx| tx.id == tx_id).unwrap();
+        assert_eq!(sent.message, "a".repeat(MAX_MESSAGE_BYTES));
+        let details = wallet_get_transaction_details(handle, tx_id).await.unwrap();
+        assert_eq!(details.message, Some("a".repeat(MAX_MESSAGE_BYTES)));
+
+        // 200 characters, but 600 bytes
+        let Err(error) = send("€".repeat(200)).await else {
+            panic!("oversized message was accepted");
+        };
+        let prefix = format!("[{}:", WalletErrorCode::MessageTooLong as i32);
+        assert!(error.reason.starts_with(&prefix));
+        assert!(error.reason.contains("the maximum is 512 bytes"));
+
+        // U+FFFD is a character like any other
+        assert!(send("unknown glyph \u{FFFD}".to_string()).await.is_ok());
+
+        let results = wallet_send_batch(
+            handle,
+            vec![JsPayment {
+                destination: "alice".to_string(),
+                amount: "1000".to_string(),
+                message: Some("a".repeat(MAX_MESSAGE_BYTES + 1)),
+            }],
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(results[0].tx_id.is_none());
+        assert!(results[0].error.as_deref().unwrap().contains("maximum is 512 bytes"));
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unseen_transactions_are_rebroadcast() {
+        let config = JsWalletConfig {
+            max_broadcast_retries: Some(2),
+            broadcast_retry_delay_seconds: Some(30),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        let sent = wallet_send_transaction(
+            handle,
+            "tari://testnet/recipient".to_string(),
+            "1000".to_string(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        let Either::A(tx_id) = sent else {
+            panic!("expected a transaction id");
+        };
+
+        let status = wallet_get_broadcast_status(handle, tx_id.clone()).await.unwrap();
+        assert_eq!(status.retry_count, 0);
+        assert!(!status.seen_by_network);
+
+        let wallet = get_wallet(handle).await.unwrap();
+        let start = Instant::now();
+        {
+            let mut wallet = wallet.write().await;
+            assert!(wallet.rebroadcast_due(start + Duration::from_secs(10)).is_empty());
+            assert_eq!(wallet.rebroadcast_due(start + Duration::from_secs(31)), vec![tx_id.clone()]);
+            assert!(wallet.rebroadcast_due(start + Duration::from_secs(40)).is_empty());
+            assert_eq!(wallet.rebroadcast_due(start + Duration::from_secs(62)), vec![tx_id.clone()]);
+            // Retries exhausted
+            assert!(wallet.rebroadcast_due(start + Duration::from_secs(100)).is_empty());
+        }
+
+        let status = wallet_get_broadcast_status(handle, tx_id).await.unwrap();
+        assert_eq!(status.retry_count, 2);
+        assert!(wallet_get_broadcast_status(handle, "tx_unknown".to_string()).await.is_err());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_chain_tip_needs_a_base_node() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        set_tip_height(handle, 1_234).await;
+
+        let Err(error) = wallet_get_chain_tip(handle).await else {
+            panic!("chain tip reported without a base node");
+        };
+        let prefix = format!("[{}:", WalletErrorCode::BaseNodeNotReachable as i32);
+        assert!(error.reason.starts_with(&prefix));
+        assert!(error.reason.contains("No base node is connected"));
+
+        let peer = format!("{}::/ip4/127.0.0.1/tcp/18189", RistrettoPublicKey::default().to_hex());
+        wallet_connect_to_base_node(handle, peer).await.unwrap();
+        assert_eq!(wallet_get_chain_tip(handle).await.unwrap(), 1_234);
+
+        // The same tip confirmations are counted against
+        let tx_id = match wallet_send_transaction(handle, "recipient".to_string(), "1000".to_string(), None, None)
+            .await
+            .unwrap()
+        {
+            Either::A(tx_id) => tx_id,
+            Either::B(_) => panic!("send returned a dry run summary"),
+        };
+        let wallet = get_wallet(handle).await.unwrap();
+        wallet.write().await.outbound_transactions.get_mut(&tx_id).unwrap().mined_height = Some(1_230);
+        let details = wallet_get_transaction_details(handle, tx_id).await.unwrap();
+        assert_eq!(details.confirmations as i64, wallet_get_chain_tip(handle).await.unwrap() - 1_230 + 1);
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rebroadcast_pending_skips_mined_transactions() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let mut tx_ids = Vec::new();
+        for _ in 0..2 {
+            let sent = wallet_send_transaction(
+                handle,
+                "tari://testnet/recipient".to_string(),
+                "1000".to_string(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+            let Either::A(tx_id) = sent else {
+                panic!("expected a transaction id");
+            };
+            tx_ids.push(tx_id);
+        }
+
+        let Err(error) = wallet_rebroadcast_pending(handle).await else {
+            panic!("rebroadcast without a base node");
+        };
+        assert!(error.reason.contains("No base node is connected"));
+
+        let peer = format!("{}::/ip4/127.0.0.1/tcp/18189", RistrettoPublicKey::default().to_hex());
+        wallet_connect_to_base_node(handle, peer).await.unwrap();
+        {
+            let wallet = get_wallet(handle).await.unwrap();
+            let mut wallet = wallet.write().await;
+            wallet.outbound_transactions.get_mut(&tx_ids[0]).unwrap().mined_height = Some(1);
+        }
+
+        // Repeating the nudge resubmits the same transactions; without a
+        // transaction service no base node acknowledges them
+        for _ in 0..2 {
+            let summary = wallet_rebroadcast_pending(handle).await.unwrap();
+            assert_eq!(
+                (summary.attempted, summary.succeeded, summary.failed, summary.unacknowledged),
+                (1, 0, 0, 1)
+            );
+        }
+        let status = wallet_get_broadcast_status(handle, tx_ids[1].clone()).await.unwrap();
+        assert_eq!(status.retry_count, 0);
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connect_and_disconnect_base_node() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let public_key = RistrettoPublicKey::default().to_hex();
+        let peer = format!("{}::/ip4/127.0.0.1/tcp/18189", public_key);
+
+        assert!(wallet_connect_to_base_node(handle, peer.clone()).await.unwrap());
+        assert!(!wallet_connect_to_base_node(handle, peer).await.unwrap());
+        assert!(wallet_connect_to_base_node(handle, "/ip4/127.0.0.1/tcp/18189".to_string())
+            .await
+            .is_err());
+
+        let stats = wallet_get_connection_stats(handle).await.unwrap();
+        assert_eq!(stats.connected_nodes, 1);
+        assert_eq!(wallet_get_network_status(handle).await.unwrap(), "online");
+        assert_eq!(stats.active_node.as_deref(), Some("/ip4/127.0.0.1/tcp/18189"));
+        assert_eq!(stats.listener_address, DEFAULT_LISTENER_ADDRESS);
+
+        wallet_disconnect_from_base_node(handle, public_key.clone()).await.unwrap();
+        assert_eq!(wallet_get_connection_stats(handle).await.unwrap().total_nodes, 0);
+        assert_eq!(wallet_get_network_status(handle).await.unwrap(), "offline");
+        assert!(wallet_disconnect_from_base_node(handle, public_key).await.is_err());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_onion_base_nodes_need_the_tor_transport() {
+        let peer = format!(
+            "{}::/onion3/{}:18141",
+            RistrettoPublicKey::default().to_hex(),
+            "a".repeat(56)
+        );
+
+        let handle = wallet_create(test_config()).await.unwrap();
+        let error = wallet_connect_to_base_node(handle, peer.clone()).await.unwrap_err();
+        assert!(error.reason.contains("only reachable over the tor transport"));
+        wallet_destroy(handle).await.unwrap();
+
+        let config = JsWalletConfig {
+            transport: Some("tor".to_string()),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        assert!(wallet_connect_to_base_node(handle, peer).await.unwrap());
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_listener_address_is_reported_in_stats() {
+        let config = JsWalletConfig {
+            listener_address: Some("[::1]:0".to_string()),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+
+        let stats = wallet_get_connection_stats(handle).await.unwrap();
+        assert_eq!(stats.listener_address, "[::1]:0");
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_peer_seeds_join_the_pool() {
+        let public_key = RistrettoPublicKey::default().to_hex();
+        let config = JsWalletConfig {
+            peer_seeds: Some(vec![format!("{}::/ip4/10.0.0.1/tcp/18189", public_key)]),
+            auto_connect_on_start: Some(false),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+
+        let stats = wallet_get_connection_stats(handle).await.unwrap();
+        assert_eq!((stats.total_nodes, stats.connected_nodes), (1, 0));
+        let health = wallet_check_base_node_health(handle).await.unwrap();
+        assert!(health[0].healthy);
+        assert_eq!(wallet_get_network_status(handle).await.unwrap(), "online");
+        wallet_destroy(handle).await.unwrap();
+
+        let config = JsWalletConfig {
+            peer_seeds: Some(vec!["/ip4/10.0.0.1/tcp/18189".to_string()]),
+            ..test_config()
+        };
+        assert!(wallet_create(config).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_auto_connect_on_start_can_be_deferred() {
+        let public_key = RistrettoPublicKey::default().to_hex();
+        let config = |auto_connect_on_start| JsWalletConfig {
+            peer_seeds: Some(vec![format!("{}::/ip4/10.0.0.1/tcp/18189", public_key)]),
+            auto_connect_on_start,
+            ..test_config()
+        };
+
+        let handle = wallet_create(config(None)).await.unwrap();
+        let mut status = wallet_get_network_status(handle).await.unwrap();
+        for _ in 0..100 {
+            if status == "online" {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            status = wallet_get_network_status(handle).await.unwrap();
+        }
+        assert_eq!(status, "online");
+        wallet_destroy(handle).await.unwrap();
+
+        let handle = wallet_create(config(Some(false))).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(wallet_get_network_status(handle).await.unwrap(), "offline");
+        // A sync starts networking
+        crate::sync::wallet_sync_to_tip(handle).await.unwrap();
+        assert_eq!(wallet_get_network_status(handle).await.unwrap(), "online");
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_network_overrides_supply_private_peers() {
+        let public_key = RistrettoPublicKey::default().to_hex();
+        let overrides = |base_nodes: Vec<String>| JsNetworkOverrides {
+            base_node_addresses: Some(base_nodes),
+        };
+        let config = |overrides| JsWalletConfig {
+            network_overrides: Some(overrides),
+            ..test_config()
+        };
+
+        let base_node = format!("{}::/dns4/node.private.example/tcp/18189", public_key);
+        let handle = wallet_create(config(overrides(vec![base_node]))).await.unwrap();
+        let stats = wallet_get_connection_stats(handle).await.unwrap();
+        assert_eq!(stats.total_nodes, 1);
+        wallet_destroy(handle).await.unwrap();
+
+        let invalid = [
+            (overrides(vec![]), "at least one base node address"),
+            (overrides(vec!["/ip4/10.0.0.1/tcp/18189".to_string()]), "Invalid base node peer"),
+        ];
+        for (overrides, reason) in invalid {
+            let Err(error) = wallet_create(config(overrides)).await else {
+                panic!("invalid network overrides were accepted");
+            };
+            assert!(error.reason.contains(reason), "{}", error.reason);
+        }
+
+        // Localnet peers are started by the tests themselves
+        let localnet = JsWalletConfig {
+            network: LOCALNET.to_string(),
+            ..config(overrides(vec![]))
+        };
+        assert!(localnet.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_transaction_labels_survive_restart() {
+        let storage_path = test_config().storage_path;
+        let config = || JsWalletConfig {
+            storage_path: storage_path.clone(),
+            ..test_config()
+        };
+
+        let handle = wallet_create(config()).await.unwrap();
+        let tx_id = match wallet_send_transaction(handle, "recipient".to_string(), "1000".to_string(), None, None)
+            .await
+            .unwrap()
+        {
+            Either::A(tx_id) => tx_id,
+            Either::B(_) => panic!("send returned a dry run summary"),
+        };
+
+        assert!(wallet_set_transaction_label(handle, "tx_missing".to_string(), Some("rent".to_string()))
+            .await
+            .is_err());
+        let too_long = "x".repeat(crate::labels::MAX_LABEL_LENGTH + 1);
+        assert!(wallet_set_transaction_label(handle, tx_id.clone(), Some(too_long))
+            .await
+            .is_err());
+
+        wallet_set_transaction_label(handle, tx_id.clone(), Some(" rent ".to_string()))
+            .await
+            .unwrap();
+        let details = wallet_get_transaction_details(handle, tx_id.clone()).await.unwrap();
+        assert_eq!(details.local_label.as_deref(), Some("rent"));
+        wallet_destroy(handle).await.unwrap();
+
+        let handle = wallet_create(config()).await.unwrap();
+        assert_eq!(
+            wallet_get_transaction_label(handle, tx_id.clone()).await.unwrap().as_deref(),
+            Some("rent")
+        );
+        wallet_destroy(handle).await.unwrap();
+
+        fs::remove_dir_all(&storage_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_confirmations_follow_chain_tip() {
+        let config = JsWalletConfig {
+            required_confirmations: Some(3),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        set_tip_height(handle, 100).await;
+        let info = wallet_send_time_locked_transaction(
+            handle,
+            "tari://testnet/recipient".to_string(),
+            "1000".to_string(),
+            150,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(info.confirmations, 0);
+
+        let wallet = get_wallet(handle).await.unwrap();
+        {
+            let mut wallet = wallet.write().await;
+            wallet.outbound_transactions.get_mut(&info.id).unwrap().mined_height = Some(101);
+            wallet.tip_height = 102;
+            assert!(wallet.newly_confirmed().is_empty());
+
+            wallet.tip_height = 103;
+            assert_eq!(wallet.newly_confirmed(), vec![(info.id.clone(), 3)]);
+            wallet.tip_height = 104;
+            assert!(wallet.newly_confirmed().is_empty());
+        }
+
+        let details = wallet_get_transaction_details(handle, info.id.clone()).await.unwrap();
+        assert_eq!((details.inputs, details.outputs), (Some(1), Some(2)));
+        assert_eq!(details.maturity_height, Some(150));
+        assert_eq!(details.block_height, Some(101));
+        assert_eq!(details.confirmations, 4);
+        assert!(wallet_get_transaction_details(handle, "tx_unknown".to_string()).await.is_err());
+
+        let confirmations = wallet_get_transaction_confirmations(handle, info.id).await;
+        assert_eq!(confirmations.unwrap(), r#"{"confirmations":4,"required":3}"#);
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mine_blocks_confirms_sends_on_localnet() {
+        let config = JsWalletConfig {
+            network: LOCALNET.to_string(),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        let before = wallet_get_balance(handle).await.unwrap().available;
+
+        let Either::A(tx_id) = wallet_send_transaction(
+            handle,
+            "tari://localnet/recipient".to_string(),
+            "1000".to_string(),
+            None,
+            None,
+        )
+        .await
+        .unwrap() else {
+            panic!("expected a transaction id");
+        };
+        assert_ne!(wallet_get_balance(handle).await.unwrap().available, before);
+
+        assert_eq!(wallet_mine_blocks(handle, 3).await.unwrap(), 3);
+        let confirmations = wallet_get_transaction_confirmations(handle, tx_id).await;
+        assert_eq!(confirmations.unwrap(), r#"{"confirmations":3,"required":3}"#);
+        wallet_destroy(handle).await.unwrap();
+
+        let handle = wallet_create(test_config()).await.unwrap();
+        let error = wallet_mine_blocks(handle, 1).await.unwrap_err();
+        assert!(error.reason.contains("only be mined on localnet"));
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fee_recommendations() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let fallback = wallet_get_fee_recommendations(handle).await.unwrap();
+        assert_eq!(fallback.source, "fallback");
+        assert_eq!(fallback.normal, DEFAULT_FEE_PER_GRAM.to_string());
+
+        // Mempool stats are not queried yet, so a base node changes nothing
+        let peer = format!("{}::/ip4/127.0.0.1/tcp/18189", RistrettoPublicKey::default().to_hex());
+        wallet_connect_to_base_node(handle, peer).await.unwrap();
+        let connected = wallet_get_fee_recommendations(handle).await.unwrap();
+        assert_eq!(connected.source, "fallback");
+
+        let live = recommend_fees(Some(MempoolFeeStats {
+            min_fee_per_gram: 0,
+            avg_fee_per_gram: 8,
+            max_fee_per_gram: 25,
+        }));
+        assert_eq!(live.source, "live");
+        assert_eq!(live.slow, MIN_FEE_PER_GRAM.to_string());
+        assert_eq!(live.normal, "8");
+        assert_eq!(live.fast, "26");
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fees_above_safety_cap_need_override() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let cap = DEFAULT_FEE_PER_GRAM * FEE_CAP_MULTIPLIER;
+        let options = |fee_per_gram: u64, allow_high_fee: Option<bool>| JsSendTransactionOptions {
+            fee_per_gram: Some(fee_per_gram.to_string()),
+            message: None,
+            is_one_sided: None,
+            allow_high_fee,
+            idempotency_key: None,
+            covenant: None,
+        };
+
+        let send = |options| {
+            wallet_send_transaction(handle, "recipient".to_string(), "1000".to_string(), Some(options), None)
+        };
+        assert!(send(options(cap, None)).await.is_ok());
+        let Err(error) = send(options(cap + 1, None)).await else {
+            panic!("fee above the cap was accepted");
+        };
+        assert!(error.reason.contains("exceeds the safety cap"));
+        assert!(send(options(cap + 1, Some(true))).await.is_ok());
+
+        let burn = wallet_burn_funds(handle, "1000".to_string(), Some((cap + 1).to_string()), None);
+        assert!(burn.await.is_err());
+        wallet_destroy(handle).await.unwrap();
+
+        // A configured cap replaces the default
+        let config = JsWalletConfig {
+            max_fee_per_gram: Some(2),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        let Err(error) = wallet_send_batch(handle, vec![], Some("3".to_string())).await else {
+            panic!("batch fee above the configured cap was accepted");
+        };
+        assert!(error.reason.contains("safety cap of 2"));
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_repeated_idempotency_key_returns_original_send() {
+        let storage_path = test_config().storage_path;
+        let config = || JsWalletConfig {
+            storage_path: storage_path.clone(),
+            ..test_config()
+        };
+        let send = |handle: WalletHandle, key: &str, recipient: &str, amount: &str, message: Option<String>| {
+            let options = JsSendTransactionOptions {
+                fee_per_gram: None,
+                message,
+                is_one_sided: None,
+                allow_high_fee: None,
+                idempotency_key: Some(key.to_string()),
+                covenant: None,
+            };
+            wallet_send_transaction_detailed(handle, recipient.to_string(), amount.to_string(), Some(options))
+        };
+        let same = |a: &JsSendResult, b: &JsSendResult| {
+            (&a.tx_id, &a.amount, &a.fee, a.status, a.timestamp) == (&b.tx_id, &b.amount, &b.fee, b.status, b.timestamp)
+        };
+
+        let handle = wallet_create(config()).await.unwrap();
+        let first = send(handle, "order-1", "recipient", "1000", None).await.unwrap();
+        let balance = wallet_get_balance(handle).await.unwrap().available;
+        assert!(same(&send(handle, "order-1", "recipient", "1000", None).await.unwrap(), &first));
+        assert_eq!(wallet_get_balance(handle).await.unwrap().available, balance);
+        assert_ne!(send(handle, "order-2", "recipient", "1000", None).await.unwrap().tx_id, first.tx_id);
+
+        // A key reused for another payment is refused, not answered with the first
+        let error = send(handle, "order-1", "recipient", "2000", None).await.err().unwrap();
+        assert!(error.reason.contains("was used to send 1000 µT to recipient, not 2000 µT"));
+        let error = send(handle, "order-1", "someone-else", "1000", None).await.err().unwrap();
+        assert!(error.reason.contains("not 1000 µT to someone-else"));
+        // and an invalid request is refused before the key is looked up
+        let too_long = Some("x".repeat(MAX_MESSAGE_BYTES + 1));
+        let error = send(handle, "order-1", "recipient", "1000", too_long).await.err().unwrap();
+        assert!(error.reason.contains("Transaction message is"));
+        wallet_destroy(handle).await.unwrap();
+
+        // Keys outlive the session, and report the send as it was made
+        let handle = wallet_create(config()).await.unwrap();
+        assert!(same(&send(handle, "order-1", "recipient", "1000", None).await.unwrap(), &first));
+        wallet_destroy(handle).await.unwrap();
+
+        fs::remove_dir_all(&storage_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_hung_operations_time_out() {
+        let config = JsWalletConfig {
+            operation_timeouts: Some(JsOperationTimeouts {
+                create_ms: None,
+                balance_ms: Some(50),
+                send_ms: None,
+                sync_ms: None,
+            }),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        assert!(wallet_get_balance(handle).await.is_ok());
+
+        // A balance query waiting on a base node that never answers
+        let hung = std::future::pending::<Result<JsBalance>>();
+        let Err(error) = with_wallet_timeout(handle, TimedOperation::Balance, hung).await else {
+            panic!("hung balance query completed");
+        };
+        assert!(error.reason.contains("Wallet balance timed out after 50ms"));
+
+        // Other operations keep their defaults
+        let send = with_wallet_timeout(handle, TimedOperation::Send, async { Ok(()) });
+        assert!(send.await.is_ok());
+        wallet_destroy(handle).await.unwrap();
+
+        let config = JsWalletConfig {
+            operation_timeouts: Some(JsOperationTimeouts {
+                create_ms: Some(0),
+                balance_ms: None,
+                send_ms: None,
+                sync_ms: None,
+            }),
+            ..test_config()
+        };
+        assert!(wallet_create(config).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_operations_behind_a_held_wallet_lock_time_out() {
+        let config = JsWalletConfig {
+            operation_timeouts: Some(JsOperationTimeouts {
+                create_ms: None,
+                balance_ms: Some(50),
+                send_ms: Some(50),
+                sync_ms: None,
+            }),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+
+        // A writer that never finishes, such as a send stuck on a dead node
+        let wallet = get_wallet(handle).await.unwrap();
+        let guard = wallet.write().await;
+
+        let balance = tokio::time::timeout(Duration::from_secs(5), wallet_get_balance(handle));
+        let error = balance.await.expect("balance query hung").err().unwrap();
+        assert!(error.reason.contains("Wallet balance timed out after 50ms"));
+
+        let refresh = tokio::time::timeout(Duration::from_secs(5), wallet_refresh_balance(handle));
+        assert!(refresh.await.expect("balance refresh hung").is_err());
+
+        let send = wallet_send_transaction(handle, "recipient".to_string(), "1000".to_string(), None, None);
+        let error = tokio::time::timeout(Duration::from_secs(5), send)
+            .await
+            .expect("send hung")
+            .err()
+            .unwrap();
+        assert!(error.reason.contains("Wallet send timed out after 50ms"));
+
+        drop(guard);
+        assert!(wallet_get_balance(handle).await.is_ok());
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_base_node_serves_last_known_balance() {
+        let config = JsWalletConfig {
+            operation_timeouts: Some(JsOperationTimeouts {
+                create_ms: None,
+                balance_ms: Some(50),
+                send_ms: None,
+                sync_ms: None,
+            }),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        let hung = || std::future::pending::<Result<JsBalance>>();
+
+        // Nothing to fall back on: never invent a balance
+        let Err(error) = query_balance(handle, hung()).await else {
+            panic!("hung balance query completed");
+        };
+        assert!(error.reason.contains("timed out"));
+
+        let fresh = wallet_get_balance(handle).await.unwrap();
+        assert!(!fresh.stale);
+
+        let fallback = query_balance(handle, hung()).await.unwrap();
+        assert!(fallback.stale);
+        assert_eq!(fallback.available, fresh.available);
+
+        // A query stuck behind an operation holding the wallet falls back too
+        let wallet = get_wallet(handle).await.unwrap();
+        let guard = wallet.write().await;
+        let balance = tokio::time::timeout(Duration::from_secs(5), wallet_get_balance(handle));
+        let held = balance.await.expect("balance query hung").unwrap();
+        assert!(held.stale);
+        assert_eq!(held.available, fresh.available);
+        drop(guard);
+
+        assert!(!wallet_refresh_balance(handle).await.unwrap().stale);
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_inbound_transactions_notify_on_detection_and_confirmation() {
+        let config = JsWalletConfig {
+            required_confirmations: Some(2),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+        let wallet = get_wallet(handle).await.unwrap();
+        let inbound = || InboundTransaction {
+            amount: 5000,
+            received_at: 0.0,
+            sender_address: Some("tari://testnet/sender".to_string()),
+            message: None,
+            mined_height: None,
+            confirmed: false,
+            output_type: OUTPUT_TYPE_STANDARD,
+            maturity_height: None,
+        };
+
+        {
+            let mut wallet = wallet.write().await;
+            let pending = wallet.record_inbound("tx_in".to_string(), inbound()).unwrap();
+            assert_eq!(pending.status, "pending");
+            assert_eq!(pending.amount, "5000");
+            assert_eq!(pending.sender_address.as_deref(), Some("tari://testnet/sender"));
+            assert!(wallet.record_inbound("tx_in".to_string(), inbound()).is_none());
+
+            wallet.tip_height = 50;
+            assert!(wallet.newly_confirmed_inbound().is_empty());
+            wallet.inbound_transactions.get_mut("tx_in").unwrap().mined_height = Some(50);
+            assert!(wallet.newly_confirmed_inbound().is_empty());
+
+            wallet.tip_height = 51;
+            let confirmed = wallet.newly_confirmed_inbound();
+            assert_eq!(confirmed.len(), 1);
+            assert_eq!(confirmed[0].tx_id, "tx_in");
+            assert_eq!(confirmed[0].status, "confirmed");
+            assert!(wallet.newly_confirmed_inbound().is_empty());
+
+            // An output detected already confirmed is only reported once
+            let mined = InboundTransaction {
+                mined_height: Some(40),
+                confirmed: true,
+                ..inbound()
+            };
+            let notification = wallet.record_inbound("tx_mined".to_string(), mined).unwrap();
+            assert_eq!(notification.status, "confirmed");
+            assert!(wallet.newly_confirmed_inbound().is_empty());
+        }
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_immature_coinbase_cannot_be_spent() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let wallet = get_wallet(handle).await.unwrap();
+        {
+            let mut wallet = wallet.write().await;
+            wallet.tip_height = 20;
+            let pending = wallet
+                .record_inbound(
+                    "tx_coinbase".to_string(),
+                    InboundTransaction {
+                        amount: 5000,
+                        received_at: 0.0,
+                        sender_address: None,
+                        message: None,
+                        mined_height: Some(10),
+                        confirmed: false,
+                        output_type: OUTPUT_TYPE_COINBASE,
+                        maturity_height: Some(1_000),
+                    },
+                )
+                .unwrap();
+            assert_eq!(pending.output_type, "coinbase");
+            assert_eq!(pending.maturity_height, Some(1_000));
+        }
+
+        let send = |amount: u64| {
+            wallet_send_transaction(
+                handle,
+                "tari://testnet/recipient".to_string(),
+                amount.to_string(),
+                None,
+                None,
+            )
+        };
+        let immature = send(PLACEHOLDER_BALANCE).await.err().unwrap();
+        assert!(immature.reason.contains("cannot be spent until height 1000"));
+        let insufficient = send(PLACEHOLDER_BALANCE + 5_000).await.err().unwrap();
+        assert!(!insufficient.reason.contains("immature"));
+        assert!(send(1_000).await.is_ok());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_transactions_are_searched_and_paged() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let alice = format!("tari://testnet/{}", "aa".repeat(32));
+        let send = |recipient: String, amount: &str| {
+            wallet_send_transaction(handle, recipient, amount.to_string(), None, None)
+        };
+        send(alice.clone(), "1000").await.unwrap();
+        send(alice.clone(), "3000").await.unwrap();
+        send("tari://testnet/bob".to_string(), "2000").await.unwrap();
+        {
+            let wallet = get_wallet(handle).await.unwrap();
+            let mut wallet = wallet.write().await;
+            wallet.record_inbound(
+                "tx_old".to_string(),
+                InboundTransaction {
+                    amount: 5000,
+                    received_at: 1_000.0,
+                    sender_address: Some(alice.clone()),
+                    message: Some("refund".to_string()),
+                    mined_height: None,
+                    confirmed: false,
+                    output_type: OUTPUT_TYPE_STANDARD,
+                    maturity_height: None,
+                },
+            );
+        }
+
+        let filter = || JsTxFilter {
+            min_amount: None,
+            max_amount: None,
+            from_timestamp: None,
+            to_timestamp: None,
+            address: None,
+            direction: None,
+        };
+        let search = |filter: JsTxFilter, offset, limit| wallet_search_transactions(handle, filter, offset, limit);
+
+        let all = search(filter(), None, None).await.unwrap();
+        assert_eq!((all.total, all.transactions.len(), all.has_more), (4, 4, false));
+        // Newest first
+        assert_eq!(all.transactions[3].id, "tx_old");
+        assert_eq!(all.transactions[3].message, "refund");
+
+        let to_alice = JsTxFilter {
+            address: Some(crate::address_to_emoji_id(alice.clone()).unwrap()),
+            direction: Some("outbound".to_string()),
+            min_amount: Some("2000".to_string()),
+            ..filter()
+        };
+        let page = search(to_alice, None, None).await.unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!((page.transactions[0].amount.as_str(), page.transactions[0].address.as_str()), ("3000", alice.as_str()));
+
+        let old = JsTxFilter {
+            to_timestamp: Some(2_000.0),
+            ..filter()
+        };
+        assert_eq!(search(old, None, None).await.unwrap().transactions[0].id, "tx_old");
+
+        let first = search(filter(), None, Some(3)).await.unwrap();
+        let second = search(filter(), Some(3), Some(3)).await.unwrap();
+        assert!(first.has_more && !second.has_more);
+        assert_eq!((first.transactions.len(), second.transactions.len()), (3, 1));
+
+        let invalid = JsTxFilter {
+            direction: Some("sideways".to_string()),
+            ..filter()
+        };
+        assert!(search(invalid, None, None).await.is_err());
+
+        // Exports are not paged
+        let inbound = JsTxFilter {
+            direction: Some("inbound".to_string()),
+            ..filter()
+        };
+        let csv = wallet_export_transactions(handle, "csv".to_string(), Some(inbound)).await.unwrap();
+        assert_eq!(csv.lines().count(), 2);
+        assert!(csv.contains("tx_old,1970-01-01T00:16:40Z,inbound,5000,0,pending"));
+        let json = wallet_export_transactions(handle, "json".to_string(), None).await.unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap().as_array().unwrap().len(), 4);
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_utxos_are_filtered_and_summarized() {
+        let handle = wallet_create(test_config()).await.unwrap();
+
+        let unspent = wallet_get_utxos(handle, None).await.unwrap();
+        assert_eq!(unspent.len(), 1);
+        assert_eq!(unspent[0].status, UTXO_STATUS_UNSPENT);
+        assert_eq!(unspent[0].amount, PLACEHOLDER_BALANCE.to_string());
+
+        {
+            let wallet = get_wallet(handle).await.unwrap();
+            let mut wallet = wallet.write().await;
+            wallet.record_inbound(
+                "tx_in".to_string(),
+                InboundTransaction {
+                    amount: 700,
+                    received_at: 0.0,
+                    sender_address: None,
+                    message: None,
+                    mined_height: None,
+                    confirmed: false,
+                    output_type: OUTPUT_TYPE_STANDARD,
+                    maturity_height: None,
+                },
+            );
+        }
+
+        let unconfirmed = wallet_get_utxos(handle, Some("unconfirmed".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(unconfirmed.len(), 1);
+        assert_eq!(unconfirmed[0].status, UTXO_STATUS_ENCUMBERED_TO_BE_RECEIVED);
+        assert_eq!(unconfirmed[0].amount, "700");
+
+        assert_eq!(wallet_get_utxos(handle, Some("ALL".to_string())).await.unwrap().len(), 2);
+        assert!(wallet_get_utxos(handle, Some("spent".to_string())).await.unwrap().is_empty());
+        assert!(wallet_get_utxos(handle, Some("locked".to_string())).await.is_err());
+
+        let summary = wallet_get_utxo_summary(handle).await.unwrap();
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.total_value, (PLACEHOLDER_BALANCE + 700).to_string());
+        assert_eq!(summary.unspent_value, PLACEHOLDER_BALANCE.to_string());
+        assert_eq!(summary.spendable_count, 1);
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dust_utxos_follow_fee_recommendation() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let threshold = dust_threshold(None);
+        assert_eq!(threshold, DEFAULT_FEE_PER_GRAM * INPUT_WEIGHT);
+
+        assert!(wallet_get_dust_utxos(handle).await.unwrap().is_empty());
+        assert_eq!(wallet_get_total_dust_value(handle).await.unwrap(), "0");
+
+        get_wallet(handle).await.unwrap().write().await.available_balance = threshold;
+        let dust = wallet_get_dust_utxos(handle).await.unwrap();
+        assert_eq!(dust.len(), 1);
+        assert_eq!(dust[0].amount, threshold.to_string());
+        assert_eq!(
+            wallet_get_total_dust_value(handle).await.unwrap(),
+            threshold.to_string()
+        );
+
+        get_wallet(handle).await.unwrap().write().await.available_balance = threshold + 1;
+        assert!(wallet_get_dust_utxos(handle).await.unwrap().is_empty());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_seed_words_require_passphrase() {
+        let config = JsWalletConfig {
+            passphrase: Some("correct horse".to_string()),
+            ..test_config()
+        };
+        let handle = wallet_create(config).await.unwrap();
+
+        let result = wallet_get_seed_words(handle, "wrong horse".to_string(), None).await;
+        assert!(result.err().unwrap().reason.contains("Invalid passphrase"));
+        assert!(wallet_get_seed_words(handle, String::new(), None).await.is_err());
+
+        let words = wallet_get_seed_words(handle, "correct horse".to_string(), None).await.unwrap();
+        assert_eq!(words.len(), 24);
+        wallet_destroy(handle).await.unwrap();
+
+        let handle = wallet_create(test_config()).await.unwrap();
+        assert!(wallet_get_seed_words(handle, String::new(), None).await.is_ok());
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_watch_only_wallet_cannot_spend() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let address = wallet_get_address(handle).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+
+        let view_key = address.rsplit('/').next().unwrap().to_string();
+        let config = JsWalletConfig {
+            view_key: Some(view_key.clone()),
+            ..test_config()
+        };
+        let watching = wallet_create(config).await.unwrap();
+        assert_eq!(wallet_get_address(watching).await.unwrap(), address);
+        assert!(wallet_get_balance(watching).await.is_ok());
+        assert!(wallet_get_utxos(watching, None).await.is_ok());
+
+        let watch_only = format!("[{}:", WalletErrorCode::WatchOnly as i32);
+        let sent = wallet_send_transaction(
+            watching,
+            "tari://testnet/recipient".to_string(),
+            "1000".to_string(),
+            None,
+            None,
+        )
+        .await;
+        assert!(sent.err().unwrap().reason.starts_with(&watch_only));
+        let words = wallet_get_seed_words(watching, String::new(), None).await;
+        assert!(words.err().unwrap().reason.starts_with(&watch_only));
+        wallet_destroy(watching).await.unwrap();
+
+        let both = wallet_create(JsWalletConfig {
+            seed_words: Some(vec!["abandon".to_string(); 24]),
+            view_key: Some(view_key),
+            ..test_config()
+        })
+        .await;
+        assert!(both.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_birthday_is_only_recorded_from_a_known_tip() {
+        let storage_path = test_config().storage_path;
+        let config = || JsWalletConfig {
+            storage_path: storage_path.clone(),
+            ..test_config()
+        };
+
+        // No base node has reported the tip, so there is no height to record
+        let handle = wallet_create(config()).await.unwrap();
+        assert_eq!(wallet_get_birthday(handle).await.unwrap(), None);
+        let words = wallet_get_seed_words(handle, String::new(), None).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+        let database = WalletDatabase::open(&config()).unwrap();
+        assert_eq!(database.birthday_height().unwrap(), None);
+        database.record_birthday(1_500).unwrap();
+        database.close();
+
+        // A reopened wallet keeps the birthday it was created with, and
+        // recovery starts from it
+        let handle = wallet_create(config()).await.unwrap();
+        assert_eq!(wallet_get_birthday(handle).await.unwrap(), Some(1_500));
+        set_tip_height(handle, 2_000).await;
+        crate::recovery::wallet_start_recovery(handle, None, None).await.unwrap();
+        let progress = crate::recovery::wallet_get_recovery_progress(handle).unwrap();
+        assert_eq!(progress.birthday_height, 1_500);
+        wallet_destroy(handle).await.unwrap();
+
+        // A restored wallet may own outputs from before it was opened here
+        let restored = wallet_create(JsWalletConfig {
+            seed_words: Some(words),
+            ..test_config()
+        })
+        .await
+        .unwrap();
+        assert_eq!(wallet_get_birthday(restored).await.unwrap(), None);
+        wallet_destroy(restored).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reopened_wallet_keeps_its_seed() {
+        let plain = test_config();
+        let encrypted = JsWalletConfig {
+            passphrase: Some("correct horse".to_string()),
+            encrypt_database: Some(true),
+            ..test_config()
+        };
+        for config in [plain, encrypted] {
+            let reopen = || JsWalletConfig {
+                storage_path: config.storage_path.clone(),
+                passphrase: config.passphrase.clone(),
+                encrypt_database: config.encrypt_database,
+                ..test_config()
+            };
+            let passphrase = config.passphrase.clone().unwrap_or_default();
+
+            let handle = wallet_create(reopen()).await.unwrap();
+            let address = wallet_get_address(handle).await.unwrap();
+            let words = wallet_get_seed_words(handle, passphrase.clone(), None).await.unwrap();
+            wallet_destroy(handle).await.unwrap();
+
+            let handle = wallet_create(reopen()).await.unwrap();
+            assert_eq!(wallet_get_address(handle).await.unwrap(), address);
+            assert_eq!(wallet_get_seed_words(handle, passphrase.clone(), None).await.unwrap(), words);
+            wallet_destroy(handle).await.unwrap();
+
+            // Restoring over the database from its own words is the same wallet
+            let handle = wallet_create(JsWalletConfig {
+                seed_words: Some(words),
+                ..reopen()
+            })
+            .await
+            .unwrap();
+            assert_eq!(wallet_get_address(handle).await.unwrap(), address);
+            wallet_destroy(handle).await.unwrap();
+
+            let other = JsWalletConfig {
+                seed_words: Some(fresh_seed_words()),
+                ..reopen()
+            };
+            let error = wallet_create(other).await.err().unwrap();
+            assert!(error.reason.contains("created from another seed"), "{}", error.reason);
+            fs::remove_dir_all(&config.storage_path).unwrap();
+        }
+
+        // A database with no seed is not silently given a new one
+        let storage_path = test_config().storage_path;
+        fs::create_dir_all(&storage_path).unwrap();
+        rusqlite::Connection::open(wallet_db_path(&storage_path))
+            .unwrap()
+            .execute_batch("CREATE TABLE legacy (id INTEGER)")
+            .unwrap();
+        let config = JsWalletConfig {
+            storage_path: storage_path.clone(),
+            ..test_config()
+        };
+        let error = wallet_create(config).await.err().unwrap();
+        assert!(error.reason.contains("has no seed recorded"), "{}", error.reason);
+        fs::remove_dir_all(&storage_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_restoring_from_exported_seed_words_recovers_the_wallet() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let address = wallet_get_address(handle).await.unwrap();
+        let words = wallet_get_seed_words(handle, String::new(), None).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+
+        let restored = wallet_create(JsWalletConfig {
+            seed_words: Some(words.clone()),
+            ..test_config()
+        })
+        .await
+        .unwrap();
+        assert_eq!(wallet_get_address(restored).await.unwrap(), address);
+        assert_eq!(wallet_get_seed_words(restored, String::new(), None).await.unwrap(), words);
+        wallet_destroy(restored).await.unwrap();
+
+        let config = JsWalletConfig {
+            seed_words: Some(words.clone()),
+            mnemonic_language: Some("english".to_string()),
+            ..test_config()
+        };
+        let restored = wallet_create(config).await.unwrap();
+        assert_eq!(wallet_get_address(restored).await.unwrap(), address);
+        let spanish = Some("spanish".to_string());
+        let spanish_words = wallet_get_seed_words(restored, String::new(), spanish).await.unwrap();
+        assert_ne!(spanish_words, words);
+        wallet_destroy(restored).await.unwrap();
+
+        let restored = wallet_create(JsWalletConfig {
+            seed_words: Some(spanish_words),
+            ..test_config()
+        })
+        .await
+        .unwrap();
+        assert_eq!(wallet_get_address(restored).await.unwrap(), address);
+        wallet_destroy(restored).await.unwrap();
+
+        let config = JsWalletConfig {
+            mnemonic_language: Some("klingon".to_string()),
+            ..test_config()
+        };
+        assert!(wallet_create(config).await.is_err());
+
+        let other = wallet_create(test_config()).await.unwrap();
+        assert_ne!(wallet_get_address(other).await.unwrap(), address);
+        wallet_destroy(other).await.unwrap();
+
+        let mut mistyped = words;
+        mistyped[7] = if mistyped[7] == "zoo" { "zone" } else { "zoo" }.to_string();
+        let config = JsWalletConfig {
+            seed_words: Some(mistyped),
+            ..test_config()
+        };
+        assert!(wallet_create(config).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_new_addresses_are_fresh_and_owned() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let first = wallet_get_new_address(handle).await.unwrap();
+        let second = wallet_get_new_address(handle).await.unwrap();
+        assert_ne!(first, second);
+        assert!(first.starts_with("tari://testnet/"));
+
+        assert!(wallet_is_own_address(handle, first.clone()).await.unwrap());
+        assert!(wallet_is_own_address(handle, second.clone()).await.unwrap());
+        let static_address = wallet_get_address(handle).await.unwrap();
+        assert!(wallet_is_own_address(handle, static_address).await.unwrap());
+        assert!(!wallet_is_own_address(handle, "tari://testnet/other".to_string())
+            .await
+            .unwrap());
+        let words = wallet_get_seed_words(handle, String::new(), None).await.unwrap();
+        wallet_destroy(handle).await.unwrap();
+
+        // The keys behind issued addresses are recovered with the seed
+        let restored = wallet_create(JsWalletConfig {
+            seed_words: Some(words),
+            ..test_config()
+        })
+        .await
+        .unwrap();
+        assert_eq!(wallet_get_new_address(restored).await.unwrap(), first);
+        assert_eq!(wallet_get_new_address(restored).await.unwrap(), second);
+        wallet_destroy(restored).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sends_accept_emoji_ids() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let address = wallet_get_new_address(handle).await.unwrap();
+        let emoji_id = crate::address_to_emoji_id(address.clone()).unwrap();
+
+        assert!(wallet_is_own_address(handle, emoji_id.clone()).await.unwrap());
+        let sent = wallet_send_time_locked_transaction(handle, emoji_id.clone(), "1000".to_string(), 10, None)
+            .await
+            .unwrap();
+        // Resolves to the same public key as the hex form
+        assert_eq!(sent.address, address);
+        wallet_send_transaction(handle, emoji_id.clone(), "1000".to_string(), None, None)
+            .await
+            .unwrap();
+
+        // A damaged emoji ID is refused rather than passed through
+        let truncated: String = emoji_id.chars().skip(1).collect();
+        let result = wallet_send_transaction(handle, truncated, "1000".to_string(), None, None).await;
+        assert!(result.is_err_and(|e| e.status == Status::InvalidArg));
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_send_does_not_spend() {
+        let handle = wallet_create(test_config()).await.unwrap();
+
+        let result = wallet_send_transaction(handle, "recipient".to_string(), "1000".to_string(), None, Some(true))
+            .await
+            .unwrap();
+        match result {
+            Either::B(summary) => {
+                assert!(summary.would_succeed);
+                assert_eq!(summary.fee_estimate, estimate_fee(DEFAULT_FEE_PER_GRAM, 1, 2).to_string());
+                assert_eq!(summary.input_count, 1);
+                assert_eq!(summary.output_count, 2);
+            }
+            Either::A(_) => panic!("dry run returned a transaction id"),
+        }
+
+        let balance = wallet_get_balance(handle).await.unwrap();
+        assert_eq!(balance.available, PLACEHOLDER_BALANCE.to_string());
+
+        let too_much = PLACEHOLDER_BALANCE.to_string();
+        let result = wallet_send_transaction(handle, "recipient".to_string(), too_much, None, Some(true)).await;
+        assert!(result.is_err());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_detailed_send_reports_fee_and_status() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let send = || {
+            let options = JsSendTransactionOptions {
+                fee_per_gram: Some("10".to_string()),
+                message: None,
+                is_one_sided: None,
+                allow_high_fee: None,
+                idempotency_key: Some("order-7".to_string()),
+                covenant: None,
+            };
+            wallet_send_transaction_detailed(handle, "recipient".to_string(), "1000".to_string(), Some(options))
+        };
+
+        let sent = send().await.unwrap();
+        let fee = estimate_fee(10, 1, 2);
+        assert_eq!(sent.amount, "1000");
+        assert_eq!(sent.fee, fee.to_string());
+        assert_eq!(sent.status, JsTransactionStatus::Pending as i32);
+        assert!(sent.timestamp > 0.0);
+
+        let balance = wallet_get_balance(handle).await.unwrap();
+        assert_eq!(balance.available, (PLACEHOLDER_BALANCE - 1000 - fee).to_string());
+
+        // A repeated send reports the original transaction
+        let repeated = send().await.unwrap();
+        assert_eq!(repeated.tx_id, sent.tx_id);
+        assert_eq!(repeated.fee, sent.fee);
+        assert_eq!(repeated.timestamp, sent.timestamp);
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_max_spendable_empties_the_wallet() {
+        let handle = wallet_create(test_config()).await.unwrap();
+
+        let max = wallet_get_max_spendable(handle, None).await.unwrap();
+        let expected = PLACEHOLDER_BALANCE - estimate_fee(DEFAULT_FEE_PER_GRAM, 1, 1);
+        assert_eq!(max, expected.to_string());
+        let cheaper = wallet_get_max_spendable(handle, Some("1".to_string())).await.unwrap();
+        assert!(cheaper.parse::<u64>().unwrap() > expected);
+
+        wallet_send_transaction(handle, "recipient".to_string(), max, None, None)
+            .await
+            .unwrap();
+        assert_eq!(wallet_get_balance(handle).await.unwrap().available, "0");
+        assert_eq!(wallet_get_max_spendable(handle, None).await.unwrap(), "0");
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    /// An output that imports, with `script` run on the spending key's public key
+    fn importable_utxo(amount: u64, spending_key: &str, script: Option<&str>) -> JsImportUtxo {
+        use tari_crypto::tari_utilities::hex;
+
+        let key = RistrettoSecretKey::from_hex(spending_key).unwrap();
+        let proof = crate::crypto::construct_range_proof(&key, amount).unwrap();
+        let sender_offset_key = RistrettoSecretKey::random(&mut rand::thread_rng());
+        let script_bytes = script.and_then(|script| hex::from_hex(script).ok()).unwrap_or_default();
+        JsImportUtxo {
+            amount: amount.to_string(),
+            spending_key: spending_key.to_string(),
+            commitment: crate::crypto::commitment_open(amount.to_string(), spending_key.to_string())
+                .unwrap(),
+            range_proof: hex::to_hex(&proof),
+            sender_offset_public_key: RistrettoPublicKey::from_secret_key(&sender_offset_key).to_hex(),
+            metadata_signature: crate::crypto::sign_output_metadata(
+                amount,
+                &key,
+                &sender_offset_key,
+                &script_bytes,
+            ),
+            script: script.map(str::to_string),
+            input_data: script.map(|_| {
+                vec![format!("PublicKey({})", RistrettoPublicKey::from_secret_key(&key).to_hex())]
+            }),
+            source_address: None,
+            message: Some("paper wallet".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_imported_utxo_must_open_its_commitment() {
+        const SPENDING_KEY: &str = "1f0d3c5e2bd7a6f0a8a2e4b1c9d0e3f45a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d";
+        let handle = wallet_create(test_config()).await.unwrap();
+        let utxo = |amount: &str, script: Option<&str>| JsImportUtxo {
+            amount: amount.to_string(),
+            ..importable_utxo(5000, SPENDING_KEY, script)
+        };
+
+        let error = wallet_import_utxo(handle, utxo("6000", None)).await.unwrap_err();
+        assert!(error.reason.contains("does not open to 6000"));
+        assert!(wallet_import_utxo(handle, utxo("5000", Some("zz"))).await.is_err());
+        assert!(wallet_import_utxo(handle, utxo("5000", Some(""))).await.is_err());
+        assert!(wallet_import_utxo(handle, utxo("0", None)).await.is_err());
+        let bad_proof = JsImportUtxo {
+            range_proof: "00".repeat(64),
+            ..utxo("5000", None)
+        };
+        let error = wallet_import_utxo(handle, bad_proof).await.unwrap_err();
+        assert!(error.reason.contains("Range proof does not verify"));
+        assert_eq!(
+            wallet_get_balance(handle).await.unwrap().available,
+            PLACEHOLDER_BALANCE.to_string()
+        );
+
+        let tx_id = wallet_import_utxo(handle, utxo("5000", Some("73"))).await.unwrap();
+        assert!(wallet_get_transaction_details(handle, tx_id).await.unwrap().is_inbound);
+        assert_eq!(
+            wallet_refresh_balance(handle).await.unwrap().available,
+            (PLACEHOLDER_BALANCE + 5000).to_string()
+        );
+
+        let duplicate = wallet_import_utxo(handle, utxo("5000", None)).await.unwrap_err();
+        assert!(duplicate.reason.contains("already been imported"));
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_imported_utxo_must_carry_a_valid_signature_and_script() {
+        const SPENDING_KEY: &str = "1f0d3c5e2bd7a6f0a8a2e4b1c9d0e3f45a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d";
+        const NOP: &str = "73";
+        let handle = wallet_create(test_config()).await.unwrap();
+        let utxo = || importable_utxo(5000, SPENDING_KEY, Some(NOP));
+        let import_error = |utxo| async move { wallet_import_utxo(handle, utxo).await.unwrap_err().reason };
+
+        // A tampered signature, or one made for another script or sender offset key
+        let mut tampered = utxo();
+        let last = tampered.metadata_signature.pop().unwrap();
+        tampered.metadata_signature.push(if last == '0' { '1' } else { '0' });
+        assert!(import_error(tampered).await.contains("Metadata signature does not verify"));
+        let other_script = JsImportUtxo {
+            script: Some(format!("{}{}", NOP, NOP)),
+            ..utxo()
+        };
+        assert!(import_error(other_script).await.contains("Metadata signature does not verify"));
+        let other_offset_key = JsImportUtxo {
+            sender_offset_public_key: importable_utxo(5000, SPENDING_KEY, Some(NOP)).sender_offset_public_key,
+            ..utxo()
+        };
+        assert!(import_error(other_offset_key).await.contains("Metadata signature does not verify"));
+        let malformed = JsImportUtxo {
+            metadata_signature: "00".repeat(10),
+            ..utxo()
+        };
+        assert!(import_error(malformed).await.contains("Invalid metadata signature"));
+
+        // Input data that does not resolve the script to the spending key
+        let stranger = RistrettoPublicKey::from_secret_key(&RistrettoSecretKey::random(&mut rand::thread_rng()));
+        let wrong_key = JsImportUtxo {
+            input_data: Some(vec![format!("PublicKey({})", stranger.to_hex())]),
+            ..utxo()
+        };
+        assert!(import_error(wrong_key).await.contains("not the spending key's public key"));
+        let no_key = JsImportUtxo {
+            input_data: Some(vec!["Number(1)".to_string()]),
+            ..utxo()
+        };
+        assert!(import_error(no_key).await.contains("expected a public key"));
+        let missing = JsImportUtxo {
+            input_data: None,
+            ..utxo()
+        };
+        assert!(import_error(missing).await.contains("empty stack"));
+        let without_script = JsImportUtxo {
+            input_data: utxo().input_data,
+            ..importable_utxo(5000, SPENDING_KEY, None)
+        };
+        assert!(import_error(without_script).await.contains("without the script"));
+
+        assert_eq!(
+            wallet_get_balance(handle).await.unwrap().available,
+            PLACEHOLDER_BALANCE.to_string()
+        );
+        wallet_import_utxo(handle, utxo()).await.unwrap();
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_amount_plus_fee_overflow_is_rejected() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let max = u64::MAX.to_string();
+
+        for dry_run in [Some(true), None] {
+            let error = wallet_send_transaction(handle, "recipient".to_string(), max.clone(), None, dry_run)
+                .await
+                .err()
+                .unwrap();
+            assert!(error.reason.contains("amount overflow"));
+        }
+        let error = wallet_send_time_locked_transaction(handle, "recipient".to_string(), max, 0, None)
+            .await
+            .err()
+            .unwrap();
+        assert!(error.reason.contains("amount overflow"));
+
+        assert_eq!(estimate_fee(u64::MAX, 1, 2), u64::MAX);
+        assert_eq!(total_with_fee(1000, 635).unwrap(), 1635);
+        assert!(total_with_fee(1, u64::MAX).is_err());
+
+        let balance = wallet_get_balance(handle).await.unwrap();
+        assert_eq!(balance.available, PLACEHOLDER_BALANCE.to_string());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_decimal_fee_per_gram_rounds_up() {
+        let rate = |fee: &str| parse_fee_per_gram(Some(fee)).unwrap();
+        // One input and two outputs weigh 124 grams
+        assert_eq!(estimate_fee(rate("0.25"), 1, 2), 31);
+        assert_eq!(estimate_fee(rate("0.001"), 1, 2), 1);
+        assert_eq!(estimate_fee(rate("1.000001"), 1, 2), 125);
+        assert_eq!(estimate_fee(rate("0.000000"), 1, 2), 0);
+        assert_eq!(rate("5"), FeePerGram::from(5));
+        assert_eq!(rate("2.50").to_string(), "2.5");
+        assert_eq!(rate("0.000001").to_string(), "0.000001");
+
+        for invalid in ["", "1.", ".5", "-1", "1e3", "1.2.3", "one"] {
+            assert!(parse_fee_per_gram(Some(invalid)).is_err(), "{} was accepted", invalid);
+        }
+        let Err(error) = parse_fee_per_gram(Some("0.1234567")) else {
+            panic!("an inexact fee per gram was accepted");
+        };
+        assert!(error.to_string().contains("more than 6 decimal places"));
+
+        let handle = wallet_create(test_config()).await.unwrap();
+        let options = JsSendTransactionOptions {
+            fee_per_gram: Some("0.25".to_string()),
+            message: None,
+            is_one_sided: None,
+            allow_high_fee: None,
+            idempotency_key: None,
+            covenant: None,
+        };
+        let sent = wallet_send_transaction_detailed(handle, "recipient".to_string(), "1000".to_string(), Some(options))
+            .await
+            .unwrap();
+        assert_eq!(sent.fee, "31");
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sends_attach_covenants() {
+        let handle = wallet_create(test_config()).await.unwrap();
+        let covenant = crate::advanced::create_covenant(serde_json::json!({
+            "type": "field_eq",
+            "field": "features_maturity",
+            "value": 300
+        }))
+        .unwrap();
+        let options = |covenant| JsSendTransactionOptions {
+            fee_per_gram: None,
+            message: None,
+            is_one_sided: None,
+            allow_high_fee: None,
+            idempotency_key: None,
+            covenant: Some(covenant),
+        };
+
+        let sent = wallet_send_transaction_detailed(
+            handle,
+            "recipient".to_string(),
+            "1000".to_string(),
+            Some(options(covenant)),
+        )
+        .await
+        .unwrap();
+        let wallet_arc = get_wallet(handle).await.unwrap();
+        let attached = read_wallet(&wallet_arc).await.outbound_transactions[&sent.tx_id]
+            .covenant
+            .clone();
+        assert_eq!(attached, Some(get_covenant(covenant).unwrap().to_bytes()));
+
+        crate::advanced::covenant_destroy(covenant).unwrap();
+        let Err(error) = wallet_send_transaction_detailed(
+            handle,
+            "recipient".to_string(),
+            "1000".to_string(),
+            Some(options(covenant)),
+        )
+        .await
+        else {
+            panic!("a destroyed covenant was attached");
+        };
+        assert!(error.reason.contains("Invalid covenant handle"));
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_sends_and_balance_reads() {
+        const WALLETS: usize = 4;
+        const SENDS_PER_WALLET: u64 = 25;
+        const AMOUNT: u64 = 100;
+
+        let mut handles = Vec::new();
+        for _ in 0..WALLETS {
+            handles.push(wallet_create(test_config()).await.unwrap());
+        }
+
+        let mut tasks = Vec::new();
+        for &handle in &handles {
+            tasks.push(tokio::spawn(async move {
+                for _ in 0..SENDS_PER_WALLET {
+                    wallet_send_transaction(handle, "recipient".to_string(), AMOUNT.to_string(), None, None)
+                        .await
+                        .unwrap();
+                }
+            }));
+            tasks.push(tokio::spawn(async move {
+                for _ in 0..SENDS_PER_WALLET {
+                    wallet_get_balance(handle).await.unwrap();
+                    tokio::task::yield_now().await;
+                }
+            }));
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let spent = SENDS_PER_WALLET * (AMOUNT + estimate_fee(DEFAULT_FEE_PER_GRAM, 1, 2));
+        for handle in handles {
+            let balance = wallet_get_balance(handle).await.unwrap();
+            assert_eq!(balance.available, (PLACEHOLDER_BALANCE - spent).to_string());
+            wallet_destroy(handle).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_burn_funds_deducts_balance() {
+        let handle = wallet_create(test_config()).await.unwrap();
+
+        let message = Some("claim on layer two".to_string());
+        let burn = wallet_burn_funds(handle, "1000".to_string(), Some("1".to_string()), message)
+            .await
+            .unwrap();
+        assert_eq!(burn.commitment.len(), 64);
+        assert!(range_proof_verifies(&burn.commitment, &burn.range_proof).unwrap());
+        // The caller can open the commitment to prove what was burnt
+        assert!(commitment_opens(&burn.commitment, 1000, &burn.blinding_factor).unwrap());
+        assert!(!commitment_opens(&burn.commitment, 1001, &burn.blinding_factor).unwrap());
+        let wallet_arc = get_wallet(handle).await.unwrap();
+        let info = read_wallet(&wallet_arc).await.transaction_info(&burn.tx_id).unwrap();
+        assert_eq!(info.message, "claim on layer two");
+
+        let balance = wallet_get_balance(handle).await.unwrap();
+        let expected = PLACEHOLDER_BALANCE - 1000 - estimate_fee(1, 1, 2);
+        assert_eq!(balance.available, expected.to_string());
+
+        wallet_destroy(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_burn_funds_validates_amount() {
+        let handle = wallet_create(test_config()).await.unwrap();
+
+        assert!(wallet_burn_funds(handle, "0".to_string(), None, None).await.is_err());
+        let too_much = (PLACEHOLDER_BALANCE + 1).to_string();
+        let result = wallet_burn_funds(handle, too_much, None, None).await;
+        assert!(result.err().unwrap().reason.contains("Insufficient funds"));
+
+        wallet_destroy(handle).await.unwrap();
+    }
 }
@@ -35,7 +35,7 @@ pub fn store_credential(item: WindowsCredentialItem) -> Result<()> {
     
     // Store the encrypted credential
     credential_manager::store_credential(
-      &item.target,
+      &crate::namespaced_key(&item.target),
       &item.username,
       &encrypted_data,
       item.comment.as_deref(),
@@ -64,7 +64,7 @@ pub fn get_credential(env: Env, target: String) -> Result<Option<JsBuffer>> {
     use crate::windows::credential_manager;
     use crate::windows::dpapi;
     
-    match credential_manager::retrieve_credential(&target) {
+    match credential_manager::retrieve_credential(&crate::namespaced_key(&target)) {
       Ok((_username, encrypted_data)) => {
         // Decrypt the password using DPAPI
         match dpapi::decrypt_data(&encrypted_data) {
@@ -98,7 +98,7 @@ pub fn delete_credential(target: String) -> Result<()> {
   {
     use crate::windows::credential_manager;
     
-    credential_manager::delete_credential(&target)
+    credential_manager::delete_credential(&crate::namespaced_key(&target))
       .map_err(|e| napi::Error::new(
         napi::Status::GenericFailure,
         format!("Failed to delete credential: {}", e)
@@ -123,7 +123,7 @@ pub fn credential_exists(target: String) -> Result<bool> {
   {
     use crate::windows::credential_manager;
     
-    match credential_manager::retrieve_credential(&target) {
+    match credential_manager::retrieve_credential(&crate::namespaced_key(&target)) {
       Ok(_) => Ok(true),
       Err(_) => Ok(false),
     }
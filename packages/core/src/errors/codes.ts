@@ -281,6 +281,8 @@ export enum WalletErrorCode {
   InsufficientUtxos = 2041,
   UtxoValidationFailed = 2042,
   InsufficientFundsWithMargin = 2043,
+  WatchOnly = 2044,
+  MessageTooLong = 2045,
   
   // FFI errors continued
   FFIOperationFailed = 5018,
@@ -363,6 +365,8 @@ export const ERROR_CATEGORIES: Record<WalletErrorCode, ErrorCategory> = {
   [WalletErrorCode.AutoRefreshFailed]: ErrorCategory.Transaction,
   [WalletErrorCode.InsufficientFundsWithMargin]: ErrorCategory.Transaction,
   [WalletErrorCode.MemoOperationFailed]: ErrorCategory.Transaction,
+  [WalletErrorCode.WatchOnly]: ErrorCategory.Transaction,
+  [WalletErrorCode.MessageTooLong]: ErrorCategory.Transaction,
   
   // Network errors (3000-3099)
   [WalletErrorCode.NetworkUnavailable]: ErrorCategory.Network,
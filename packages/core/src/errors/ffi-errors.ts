@@ -6,7 +6,7 @@
  */
 
 import { WalletError, ErrorContext, createWalletError, wrapError } from './wallet-error';
-import { WalletErrorCode } from './codes';
+import { WalletErrorCode, ErrorCategory } from './codes';
 import type { NativeErrorInfo } from '../ffi/native';
 
 /**
@@ -179,6 +179,11 @@ export interface FFIErrorInfo {
   code: number;
   /** Error message from FFI */
   message: string;
+  /**
+   * Error category reported by the NAPI module, such as `Network` or
+   * `Validation`. When present, `code` is already a WalletErrorCode.
+   */
+  kind?: ErrorCategory;
   /** Whether the error is recoverable */
   recoverable?: boolean;
   /** Additional context from FFI */
@@ -187,10 +192,28 @@ export interface FFIErrorInfo {
   nativeStackTrace?: string;
 }
 
+/**
+ * `[code:Kind] message` prefix the NAPI module puts on its errors
+ */
+const NAPI_ERROR_PATTERN = /^\[(\d+):(\w+)\] ([\s\S]*)$/;
+
 /**
  * Extract error information from various FFI error formats
  */
 export function extractFFIErrorInfo(error: unknown): FFIErrorInfo {
+  // Handle errors thrown by the NAPI module, whose `code` is the NAPI status
+  if (error instanceof Error) {
+    const napiMatch = error.message.match(NAPI_ERROR_PATTERN);
+    if (napiMatch) {
+      return {
+        code: parseInt(napiMatch[1], 10),
+        kind: napiMatch[2] as ErrorCategory,
+        message: napiMatch[3],
+        nativeStackTrace: error.stack,
+      };
+    }
+  }
+
   // Handle NativeErrorInfo from FFI
   if (error && typeof error === 'object' && 'code' in error) {
     const nativeError = error as NativeErrorInfo;
@@ -247,7 +270,9 @@ export function createFFIError(
   ffiError: FFIErrorInfo,
   context?: Partial<ErrorContext>
 ): WalletError {
-  const walletCode = translateFFIErrorCode(ffiError.code);
+  const walletCode = ffiError.kind
+    ? (ffiError.code as WalletErrorCode)
+    : translateFFIErrorCode(ffiError.code);
   const details = ffiError.message;
   
   const errorContext: ErrorContext = {
@@ -255,6 +280,7 @@ export function createFFIError(
     component: 'FFI',
     metadata: {
       ffiErrorCode: ffiError.code,
+      ...(ffiError.kind && { errorKind: ffiError.kind }),
       recoverable: ffiError.recoverable,
       nativeContext: ffiError.context,
       ...(ffiError.nativeStackTrace && { nativeStackTrace: ffiError.nativeStackTrace }),
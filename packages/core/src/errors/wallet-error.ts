@@ -130,6 +130,8 @@ export class WalletError extends Error {
       [WalletErrorCode.TransactionExpired]: 'Transaction has expired',
       [WalletErrorCode.DuplicateTransaction]: 'Duplicate transaction detected',
       [WalletErrorCode.TransactionRejected]: 'Transaction rejected by network',
+      [WalletErrorCode.WatchOnly]: 'Watch-only wallets cannot spend funds',
+      [WalletErrorCode.MessageTooLong]: 'Transaction message is too long',
       
       // Network errors
       [WalletErrorCode.NetworkUnavailable]: 'Network is unavailable',
@@ -16,12 +16,19 @@ interface MockWalletState {
     pending_incoming: string;
     pending_outgoing: string;
     timelocked: string;
+    stale: boolean;
+    last_updated_at?: number;
+    synced: boolean;
   };
   address: string;
+  issuedAddresses: string[];
   seedWords: string[];
   destroyed: boolean;
   eventCallback?: (payload: string) => void;
+  transactionReceivedCallback?: (transaction: any) => void;
+  outputManagerEventCallback?: (event: any) => void;
   transactionMemos: Map<string, string>;
+  transactionLabels: Map<string, string>;
   transactions: Array<{
     id: string;
     amount: string;
@@ -49,15 +56,31 @@ interface MockWalletState {
   }>;
 }
 
+// Chain tip height mock recoveries scan up to
+const MOCK_TIP_HEIGHT = 500000;
+
 /**
  * Mock native module implementation
  */
 class MockNativeBindings implements NativeBindings {
   private wallets = new Map<number, MockWalletState>();
   private nextHandle = 1;
+  private destroyedHandles = new Set<number>();
   private shouldFail = false;
   private failureRate = 0;
   private latency = 0;
+  private objects = new Map<number, { kind: string; value: any }>();
+  private nextObjectHandle = 1;
+  private runtimeWorkerThreads = 4;
+  private runtimeStackSizeBytes = 3 * 1024 * 1024;
+  private logCallback?: (record: any) => void;
+  private recoveries = new Map<number, any>();
+  private recoveryCheckpoints = new Map<
+    number,
+    { scannedHeight: number; utxosFound: number; birthdayHeight: number }
+  >();
+  private syncedHeights = new Map<number, number>();
+  private maxCallbacks = 100;
 
   // Logging functions
   async init_logging(level: number): Promise<void> {
@@ -83,11 +106,15 @@ class MockNativeBindings implements NativeBindings {
         pending_incoming: '0',
         pending_outgoing: '0',
         timelocked: '0',
+        stale: false,
+        synced: true,
       },
       address: this.generateMockAddress(handle),
+      issuedAddresses: [],
       seedWords: this.generateMockSeedWords(),
       destroyed: false,
       transactionMemos: new Map(),
+      transactionLabels: new Map(),
       transactions: [],
       pendingInbound: [],
       pendingOutbound: [],
@@ -104,11 +131,16 @@ class MockNativeBindings implements NativeBindings {
 
     const wallet = this.wallets.get(handle);
     if (!wallet) {
+      // Destroying an already destroyed wallet is a no-op, as in the native module
+      if (this.destroyedHandles.has(handle)) {
+        return;
+      }
       throw new Error(`Mock wallet handle ${handle} not found`);
     }
 
     wallet.destroyed = true;
     this.wallets.delete(handle);
+    this.destroyedHandles.add(handle);
   }
 
   // Wallet operations
@@ -117,6 +149,9 @@ class MockNativeBindings implements NativeBindings {
     pending_incoming: string;
     pending_outgoing: string;
     timelocked: string;
+    stale: boolean;
+    last_updated_at?: number;
+    synced: boolean;
   }> {
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock balance query failed');
@@ -127,6 +162,18 @@ class MockNativeBindings implements NativeBindings {
     return wallet.balance;
   }
 
+  async walletRefreshBalance(handle: number): Promise<{
+    available: string;
+    pending_incoming: string;
+    pending_outgoing: string;
+    timelocked: string;
+    stale: boolean;
+    last_updated_at?: number;
+    synced: boolean;
+  }> {
+    return this.walletGetBalance(handle);
+  }
+
   async walletGetAddress(handle: number): Promise<string> {
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock address query failed');
@@ -137,18 +184,41 @@ class MockNativeBindings implements NativeBindings {
     return wallet.address;
   }
 
+  async walletGetNewAddress(handle: number): Promise<string> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock address generation failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    const address = `tari://testnet/mock_address_${handle}_${wallet.issuedAddresses.length}`;
+    wallet.issuedAddresses.push(address);
+    return address;
+  }
+
+  async walletIsOwnAddress(handle: number, address: string): Promise<boolean> {
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    return address === wallet.address || wallet.issuedAddresses.includes(address);
+  }
+
   async walletSendTransaction(
     handle: number,
     recipient: string,
     amount: string,
-    options?: any
-  ): Promise<string> {
+    options?: any,
+    dryRun?: boolean
+  ): Promise<any> {
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock transaction failed');
     }
     await this.simulateLatency();
 
     const wallet = this.getWallet(handle);
+    if (wallet.config?.view_key) {
+      throw new Error('Watch-only wallets hold no spending keys');
+    }
     
     // Validate amount
     const amountNum = parseInt(amount, 10);
@@ -158,6 +228,15 @@ class MockNativeBindings implements NativeBindings {
       throw new Error('Insufficient funds');
     }
 
+    if (dryRun) {
+      return {
+        feeEstimate: '625',
+        inputCount: 1,
+        outputCount: 2,
+        wouldSucceed: true,
+      };
+    }
+
     // Update balance
     wallet.balance.available = (availableNum - amountNum).toString();
     wallet.balance.pending_outgoing = (parseInt(wallet.balance.pending_outgoing, 10) + amountNum).toString();
@@ -165,16 +244,229 @@ class MockNativeBindings implements NativeBindings {
     return `mock_tx_${Date.now()}_${Math.random().toString(36).substr(2, 9)}`;
   }
 
-  async walletGetSeedWords(handle: number): Promise<string[]> {
+  async walletSendTransactionDetailed(
+    handle: number,
+    recipient: string,
+    amount: string,
+    options?: any
+  ): Promise<any> {
+    const txId = await this.walletSendTransaction(handle, recipient, amount, options);
+    return {
+      txId,
+      amount,
+      fee: '625',
+      status: 0,
+      timestamp: Math.floor(Date.now() / 1000),
+    };
+  }
+
+  async walletBuildUnsignedTransaction(
+    handle: number,
+    recipientAddress: string,
+    amount: string,
+    feePerGram?: string
+  ): Promise<string> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock build unsigned transaction failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    if (parseInt(amount, 10) > parseInt(wallet.balance.available, 10)) {
+      throw new Error('Insufficient funds');
+    }
+    return JSON.stringify({
+      format: 'tari-offline-tx',
+      version: 1,
+      network: 'testnet',
+      senderPublicKey: 'mock_public_key',
+      recipientAddress,
+      amount,
+      feePerGram: feePerGram ?? '5',
+      fee: '635',
+      nonce: Math.random().toString(16).substr(2),
+    });
+  }
+
+  async walletSignTransaction(handle: number, unsignedTransaction: string): Promise<string> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock sign transaction failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return JSON.stringify({
+      transaction: JSON.parse(unsignedTransaction),
+      signature: 'mock_signature',
+    });
+  }
+
+  async walletBroadcastSignedTransaction(handle: number, signedTransaction: string): Promise<string> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock broadcast signed transaction failed');
+    }
+
+    const { transaction, signature } = JSON.parse(signedTransaction);
+    if (!signature) {
+      throw new Error('Malformed signed transaction: missing signature');
+    }
+    return this.walletSendTransaction(handle, transaction.recipientAddress, transaction.amount);
+  }
+
+  async walletSendBatch(
+    handle: number,
+    payments: Array<{ destination: string; amount: string; message?: string }>,
+    feePerGram?: string
+  ): Promise<Array<{ txId?: string; error?: string }>> {
+    const results: Array<{ txId?: string; error?: string }> = [];
+    for (const payment of payments) {
+      try {
+        const txId = await this.walletSendTransaction(handle, payment.destination, payment.amount);
+        results.push({ txId });
+      } catch (error) {
+        results.push({ error: error instanceof Error ? error.message : String(error) });
+      }
+    }
+    return results;
+  }
+
+  async walletGetBroadcastStatus(handle: number, transactionId: string): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock broadcast status failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return {
+      retryCount: 0,
+      lastBroadcastAt: Math.floor(Date.now() / 1000),
+      seenByNetwork: true,
+    };
+  }
+
+  async walletRebroadcastPending(handle: number): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock rebroadcast failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return { attempted: 0, succeeded: 0, failed: 0, unacknowledged: 0 };
+  }
+
+  async walletGetFeeRecommendations(handle: number): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock fee recommendation failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return { slow: '2', normal: '5', fast: '10', source: 'fallback' };
+  }
+
+  async walletGetUtxos(handle: number, statusFilter?: string): Promise<any[]> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock UTXO query failed');
+    }
+    await this.simulateLatency();
+
+    const filter = statusFilter?.toLowerCase() ?? 'unspent';
+    if (!['unspent', 'spent', 'unconfirmed', 'all'].includes(filter)) {
+      throw new Error(`Invalid UTXO status filter: ${statusFilter}`);
+    }
+    const wallet = this.getWallet(handle);
+    if (filter === 'spent' || filter === 'unconfirmed' || wallet.balance.available === '0') {
+      return [];
+    }
+    return [{
+      amount: wallet.balance.available,
+      commitment: '0'.repeat(64),
+      features: 0,
+      maturity: '0',
+      status: 0,
+    }];
+  }
+
+  async walletGetUtxoSummary(handle: number): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock UTXO summary failed');
+    }
+    await this.simulateLatency();
+
+    const utxos = await this.walletGetUtxos(handle, 'all');
+    const total = utxos.reduce((sum, utxo) => sum + BigInt(utxo.amount), 0n);
+    return {
+      count: utxos.length,
+      totalValue: total.toString(),
+      unspentValue: total.toString(),
+      spendableCount: utxos.length,
+    };
+  }
+
+  async walletGetMaxSpendable(handle: number, feePerGram?: string): Promise<string> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock max spendable failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    // One input and no change output: 10 + 8 + 53 grams
+    const fee = BigInt(feePerGram ?? '5') * 71n;
+    const available = BigInt(wallet.balance.available);
+    return (available > fee ? available - fee : 0n).toString();
+  }
+
+  async walletGetDustUtxos(handle: number): Promise<any[]> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock dust UTXO query failed');
+    }
+    await this.simulateLatency();
+
+    // Spending one 8 gram input at the normal 5 µT per gram
+    const threshold = 40n;
+    const utxos = await this.walletGetUtxos(handle, 'unspent');
+    return utxos.filter((utxo) => BigInt(utxo.amount) <= threshold);
+  }
+
+  async walletGetTotalDustValue(handle: number): Promise<string> {
+    const dust = await this.walletGetDustUtxos(handle);
+    return dust.reduce((sum, utxo) => sum + BigInt(utxo.amount), 0n).toString();
+  }
+
+  async walletGetSeedWords(handle: number, passphrase: string, language?: string): Promise<string[]> {
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock seed words query failed');
     }
     await this.simulateLatency();
 
     const wallet = this.getWallet(handle);
+    if (wallet.config?.view_key) {
+      throw new Error('Watch-only wallets hold no spending keys');
+    }
+    if ((wallet.config?.passphrase ?? '') !== passphrase) {
+      throw new Error('Invalid passphrase');
+    }
     return wallet.seedWords;
   }
 
+  async validateSeedWords(words: string[], language?: string): Promise<string> {
+    await this.simulateLatency();
+
+    // Only the mock's own words decode; the mock has no cipher seed to check others against
+    const known = this.generateMockSeedWords();
+    if (words.length !== known.length) {
+      throw new Error(`Seed words must contain exactly ${known.length} words`);
+    }
+    const unknown = words.find((word) => !known.includes(word.trim().toLowerCase()));
+    if (unknown !== undefined) {
+      throw new Error(`Not a seed word: ${unknown}`);
+    }
+    if (language !== undefined && language !== 'english') {
+      throw new Error('Seed words could not be deciphered');
+    }
+    return 'english';
+  }
+
   async walletSetBaseNode(handle: number, baseNode: any): Promise<void> {
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock base node configuration failed');
@@ -184,6 +476,138 @@ class MockNativeBindings implements NativeBindings {
     this.getWallet(handle); // Validate handle exists
   }
 
+  async walletConnectToBaseNode(handle: number, address: string): Promise<boolean> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock base node connection failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    if (!address.includes('::/')) {
+      throw new Error(`Invalid base node peer ${address}: expected <public key>::<multiaddr>`);
+    }
+    return true;
+  }
+
+  async walletDisconnectFromBaseNode(handle: number, publicKey: string): Promise<void> {
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+  }
+
+  async walletGetNetworkStatus(handle: number): Promise<any> {
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return 'online';
+  }
+
+  async walletOnConnectivityChanged(handle: number, callback: (status: any) => void): Promise<void> {
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+  }
+
+  async walletGetConnectionStats(handle: number): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock connection stats failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return {
+      totalNodes: 1,
+      connectedNodes: 1,
+      activeNode: '/ip4/127.0.0.1/tcp/18189',
+      averageLatencyMs: undefined,
+      maxConnections: 8,
+      listenerAddress: '127.0.0.1:18188',
+    };
+  }
+
+  async walletGetBirthday(handle: number): Promise<number | null> {
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    return wallet.config?.seed_words || wallet.config?.view_key ? null : MOCK_TIP_HEIGHT;
+  }
+
+  async walletGetChainTip(handle: number): Promise<number> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('No base node is connected to report the chain tip');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return MOCK_TIP_HEIGHT;
+  }
+
+  async walletBanBaseNode(handle: number, publicKey: string, durationSeconds?: number): Promise<void> {
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+  }
+
+  async walletIsPeerBanned(handle: number, publicKey: string): Promise<boolean> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock is peer banned failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return false;
+  }
+
+  async walletGetPeerReputations(handle: number): Promise<any[]> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock get peer reputations failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return [];
+  }
+
+  async walletCheckBaseNodeHealth(handle: number): Promise<any[]> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock base node health check failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return [];
+  }
+
+  // Database backup and restore
+  async walletBackupDatabase(handle: number, destinationPath: string): Promise<void> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock database backup failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+  }
+
+  async walletRestoreDatabase(storagePath: string, sourcePath: string, force?: boolean): Promise<void> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock database restore failed');
+    }
+    await this.simulateLatency();
+
+    const isOpen = Array.from(this.wallets.values()).some(
+      wallet => wallet.config?.storage_path === storagePath
+    );
+    if (isOpen && !force) {
+      throw new Error(`Wallet at ${storagePath} is open; pass force to overwrite its database`);
+    }
+  }
+
+  walletExists(storagePath: string): boolean {
+    return Array.from(this.wallets.values()).some(
+      wallet => wallet.config?.storage_path === storagePath
+    );
+  }
+
   // Utility functions
   async walletGetActiveHandleCount(): Promise<number> {
     await this.simulateLatency();
@@ -203,6 +627,17 @@ class MockNativeBindings implements NativeBindings {
     return count;
   }
 
+  // Build information
+  getBuildInfo(): any {
+    return {
+      sdkVersion: '0.0.1',
+      tariCoreVersion: '4.3.1',
+      network: 'testnet',
+      gitCommit: 'unknown',
+      buildProfile: 'debug',
+    };
+  }
+
   // Address utilities
   async validateAddress(address: string, network: string): Promise<boolean> {
     if (this.shouldSimulateFailure()) {
@@ -232,15 +667,49 @@ class MockNativeBindings implements NativeBindings {
     return this.generateMockEmojiId(hash);
   }
 
+  microTariToTari(amount: string): string {
+    if (!/^\d+$/.test(amount)) {
+      throw new Error(`Malformed amount: ${amount}`);
+    }
+    const microTari = BigInt(amount);
+    const whole = microTari / 1_000_000n;
+    const fraction = (microTari % 1_000_000n).toString().padStart(6, '0').replace(/0+$/, '');
+    return fraction ? `${whole}.${fraction}` : whole.toString();
+  }
+
+  tariToMicroTari(amount: string): string {
+    const match = /^(\d+)(?:\.(\d{1,6}))?$/.exec(amount);
+    if (!match) {
+      throw new Error(`Malformed amount: ${amount}`);
+    }
+    const [, whole, fraction = ''] = match;
+    return (BigInt(whole) * 1_000_000n + BigInt(fraction.padEnd(6, '0'))).toString();
+  }
+
   // Event callbacks (Phase 8) - Mock implementations
-  async walletSetEventCallback(handle: number, callback: (payload: string) => void): Promise<void> {
+  async walletSetEventCallback(
+    handle: number,
+    callback: (payload: string) => void,
+    eventTypes?: string[]
+  ): Promise<void> {
     await this.simulateLatency();
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock: Failed to set event callback');
     }
 
     const wallet = this.getWallet(handle);
-    wallet.eventCallback = callback;
+    const registered = Array.from(this.wallets.values()).filter(w => w.eventCallback).length;
+    if (!wallet.eventCallback && registered >= this.maxCallbacks) {
+      throw new Error(`Callback limit of ${this.maxCallbacks} reached`);
+    }
+    const wanted = eventTypes?.length ? new Set(eventTypes) : undefined;
+    wallet.eventCallback = wanted
+      ? (payload: string) => {
+          if (wanted.has(JSON.parse(payload).event_type)) {
+            callback(payload);
+          }
+        }
+      : callback;
     
     // Simulate a test event - use synchronous callback in test environment
     if (process.env.NODE_ENV === 'test' || process.env.JEST_WORKER_ID) {
@@ -281,6 +750,21 @@ class MockNativeBindings implements NativeBindings {
     wallet.eventCallback = undefined;
   }
 
+  walletOnTransactionReceived(handle: number, callback: (transaction: any) => void): void {
+    const wallet = this.getWallet(handle);
+    wallet.transactionReceivedCallback = callback;
+  }
+
+  walletSubscribeOutputManagerEvents(handle: number, callback: (event: any) => void): void {
+    const wallet = this.getWallet(handle);
+    wallet.outputManagerEventCallback = callback;
+  }
+
+  walletUnsubscribeOutputManagerEvents(handle: number): void {
+    const wallet = this.getWallet(handle);
+    wallet.outputManagerEventCallback = undefined;
+  }
+
   async getCallbackStats(): Promise<{ registeredWallets: number; activeCallbacks: number }> {
     await this.simulateLatency();
     if (this.shouldSimulateFailure()) {
@@ -302,9 +786,18 @@ class MockNativeBindings implements NativeBindings {
 
     for (const wallet of this.wallets.values()) {
       wallet.eventCallback = undefined;
+      wallet.transactionReceivedCallback = undefined;
+      wallet.outputManagerEventCallback = undefined;
     }
   }
 
+  setMaxCallbacks(max: number): void {
+    if (max < 1) {
+      throw new Error('Callback limit must be at least 1');
+    }
+    this.maxCallbacks = max;
+  }
+
   async emojiIdToPublicKey(emojiId: string): Promise<string> {
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock emoji ID to public key conversion failed');
@@ -584,6 +1077,84 @@ class MockNativeBindings implements NativeBindings {
     return JSON.stringify({ confirmations: 3, required: 1 });
   }
 
+  async walletGetTransactionDetails(handle: number, transactionId: string): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock get transaction details failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    return {
+      txId: transactionId,
+      isInbound: false,
+      kernels: 1,
+      inputs: 1,
+      outputs: 2,
+      maturityHeight: undefined,
+      confirmations: 0,
+      blockHeight: undefined,
+      kernelSignature: undefined,
+      message: undefined,
+      localLabel: wallet.transactionLabels.get(transactionId),
+    };
+  }
+
+  async walletSetTransactionLabel(handle: number, transactionId: string, label?: string): Promise<void> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock set transaction label failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    const trimmed = label?.trim();
+    if (trimmed && trimmed.length > 256) {
+      throw new Error('Label is longer than 256 characters');
+    }
+    if (trimmed) {
+      wallet.transactionLabels.set(transactionId, trimmed);
+    } else {
+      wallet.transactionLabels.delete(transactionId);
+    }
+  }
+
+  async walletGetTransactionLabel(handle: number, transactionId: string): Promise<string | null> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock get transaction label failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    return wallet.transactionLabels.get(transactionId) ?? null;
+  }
+
+  async walletSearchTransactions(
+    handle: number,
+    filter: any,
+    offset?: number,
+    limit?: number
+  ): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock search transactions failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return { transactions: [], total: 0, offset: offset ?? 0, hasMore: false };
+  }
+
+  async walletExportTransactions(handle: number, format: string, filter?: any): Promise<string> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock export transactions failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    if (format === 'json') {
+      return '[]';
+    }
+    return 'id,timestamp,direction,amount,fee,status,address,message,label\r\n';
+  }
+
   async walletGetBlockchainHeight(handle: number): Promise<string> {
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock get blockchain height failed');
@@ -594,6 +1165,16 @@ class MockNativeBindings implements NativeBindings {
     return JSON.stringify({ height: 12345 });
   }
 
+  async walletMineBlocks(handle: number, count: number): Promise<number> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock mine blocks failed');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    return 12345 + count;
+  }
+
   async walletGetTransactionInputs(handle: number, transactionId: string): Promise<string> {
     if (this.shouldSimulateFailure()) {
       throw new Error('Mock get transaction inputs failed');
@@ -634,6 +1215,394 @@ class MockNativeBindings implements NativeBindings {
     return JSON.stringify({ height: blockHeight, hash: 'mock_hash' });
   }
 
+  // Key management and message signing
+  privateKeyGenerate(): number {
+    return this.storeObject('private_key', this.randomHex(32));
+  }
+
+  privateKeyFromHex(hex: string): number {
+    if (!/^[0-9a-f]{64}$/i.test(hex)) {
+      throw new Error('Invalid private key');
+    }
+    return this.storeObject('private_key', hex.toLowerCase());
+  }
+
+  privateKeyDestroy(handle: number): void {
+    this.takeObject('private_key', handle);
+  }
+
+  publicKeyFromPrivateKey(privateKeyHandle: number): number {
+    const privateKey = this.getObject<string>('private_key', privateKeyHandle);
+    return this.storeObject('public_key', this.mockPublicKey(privateKey));
+  }
+
+  publicKeyFromHex(hex: string): number {
+    if (!/^[0-9a-f]{64}$/i.test(hex)) {
+      throw new Error('Invalid public key');
+    }
+    return this.storeObject('public_key', hex.toLowerCase());
+  }
+
+  publicKeyToHex(handle: number): string {
+    return this.getObject<string>('public_key', handle);
+  }
+
+  publicKeyDestroy(handle: number): void {
+    this.takeObject('public_key', handle);
+  }
+
+  signMessage(privateKeyHandle: number, message: number[]): string {
+    const privateKey = this.getObject<string>('private_key', privateKeyHandle);
+    return this.mockPublicKey(privateKey) + this.mockDigest(message);
+  }
+
+  verifyMessage(publicKeyHandle: number, message: number[], signature: string): boolean {
+    if (!/^[0-9a-f]{128}$/i.test(signature)) {
+      throw new Error('Invalid signature: expected 128 hex characters');
+    }
+    const publicKey = this.getObject<string>('public_key', publicKeyHandle);
+    return signature.toLowerCase() === publicKey + this.mockDigest(message);
+  }
+
+  diffieHellman(privateKeyHandle: number, publicKeyHandle: number): string {
+    // XOR of both public keys, so both parties derive the same mock secret
+    const ownPublicKey = this.mockPublicKey(this.getObject<string>('private_key', privateKeyHandle));
+    const otherPublicKey = this.getObject<string>('public_key', publicKeyHandle);
+    return ownPublicKey
+      .match(/../g)!
+      .map((byte, i) => (parseInt(byte, 16) ^ parseInt(otherPublicKey.substr(i * 2, 2), 16)).toString(16).padStart(2, '0'))
+      .join('');
+  }
+
+  aggregatePublicKeys(publicKeyHandles: number[]): number {
+    if (publicKeyHandles.length < 2) {
+      throw new Error('Key aggregation needs at least two public keys');
+    }
+    // Order-independent like the native aggregation: XOR of the sorted keys
+    const keys = publicKeyHandles.map(handle => this.getObject<string>('public_key', handle)).sort();
+    const joint = keys.reduce((acc, key) =>
+      acc
+        .match(/../g)!
+        .map((byte, i) => (parseInt(byte, 16) ^ parseInt(key.substr(i * 2, 2), 16)).toString(16).padStart(2, '0'))
+        .join('')
+    );
+    return this.storeObject('public_key', joint);
+  }
+
+  commitmentOpen(value: string, blindingFactor: string): string {
+    if (!/^\d+$/.test(value)) {
+      throw new Error(`Invalid value: ${value}`);
+    }
+    if (!/^[0-9a-f]{64}$/i.test(blindingFactor)) {
+      throw new Error('Invalid blinding factor');
+    }
+    // Zero commits to the blinding factor's public key, as natively
+    const blindingKey = this.mockPublicKey(blindingFactor.toLowerCase());
+    return /^0+$/.test(value)
+      ? blindingKey
+      : blindingKey.slice(0, 32) + this.mockDigest(Array.from(Buffer.from(value))).slice(0, 32);
+  }
+
+  commitmentVerify(commitment: string, value: string, blindingFactor: string): boolean {
+    if (!/^[0-9a-f]{64}$/i.test(commitment)) {
+      throw new Error('Invalid commitment');
+    }
+    return commitment.toLowerCase() === this.commitmentOpen(value, blindingFactor);
+  }
+
+  verifyRangeProof(commitment: string, proof: string): boolean {
+    if (!/^[0-9a-f]{64}$/i.test(commitment)) {
+      throw new Error('Invalid commitment');
+    }
+    if (!/^([0-9a-f]{2})*$/i.test(proof)) {
+      throw new Error('Invalid range proof');
+    }
+    // Any non-empty proof other than all zeroes passes in the mock
+    return proof.length > 0 && !/^0+$/.test(proof);
+  }
+
+  // Covenants
+  createCovenant(spec: any): number {
+    if (!spec || typeof spec.type !== 'string') {
+      throw new Error("Covenant filter requires a string 'type' field");
+    }
+    return this.storeObject('covenant', spec);
+  }
+
+  covenantToHex(handle: number): string {
+    this.getObject('covenant', handle);
+    return '20';
+  }
+
+  covenantDestroy(handle: number): void {
+    this.takeObject('covenant', handle);
+  }
+
+  // TariScript
+  compileScript(source: string): number {
+    if (source.trim() === '') {
+      throw new Error('Script cannot be empty');
+    }
+    return this.storeObject('script', { source, threshold: undefined, keyCount: undefined });
+  }
+
+  executeScript(scriptHandle: number, stackInputs: string[], blockHeight?: number): any {
+    this.getObject('script', scriptHandle);
+    return { success: true, finalStack: ['Number(1)'], error: undefined };
+  }
+
+  getScriptInfo(scriptHandle: number): any {
+    const script = this.getObject<any>('script', scriptHandle);
+    return {
+      opcodeCount: script.source.trim().split(/\s+/).length,
+      serializedSize: script.source.length,
+      lockHeight: undefined,
+      hasSignatureCheck: /CheckSig|CheckMultiSig/.test(script.source),
+      multisigThreshold: script.threshold,
+      multisigKeyCount: script.keyCount,
+    };
+  }
+
+  scriptToHex(scriptHandle: number): string {
+    const script = this.getObject<any>('script', scriptHandle);
+    return Buffer.from(script.source).toString('hex');
+  }
+
+  scriptDestroy(scriptHandle: number): void {
+    this.takeObject('script', scriptHandle);
+  }
+
+  compileMultisigScript(threshold: number, publicKeys: string[], message?: string): number {
+    if (threshold <= 0 || threshold > publicKeys.length) {
+      throw new Error(`Invalid multisig threshold ${threshold} for ${publicKeys.length} keys`);
+    }
+    return this.storeObject('script', {
+      source: 'CheckMultiSig',
+      threshold,
+      keyCount: publicKeys.length,
+    });
+  }
+
+  // Time-locked and burn transactions
+  async walletSendTimeLockedTransaction(
+    handle: number,
+    recipientAddress: string,
+    amount: string,
+    lockHeight: number,
+    feePerGram?: string
+  ): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock time-locked transaction failed');
+    }
+    await this.simulateLatency();
+
+    const txId = await this.walletSendTransaction(handle, recipientAddress, amount);
+    const fee = '625';
+    return {
+      id: txId,
+      amount,
+      gross_amount: (BigInt(amount) + BigInt(fee)).toString(),
+      fee,
+      status: 0,
+      message: '',
+      timestamp: Date.now() / 1000,
+      is_inbound: false,
+      address: recipientAddress,
+      lock_height: lockHeight,
+      confirmations: 0,
+      maturity_height: lockHeight,
+      output_type: 'standard',
+    };
+  }
+
+  async walletImportUtxo(handle: number, utxo: any): Promise<string> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock UTXO import failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    if (!(BigInt(utxo.amount) > 0n)) {
+      throw new Error('Amount must be greater than zero');
+    }
+    if (!this.commitmentVerify(utxo.commitment, utxo.amount, utxo.spendingKey)) {
+      throw new Error(`Commitment ${utxo.commitment} does not open to ${utxo.amount} µT under the given spending key`);
+    }
+    if (!this.verifyRangeProof(utxo.commitment, utxo.rangeProof)) {
+      throw new Error(`Range proof does not verify for commitment ${utxo.commitment}`);
+    }
+
+    wallet.balance.available = (BigInt(wallet.balance.available) + BigInt(utxo.amount)).toString();
+    return `mock_import_${Date.now()}`;
+  }
+
+  async walletBurnFunds(
+    handle: number,
+    amount: string,
+    feePerGram?: string,
+    message?: string
+  ): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock burn failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    const amountNum = parseInt(amount, 10);
+    const availableNum = parseInt(wallet.balance.available, 10);
+    if (!(amountNum > 0)) {
+      throw new Error('Amount must be greater than zero');
+    }
+    if (amountNum > availableNum) {
+      throw new Error('Insufficient funds');
+    }
+
+    wallet.balance.available = (availableNum - amountNum).toString();
+    return {
+      txId: `mock_burn_${Date.now()}`,
+      commitment: this.randomHex(32),
+      rangeProof: this.randomHex(64),
+      blindingFactor: this.randomHex(32),
+    };
+  }
+
+  // Recovery
+  async walletStartRecovery(
+    handle: number,
+    options?: { restart?: boolean; birthdayHeight?: number },
+    callback?: (progress: any) => void
+  ): Promise<void> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock recovery failed to start');
+    }
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    const birthdayHeight = options?.birthdayHeight ?? 0;
+    if (birthdayHeight < 0 || birthdayHeight > MOCK_TIP_HEIGHT) {
+      throw new Error(`Birthday height ${birthdayHeight} must be between 0 and the chain tip ${MOCK_TIP_HEIGHT}`);
+    }
+    if (options?.restart) {
+      this.recoveryCheckpoints.delete(handle);
+    }
+    this.completeRecovery(handle, birthdayHeight, callback);
+  }
+
+  async walletResumeRecovery(handle: number, callback?: (progress: any) => void): Promise<void> {
+    await this.simulateLatency();
+
+    this.getWallet(handle); // Validate handle exists
+    if (!this.recoveryCheckpoints.has(handle)) {
+      throw new Error(`No recovery checkpoint to resume for wallet ${handle}`);
+    }
+    this.completeRecovery(handle, 0, callback);
+  }
+
+  walletGetRecoveryProgress(handle: number): any {
+    const progress = this.recoveries.get(handle);
+    if (!progress) {
+      throw new Error(`No recovery has been started for wallet ${handle}`);
+    }
+    return progress;
+  }
+
+  // Mock recoveries finish immediately, consuming any checkpoint
+  private completeRecovery(handle: number, birthdayHeight: number, callback?: (progress: any) => void): void {
+    const tipHeight = MOCK_TIP_HEIGHT;
+    const checkpoint = this.recoveryCheckpoints.get(handle);
+    this.recoveryCheckpoints.delete(handle);
+    const startHeight = checkpoint?.birthdayHeight ?? birthdayHeight;
+    this.recoveries.set(handle, {
+      birthdayHeight: startHeight,
+      currentHeight: tipHeight,
+      tipHeight,
+      utxosFound: checkpoint?.utxosFound ?? 0,
+      isRunning: false,
+      resumedFromHeight: checkpoint?.scannedHeight,
+    });
+    callback?.({
+      blocksScanned: tipHeight - startHeight,
+      totalBlocks: tipHeight - startHeight,
+      utxosFound: checkpoint?.utxosFound ?? 0,
+      scanRate: 0,
+      currentHeight: tipHeight,
+    });
+  }
+
+  // Chain sync
+  async walletSyncToTip(handle: number): Promise<any> {
+    if (this.shouldSimulateFailure()) {
+      throw new Error('Mock sync failed');
+    }
+    await this.simulateLatency();
+
+    const wallet = this.getWallet(handle);
+    const startHeight = this.syncedHeights.get(handle) ?? 0;
+    this.syncedHeights.set(handle, MOCK_TIP_HEIGHT);
+    wallet.eventCallback?.(JSON.stringify({
+      event_type: 'sync:progress',
+      wallet_handle: handle,
+      data: { current: MOCK_TIP_HEIGHT, total: MOCK_TIP_HEIGHT, percent: 100 },
+      timestamp: Date.now(),
+    }));
+    return {
+      startHeight,
+      endHeight: MOCK_TIP_HEIGHT,
+      durationMs: 0,
+      reorgsHandled: 0,
+      blocksRolledBack: 0,
+    };
+  }
+
+  // Mock syncs finish immediately, so there is never one to cancel
+  walletCancelSync(handle: number): boolean {
+    this.getWallet(handle); // Validate handle exists
+    return false;
+  }
+
+  walletGetSyncStatus(handle: number): string {
+    return this.syncedHeights.has(handle) ? 'synced' : 'idle';
+  }
+
+  // Runtime pool
+  getRuntimePoolStats(): any {
+    return {
+      workerThreads: this.runtimeWorkerThreads,
+      stackSizeBytes: this.runtimeStackSizeBytes,
+      activeThreads: 0,
+      queuedTasks: 0,
+      completedTasks: 0,
+    };
+  }
+
+  configureRuntimePool(workerThreads: number, stackSizeBytes?: number): void {
+    if (workerThreads < 1 || workerThreads > 64) {
+      throw new Error('Worker threads must be between 1 and 64');
+    }
+    if (
+      stackSizeBytes !== undefined &&
+      (stackSizeBytes < 512 * 1024 || stackSizeBytes > 256 * 1024 * 1024)
+    ) {
+      throw new Error('Worker stack size must be between 524288 and 268435456 bytes');
+    }
+    this.runtimeWorkerThreads = workerThreads;
+    this.runtimeStackSizeBytes = stackSizeBytes ?? this.runtimeStackSizeBytes;
+  }
+
+  setLogLevel(level: string): void {
+    if (!['off', 'error', 'warn', 'info', 'debug', 'trace'].includes(level.toLowerCase())) {
+      throw new Error(`Invalid log level: ${level}`);
+    }
+  }
+
+  setLogCallback(callback: (record: any) => void): void {
+    this.logCallback = callback;
+  }
+
+  clearLogCallback(): void {
+    this.logCallback = undefined;
+  }
+
   // Mock control methods (not part of NativeBindings interface)
   setFailureMode(shouldFail: boolean): void {
     this.shouldFail = shouldFail;
@@ -662,7 +1631,17 @@ class MockNativeBindings implements NativeBindings {
     }
     
     this.wallets.clear();
+    this.destroyedHandles.clear();
     this.nextHandle = 1;
+    this.objects.clear();
+    this.nextObjectHandle = 1;
+    this.runtimeWorkerThreads = 4;
+    this.runtimeStackSizeBytes = 3 * 1024 * 1024;
+    this.logCallback = undefined;
+    this.recoveries.clear();
+    this.recoveryCheckpoints.clear();
+    this.syncedHeights.clear();
+    this.maxCallbacks = 100;
     this.shouldFail = false;
     this.failureRate = 0;
     this.latency = 0;
@@ -688,6 +1667,45 @@ class MockNativeBindings implements NativeBindings {
     return wallet;
   }
 
+  private storeObject(kind: string, value: any): number {
+    const handle = this.nextObjectHandle++;
+    this.objects.set(handle, { kind, value });
+    return handle;
+  }
+
+  private getObject<T = any>(kind: string, handle: number): T {
+    const entry = this.objects.get(handle);
+    if (!entry || entry.kind !== kind) {
+      throw new Error(`Invalid ${kind.replace('_', ' ')} handle: ${handle}`);
+    }
+    return entry.value;
+  }
+
+  private takeObject(kind: string, handle: number): void {
+    this.getObject(kind, handle);
+    this.objects.delete(handle);
+  }
+
+  private randomHex(bytes: number): string {
+    let hex = '';
+    for (let i = 0; i < bytes; i++) {
+      hex += Math.floor(Math.random() * 256).toString(16).padStart(2, '0');
+    }
+    return hex;
+  }
+
+  private mockPublicKey(privateKey: string): string {
+    return privateKey.split('').reverse().join('');
+  }
+
+  private mockDigest(message: number[]): string {
+    const digest = new Array(32).fill(0);
+    message.forEach((byte, i) => {
+      digest[i % 32] = (digest[i % 32] * 31 + byte + 1) & 0xff;
+    });
+    return digest.map(byte => byte.toString(16).padStart(2, '0')).join('');
+  }
+
   private shouldSimulateFailure(): boolean {
     if (this.shouldFail) {
       return true;
@@ -718,11 +1736,11 @@ class MockNativeBindings implements NativeBindings {
   }
 
   private generateMockSeedWords(): string[] {
-    // Generate valid BIP39 test seed words (this is a valid 24-word BIP39 mnemonic for testing)
+    // Seed words of a real Tari cipher seed, which fail a BIP39 checksum check
     const words = [
-      'abandon', 'ability', 'able', 'about', 'above', 'absent', 'absorb', 'abstract',
-      'absurd', 'abuse', 'access', 'accident', 'account', 'accuse', 'achieve', 'acid',
-      'acoustic', 'acquire', 'across', 'act', 'action', 'actor', 'actress', 'actual'
+      'scare', 'nation', 'measure', 'use', 'copper', 'among', 'chuckle', 'inform',
+      'mom', 'sand', 'shaft', 'crowd', 'chicken', 'sheriff', 'cram', 'forward',
+      'tourist', 'thing', 'change', 'pioneer', 'artist', 'chaos', 'submit', 'soda'
     ];
     return words;
   }
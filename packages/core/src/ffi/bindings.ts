@@ -4,7 +4,20 @@
  */
 
 import { loadNativeModule } from './loader';
-import type { NativeBindings } from './native';
+import type {
+  NativeBaseNodeHealth,
+  NativeBindings,
+  NativeBuildInfo,
+  NativeImportUtxo,
+  NativePeerReputation,
+  NativeRebroadcastSummary,
+  NativeSendResult,
+  NativeSyncResult,
+  NativeTransactionDetails,
+  NativeTransactionPage,
+  NativeTxFilter,
+  NativeUtxoSummary,
+} from './native';
 import { executeFFICall, type CallOptions } from './call-manager';
 import { getRetryPolicyForOperation, policyToCallOptions } from './retry';
 import type {
@@ -12,6 +25,7 @@ import type {
   FFIWalletConfig,
   FFIBalance,
   FFITransactionInfo,
+  FFIUtxoInfo,
   FFISendTransactionOptions,
   FFIBaseNodePeer,
 } from './types';
@@ -20,6 +34,7 @@ import {
   unwrapWalletHandle,
   validateFFIWalletConfig,
   validateTransactionAmount,
+  validateTransactionMessage,
   validateTariAddress,
 } from './types';
 
@@ -88,6 +103,8 @@ export class FFIBindings {
       log_level: config.logLevel,
       passphrase: config.passphrase,
       seed_words: config.seedWords,
+      mnemonic_language: config.mnemonicLanguage,
+      view_key: config.viewKey,
       num_rolling_log_files: config.numRollingLogFiles,
       rolling_log_file_size: config.rollingLogFileSize,
     };
@@ -148,6 +165,9 @@ export class FFIBindings {
       pendingIncoming: balance.pending_incoming,
       pendingOutgoing: balance.pending_outgoing,
       timelocked: balance.timelocked,
+      stale: balance.stale,
+      lastUpdatedAt: balance.last_updated_at,
+      synced: balance.synced,
     };
   }
 
@@ -170,12 +190,15 @@ export class FFIBindings {
   ): Promise<string> {
     validateTransactionAmount(amount);
     validateTariAddress(recipientAddress);
+    validateTransactionMessage(options?.message);
 
     const native = this.getNativeModule();
     const nativeOptions = options ? {
       fee_per_gram: options.feePerGram,
       message: options.message,
       is_one_sided: options.isOneSided,
+      allow_high_fee: options.allowHighFee,
+      idempotency_key: options.idempotencyKey,
     } : undefined;
 
     return native.walletSendTransaction(
@@ -187,11 +210,214 @@ export class FFIBindings {
   }
 
   /**
-   * Get wallet seed words
+   * Send a transaction, returning the fee charged and its initial status
+   */
+  public async sendTransactionDetailed(
+    handle: WalletHandle,
+    recipientAddress: string,
+    amount: string,
+    options?: FFISendTransactionOptions
+  ): Promise<NativeSendResult> {
+    validateTransactionAmount(amount);
+    validateTariAddress(recipientAddress);
+    validateTransactionMessage(options?.message);
+
+    const native = this.getNativeModule();
+    const nativeOptions = options ? {
+      fee_per_gram: options.feePerGram,
+      message: options.message,
+      is_one_sided: options.isOneSided,
+      allow_high_fee: options.allowHighFee,
+      idempotency_key: options.idempotencyKey,
+    } : undefined;
+
+    return native.walletSendTransactionDetailed(
+      unwrapWalletHandle(handle),
+      recipientAddress,
+      amount,
+      nativeOptions
+    );
+  }
+
+  /**
+   * Import an existing UTXO after checking its commitment opens to the amount
+   */
+  public async importUtxo(handle: WalletHandle, utxo: NativeImportUtxo): Promise<string> {
+    validateTransactionAmount(utxo.amount);
+
+    const native = this.getNativeModule();
+    return native.walletImportUtxo(unwrapWalletHandle(handle), utxo);
+  }
+
+  /**
+   * Get the chain height the wallet was created at, if it is known
+   */
+  public async getBirthday(handle: WalletHandle): Promise<number | null> {
+    const native = this.getNativeModule();
+    return native.walletGetBirthday(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Get the best block height known to the connected base nodes
+   */
+  public async getChainTip(handle: WalletHandle): Promise<number> {
+    const native = this.getNativeModule();
+    return native.walletGetChainTip(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Rebroadcast every pending transaction, e.g. after a long time offline
+   */
+  public async rebroadcastPending(handle: WalletHandle): Promise<NativeRebroadcastSummary> {
+    const native = this.getNativeModule();
+    return native.walletRebroadcastPending(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Build a payment for an offline wallet to sign, serialized as JSON
+   */
+  public async buildUnsignedTransaction(
+    handle: WalletHandle,
+    recipientAddress: string,
+    amount: string,
+    feePerGram?: string
+  ): Promise<string> {
+    validateTransactionAmount(amount);
+    validateTariAddress(recipientAddress);
+
+    const native = this.getNativeModule();
+    return native.walletBuildUnsignedTransaction(
+      unwrapWalletHandle(handle),
+      recipientAddress,
+      amount,
+      feePerGram
+    );
+  }
+
+  /**
+   * Sign a serialized unsigned transaction; needs no network access
+   */
+  public async signTransaction(handle: WalletHandle, unsignedTransaction: string): Promise<string> {
+    const native = this.getNativeModule();
+    return native.walletSignTransaction(unwrapWalletHandle(handle), unsignedTransaction);
+  }
+
+  /**
+   * Broadcast a transaction signed offline, returning its id
+   */
+  public async broadcastSignedTransaction(handle: WalletHandle, signedTransaction: string): Promise<string> {
+    const native = this.getNativeModule();
+    return native.walletBroadcastSignedTransaction(unwrapWalletHandle(handle), signedTransaction);
+  }
+
+  /**
+   * Get wallet UTXOs: 'unspent' (default), 'spent', 'unconfirmed' or 'all'
+   */
+  public async getUtxos(handle: WalletHandle, statusFilter?: string): Promise<FFIUtxoInfo[]> {
+    const native = this.getNativeModule();
+    const utxos = await native.walletGetUtxos(unwrapWalletHandle(handle), statusFilter);
+    return utxos as FFIUtxoInfo[];
+  }
+
+  /**
+   * Get the number and total µT value of wallet UTXOs without fetching them
+   */
+  public async getUtxoSummary(handle: WalletHandle): Promise<NativeUtxoSummary> {
+    const native = this.getNativeModule();
+    return native.walletGetUtxoSummary(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Get the largest µT amount a single send can pay after fees, for "send max"
+   */
+  public async getMaxSpendable(handle: WalletHandle, feePerGram?: string): Promise<string> {
+    const native = this.getNativeModule();
+    return native.walletGetMaxSpendable(unwrapWalletHandle(handle), feePerGram);
+  }
+
+  /**
+   * Get unspent UTXOs worth no more than the fee to spend them at current fees
+   */
+  public async getDustUtxos(handle: WalletHandle): Promise<FFIUtxoInfo[]> {
+    const native = this.getNativeModule();
+    const utxos = await native.walletGetDustUtxos(unwrapWalletHandle(handle));
+    return utxos as FFIUtxoInfo[];
+  }
+
+  /**
+   * Get the total µT value of dust UTXOs
+   */
+  public async getTotalDustValue(handle: WalletHandle): Promise<string> {
+    const native = this.getNativeModule();
+    return native.walletGetTotalDustValue(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Sync the wallet to the chain tip, resolving once it is reached
+   */
+  public async syncToTip(handle: WalletHandle): Promise<NativeSyncResult> {
+    const native = this.getNativeModule();
+    return native.walletSyncToTip(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Cancel a running sync; false if none is running
+   */
+  public cancelSync(handle: WalletHandle): boolean {
+    const native = this.getNativeModule();
+    return native.walletCancelSync(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Get the wallet's sync status: 'idle', 'syncing', 'synced' or 'cancelled'
+   */
+  public getSyncStatus(handle: WalletHandle): string {
+    const native = this.getNativeModule();
+    return native.walletGetSyncStatus(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Get the reputation score of every base node the wallet has dialed, best first
    */
-  public async getSeedWords(handle: WalletHandle): Promise<string[]> {
+  public async getPeerReputations(handle: WalletHandle): Promise<NativePeerReputation[]> {
     const native = this.getNativeModule();
-    return native.walletGetSeedWords(unwrapWalletHandle(handle));
+    return native.walletGetPeerReputations(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Check whether a base node is banned, ignoring bans that have expired
+   */
+  public async isPeerBanned(handle: WalletHandle, publicKey: string): Promise<boolean> {
+    const native = this.getNativeModule();
+    return native.walletIsPeerBanned(unwrapWalletHandle(handle), publicKey);
+  }
+
+  /**
+   * Check every known base node concurrently, updating their reputations
+   */
+  public async checkBaseNodeHealth(handle: WalletHandle): Promise<NativeBaseNodeHealth[]> {
+    const native = this.getNativeModule();
+    return native.walletCheckBaseNodeHealth(unwrapWalletHandle(handle));
+  }
+
+  /**
+   * Get wallet seed words, verifying the wallet passphrase first
+   *
+   * Words are in `language` if given, otherwise in the wallet's mnemonic language.
+   */
+  public async getSeedWords(handle: WalletHandle, passphrase: string, language?: string): Promise<string[]> {
+    const native = this.getNativeModule();
+    return native.walletGetSeedWords(unwrapWalletHandle(handle), passphrase, language);
+  }
+
+  /**
+   * Check seed words decode as a Tari cipher seed, resolving to their language
+   *
+   * Tari seed words are not a BIP39 mnemonic and fail its checksum.
+   */
+  public async validateSeedWords(words: string[], language?: string): Promise<string> {
+    const native = this.getNativeModule();
+    return native.validateSeedWords(words, language);
   }
 
   /**
@@ -281,6 +507,16 @@ export class FFIBindings {
     return native.walletCleanupAll();
   }
 
+  // Build information
+
+  /**
+   * Get the versions and build settings of the native module
+   */
+  public getBuildInfo(): NativeBuildInfo {
+    const native = this.getNativeModule();
+    return native.getBuildInfo();
+  }
+
   // Address utilities
 
   /**
@@ -335,14 +571,15 @@ export class FFIBindings {
   // Event callback methods (Phase 8)
 
   /**
-   * Set event callback for a wallet
+   * Set event callback for a wallet, optionally only for some event types
    */
   public async walletSetEventCallback(
     walletHandle: WalletHandle, 
-    callback: (payload: string) => void
+    callback: (payload: string) => void,
+    eventTypes?: string[]
   ): Promise<void> {
     const native = this.getNativeModule();
-    return native.walletSetEventCallback(unwrapWalletHandle(walletHandle), callback);
+    return native.walletSetEventCallback(unwrapWalletHandle(walletHandle), callback, eventTypes);
   }
 
   /**
@@ -372,6 +609,14 @@ export class FFIBindings {
     return native.cleanupAllCallbacks();
   }
 
+  /**
+   * Limit the number of registered callbacks of each kind (default 100)
+   */
+  public setMaxCallbacks(max: number): void {
+    const native = this.getNativeModule();
+    native.setMaxCallbacks(max);
+  }
+
   /**
    * Preview UTXO selection for a transaction
    */
@@ -507,7 +752,10 @@ export class FFIBindings {
       available: nativeBalance.available,
       pendingIncoming: nativeBalance.pending_incoming,
       pendingOutgoing: nativeBalance.pending_outgoing,
-      timelocked: nativeBalance.timelocked
+      timelocked: nativeBalance.timelocked,
+      stale: nativeBalance.stale,
+      lastUpdatedAt: nativeBalance.last_updated_at,
+      synced: nativeBalance.synced
     };
   }
 
@@ -542,6 +790,7 @@ export class FFIBindings {
   ): Promise<string> {
     const native = this.getNativeModule();
     validateTariAddress(recipientAddress);
+    validateTransactionMessage(message);
     
     const options: FFISendTransactionOptions = {
       feePerGram,
@@ -698,6 +947,65 @@ export class FFIBindings {
     return native.walletGetTransactionConfirmations(unwrapWalletHandle(handle), transactionId);
   }
 
+  /**
+   * Get the kernel, input and output counts and mining state of a transaction
+   */
+  public async walletGetTransactionDetails(
+    handle: WalletHandle,
+    transactionId: string
+  ): Promise<NativeTransactionDetails> {
+    const native = this.getNativeModule();
+    return native.walletGetTransactionDetails(unwrapWalletHandle(handle), transactionId);
+  }
+
+  /**
+   * Label a transaction for local bookkeeping; an empty label clears it
+   */
+  public async walletSetTransactionLabel(
+    handle: WalletHandle,
+    transactionId: string,
+    label?: string
+  ): Promise<void> {
+    const native = this.getNativeModule();
+    return native.walletSetTransactionLabel(unwrapWalletHandle(handle), transactionId, label);
+  }
+
+  /**
+   * Get a transaction's local label, or null if it has none
+   */
+  public async walletGetTransactionLabel(
+    handle: WalletHandle,
+    transactionId: string
+  ): Promise<string | null> {
+    const native = this.getNativeModule();
+    return native.walletGetTransactionLabel(unwrapWalletHandle(handle), transactionId);
+  }
+
+  /**
+   * Search transactions of both directions, newest first, a page at a time
+   */
+  public async walletSearchTransactions(
+    handle: WalletHandle,
+    filter: NativeTxFilter,
+    offset?: number,
+    limit?: number
+  ): Promise<NativeTransactionPage> {
+    const native = this.getNativeModule();
+    return native.walletSearchTransactions(unwrapWalletHandle(handle), filter, offset, limit);
+  }
+
+  /**
+   * Export the transaction history as CSV or JSON for bookkeeping
+   */
+  public async walletExportTransactions(
+    handle: WalletHandle,
+    format: 'csv' | 'json',
+    filter?: NativeTxFilter
+  ): Promise<string> {
+    const native = this.getNativeModule();
+    return native.walletExportTransactions(unwrapWalletHandle(handle), format, filter);
+  }
+
   /**
    * Get blockchain height
    */
@@ -36,16 +36,18 @@ export interface CallbackStats {
  * 
  * @param walletHandle - The wallet handle to register callback for
  * @param callback - JavaScript function to call when events occur
+ * @param eventTypes - Only call back for these event types, e.g. ['tx:received']; all if omitted
  * @throws {Error} If callback registration fails
  */
 export async function setWalletEventCallback(
   walletHandle: WalletHandle,
-  callback: FFIEventCallback
+  callback: FFIEventCallback,
+  eventTypes?: string[]
 ): Promise<void> {
   const ffi = await getFFIBindings();
   
   try {
-    await ffi.walletSetEventCallback(walletHandle, callback);
+    await ffi.walletSetEventCallback(walletHandle, callback, eventTypes);
   } catch (error) {
     throw new Error(
       `Failed to register event callback for wallet ${walletHandle}: ${
@@ -14,8 +14,78 @@ export interface NativeWalletConfig {
   log_level?: number;
   passphrase?: string;
   seed_words?: string[];
+  mnemonic_language?: string;
+  view_key?: string;
   num_rolling_log_files?: number;
   rolling_log_file_size?: number;
+  balance_cache_ttl_ms?: number;
+  encrypt_database?: boolean;
+  max_broadcast_retries?: number;
+  broadcast_retry_delay_seconds?: number;
+  connection_timeout_ms?: number;
+  required_confirmations?: number;
+  max_fee_per_gram?: number;
+  operation_timeouts?: NativeOperationTimeouts;
+  /** Base nodes as `<public key>::<multiaddr>`, e.g. for private networks */
+  peer_seeds?: string[];
+  /** Backoff between attempts to dial a base node */
+  connection_retry?: NativeRetryPolicy;
+  /** Tune the wallet database for its storage medium */
+  sqlite_pragmas?: NativeSqlitePragmas;
+  /** Read-only database connections shared by queries, 1 to 64; defaults to 4 */
+  max_read_connections?: number;
+  /** Peers for a private Tari network, replacing the network's built-in ones */
+  network_overrides?: NativeNetworkOverrides;
+  /** Wallet state changes buffered for each internal subscriber, such as caches */
+  event_bus_capacity?: number;
+  /** Dial the configured base nodes when the wallet opens; defaults to true */
+  auto_connect_on_start?: boolean;
+  /** How peers are reached; 'tor' routes every connection through a SOCKS5 proxy */
+  transport?: 'tcp' | 'tor';
+  /** Proxy for the tor transport; defaults to the local Tor daemon at 127.0.0.1:9050 */
+  tor_socks_proxy?: NativeTorSocksProxy;
+  /** `<ip>:<port>` to accept peer connections on; defaults to loopback, 127.0.0.1:18188 */
+  listener_address?: string;
+}
+
+export interface NativeTorSocksProxy {
+  /** `<host>:<port>` */
+  address?: string;
+  /** Set with password if the proxy needs authentication */
+  username?: string;
+  password?: string;
+}
+
+export interface NativeNetworkOverrides {
+  /** Base nodes as `<public key>::<multiaddr>` */
+  base_node_addresses?: string[];
+}
+
+export interface NativeSqlitePragmas {
+  journal_mode?: 'WAL' | 'DELETE' | 'MEMORY';
+  synchronous?: 'OFF' | 'NORMAL' | 'FULL' | 'EXTRA';
+  /** Pages if positive, KiB if negative */
+  cache_size?: number;
+  temp_store?: 'DEFAULT' | 'FILE' | 'MEMORY';
+  /** Bytes of the database to memory map; 0 disables it */
+  mmap_size?: number;
+}
+
+export interface NativeRetryPolicy {
+  /** Including the first attempt; 1 disables retrying */
+  max_attempts?: number;
+  base_delay_ms?: number;
+  max_delay_ms?: number;
+  multiplier?: number;
+  /** Randomise each delay to between half and all of it */
+  jitter?: boolean;
+}
+
+export interface NativeOperationTimeouts {
+  create_ms?: number;
+  balance_ms?: number;
+  send_ms?: number;
+  sync_ms?: number;
 }
 
 export interface NativeBalance {
@@ -23,17 +93,58 @@ export interface NativeBalance {
   pending_incoming: string;
   pending_outgoing: string;
   timelocked: string;
+  /** Last known balance, served because the base node could not be reached */
+  stale: boolean;
+  /** Unix timestamp of the sync the balance reflects */
+  last_updated_at?: number;
+  /** Whether the wallet had scanned up to the chain tip */
+  synced: boolean;
 }
 
 export interface NativeTransactionInfo {
   id: string;
+  /** Credited to the recipient, net of the fee */
   amount: string;
+  /** Debited from the sender: amount plus fee outbound, amount inbound */
+  gross_amount: string;
+  /** Paid by this wallet; '0' for inbound transactions */
   fee: string;
   status: number;
   message: string;
   timestamp: number;
   is_inbound: boolean;
   address: string;
+  lock_height?: number;
+  confirmations: number;
+  /** Height from which the output can be spent, for coinbase and time-locked outputs */
+  maturity_height?: number;
+  output_type: 'standard' | 'coinbase' | 'burn';
+  /** Local bookkeeping label; never broadcast */
+  local_label?: string;
+}
+
+/** Criteria for searching transactions; unset criteria match everything */
+export interface NativeTxFilter {
+  /** µT, inclusive */
+  minAmount?: string;
+  /** µT, inclusive */
+  maxAmount?: string;
+  /** Unix seconds, inclusive */
+  fromTimestamp?: number;
+  /** Unix seconds, inclusive */
+  toTimestamp?: number;
+  /** Counterparty address or emoji ID */
+  address?: string;
+  direction?: 'inbound' | 'outbound';
+}
+
+/** One page of transaction search results, newest first */
+export interface NativeTransactionPage {
+  transactions: NativeTransactionInfo[];
+  /** Matching transactions across all pages */
+  total: number;
+  offset: number;
+  hasMore: boolean;
 }
 
 export interface NativeContact {
@@ -51,10 +162,116 @@ export interface NativeUtxoInfo {
   status: number;
 }
 
+export interface NativeUtxoSummary {
+  count: number;
+  totalValue: string;
+  unspentValue: string;
+  spendableCount: number;
+}
+
 export interface NativeSendTransactionOptions {
   fee_per_gram?: string;
   message?: string;
   is_one_sided?: boolean;
+  allow_high_fee?: boolean;
+  idempotency_key?: string;
+  /** Handle from createCovenant, attached to the recipient's output */
+  covenant?: number;
+}
+
+export interface NativePayment {
+  destination: string;
+  amount: string;
+  message?: string;
+}
+
+export interface NativeImportUtxo {
+  /** µT */
+  amount: string;
+  /** Hex blinding factor the commitment was made with */
+  spendingKey: string;
+  /** Hex Pedersen commitment, as on chain */
+  commitment: string;
+  /** Hex Bulletproofs+ proof for the commitment */
+  rangeProof: string;
+  /** Hex public key of the sender's offset key */
+  senderOffsetPublicKey: string;
+  /** Hex commitment-and-public-key signature over the output's metadata */
+  metadataSignature: string;
+  /** Hex serialized TariScript */
+  script?: string;
+  /** Script input stack items such as `PublicKey(<hex>)`, bottom first */
+  inputData?: string[];
+  sourceAddress?: string;
+  message?: string;
+}
+
+export interface NativeBatchResult {
+  txId?: string;
+  error?: string;
+}
+
+export interface NativeDryRunResult {
+  feeEstimate: string;
+  inputCount: number;
+  outputCount: number;
+  wouldSucceed: boolean;
+}
+
+export interface NativeSendResult {
+  txId: string;
+  amount: string;
+  fee: string;
+  status: number;
+  timestamp: number;
+}
+
+export interface NativeBurnResult {
+  txId: string;
+  commitment: string;
+  rangeProof: string;
+  blindingFactor: string;
+}
+
+export interface NativeBroadcastStatus {
+  retryCount: number;
+  lastBroadcastAt: number;
+  seenByNetwork: boolean;
+}
+
+export interface NativeRebroadcastSummary {
+  attempted: number;
+  /** Accepted by a base node */
+  succeeded: number;
+  failed: number;
+  /** Submitted, but no base node confirmed receiving it */
+  unacknowledged: number;
+}
+
+export interface NativeTransactionDetails {
+  txId: string;
+  isInbound: boolean;
+  kernels: number;
+  /** Unknown for inbound transactions, whose inputs are the sender's */
+  inputs?: number;
+  outputs?: number;
+  maturityHeight?: number;
+  confirmations: number;
+  /** Unset until mined */
+  blockHeight?: number;
+  /** Unset until mined */
+  kernelSignature?: string;
+  /** Sent with the transaction, if it had one */
+  message?: string;
+  localLabel?: string;
+}
+
+export interface NativeFeeRecommendations {
+  slow: string;
+  normal: string;
+  fast: string;
+  /** Always 'fallback' defaults; base node mempool stats are not queried yet */
+  source: 'fallback';
 }
 
 export interface NativeBaseNodePeer {
@@ -62,10 +279,152 @@ export interface NativeBaseNodePeer {
   address: string;
 }
 
+export type NativeNetworkStatus = 'online' | 'connecting' | 'offline';
+
+export interface NativeConnectionStats {
+  totalNodes: number;
+  connectedNodes: number;
+  activeNode?: string;
+  averageLatencyMs?: number;
+  maxConnections: number;
+  /** Address the comms listener accepts peer connections on */
+  listenerAddress: string;
+}
+
+export interface NativePeerReputation {
+  publicKey: string;
+  /** Unset once the node has been removed from the pool */
+  address?: string;
+  score: number;
+  banned: boolean;
+  /** Unix time in seconds a temporary ban ends; unset for permanent bans */
+  bannedUntil?: number;
+  connected: boolean;
+}
+
+export interface NativeBaseNodeHealth {
+  publicKey: string;
+  address: string;
+  healthy: boolean;
+  /** Time taken to connect, when the check succeeded */
+  latencyMs?: number;
+  error?: string;
+}
+
+export interface NativeReceivedTransaction {
+  txId: string;
+  amount: string;
+  senderAddress?: string;
+  message?: string;
+  /** 'pending' when first detected, 'confirmed' once sufficiently confirmed */
+  status: 'pending' | 'confirmed';
+  /** Set while the received output is locked, e.g. for coinbase rewards */
+  maturityHeight?: number;
+  outputType: 'standard' | 'coinbase' | 'burn';
+}
+
+export interface NativeOutputManagerEvent {
+  kind: NativeOutputEventKind;
+  /** Transaction that changed the outputs, if one did */
+  txId?: string;
+  /** Amount spent in µT, for OutputSpent */
+  amount?: string;
+  /** Height synced to or rolled back to, for BalanceUpdated */
+  height?: number;
+}
+
 export interface NativeSeedWords {
   words: string[];
 }
 
+// Covenant filter specification
+export type NativeCovenantSpec =
+  | { type: 'identity' }
+  | { type: 'and' | 'or'; filters: NativeCovenantSpec[] }
+  | { type: 'output_hash_eq'; value: string }
+  | {
+      type: 'field_eq';
+      field: 'commitment' | 'sender_offset_public_key' | 'features_output_type' | 'features_maturity';
+      value: string | number;
+    };
+
+// TariScript execution
+export interface NativeScriptResult {
+  success: boolean;
+  finalStack: string[];
+  error?: string;
+}
+
+export interface NativeScriptInfo {
+  opcodeCount: number;
+  serializedSize: number;
+  lockHeight?: number;
+  hasSignatureCheck: boolean;
+  multisigThreshold?: number;
+  multisigKeyCount?: number;
+}
+
+// Wallet recovery
+export interface NativeRecoveryOptions {
+  restart?: boolean;
+  /** Height to start scanning from; a height above the wallet's first output misses funds */
+  birthdayHeight?: number;
+}
+
+export interface NativeRecoveryProgressUpdate {
+  blocksScanned: number;
+  totalBlocks: number;
+  utxosFound: number;
+  scanRate: number;
+  currentHeight: number;
+}
+
+export interface NativeRecoveryProgress {
+  birthdayHeight: number;
+  currentHeight: number;
+  tipHeight: number;
+  utxosFound: number;
+  isRunning: boolean;
+  resumedFromHeight?: number;
+  error?: string;
+}
+
+export interface NativeSyncResult {
+  startHeight: number;
+  endHeight: number;
+  durationMs: number;
+  reorgsHandled: number;
+  blocksRolledBack: number;
+}
+
+// Runtime pool statistics
+export interface NativeRuntimePoolStats {
+  workerThreads: number;
+  stackSizeBytes: number;
+  activeThreads: number;
+  queuedTasks: number;
+  completedTasks: number;
+}
+
+// Versions and build settings of the native module
+export interface NativeBuildInfo {
+  sdkVersion: string;
+  tariCoreVersion: string;
+  network: string;
+  /** Short commit hash, or 'unknown' when built outside a git checkout */
+  gitCommit: string;
+  buildProfile: string;
+}
+
+// Structured log record routed to a log callback
+export interface NativeLogRecord {
+  level: 'error' | 'warn' | 'info' | 'debug' | 'trace';
+  target: string;
+  message: string;
+  timestamp: number;
+  fields: Record<string, string>;
+}
+
 // Error information
 export interface NativeErrorInfo {
   code: number;
@@ -92,6 +451,13 @@ export enum NativeTransactionStatus {
   Coinbase = 7,
 }
 
+export enum NativeOutputEventKind {
+  OutputReceived = 0,
+  OutputConfirmed = 1,
+  OutputSpent = 2,
+  BalanceUpdated = 3,
+}
+
 export enum NativeLogLevel {
   Off = 0,
   Error = 1,
@@ -112,27 +478,134 @@ export interface NativeBindings {
 
   // Wallet operations
   walletGetBalance(handle: WalletHandle): Promise<NativeBalance>;
+  walletRefreshBalance(handle: WalletHandle): Promise<NativeBalance>;
   walletGetAddress(handle: WalletHandle): Promise<string>;
+  walletGetNewAddress(handle: WalletHandle): Promise<string>;
+  walletIsOwnAddress(handle: WalletHandle, address: string): Promise<boolean>;
   walletSendTransaction(
+    handle: WalletHandle,
+    recipientAddress: string,
+    amount: string,
+    options?: NativeSendTransactionOptions,
+    dryRun?: false
+  ): Promise<string>;
+  walletSendTransaction(
+    handle: WalletHandle,
+    recipientAddress: string,
+    amount: string,
+    options: NativeSendTransactionOptions | undefined,
+    dryRun: true
+  ): Promise<NativeDryRunResult>;
+  walletSendTransactionDetailed(
     handle: WalletHandle,
     recipientAddress: string,
     amount: string,
     options?: NativeSendTransactionOptions
+  ): Promise<NativeSendResult>;
+  /** Offline signing; both transaction documents are serialized JSON strings */
+  walletBuildUnsignedTransaction(
+    handle: WalletHandle,
+    recipientAddress: string,
+    amount: string,
+    feePerGram?: string
   ): Promise<string>;
-  walletGetSeedWords(handle: WalletHandle): Promise<string[]>;
+  walletSignTransaction(handle: WalletHandle, unsignedTransaction: string): Promise<string>;
+  walletBroadcastSignedTransaction(handle: WalletHandle, signedTransaction: string): Promise<string>;
+  walletSendBatch(
+    handle: WalletHandle,
+    payments: NativePayment[],
+    feePerGram?: string
+  ): Promise<NativeBatchResult[]>;
+  walletSendTimeLockedTransaction(
+    handle: WalletHandle,
+    recipientAddress: string,
+    amount: string,
+    lockHeight: number,
+    feePerGram?: string
+  ): Promise<NativeTransactionInfo>;
+  walletImportUtxo(handle: WalletHandle, utxo: NativeImportUtxo): Promise<string>;
+  walletBurnFunds(
+    handle: WalletHandle,
+    amount: string,
+    feePerGram?: string,
+    message?: string
+  ): Promise<NativeBurnResult>;
+  walletGetBroadcastStatus(handle: WalletHandle, transactionId: string): Promise<NativeBroadcastStatus>;
+  walletRebroadcastPending(handle: WalletHandle): Promise<NativeRebroadcastSummary>;
+  walletGetFeeRecommendations(handle: WalletHandle): Promise<NativeFeeRecommendations>;
+  /** `statusFilter` is 'unspent' (default), 'spent', 'unconfirmed' or 'all' */
+  walletGetUtxos(handle: WalletHandle, statusFilter?: string): Promise<NativeUtxoInfo[]>;
+  walletGetUtxoSummary(handle: WalletHandle): Promise<NativeUtxoSummary>;
+  walletGetMaxSpendable(handle: WalletHandle, feePerGram?: string): Promise<string>;
+  walletGetDustUtxos(handle: WalletHandle): Promise<NativeUtxoInfo[]>;
+  walletGetTotalDustValue(handle: WalletHandle): Promise<string>;
+  /** Rejects unless `passphrase` matches the wallet's; use '' for wallets without one */
+  walletGetSeedWords(handle: WalletHandle, passphrase: string, language?: string): Promise<string[]>;
+  /** Resolves to the language of seed words that decode as a Tari cipher seed; rejects otherwise */
+  validateSeedWords(words: string[], language?: string): Promise<string>;
   walletSetBaseNode(handle: WalletHandle, baseNode: NativeBaseNodePeer): Promise<void>;
+  /** Connect to a base node given as `<public key>::<multiaddr>`; false if already connected */
+  walletConnectToBaseNode(handle: WalletHandle, address: string): Promise<boolean>;
+  walletDisconnectFromBaseNode(handle: WalletHandle, publicKey: string): Promise<void>;
+  walletGetConnectionStats(handle: WalletHandle): Promise<NativeConnectionStats>;
+  /** Chain height the wallet was created at; null for restored and watch-only wallets */
+  walletGetBirthday(handle: WalletHandle): Promise<number | null>;
+  /** Best block height known to the connected base nodes; rejects when none is connected */
+  walletGetChainTip(handle: WalletHandle): Promise<number>;
+  walletBanBaseNode(handle: WalletHandle, publicKey: string, durationSeconds?: number): Promise<void>;
+  walletIsPeerBanned(handle: WalletHandle, publicKey: string): Promise<boolean>;
+  walletGetPeerReputations(handle: WalletHandle): Promise<NativePeerReputation[]>;
+  /** Dials every known base node concurrently, within the connection timeout overall */
+  walletCheckBaseNodeHealth(handle: WalletHandle): Promise<NativeBaseNodeHealth[]>;
+  walletGetNetworkStatus(handle: WalletHandle): Promise<NativeNetworkStatus>;
+  walletOnConnectivityChanged(
+    handle: WalletHandle,
+    callback: (status: NativeNetworkStatus) => void
+  ): Promise<void>;
+
+  // Database backup and restore
+  walletBackupDatabase(handle: WalletHandle, destinationPath: string): Promise<void>;
+  walletRestoreDatabase(storagePath: string, sourcePath: string, force?: boolean): Promise<void>;
+  walletExists(storagePath: string): boolean;
+
+  // Wallet recovery
+  walletStartRecovery(
+    handle: WalletHandle,
+    options?: NativeRecoveryOptions,
+    callback?: (progress: NativeRecoveryProgressUpdate) => void
+  ): Promise<void>;
+  walletResumeRecovery(
+    handle: WalletHandle,
+    callback?: (progress: NativeRecoveryProgressUpdate) => void
+  ): Promise<void>;
+  walletGetRecoveryProgress(handle: WalletHandle): NativeRecoveryProgress;
+
+  // Chain sync
+  /** Resolves once the wallet reaches the chain tip; progress arrives as `sync:progress` events */
+  walletSyncToTip(handle: WalletHandle): Promise<NativeSyncResult>;
+  /** Cancel a running sync; false if none is running */
+  walletCancelSync(handle: WalletHandle): boolean;
+  /** 'idle', 'syncing', 'synced' or 'cancelled' */
+  walletGetSyncStatus(handle: WalletHandle): string;
 
   // Utility functions
   walletGetActiveHandleCount(): Promise<number>;
   walletValidateHandle(handle: WalletHandle): Promise<boolean>;
   walletCleanupAll(): Promise<number>;
 
+  // Build information
+  getBuildInfo(): NativeBuildInfo;
+
   // Address utilities
   validateAddress(address: string, network: string): Promise<boolean>;
   emojiIdToAddress(emojiId: string, network: string): Promise<string>;
   addressToEmojiId(address: string): Promise<string>;
   emojiIdToPublicKey(emojiId: string): Promise<string>;
 
+  // Amount conversion
+  microTariToTari(amount: string): string;
+  tariToMicroTari(amount: string): string;
+
   // Transaction utilities
   walletPreviewUtxoSelection(
     handle: WalletHandle,
@@ -183,17 +656,82 @@ export interface NativeBindings {
   walletGetPendingOutboundTransaction(handle: WalletHandle, transactionId: string): Promise<string | null>;
   walletCancelPendingTransaction(handle: WalletHandle, transactionId: string): Promise<boolean>;
   walletGetTransactionConfirmations(handle: WalletHandle, transactionId: string): Promise<string>;
+  walletGetTransactionDetails(handle: WalletHandle, transactionId: string): Promise<NativeTransactionDetails>;
+  walletSetTransactionLabel(handle: WalletHandle, transactionId: string, label?: string): Promise<void>;
+  walletGetTransactionLabel(handle: WalletHandle, transactionId: string): Promise<string | null>;
+  walletSearchTransactions(
+    handle: WalletHandle,
+    filter: NativeTxFilter,
+    offset?: number,
+    limit?: number
+  ): Promise<NativeTransactionPage>;
+  walletExportTransactions(handle: WalletHandle, format: 'csv' | 'json', filter?: NativeTxFilter): Promise<string>;
   walletGetBlockchainHeight(handle: WalletHandle): Promise<string>;
+  /** Only present in native builds with the `localnet` feature; rejects on other networks */
+  walletMineBlocks?(handle: WalletHandle, count: number): Promise<number>;
   walletGetTransactionInputs(handle: WalletHandle, transactionId: string): Promise<string>;
   walletGetTransactionOutputs(handle: WalletHandle, transactionId: string): Promise<string>;
   walletGetTransactionKernels(handle: WalletHandle, transactionId: string): Promise<string>;
   walletGetBlockInfo(handle: WalletHandle, blockHeight: number): Promise<string>;
 
+  // Key management and message signing
+  privateKeyGenerate(): number;
+  privateKeyFromHex(hex: string): number;
+  privateKeyDestroy(handle: number): void;
+  publicKeyFromPrivateKey(privateKeyHandle: number): number;
+  publicKeyFromHex(hex: string): number;
+  publicKeyToHex(handle: number): string;
+  publicKeyDestroy(handle: number): void;
+  signMessage(privateKeyHandle: number, message: number[]): string;
+  verifyMessage(publicKeyHandle: number, message: number[], signature: string): boolean;
+  diffieHellman(privateKeyHandle: number, publicKeyHandle: number): string;
+  aggregatePublicKeys(publicKeyHandles: number[]): number;
+  commitmentOpen(value: string, blindingFactor: string): string;
+  commitmentVerify(commitment: string, value: string, blindingFactor: string): boolean;
+  verifyRangeProof(commitment: string, proof: string): boolean;
+
+  // Covenants
+  createCovenant(spec: NativeCovenantSpec): number;
+  covenantToHex(handle: number): string;
+  covenantDestroy(handle: number): void;
+
+  // TariScript
+  compileScript(source: string): number;
+  compileMultisigScript(threshold: number, publicKeys: string[], message?: string): number;
+  executeScript(scriptHandle: number, stackInputs: string[], blockHeight?: number): NativeScriptResult;
+  getScriptInfo(scriptHandle: number): NativeScriptInfo;
+  scriptToHex(scriptHandle: number): string;
+  scriptDestroy(scriptHandle: number): void;
+
+  // Runtime pool
+  getRuntimePoolStats(): NativeRuntimePoolStats;
+  /** `stackSizeBytes` keeps the current worker stack size if omitted */
+  configureRuntimePool(workerThreads: number, stackSizeBytes?: number): void;
+
+  // Logging
+  setLogLevel(level: 'off' | 'error' | 'warn' | 'info' | 'debug' | 'trace'): void;
+  setLogCallback(callback: (record: NativeLogRecord) => void): void;
+  clearLogCallback(): void;
+
   // Event callbacks (Phase 8)
-  walletSetEventCallback(handle: WalletHandle, callback: (payload: string) => void): Promise<void>;
+  walletSetEventCallback(
+    handle: WalletHandle,
+    callback: (payload: string) => void,
+    eventTypes?: string[]
+  ): Promise<void>;
   walletRemoveEventCallback(handle: WalletHandle): Promise<void>;
+  walletOnTransactionReceived(
+    handle: WalletHandle,
+    callback: (transaction: NativeReceivedTransaction) => void
+  ): void;
+  walletSubscribeOutputManagerEvents(
+    handle: WalletHandle,
+    callback: (event: NativeOutputManagerEvent) => void
+  ): void;
+  walletUnsubscribeOutputManagerEvents(handle: WalletHandle): void;
   getCallbackStats(): Promise<{ registeredWallets: number; activeCallbacks: number }>;
   cleanupAllCallbacks(): Promise<void>;
+  setMaxCallbacks(max: number): void;
 }
 
 // Default export type for the native module
@@ -3,6 +3,8 @@
  * These types bridge the native FFI and high-level TypeScript APIs
  */
 
+import { LONE_SURROGATE_PATTERN } from '../types/transaction';
+
 // Re-export core types from the core package
 export { NetworkType, LogLevel } from '../types/index';
 
@@ -25,6 +27,10 @@ export interface FFIWalletConfig {
   logLevel?: number;
   passphrase?: string;
   seedWords?: string[];
+  /** Seed word language, e.g. 'english'; detected from seedWords if unset */
+  mnemonicLanguage?: string;
+  /** Hex public key of a wallet to watch; the wallet sees funds but cannot spend */
+  viewKey?: string;
   numRollingLogFiles?: number;
   rollingLogFileSize?: number;
 }
@@ -35,6 +41,12 @@ export interface FFIBalance {
   pendingIncoming: string;
   pendingOutgoing: string;
   timelocked: string;
+  /** Last known balance, served because the base node could not be reached */
+  stale?: boolean;
+  /** Unix timestamp of the sync the balance reflects */
+  lastUpdatedAt?: number;
+  /** Whether the wallet had scanned up to the chain tip */
+  synced?: boolean;
 }
 
 // FFI transaction information
@@ -68,9 +80,17 @@ export interface FFIUtxoInfo {
 
 // Transaction sending options
 export interface FFISendTransactionOptions {
+  /** µT per gram, up to 6 decimal places; the total fee always rounds up */
   feePerGram?: string;
   message?: string;
   isOneSided?: boolean;
+  /** Send even if feePerGram exceeds the wallet's safety cap */
+  allowHighFee?: boolean;
+  /**
+   * Resending with a key used in the last 24 hours returns the original transaction id;
+   * reusing it for another recipient or amount is rejected
+   */
+  idempotencyKey?: string;
 }
 
 // Base node peer information
@@ -195,6 +215,16 @@ export function validateTransactionAmount(amount: string): void {
   }
 }
 
+/**
+ * Reject a message with unpaired surrogates; the native module would
+ * receive each one as U+FFFD, which is itself a valid character
+ */
+export function validateTransactionMessage(message?: string): void {
+  if (message !== undefined && LONE_SURROGATE_PATTERN.test(message)) {
+    throw new Error('Transaction message is not valid UTF-8');
+  }
+}
+
 export function validateTariAddress(address: string, network?: string): void {
   if (!address || typeof address !== 'string') {
     throw new Error('Address is required and must be a string');
@@ -221,6 +251,8 @@ export function convertToFFIConfig(config: any): FFIWalletConfig {
     logLevel: config.logLevel,
     passphrase: config.passphrase,
     seedWords: config.seedWords,
+    mnemonicLanguage: config.mnemonicLanguage,
+    viewKey: config.viewKey,
     numRollingLogFiles: config.numRollingLogFiles,
     rollingLogFileSize: config.rollingLogFileSize,
   };
@@ -50,8 +50,10 @@ export interface FFIUtxoInfo {
 // FFI Transaction interface (matches native structure)
 export interface FFITransaction {
   id: string; // BigInt as string
-  amount: string; // BigInt as string
-  fee: string; // BigInt as string
+  amount: string; // BigInt as string, net of the fee
+  gross_amount?: string; // BigInt as string, amount plus fee for outbound
+  local_label?: string; // Local bookkeeping label, never broadcast
+  fee: string; // BigInt as string, '0' for inbound
   status: number; // Enum as number
   message: string;
   timestamp: number;
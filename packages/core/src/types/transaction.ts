@@ -117,6 +117,12 @@ export interface CoinbaseTransaction extends TransactionInfo {
   readonly isMature: boolean;
 }
 
+/** Largest transaction message the protocol carries, in UTF-8 bytes */
+export const MAX_MESSAGE_BYTES = 512;
+
+/** Unpaired UTF-16 surrogates, which have no UTF-8 encoding */
+export const LONE_SURROGATE_PATTERN = /[\uD800-\uDBFF](?![\uDC00-\uDFFF])|(?<![\uD800-\uDBFF])[\uDC00-\uDFFF]/;
+
 // Transaction cancellation reasons
 export const TransactionCancellationReason = {
   UserCancelled: 'user_cancelled',
@@ -679,13 +685,21 @@ export class TransactionUtils {
       });
     }
 
-    // Validate message length
-    if (params.message && params.message.length > 512) {
-      errors.push({
-        code: 'MESSAGE_TOO_LONG',
-        message: 'Transaction message exceeds maximum length of 512 characters',
-        field: 'message'
-      });
+    // Validate message encoding and length; the limit is in UTF-8 bytes
+    if (params.message) {
+      if (LONE_SURROGATE_PATTERN.test(params.message)) {
+        errors.push({
+          code: 'INVALID_MESSAGE_ENCODING',
+          message: 'Transaction message is not valid UTF-8',
+          field: 'message'
+        });
+      } else if (Buffer.byteLength(params.message, 'utf8') > MAX_MESSAGE_BYTES) {
+        errors.push({
+          code: 'MESSAGE_TOO_LONG',
+          message: `Transaction message exceeds maximum length of ${MAX_MESSAGE_BYTES} bytes`,
+          field: 'message'
+        });
+      }
     }
 
     return {
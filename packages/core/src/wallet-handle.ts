@@ -242,7 +242,11 @@ export class WalletHandleWrapper extends FFIResource {
   /**
    * Get wallet seed words with retry logic
    */
-  async getSeedWords(options?: Partial<CallOptions>): Promise<WalletOperationResult<string[]>> {
+  async getSeedWords(
+    passphrase: string,
+    language?: string,
+    options?: Partial<CallOptions>
+  ): Promise<WalletOperationResult<string[]>> {
     this.ensureNotDisposed();
     
     const callOptions = this.mergeCallOptions('wallet_get_seed_words', options);
@@ -250,7 +254,7 @@ export class WalletHandleWrapper extends FFIResource {
     
     const result = await this.callManager.execute(
       'wallet_get_seed_words',
-      (handle: WalletHandle) => this.bindings.getSeedWords(handle),
+      (handle: WalletHandle) => this.bindings.getSeedWords(handle, passphrase, language),
       [this.getHandle()],
       callOptions
     );
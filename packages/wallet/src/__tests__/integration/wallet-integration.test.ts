@@ -182,7 +182,7 @@ describeIfFFIAvailable('TariWallet Integration Tests', () => {
       const wallet = await TariWallet.create(config);
       global.testUtils.registerWalletForCleanup(wallet);
       
-      const recoveredSeedWords = await wallet.getSeedWords();
+      const recoveredSeedWords = await wallet.getSeedWords(passphrase);
       expect(recoveredSeedWords).toEqual(seedWords);
       
       await wallet.destroy();
@@ -301,6 +301,17 @@ describe('TariWallet Unit Tests', () => {
       expect(seedWords.every(word => typeof word === 'string')).toBe(true);
     });
 
+    test('should restore from the seed words it exported', async () => {
+      const seedWords = await wallet.getSeedWords();
+
+      const restored = await TariWallet.restore(seedWords, WalletConfigFactory.testnet());
+      expect(mockFFI.getWalletCount()).toBe(2);
+      await restored.destroy();
+
+      const mistyped = [...seedWords.slice(0, -1), 'zoo'];
+      await expect(TariWallet.restore(mistyped, WalletConfigFactory.testnet())).rejects.toThrow('Invalid seed words');
+    });
+
     test('should handle seed words retrieval failure', async () => {
       mockFFI.setFailureMode(true);
       
@@ -245,6 +245,20 @@ export class ContactManager {
     }
   }
 
+  /**
+   * Get the alias of the contact with an address, ignoring case
+   */
+  public async resolveAlias(address: string): Promise<string | undefined> {
+    const exact = await this.getByAddress(address);
+    if (exact) {
+      return exact.alias;
+    }
+
+    const normalized = address.toLowerCase();
+    const contacts = await this.store.list();
+    return contacts.find(contact => contact.address.toLowerCase() === normalized)?.alias;
+  }
+
   /**
    * List all contacts with optional filtering and pagination
    */
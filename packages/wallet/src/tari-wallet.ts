@@ -249,11 +249,19 @@ export class TariWallet implements AsyncDisposable {
     this.messageSigner = new MessageSigner(handle, this.instanceId);
     this.signatureVerifier = new SignatureVerifier();
 
+    // Initialize contacts first so history entries can show contact aliases
+    this.contactManager = new ContactManager(handle, config.storagePath);
+
     // Initialize transaction API
-    this.transactionAPI = new TransactionAPI(handle, transactionConfig);
+    this.transactionAPI = new TransactionAPI(handle, {
+      ...transactionConfig,
+      historyService: {
+        aliasResolver: address => this.contactManager.resolveAlias(address),
+        ...transactionConfig?.historyService
+      }
+    });
 
     // Initialize advanced services
-    this.contactManager = new ContactManager(handle, config.storagePath);
     this.utxoService = new UtxoService(handle);
     this.coinService = new CoinService(handle, this.utxoService);
 
@@ -819,9 +827,9 @@ export class TariWallet implements AsyncDisposable {
   }
 
   /**
-   * Get wallet seed words with BIP39 validation and secure handling
+   * Get wallet seed words, checked to decode as a Tari cipher seed
    * 
-   * @param passphrase - Optional passphrase for encrypted wallets
+   * @param passphrase - Wallet passphrase; omit for wallets created without one
    * @returns Promise resolving to array of seed words
    */
   async getSeedWords(passphrase?: string): Promise<string[]> {
@@ -839,7 +847,7 @@ export class TariWallet implements AsyncDisposable {
   /**
    * Get wallet seed words in a secure buffer for advanced handling
    * 
-   * @param passphrase - Optional passphrase for encrypted wallets
+   * @param passphrase - Wallet passphrase; omit for wallets created without one
    * @returns Promise resolving to SecureBuffer containing seed words
    */
   async getSeedWordsSecure(passphrase?: string): Promise<SecureBuffer> {
@@ -849,20 +857,20 @@ export class TariWallet implements AsyncDisposable {
       const bindings = getFFIBindings();
       
       // Get raw seed words from FFI
-      const seedWords = await bindings.getSeedWords(this.handle);
+      const seedWords = await bindings.getSeedWords(this.handle, passphrase ?? '');
       
-      // Validate the seed words using our BIP39 system
-      const validationResult = await SeedManager.validateSeedPhrase(seedWords);
-      if (!validationResult.isValid) {
+      // Tari seed words are not BIP39, so they are decoded natively
+      try {
+        await bindings.validateSeedWords(seedWords);
+      } catch (error: unknown) {
         throw new WalletError(
           WalletErrorCode.CryptoError,
-          `Retrieved seed words failed validation: ${validationResult.errors.join(', ')}`,
-          { severity: ErrorSeverity.Error }
+          `Retrieved seed words failed validation: ${(error as Error).message}`,
+          { severity: ErrorSeverity.Error, cause: error as Error }
         );
       }
 
-      // Create secure buffer with normalized words
-      return SeedManager.createSecureBuffer(validationResult.normalizedWords!);
+      return SeedManager.createSecureBuffer(seedWords);
     } catch (error: unknown) {
       if (error instanceof WalletError) {
         throw error;
@@ -95,6 +95,8 @@ export class SecureInvoke {
       allowedCommands: [
         'secure_storage_store',
         'secure_storage_retrieve',
+        'secure_storage_store_batch',
+        'secure_storage_retrieve_batch',
         'secure_storage_remove',
         'secure_storage_exists',
         'secure_storage_list',
@@ -526,6 +528,8 @@ export const TAURI_SECURITY_POLICY = {
   ALLOWED_COMMANDS: [
     'secure_storage_store',
     'secure_storage_retrieve',
+    'secure_storage_store_batch',
+    'secure_storage_retrieve_batch',
     'secure_storage_remove',
     'secure_storage_exists',
     'secure_storage_list',
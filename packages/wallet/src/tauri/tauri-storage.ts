@@ -19,6 +19,25 @@ export interface TauriStorageMetadata {
   encrypted: boolean;
 }
 
+/**
+ * Per-key outcome of a batch operation, as returned by the backend
+ */
+interface TauriBatchEntryResult {
+  key: string;
+  value: number[] | null;
+  error: string | null;
+}
+
+/**
+ * Per-key outcome of a batch operation
+ */
+export interface BatchEntryResult {
+  key: string;
+  /** Retrieved value, for retrieveBatch */
+  value?: Buffer;
+  error?: string;
+}
+
 /**
  * Storage information interface
  */
@@ -46,6 +65,17 @@ export interface TauriStorageConfig {
   encryptionMode?: 'none' | 'base64' | 'aes';
 }
 
+/**
+ * Convert a backend batch result, whose value is a byte array
+ */
+function convertBatchEntry(entry: TauriBatchEntryResult): BatchEntryResult {
+  return {
+    key: entry.key,
+    value: entry.value ? Buffer.from(entry.value) : undefined,
+    error: entry.error ?? undefined,
+  };
+}
+
 /**
  * Tauri secure storage implementation using invoke commands
  */
@@ -131,6 +161,71 @@ export class TauriStorage implements SecureStorage {
     }
   }
 
+  /**
+   * Store several values in a single invoke
+   *
+   * Entries are stored independently; check each result's error.
+   */
+  async storeBatch(entries: Array<{ key: string; value: Buffer }>): Promise<StorageResult<BatchEntryResult[]>> {
+    for (const { key, value } of entries) {
+      this.validateKey(key);
+      this.validateValue(value);
+    }
+
+    const operationId = this.generateOperationId();
+
+    try {
+      if (this.config.enableLogging) {
+        console.log(`TauriStorage[${operationId}]: Storing ${entries.length} keys`);
+      }
+
+      const response = await this.invokeWithRetry<TauriBatchEntryResult[]>(
+        'secure_storage_store_batch',
+        {
+          entries: entries.map(({ key, value }) => ({ key, value: Array.from(value) })),
+        }
+      );
+
+      if (response.success) {
+        return StorageResults.ok((response.data ?? []).map(convertBatchEntry));
+      } else {
+        return StorageResults.internalError(response.error || 'Failed to store batch');
+      }
+
+    } catch (error) {
+      return this.handleError(error, operationId, 'storeBatch');
+    }
+  }
+
+  /**
+   * Retrieve several values in a single invoke
+   */
+  async retrieveBatch(keys: string[]): Promise<StorageResult<BatchEntryResult[]>> {
+    keys.forEach(key => this.validateKey(key));
+
+    const operationId = this.generateOperationId();
+
+    try {
+      if (this.config.enableLogging) {
+        console.log(`TauriStorage[${operationId}]: Retrieving ${keys.length} keys`);
+      }
+
+      const response = await this.invokeWithRetry<TauriBatchEntryResult[]>(
+        'secure_storage_retrieve_batch',
+        { keys }
+      );
+
+      if (response.success) {
+        return StorageResults.ok((response.data ?? []).map(convertBatchEntry));
+      } else {
+        return StorageResults.internalError(response.error || 'Failed to retrieve batch');
+      }
+
+    } catch (error) {
+      return this.handleError(error, operationId, 'retrieveBatch');
+    }
+  }
+
   /**
    * Remove data securely
    */
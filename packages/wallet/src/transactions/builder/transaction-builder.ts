@@ -11,6 +11,7 @@ import {
   WalletErrorCode,
   ErrorSeverity,
   withErrorContext,
+  MAX_MESSAGE_BYTES,
   type MicroTari,
   type TariAddressString,
   type TransactionId
@@ -560,8 +561,8 @@ export class BuilderUtils {
       errors.push('Valid amount is required');
     }
 
-    if (state.message && state.message.length > 512) {
-      errors.push('Message exceeds maximum length of 512 characters');
+    if (state.message && Buffer.byteLength(state.message, 'utf8') > MAX_MESSAGE_BYTES) {
+      errors.push(`Message exceeds maximum length of ${MAX_MESSAGE_BYTES} bytes`);
     }
 
     if (state.lockHeight && state.lockHeight < 0) {
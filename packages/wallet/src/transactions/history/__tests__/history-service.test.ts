@@ -216,6 +216,23 @@ describe('HistoryService', () => {
       expect(enrichedTx.tags).toContain('completed');
     });
 
+    it('should resolve contact aliases for addresses', async () => {
+      const aliasResolver = jest.fn(async (address: string) => {
+        if (address === 'address2') {
+          throw new Error('Contact store unavailable');
+        }
+        return address === 'address1' ? 'Alice' : undefined;
+      });
+      const aliasService = new HistoryService({ ...config, aliasResolver }, mockRepository);
+
+      const result = await aliasService.getTransactionHistory();
+
+      expect(result.data.map(tx => tx.aliasName)).toEqual(['Alice', undefined, undefined]);
+      expect(result.data[0].address).toBe('address1');
+      expect(aliasResolver).toHaveBeenCalledTimes(3);
+      await aliasService.dispose();
+    });
+
     it('should cache results when caching is enabled', async () => {
       // First call
       const result1 = await historyService.getTransactionHistory();
@@ -48,8 +48,15 @@ export interface HistoryServiceConfig {
   cacheTtlMs: number;
   /** Whether to include pending transactions in history */
   includePending: boolean;
+  /** Looks up contact aliases for transaction addresses */
+  aliasResolver?: AliasResolver;
 }
 
+/**
+ * Resolves an address to the alias of the matching contact, if any
+ */
+export type AliasResolver = (address: string) => Promise<string | undefined>;
+
 /**
  * History service events
  */
@@ -74,6 +81,8 @@ export interface HistoryEntry extends TransactionInfo {
   statusLabel: string;
   /** Categorization tags */
   tags: string[];
+  /** Alias of the contact with this transaction's address */
+  aliasName?: string;
 }
 
 /**
@@ -382,6 +391,7 @@ export class HistoryService extends TypedEventEmitter {
    */
   private async enrichTransactions(transactions: TransactionInfo[]): Promise<HistoryEntry[]> {
     const now = Date.now();
+    const aliases = await this.resolveAliases(transactions);
     
     return transactions.map(tx => {
       const age = now - Number(tx.timestamp);
@@ -396,11 +406,38 @@ export class HistoryService extends TypedEventEmitter {
         isSearchable,
         displayAmount,
         statusLabel,
-        tags
+        tags,
+        aliasName: aliases.get(tx.address)
       } as HistoryEntry;
     });
   }
 
+  /**
+   * Look up contact aliases once per distinct address
+   *
+   * Addresses the resolver fails on are left without an alias.
+   */
+  private async resolveAliases(transactions: TransactionInfo[]): Promise<Map<string, string>> {
+    const aliases = new Map<string, string>();
+    const resolver = this.config.aliasResolver;
+    if (!resolver) {
+      return aliases;
+    }
+
+    const addresses = new Set<string>(transactions.map(tx => tx.address));
+    await Promise.all(Array.from(addresses, async address => {
+      try {
+        const alias = await resolver(address);
+        if (alias) {
+          aliases.set(address, alias);
+        }
+      } catch {
+        // Fall back to the raw address
+      }
+    }));
+    return aliases;
+  }
+
   /**
    * Score search results by relevance to query
    */
@@ -11,6 +11,7 @@ export {
   type HistoryServiceConfig,
   type HistoryServiceEvents,
   type HistoryEntry,
+  type AliasResolver,
   type SearchResult,
   DEFAULT_HISTORY_SERVICE_CONFIG
 } from './history-service.js';
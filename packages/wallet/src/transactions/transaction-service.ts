@@ -18,6 +18,7 @@ import {
   microTariFromFFI,
   microTariToFFI,
   transactionIdToString,
+  MAX_MESSAGE_BYTES,
   type WalletHandle,
   type MicroTari,
   type TransactionId,
@@ -678,11 +679,11 @@ export class TransactionService extends TypedEventEmitter<TransactionServiceEven
       });
     }
 
-    // Validate message length
-    if (params.message && params.message.length > 512) {
+    // Validate message length, which the protocol limits in UTF-8 bytes
+    if (params.message && Buffer.byteLength(params.message, 'utf8') > MAX_MESSAGE_BYTES) {
       errors.push({
         code: 'MESSAGE_TOO_LONG',
-        message: 'Transaction message exceeds maximum length of 512 characters',
+        message: `Transaction message exceeds maximum length of ${MAX_MESSAGE_BYTES} bytes`,
         field: 'message'
       });
     }
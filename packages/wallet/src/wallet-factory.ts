@@ -175,9 +175,9 @@ export class WalletFactory {
   }
 
   /**
-   * Restore a wallet from seed words with BIP39 validation
+   * Restore a wallet from its Tari seed words
    * 
-   * @param seedWords - Array of 12, 15, 18, 21, or 24 seed words
+   * @param seedWords - The 24 seed words of the wallet's cipher seed
    * @param userConfig - Partial wallet configuration
    * @param options - Factory options
    * @returns Promise resolving to restored TariWallet instance
@@ -189,19 +189,19 @@ export class WalletFactory {
   ): Promise<TariWallet> {
     await this.ensureInitialized();
 
-    // Validate seed words using BIP39 standards
-    const validationResult = await SeedManager.validateSeedPhrase(seedWords);
-    if (!validationResult.isValid) {
+    const normalizedSeedWords = SeedManager.normalizeSeedPhrase(seedWords);
+
+    // Tari seed words are not BIP39, so they are decoded natively
+    try {
+      await getFFIBindings().validateSeedWords(normalizedSeedWords);
+    } catch (error: unknown) {
       throw new WalletError(
         WalletErrorCode.CryptoError,
-        `Invalid seed words: ${validationResult.errors.join(', ')}`,
-        { severity: ErrorSeverity.Error }
+        `Invalid seed words: ${(error as Error).message}`,
+        { severity: ErrorSeverity.Error, cause: error as Error }
       );
     }
 
-    // Use normalized seed words
-    const normalizedSeedWords = validationResult.normalizedWords!;
-
     const opts = { ...DEFAULT_FACTORY_OPTIONS, ...options };
     
     // Merge config with normalized seed words
@@ -44,10 +44,11 @@ describe('TariWallet', () => {
   });
 
   describe('Wallet Restoration', () => {
+    // Tari cipher seed words, which fail a BIP39 checksum check
     const validSeedWords = [
-      'abandon', 'ability', 'able', 'about', 'above', 'absent', 'absorb', 'abstract',
-      'absurd', 'abuse', 'access', 'accident', 'account', 'accuse', 'achieve', 'acid',
-      'acoustic', 'acquire', 'across', 'act', 'action', 'actor', 'actress', 'actual'
+      'scare', 'nation', 'measure', 'use', 'copper', 'among', 'chuckle', 'inform',
+      'mom', 'sand', 'shaft', 'crowd', 'chicken', 'sheriff', 'cram', 'forward',
+      'tourist', 'thing', 'change', 'pioneer', 'artist', 'chaos', 'submit', 'soda'
     ];
 
     it('should create wallet from valid seed words', async () => {
@@ -59,11 +60,11 @@ describe('TariWallet', () => {
 
     it('should throw error for invalid seed word count', async () => {
       const invalidSeeds = ['abandon', 'ability'];
-      await expect(TariWallet.restore(invalidSeeds, validConfig)).rejects.toThrow('Invalid word count');
+      await expect(TariWallet.restore(invalidSeeds, validConfig)).rejects.toThrow('exactly 24 words');
     });
 
     it('should throw error for empty seed words', async () => {
-      await expect(TariWallet.restore([], validConfig)).rejects.toThrow('Seed phrase cannot be empty');
+      await expect(TariWallet.restore([], validConfig)).rejects.toThrow('exactly 24 words');
     });
   });
 